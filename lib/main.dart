@@ -146,7 +146,6 @@ class MyApp extends StatelessWidget {
                                 await audioProcessorEngine.addFile(
                                   fileName,
                                   await file.readAll(),
-                                  format: ext,
                                 );
                                 audioProcessorEngine.engine().then((
                                   engine,
@@ -1,74 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.12.0.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../../frb_generated.dart';
-import '../types/chart.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-class StreamCommunicator {
-  const StreamCommunicator();
-
-  Future<void> addChart({required String key, required Chart chart}) => RustLib
-      .instance
-      .api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorAddChart(
-        that: this,
-        key: key,
-        chart: chart,
-      );
-
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<StreamCommunicator> newInstance() => RustLib.instance.api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorNew();
-
-  Future<void> removeAllCharts() => RustLib.instance.api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveAllCharts(
-        that: this,
-      );
-
-  Future<void> removeChart({
-    required String key,
-    required DataType dataType,
-  }) => RustLib.instance.api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveChart(
-        that: this,
-        key: key,
-        dataType: dataType,
-      );
-
-  Future<void> updateAllCharts({required List<ChartWIthKey> charts}) => RustLib
-      .instance
-      .api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateAllCharts(
-        that: this,
-        charts: charts,
-      );
-
-  Future<void> updateMaxIndex({required double maxIndex}) => RustLib
-      .instance
-      .api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateMaxIndex(
-        that: this,
-        maxIndex: maxIndex,
-      );
-
-  Future<void> updateYRange({
-    required double minY,
-    required double maxY,
-  }) => RustLib.instance.api
-      .crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateYRange(
-        that: this,
-        minY: minY,
-        maxY: maxY,
-      );
-
-  @override
-  int get hashCode => 0;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is StreamCommunicator && runtimeType == other.runtimeType;
-}
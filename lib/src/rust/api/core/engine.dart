@@ -4,26 +4,121 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../types/audio.dart';
 import '../types/chart.dart';
 import '../types/config.dart';
+import '../types/decode.dart';
+import '../types/encode.dart';
 import '../types/error.dart';
+import '../types/file.dart';
+import '../types/memory.dart';
+import '../types/samples.dart';
+import '../util/format_detector.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `update_all`, `update_max_index`
+// These functions are ignored because they are not marked as `pub`: `chart_params`, `compute_chart`, `core`, `damaged_regions`, `down_sample`, `down_sample_method`, `down_sample_view`, `encoder_for`, `ingest`, `publish_audio`, `push_axes`, `push_chart`, `push_overview`, `spawn_dedicated`, `y_extent`
 
 Future<AudioProcessorEngine> createDefaultEngine({required Config config}) =>
     RustLib.instance.api.crateApiCoreEngineCreateDefaultEngine(config: config);
 
+/// Like [`create_default_engine`], with a choice of where decoded audio and
+/// computed charts are kept.
+Future<AudioProcessorEngine> createEngine({
+  required Config config,
+  required AudioStorageBackend storage,
+  required ChartCacheBackend cache,
+}) => RustLib.instance.api.crateApiCoreEngineCreateEngine(
+  config: config,
+  storage: storage,
+  cache: cache,
+);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>
+/// Every call takes `&self` and locks the engine's state only for as long as
+/// it needs it, so a long decode does not hold up viewport changes or
+/// cancelling.
 abstract class AudioProcessorEngine implements RustOpaqueInterface {
   Future<void> add({
     required String filePath,
-    required String format,
     required List<int> audioData,
+    int? trackId,
+  });
+
+  /// Decodes every audio track of `source` under its own key,
+  /// `"{file_path}#track{id}"`, and returns the keys. If a track fails, the
+  /// ones already added are removed again and its error is returned.
+  Future<List<String>> addAllTracks({
+    required String filePath,
+    required AudioSource source,
+    required CancellationToken token,
+  });
+
+  /// Decodes on a thread of its own, streaming [`DecodeEvent`]s and charting
+  /// the audio decoded so far while the rest is still being read. The
+  /// engine stays free for other calls meanwhile. Cancelling `token` keeps
+  /// whatever was decoded up to that point.
+  Future<void> addCancellable({
+    required String filePath,
+    required List<int> audioData,
+    int? trackId,
+    required CancellationToken token,
   });
 
+  /// Stores every channel of a multichannel file as its own mono audio under
+  /// `"{file_path}#ch{n}"` and charts each of them.
+  Future<List<String>> addChannelCharts({required String filePath});
+
+  /// Adds a chart and shows it in the main viewport.
   Future<void> addChart({required String filePath, required DataType dataType});
 
+  /// Opens `file_path` on the Rust side and streams it into the decoder, so
+  /// the file is neither copied across the bridge nor held in memory whole.
+  Future<void> addFromPath({
+    required String filePath,
+    int? trackId,
+    required CancellationToken token,
+  });
+
+  /// Imports a headerless PCM dump with the [`RawPcmDecoder`] instead of the
+  /// engine's probing decoder.
+  Future<void> addRawPcm({
+    required String filePath,
+    required AudioSource source,
+    required RawPcmFormat format,
+    required CancellationToken token,
+  });
+
+  /// Adds a viewport with no charts in it, keeping the one already there
+  /// if `id` is taken.
+  Future<void> addViewport({required String id});
+
+  /// Container and codec recognised from the content, regardless of the
+  /// file name.
+  Future<DetectedFormat> detectFormat({required AudioSource source});
+
+  /// Encodes any stored audio, e.g. a single channel or track, as WAV or FLAC.
+  Future<Uint8List> export({
+    required String key,
+    required ExportOptions options,
+  });
+
+  Future<void> exportToPath({
+    required String key,
+    required String path,
+    required ExportOptions options,
+  });
+
+  /// Properties of a stored file for display, such as codec, duration and tags.
+  Future<AudioInfo> getAudioInfo({required String filePath});
+
+  /// What went wrong while decoding a file, if anything.
+  Future<DecodeReport> getDecodeReport({required String filePath});
+
+  /// Audio tracks of a container, for picking one before calling `add`.
+  Future<List<TrackInfo>> listTracks({required AudioSource source});
+
+  Future<MemoryUsage> memoryUsage();
+
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<AudioProcessorEngine> newInstance({
     required Config config,
@@ -39,22 +134,118 @@ abstract class AudioProcessorEngine implements RustOpaqueInterface {
     communicator: communicator,
   );
 
+  /// Removes a stored file, the channels split from it by
+  /// [`Self::add_channel_charts`] and every chart of either.
   Future<void> removeAudio({required String filePath});
 
+  /// Removes a chart from every viewport and the cache.
   Future<void> removeChart({
     required String filePath,
     required DataType dataType,
   });
 
+  /// Removes a viewport. The main one cannot be removed.
+  Future<void> removeViewport({required String id});
+
+  /// Sends the whole chart again on the next render of the viewport, for a
+  /// client that cannot apply an edit because it dropped the frame the edit
+  /// builds on.
+  Future<void> resendChart({
+    required String viewport,
+    required String filePath,
+    required DataType dataType,
+  });
+
   Future<void> reserveVisible({required String chartName});
 
+  /// Selects which channel or downmix of a multichannel file is charted. The
+  /// charts already cached for it are recomputed in the background. Fails
+  /// for a channel the stored audio does not have.
+  Future<void> setChannelMix({
+    required String filePath,
+    required ChannelMix mix,
+  });
+
+  /// Overrides the down-sampling of one chart; `None` goes back to the
+  /// method set for its data type.
+  Future<void> setChartDownSampleMethod({
+    required String filePath,
+    required DataType dataType,
+    DownSampleMethod? method,
+  });
+
+  /// Charts computed with the old config stay on screen until their
+  /// replacements, computed in the background, are pushed.
   Future<void> setConfig({required Config config});
 
+  /// Chooses how charts of `data_type` are reduced to the requested number
+  /// of points, such as LTTB for smooth feature curves.
+  Future<void> setDownSampleMethod({
+    required DataType dataType,
+    required DownSampleMethod method,
+  });
+
+  /// Sets the point budget of the main viewport.
   Future<void> setDownSamplePointsNum({required BigInt pointsNum});
 
+  /// Sets the x range of the main viewport.
   Future<void> setIndexRange({required double start, required double end});
 
+  /// Caps the memory used by decoded audio and by computed charts, evicting
+  /// the least recently used first. Evicted charts are recomputed from the
+  /// stored audio when shown again, and evicted audio is read back from
+  /// disk. Memory storage holds the only copy of the audio, so an audio
+  /// budget fails with it and neither budget is changed.
+  Future<void> setMemoryBudget({BigInt? audioBytes, BigInt? chartBytes});
+
+  /// Sets the number of points in the overview of each chart.
+  Future<void> setOverviewPointsNum({required BigInt pointsNum});
+
+  /// Chooses how audio added from now on keeps its samples in memory and
+  /// on disk. Audio that is already stored keeps its format.
+  Future<void> setSampleStorage({required SampleStorage storage});
+
   Future<void> setSelectedAudio({String? chartName});
+
+  /// When on, a waveform zoomed in past one sample per point is drawn as
+  /// the smooth band-limited curve through its samples instead of straight
+  /// lines between them.
+  Future<void> setSincInterpolation({required bool enabled});
+
+  /// Shows or hides an added chart in a viewport.
+  Future<void> setViewportChartVisible({
+    required String id,
+    required String filePath,
+    required DataType dataType,
+    required bool visible,
+  });
+
+  /// Coalesces with other changes to the same viewport, so only the latest
+  /// is rendered.
+  Future<void> setViewportPointsNum({
+    required String id,
+    required BigInt pointsNum,
+  });
+
+  /// Coalesces with other changes to the same viewport, so only the latest
+  /// is rendered.
+  Future<void> setViewportRange({
+    required String id,
+    required double start,
+    required double end,
+  });
+
+  /// Keys of all stored audio. With disk storage this includes audio decoded
+  /// before a restart, which can be shown again with [`Self::add_chart`].
+  Future<List<String>> storedAudioKeys();
+
+  /// The true peak of the charted waveform of `file_path` between frames
+  /// `start` and `end`, with the frame it falls on.
+  Future<TruePeak> truePeak({
+    required String filePath,
+    required double start,
+    required double end,
+  });
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box < dyn AudioDecoder + Send + Sync >>>
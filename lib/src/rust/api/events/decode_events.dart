@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../types/decode.dart';
+import '../types/events.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Stream<DecodeEvent> createDecodeEventStream() =>
+    RustLib.instance.api.crateApiEventsDecodeEventsCreateDecodeEventStream();
+
+Future<void> emitDecodeEvent({required DecodeEvent event}) =>
+    RustLib.instance.api.crateApiEventsDecodeEventsEmitDecodeEvent(
+      event: event,
+    );
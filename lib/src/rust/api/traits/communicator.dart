@@ -1,22 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.12.0.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../../frb_generated.dart';
-import '../types/chart.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-abstract class Communicator {
-  Future<void> addChart({required String key, required Chart chart});
-
-  Future<void> removeAllCharts();
-
-  Future<void> removeChart({required String key, required DataType dataType});
-
-  Future<void> updateAllCharts({required List<ChartWIthKey> charts});
-
-  Future<void> updateMaxIndex({required double maxIndex});
-
-  Future<void> updateYRange({required double minY, required double maxY});
-}
@@ -1,30 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.12.0.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../../frb_generated.dart';
-import '../types/audio.dart';
-import '../types/chart.dart';
-import '../types/config.dart';
-import '../types/error.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-class FftTransform {
-  const FftTransform();
-
-  Future<Chart> transform({required Audio data, required Config config}) =>
-      RustLib.instance.api.crateApiTransformFftFftTransformTransform(
-        that: this,
-        data: data,
-        config: config,
-      );
-
-  @override
-  int get hashCode => 0;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FftTransform && runtimeType == other.runtimeType;
-}
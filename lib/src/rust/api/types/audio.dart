@@ -4,44 +4,167 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
-import '../../lib.dart';
-import 'chart.dart';
+import 'decode.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'audio.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`
+class AudioInfo {
+  final int sampleRate;
+  final int channels;
+  final ChannelLayout channelLayout;
+  /// Short codec name as reported by Symphonia, e.g. `"pcm_s16le"` or `"mp3"`.
+  final String codec;
+  final String container;
+  final int? bitsPerSample;
+  /// Frames per channel actually decoded.
+  final BigInt totalFrames;
+  /// Duration in seconds derived from `total_frames`.
+  final double duration;
+  /// Priming frames the encoder put in front of the audio.
+  final int? encoderDelay;
+  /// Padding frames the encoder appended to the audio.
+  final int? encoderPadding;
+  final List<AudioTag> tags;
+  /// Damage found while decoding; clean for intact input.
+  final DecodeReport decodeReport;
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>
-abstract class Audio implements RustOpaqueInterface {
-  Future<Chart> audioToChart();
+  const AudioInfo({
+    required this.sampleRate,
+    required this.channels,
+    required this.channelLayout,
+    required this.codec,
+    required this.container,
+    this.bitsPerSample,
+    required this.totalFrames,
+    required this.duration,
+    this.encoderDelay,
+    this.encoderPadding,
+    required this.tags,
+    required this.decodeReport,
+  });
 
-  AudioData get data;
+  @override
+  int get hashCode =>
+      sampleRate.hashCode ^
+      channels.hashCode ^
+      channelLayout.hashCode ^
+      codec.hashCode ^
+      container.hashCode ^
+      bitsPerSample.hashCode ^
+      totalFrames.hashCode ^
+      duration.hashCode ^
+      encoderDelay.hashCode ^
+      encoderPadding.hashCode ^
+      tags.hashCode ^
+      decodeReport.hashCode;
 
-  AudioInfo get info;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioInfo &&
+          runtimeType == other.runtimeType &&
+          sampleRate == other.sampleRate &&
+          channels == other.channels &&
+          channelLayout == other.channelLayout &&
+          codec == other.codec &&
+          container == other.container &&
+          bitsPerSample == other.bitsPerSample &&
+          totalFrames == other.totalFrames &&
+          duration == other.duration &&
+          encoderDelay == other.encoderDelay &&
+          encoderPadding == other.encoderPadding &&
+          tags == other.tags &&
+          decodeReport == other.decodeReport;
+}
 
-  set data(AudioData data);
+class AudioTag {
+  final String key;
+  final String value;
 
-  set info(AudioInfo info);
+  const AudioTag({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioTag &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
 }
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>
-abstract class AudioData implements RustOpaqueInterface {
-  ArcVecF32 get samples;
+@freezed
+sealed class ChannelLayout with _$ChannelLayout {
+  const ChannelLayout._();
 
-  set samples(ArcVecF32 samples);
+  const factory ChannelLayout.mono() = ChannelLayout_Mono;
+  const factory ChannelLayout.stereo() = ChannelLayout_Stereo;
+  /// Speaker positions in channel order, e.g. `["FL", "FR", "FC", "LFE"]`.
+  const factory ChannelLayout.multichannel(List<String> field0) =
+      ChannelLayout_Multichannel;
 }
 
-class AudioInfo {
+/// How a multichannel [`Audio`] is reduced to the single signal that gets charted.
+@freezed
+sealed class ChannelMix with _$ChannelMix {
+  const ChannelMix._();
+
+  const factory ChannelMix.mean() = ChannelMix_Mean;
+  const factory ChannelMix.left() = ChannelMix_Left;
+  const factory ChannelMix.right() = ChannelMix_Right;
+  /// `(L + R) / 2`
+  const factory ChannelMix.mid() = ChannelMix_Mid;
+  /// `(L - R) / 2`
+  const factory ChannelMix.side() = ChannelMix_Side;
+  /// A single channel by zero-based index.
+  const factory ChannelMix.channel(int field0) = ChannelMix_Channel;
+}
+
+/// An audio track as listed by the container, before it is decoded.
+class TrackInfo {
+  final int id;
+  final String codec;
+  /// ISO 639 language code, if the container declares one.
+  final String? language;
   final int sampleRate;
+  /// Zero when the container leaves it to the codec.
+  final int channels;
+  final ChannelLayout? channelLayout;
+  final BigInt? totalFrames;
 
-  const AudioInfo({required this.sampleRate});
+  const TrackInfo({
+    required this.id,
+    required this.codec,
+    this.language,
+    required this.sampleRate,
+    required this.channels,
+    this.channelLayout,
+    this.totalFrames,
+  });
 
   @override
-  int get hashCode => sampleRate.hashCode;
+  int get hashCode =>
+      id.hashCode ^
+      codec.hashCode ^
+      language.hashCode ^
+      sampleRate.hashCode ^
+      channels.hashCode ^
+      channelLayout.hashCode ^
+      totalFrames.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is AudioInfo &&
+      other is TrackInfo &&
           runtimeType == other.runtimeType &&
-          sampleRate == other.sampleRate;
+          id == other.id &&
+          codec == other.codec &&
+          language == other.language &&
+          sampleRate == other.sampleRate &&
+          channels == other.channels &&
+          channelLayout == other.channelLayout &&
+          totalFrames == other.totalFrames;
 }
@@ -0,0 +1,726 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'audio.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ChannelLayout {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelLayout);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelLayout()';
+}
+
+
+}
+
+/// @nodoc
+class $ChannelLayoutCopyWith<$Res>  {
+$ChannelLayoutCopyWith(ChannelLayout _, $Res Function(ChannelLayout) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ChannelLayout].
+extension ChannelLayoutPatterns on ChannelLayout {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChannelLayout_Mono value)?  mono,TResult Function( ChannelLayout_Stereo value)?  stereo,TResult Function( ChannelLayout_Multichannel value)?  multichannel,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ChannelLayout_Mono() when mono != null:
+return mono(_that);case ChannelLayout_Stereo() when stereo != null:
+return stereo(_that);case ChannelLayout_Multichannel() when multichannel != null:
+return multichannel(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChannelLayout_Mono value)  mono,required TResult Function( ChannelLayout_Stereo value)  stereo,required TResult Function( ChannelLayout_Multichannel value)  multichannel,}){
+final _that = this;
+switch (_that) {
+case ChannelLayout_Mono():
+return mono(_that);case ChannelLayout_Stereo():
+return stereo(_that);case ChannelLayout_Multichannel():
+return multichannel(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChannelLayout_Mono value)?  mono,TResult? Function( ChannelLayout_Stereo value)?  stereo,TResult? Function( ChannelLayout_Multichannel value)?  multichannel,}){
+final _that = this;
+switch (_that) {
+case ChannelLayout_Mono() when mono != null:
+return mono(_that);case ChannelLayout_Stereo() when stereo != null:
+return stereo(_that);case ChannelLayout_Multichannel() when multichannel != null:
+return multichannel(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  mono,TResult Function()?  stereo,TResult Function( List<String> field0)?  multichannel,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ChannelLayout_Mono() when mono != null:
+return mono();case ChannelLayout_Stereo() when stereo != null:
+return stereo();case ChannelLayout_Multichannel() when multichannel != null:
+return multichannel(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  mono,required TResult Function()  stereo,required TResult Function( List<String> field0)  multichannel,}) {final _that = this;
+switch (_that) {
+case ChannelLayout_Mono():
+return mono();case ChannelLayout_Stereo():
+return stereo();case ChannelLayout_Multichannel():
+return multichannel(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  mono,TResult? Function()?  stereo,TResult? Function( List<String> field0)?  multichannel,}) {final _that = this;
+switch (_that) {
+case ChannelLayout_Mono() when mono != null:
+return mono();case ChannelLayout_Stereo() when stereo != null:
+return stereo();case ChannelLayout_Multichannel() when multichannel != null:
+return multichannel(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ChannelLayout_Mono extends ChannelLayout {
+  const ChannelLayout_Mono(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelLayout_Mono);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelLayout.mono()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ChannelLayout_Stereo extends ChannelLayout {
+  const ChannelLayout_Stereo(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelLayout_Stereo);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelLayout.stereo()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// Speaker positions in channel order, e.g. `["FL", "FR", "FC", "LFE"]`.
+
+class ChannelLayout_Multichannel extends ChannelLayout {
+  const ChannelLayout_Multichannel(final  List<String> field0): _field0 = field0,super._();
+  
+
+ final  List<String> _field0;
+ List<String> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of ChannelLayout
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChannelLayout_MultichannelCopyWith<ChannelLayout_Multichannel> get copyWith => _$ChannelLayout_MultichannelCopyWithImpl<ChannelLayout_Multichannel>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelLayout_Multichannel&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'ChannelLayout.multichannel(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChannelLayout_MultichannelCopyWith<$Res> implements $ChannelLayoutCopyWith<$Res> {
+  factory $ChannelLayout_MultichannelCopyWith(ChannelLayout_Multichannel value, $Res Function(ChannelLayout_Multichannel) _then) = _$ChannelLayout_MultichannelCopyWithImpl;
+@useResult
+$Res call({
+ List<String> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChannelLayout_MultichannelCopyWithImpl<$Res>
+    implements $ChannelLayout_MultichannelCopyWith<$Res> {
+  _$ChannelLayout_MultichannelCopyWithImpl(this._self, this._then);
+
+  final ChannelLayout_Multichannel _self;
+  final $Res Function(ChannelLayout_Multichannel) _then;
+
+/// Create a copy of ChannelLayout
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChannelLayout_Multichannel(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ChannelMix {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix()';
+}
+
+
+}
+
+/// @nodoc
+class $ChannelMixCopyWith<$Res>  {
+$ChannelMixCopyWith(ChannelMix _, $Res Function(ChannelMix) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ChannelMix].
+extension ChannelMixPatterns on ChannelMix {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChannelMix_Mean value)?  mean,TResult Function( ChannelMix_Left value)?  left,TResult Function( ChannelMix_Right value)?  right,TResult Function( ChannelMix_Mid value)?  mid,TResult Function( ChannelMix_Side value)?  side,TResult Function( ChannelMix_Channel value)?  channel,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ChannelMix_Mean() when mean != null:
+return mean(_that);case ChannelMix_Left() when left != null:
+return left(_that);case ChannelMix_Right() when right != null:
+return right(_that);case ChannelMix_Mid() when mid != null:
+return mid(_that);case ChannelMix_Side() when side != null:
+return side(_that);case ChannelMix_Channel() when channel != null:
+return channel(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChannelMix_Mean value)  mean,required TResult Function( ChannelMix_Left value)  left,required TResult Function( ChannelMix_Right value)  right,required TResult Function( ChannelMix_Mid value)  mid,required TResult Function( ChannelMix_Side value)  side,required TResult Function( ChannelMix_Channel value)  channel,}){
+final _that = this;
+switch (_that) {
+case ChannelMix_Mean():
+return mean(_that);case ChannelMix_Left():
+return left(_that);case ChannelMix_Right():
+return right(_that);case ChannelMix_Mid():
+return mid(_that);case ChannelMix_Side():
+return side(_that);case ChannelMix_Channel():
+return channel(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChannelMix_Mean value)?  mean,TResult? Function( ChannelMix_Left value)?  left,TResult? Function( ChannelMix_Right value)?  right,TResult? Function( ChannelMix_Mid value)?  mid,TResult? Function( ChannelMix_Side value)?  side,TResult? Function( ChannelMix_Channel value)?  channel,}){
+final _that = this;
+switch (_that) {
+case ChannelMix_Mean() when mean != null:
+return mean(_that);case ChannelMix_Left() when left != null:
+return left(_that);case ChannelMix_Right() when right != null:
+return right(_that);case ChannelMix_Mid() when mid != null:
+return mid(_that);case ChannelMix_Side() when side != null:
+return side(_that);case ChannelMix_Channel() when channel != null:
+return channel(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  mean,TResult Function()?  left,TResult Function()?  right,TResult Function()?  mid,TResult Function()?  side,TResult Function( int field0)?  channel,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ChannelMix_Mean() when mean != null:
+return mean();case ChannelMix_Left() when left != null:
+return left();case ChannelMix_Right() when right != null:
+return right();case ChannelMix_Mid() when mid != null:
+return mid();case ChannelMix_Side() when side != null:
+return side();case ChannelMix_Channel() when channel != null:
+return channel(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  mean,required TResult Function()  left,required TResult Function()  right,required TResult Function()  mid,required TResult Function()  side,required TResult Function( int field0)  channel,}) {final _that = this;
+switch (_that) {
+case ChannelMix_Mean():
+return mean();case ChannelMix_Left():
+return left();case ChannelMix_Right():
+return right();case ChannelMix_Mid():
+return mid();case ChannelMix_Side():
+return side();case ChannelMix_Channel():
+return channel(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  mean,TResult? Function()?  left,TResult? Function()?  right,TResult? Function()?  mid,TResult? Function()?  side,TResult? Function( int field0)?  channel,}) {final _that = this;
+switch (_that) {
+case ChannelMix_Mean() when mean != null:
+return mean();case ChannelMix_Left() when left != null:
+return left();case ChannelMix_Right() when right != null:
+return right();case ChannelMix_Mid() when mid != null:
+return mid();case ChannelMix_Side() when side != null:
+return side();case ChannelMix_Channel() when channel != null:
+return channel(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ChannelMix_Mean extends ChannelMix {
+  const ChannelMix_Mean(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Mean);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix.mean()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ChannelMix_Left extends ChannelMix {
+  const ChannelMix_Left(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Left);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix.left()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ChannelMix_Right extends ChannelMix {
+  const ChannelMix_Right(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Right);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix.right()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// `(L + R) / 2`
+
+class ChannelMix_Mid extends ChannelMix {
+  const ChannelMix_Mid(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Mid);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix.mid()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// `(L - R) / 2`
+
+class ChannelMix_Side extends ChannelMix {
+  const ChannelMix_Side(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Side);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChannelMix.side()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// A single channel by zero-based index.
+
+class ChannelMix_Channel extends ChannelMix {
+  const ChannelMix_Channel(this.field0): super._();
+  
+
+ final  int field0;
+
+/// Create a copy of ChannelMix
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChannelMix_ChannelCopyWith<ChannelMix_Channel> get copyWith => _$ChannelMix_ChannelCopyWithImpl<ChannelMix_Channel>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChannelMix_Channel&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChannelMix.channel(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChannelMix_ChannelCopyWith<$Res> implements $ChannelMixCopyWith<$Res> {
+  factory $ChannelMix_ChannelCopyWith(ChannelMix_Channel value, $Res Function(ChannelMix_Channel) _then) = _$ChannelMix_ChannelCopyWithImpl;
+@useResult
+$Res call({
+ int field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChannelMix_ChannelCopyWithImpl<$Res>
+    implements $ChannelMix_ChannelCopyWith<$Res> {
+  _$ChannelMix_ChannelCopyWithImpl(this._self, this._then);
+
+  final ChannelMix_Channel _self;
+  final $Res Function(ChannelMix_Channel) _then;
+
+/// Create a copy of ChannelMix
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChannelMix_Channel(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -4,51 +4,100 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
-import '../../lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'chart.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>
-abstract class Chart implements RustOpaqueInterface {
-  DataType get dataType;
-
-  double get maxY;
-
-  double get minY;
-
-  ArcVecPoint get points;
-
-  ArcAtomicBool get visible;
-
-  set dataType(DataType dataType);
-
-  set maxY(double maxY);
-
-  set minY(double minY);
+/// A y axis of a viewport. Charts of one data type share an axis, so values
+/// in the same unit stay comparable while other types get their own scale.
+class ChartAxis {
+  final DataType dataType;
+  final String label;
+  final String unit;
+  final double minY;
+  final double maxY;
+  /// Keys of the charts drawn against this axis.
+  final List<String> keys;
+
+  const ChartAxis({
+    required this.dataType,
+    required this.label,
+    required this.unit,
+    required this.minY,
+    required this.maxY,
+    required this.keys,
+  });
 
-  set points(ArcVecPoint points);
+  @override
+  int get hashCode =>
+      dataType.hashCode ^
+      label.hashCode ^
+      unit.hashCode ^
+      minY.hashCode ^
+      maxY.hashCode ^
+      keys.hashCode;
 
-  set visible(ArcAtomicBool visible);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChartAxis &&
+          runtimeType == other.runtimeType &&
+          dataType == other.dataType &&
+          label == other.label &&
+          unit == other.unit &&
+          minY == other.minY &&
+          maxY == other.maxY &&
+          keys == other.keys;
+}
 
-  Future<Chart> getRange({required double startX, required double endX});
+/// Points as plain f32 buffers, which reach Dart as `Float32List`s rather
+/// than a list of point objects.
+@freezed
+sealed class ChartPayload with _$ChartPayload {
+  const ChartPayload._();
+
+  /// Point `i` is `(xs[i], ys[i])`.
+  const factory ChartPayload.points({
+    required Float32List xs,
+    required Float32List ys,
+  }) = ChartPayload_Points;
+  /// Point `i` is `(x0 + i * dx, ys[i])`; no x is sent at all.
+  const factory ChartPayload.uniform({
+    required double x0,
+    required double dx,
+    required Float32List ys,
+  }) = ChartPayload_Uniform;
 }
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>
-abstract class ChartWIthKey implements RustOpaqueInterface {
-  Chart get chart;
+/// A highlighted span of the x axis, such as audio damaged during decoding.
+class ChartRegion {
+  final double startX;
+  final double endX;
+  final String label;
 
-  String get key;
+  const ChartRegion({
+    required this.startX,
+    required this.endX,
+    required this.label,
+  });
 
-  set chart(Chart chart);
+  @override
+  int get hashCode => startX.hashCode ^ endX.hashCode ^ label.hashCode;
 
-  set key(String key);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChartRegion &&
+          runtimeType == other.runtimeType &&
+          startX == other.startX &&
+          endX == other.endX &&
+          label == other.label;
 }
 
 class CommunicatorChart {
   final String key;
   final DataType dataType;
-  final List<Point> chart;
+  final ChartPayload chart;
 
   const CommunicatorChart({
     required this.key,
@@ -71,20 +120,37 @@ class CommunicatorChart {
 
 enum DataType { audio, spectrum, energy, zeroCrossingRate }
 
-class Point {
+/// How a chart is reduced to the requested number of points.
+enum DownSampleMethod {
+  /// Lowest and highest point per bucket; keeps every peak of a waveform.
+  minMax,
+  /// Every n-th point.
+  equalStep,
+  /// Largest-Triangle-Three-Buckets; follows smooth curves closely.
+  lttb,
+  /// First, lowest, highest and last point per column of the x span.
+  m4,
+}
+
+/// The highest magnitude of a waveform reconstructed between its samples,
+/// and where it is.
+class TruePeak {
+  /// Position in the chart's x units, the frame index for a waveform. Falls
+  /// between two samples for an inter-sample peak.
   final double x;
-  final double y;
+  /// The reconstructed value at `x`, whose magnitude is the true peak.
+  final double value;
 
-  const Point({required this.x, required this.y});
+  const TruePeak({required this.x, required this.value});
 
   @override
-  int get hashCode => x.hashCode ^ y.hashCode;
+  int get hashCode => x.hashCode ^ value.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Point &&
+      other is TruePeak &&
           runtimeType == other.runtimeType &&
           x == other.x &&
-          y == other.y;
+          value == other.value;
 }
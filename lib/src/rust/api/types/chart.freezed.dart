@@ -0,0 +1,343 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'chart.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ChartPayload {
+
+ Float32List get ys;
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartPayloadCopyWith<ChartPayload> get copyWith => _$ChartPayloadCopyWithImpl<ChartPayload>(this as ChartPayload, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartPayload&&const DeepCollectionEquality().equals(other.ys, ys));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(ys));
+
+@override
+String toString() {
+  return 'ChartPayload(ys: $ys)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartPayloadCopyWith<$Res>  {
+  factory $ChartPayloadCopyWith(ChartPayload value, $Res Function(ChartPayload) _then) = _$ChartPayloadCopyWithImpl;
+@useResult
+$Res call({
+ Float32List ys
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartPayloadCopyWithImpl<$Res>
+    implements $ChartPayloadCopyWith<$Res> {
+  _$ChartPayloadCopyWithImpl(this._self, this._then);
+
+  final ChartPayload _self;
+  final $Res Function(ChartPayload) _then;
+
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? ys = null,}) {
+  return _then(_self.copyWith(
+ys: null == ys ? _self.ys : ys // ignore: cast_nullable_to_non_nullable
+as Float32List,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ChartPayload].
+extension ChartPayloadPatterns on ChartPayload {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChartPayload_Points value)?  points,TResult Function( ChartPayload_Uniform value)?  uniform,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ChartPayload_Points() when points != null:
+return points(_that);case ChartPayload_Uniform() when uniform != null:
+return uniform(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChartPayload_Points value)  points,required TResult Function( ChartPayload_Uniform value)  uniform,}){
+final _that = this;
+switch (_that) {
+case ChartPayload_Points():
+return points(_that);case ChartPayload_Uniform():
+return uniform(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChartPayload_Points value)?  points,TResult? Function( ChartPayload_Uniform value)?  uniform,}){
+final _that = this;
+switch (_that) {
+case ChartPayload_Points() when points != null:
+return points(_that);case ChartPayload_Uniform() when uniform != null:
+return uniform(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Float32List xs,  Float32List ys)?  points,TResult Function( double x0,  double dx,  Float32List ys)?  uniform,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ChartPayload_Points() when points != null:
+return points(_that.xs,_that.ys);case ChartPayload_Uniform() when uniform != null:
+return uniform(_that.x0,_that.dx,_that.ys);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Float32List xs,  Float32List ys)  points,required TResult Function( double x0,  double dx,  Float32List ys)  uniform,}) {final _that = this;
+switch (_that) {
+case ChartPayload_Points():
+return points(_that.xs,_that.ys);case ChartPayload_Uniform():
+return uniform(_that.x0,_that.dx,_that.ys);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Float32List xs,  Float32List ys)?  points,TResult? Function( double x0,  double dx,  Float32List ys)?  uniform,}) {final _that = this;
+switch (_that) {
+case ChartPayload_Points() when points != null:
+return points(_that.xs,_that.ys);case ChartPayload_Uniform() when uniform != null:
+return uniform(_that.x0,_that.dx,_that.ys);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+/// Point `i` is `(xs[i], ys[i])`.
+
+class ChartPayload_Points extends ChartPayload {
+  const ChartPayload_Points({required this.xs, required this.ys}): super._();
+  
+
+ final  Float32List xs;
+@override final  Float32List ys;
+
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartPayload_PointsCopyWith<ChartPayload_Points> get copyWith => _$ChartPayload_PointsCopyWithImpl<ChartPayload_Points>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartPayload_Points&&const DeepCollectionEquality().equals(other.xs, xs)&&const DeepCollectionEquality().equals(other.ys, ys));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(xs),const DeepCollectionEquality().hash(ys));
+
+@override
+String toString() {
+  return 'ChartPayload.points(xs: $xs, ys: $ys)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartPayload_PointsCopyWith<$Res> implements $ChartPayloadCopyWith<$Res> {
+  factory $ChartPayload_PointsCopyWith(ChartPayload_Points value, $Res Function(ChartPayload_Points) _then) = _$ChartPayload_PointsCopyWithImpl;
+@override @useResult
+$Res call({
+ Float32List xs, Float32List ys
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartPayload_PointsCopyWithImpl<$Res>
+    implements $ChartPayload_PointsCopyWith<$Res> {
+  _$ChartPayload_PointsCopyWithImpl(this._self, this._then);
+
+  final ChartPayload_Points _self;
+  final $Res Function(ChartPayload_Points) _then;
+
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? xs = null,Object? ys = null,}) {
+  return _then(ChartPayload_Points(
+xs: null == xs ? _self.xs : xs // ignore: cast_nullable_to_non_nullable
+as Float32List,ys: null == ys ? _self.ys : ys // ignore: cast_nullable_to_non_nullable
+as Float32List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+/// Point `i` is `(x0 + i * dx, ys[i])`; no x is sent at all.
+
+class ChartPayload_Uniform extends ChartPayload {
+  const ChartPayload_Uniform({required this.x0, required this.dx, required this.ys}): super._();
+  
+
+ final  double x0;
+ final  double dx;
+@override final  Float32List ys;
+
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartPayload_UniformCopyWith<ChartPayload_Uniform> get copyWith => _$ChartPayload_UniformCopyWithImpl<ChartPayload_Uniform>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartPayload_Uniform&&(identical(other.x0, x0) || other.x0 == x0)&&(identical(other.dx, dx) || other.dx == dx)&&const DeepCollectionEquality().equals(other.ys, ys));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,x0,dx,const DeepCollectionEquality().hash(ys));
+
+@override
+String toString() {
+  return 'ChartPayload.uniform(x0: $x0, dx: $dx, ys: $ys)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartPayload_UniformCopyWith<$Res> implements $ChartPayloadCopyWith<$Res> {
+  factory $ChartPayload_UniformCopyWith(ChartPayload_Uniform value, $Res Function(ChartPayload_Uniform) _then) = _$ChartPayload_UniformCopyWithImpl;
+@override @useResult
+$Res call({
+ double x0, double dx, Float32List ys
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartPayload_UniformCopyWithImpl<$Res>
+    implements $ChartPayload_UniformCopyWith<$Res> {
+  _$ChartPayload_UniformCopyWithImpl(this._self, this._then);
+
+  final ChartPayload_Uniform _self;
+  final $Res Function(ChartPayload_Uniform) _then;
+
+/// Create a copy of ChartPayload
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? x0 = null,Object? dx = null,Object? ys = null,}) {
+  return _then(ChartPayload_Uniform(
+x0: null == x0 ? _self.x0 : x0 // ignore: cast_nullable_to_non_nullable
+as double,dx: null == dx ? _self.dx : dx // ignore: cast_nullable_to_non_nullable
+as double,ys: null == ys ? _self.ys : ys // ignore: cast_nullable_to_non_nullable
+as Float32List,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -5,17 +5,41 @@
 
 import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'config.freezed.dart';
+
+/// Where the engine keeps decoded audio.
+@freezed
+sealed class AudioStorageBackend with _$AudioStorageBackend {
+  const AudioStorageBackend._();
+
+  /// Lost when the app exits.
+  const factory AudioStorageBackend.memory() = AudioStorageBackend_Memory;
+  /// Kept in `directory` and available again after a restart. Not
+  /// available on web.
+  const factory AudioStorageBackend.disk({required String directory}) =
+      AudioStorageBackend_Disk;
+}
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+/// Where the engine keeps computed charts.
+@freezed
+sealed class ChartCacheBackend with _$ChartCacheBackend {
+  const ChartCacheBackend._();
+
+  /// Recomputed after every restart.
+  const factory ChartCacheBackend.memory() = ChartCacheBackend_Memory;
+  /// Spectrum and feature charts are also written to `directory` and read
+  /// back when the same audio is charted with the same config. Not
+  /// available on web.
+  const factory ChartCacheBackend.disk({required String directory}) =
+      ChartCacheBackend_Disk;
+}
 
 class Config {
   final BigInt frameSize;
 
   const Config({required this.frameSize});
 
-  static Future<Config> default_() =>
-      RustLib.instance.api.crateApiTypesConfigConfigDefault();
-
   @override
   int get hashCode => frameSize.hashCode;
 
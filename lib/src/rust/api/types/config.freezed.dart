@@ -0,0 +1,533 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'config.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$AudioStorageBackend {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioStorageBackend);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'AudioStorageBackend()';
+}
+
+
+}
+
+/// @nodoc
+class $AudioStorageBackendCopyWith<$Res>  {
+$AudioStorageBackendCopyWith(AudioStorageBackend _, $Res Function(AudioStorageBackend) __);
+}
+
+
+/// Adds pattern-matching-related methods to [AudioStorageBackend].
+extension AudioStorageBackendPatterns on AudioStorageBackend {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioStorageBackend_Memory value)?  memory,TResult Function( AudioStorageBackend_Disk value)?  disk,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory() when memory != null:
+return memory(_that);case AudioStorageBackend_Disk() when disk != null:
+return disk(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioStorageBackend_Memory value)  memory,required TResult Function( AudioStorageBackend_Disk value)  disk,}){
+final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory():
+return memory(_that);case AudioStorageBackend_Disk():
+return disk(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioStorageBackend_Memory value)?  memory,TResult? Function( AudioStorageBackend_Disk value)?  disk,}){
+final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory() when memory != null:
+return memory(_that);case AudioStorageBackend_Disk() when disk != null:
+return disk(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  memory,TResult Function( String directory)?  disk,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory() when memory != null:
+return memory();case AudioStorageBackend_Disk() when disk != null:
+return disk(_that.directory);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  memory,required TResult Function( String directory)  disk,}) {final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory():
+return memory();case AudioStorageBackend_Disk():
+return disk(_that.directory);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  memory,TResult? Function( String directory)?  disk,}) {final _that = this;
+switch (_that) {
+case AudioStorageBackend_Memory() when memory != null:
+return memory();case AudioStorageBackend_Disk() when disk != null:
+return disk(_that.directory);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+/// Lost when the app exits.
+
+class AudioStorageBackend_Memory extends AudioStorageBackend {
+  const AudioStorageBackend_Memory(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioStorageBackend_Memory);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'AudioStorageBackend.memory()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// Kept in `directory` and available again after a restart. Not
+/// available on web.
+
+class AudioStorageBackend_Disk extends AudioStorageBackend {
+  const AudioStorageBackend_Disk({required this.directory}): super._();
+  
+
+ final  String directory;
+
+/// Create a copy of AudioStorageBackend
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioStorageBackend_DiskCopyWith<AudioStorageBackend_Disk> get copyWith => _$AudioStorageBackend_DiskCopyWithImpl<AudioStorageBackend_Disk>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioStorageBackend_Disk&&(identical(other.directory, directory) || other.directory == directory));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,directory);
+
+@override
+String toString() {
+  return 'AudioStorageBackend.disk(directory: $directory)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioStorageBackend_DiskCopyWith<$Res> implements $AudioStorageBackendCopyWith<$Res> {
+  factory $AudioStorageBackend_DiskCopyWith(AudioStorageBackend_Disk value, $Res Function(AudioStorageBackend_Disk) _then) = _$AudioStorageBackend_DiskCopyWithImpl;
+@useResult
+$Res call({
+ String directory
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioStorageBackend_DiskCopyWithImpl<$Res>
+    implements $AudioStorageBackend_DiskCopyWith<$Res> {
+  _$AudioStorageBackend_DiskCopyWithImpl(this._self, this._then);
+
+  final AudioStorageBackend_Disk _self;
+  final $Res Function(AudioStorageBackend_Disk) _then;
+
+/// Create a copy of AudioStorageBackend
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? directory = null,}) {
+  return _then(AudioStorageBackend_Disk(
+directory: null == directory ? _self.directory : directory // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ChartCacheBackend {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartCacheBackend);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChartCacheBackend()';
+}
+
+
+}
+
+/// @nodoc
+class $ChartCacheBackendCopyWith<$Res>  {
+$ChartCacheBackendCopyWith(ChartCacheBackend _, $Res Function(ChartCacheBackend) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ChartCacheBackend].
+extension ChartCacheBackendPatterns on ChartCacheBackend {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChartCacheBackend_Memory value)?  memory,TResult Function( ChartCacheBackend_Disk value)?  disk,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory() when memory != null:
+return memory(_that);case ChartCacheBackend_Disk() when disk != null:
+return disk(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChartCacheBackend_Memory value)  memory,required TResult Function( ChartCacheBackend_Disk value)  disk,}){
+final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory():
+return memory(_that);case ChartCacheBackend_Disk():
+return disk(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChartCacheBackend_Memory value)?  memory,TResult? Function( ChartCacheBackend_Disk value)?  disk,}){
+final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory() when memory != null:
+return memory(_that);case ChartCacheBackend_Disk() when disk != null:
+return disk(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  memory,TResult Function( String directory)?  disk,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory() when memory != null:
+return memory();case ChartCacheBackend_Disk() when disk != null:
+return disk(_that.directory);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  memory,required TResult Function( String directory)  disk,}) {final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory():
+return memory();case ChartCacheBackend_Disk():
+return disk(_that.directory);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  memory,TResult? Function( String directory)?  disk,}) {final _that = this;
+switch (_that) {
+case ChartCacheBackend_Memory() when memory != null:
+return memory();case ChartCacheBackend_Disk() when disk != null:
+return disk(_that.directory);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+/// Recomputed after every restart.
+
+class ChartCacheBackend_Memory extends ChartCacheBackend {
+  const ChartCacheBackend_Memory(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartCacheBackend_Memory);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChartCacheBackend.memory()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+/// Spectrum and feature charts are also written to `directory` and read
+/// back when the same audio is charted with the same config. Not
+/// available on web.
+
+class ChartCacheBackend_Disk extends ChartCacheBackend {
+  const ChartCacheBackend_Disk({required this.directory}): super._();
+  
+
+ final  String directory;
+
+/// Create a copy of ChartCacheBackend
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartCacheBackend_DiskCopyWith<ChartCacheBackend_Disk> get copyWith => _$ChartCacheBackend_DiskCopyWithImpl<ChartCacheBackend_Disk>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartCacheBackend_Disk&&(identical(other.directory, directory) || other.directory == directory));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,directory);
+
+@override
+String toString() {
+  return 'ChartCacheBackend.disk(directory: $directory)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartCacheBackend_DiskCopyWith<$Res> implements $ChartCacheBackendCopyWith<$Res> {
+  factory $ChartCacheBackend_DiskCopyWith(ChartCacheBackend_Disk value, $Res Function(ChartCacheBackend_Disk) _then) = _$ChartCacheBackend_DiskCopyWithImpl;
+@useResult
+$Res call({
+ String directory
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartCacheBackend_DiskCopyWithImpl<$Res>
+    implements $ChartCacheBackend_DiskCopyWith<$Res> {
+  _$ChartCacheBackend_DiskCopyWithImpl(this._self, this._then);
+
+  final ChartCacheBackend_Disk _self;
+  final $Res Function(ChartCacheBackend_Disk) _then;
+
+/// Create a copy of ChartCacheBackend
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? directory = null,}) {
+  return _then(ChartCacheBackend_Disk(
+directory: null == directory ? _self.directory : directory // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
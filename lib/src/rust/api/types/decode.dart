@@ -0,0 +1,240 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
+/// Shared flag that lets the caller stop a running decode.
+///
+/// Clones observe the same flag, so the Dart side can keep one handle and
+/// cancel while the engine holds another.
+abstract class CancellationToken implements RustOpaqueInterface {
+  Future<void> cancel();
+
+  Future<bool> isCancelled();
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+  static Future<CancellationToken> newInstance() =>
+      RustLib.instance.api.crateApiTypesDecodeCancellationTokenNew();
+}
+
+/// A stretch of output that was not decoded from the input. Zero-filled
+/// packets span their duration; a discontinuity where the reader skipped
+/// unreadable data has `start_frame == end_frame`.
+class DamagedRange {
+  final BigInt startFrame;
+  final BigInt endFrame;
+  /// Seconds from the start of the decoded audio.
+  final double start;
+  final double end;
+  final String reason;
+
+  const DamagedRange({
+    required this.startFrame,
+    required this.endFrame,
+    required this.start,
+    required this.end,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode =>
+      startFrame.hashCode ^
+      endFrame.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DamagedRange &&
+          runtimeType == other.runtimeType &&
+          startFrame == other.startFrame &&
+          endFrame == other.endFrame &&
+          start == other.start &&
+          end == other.end &&
+          reason == other.reason;
+}
+
+class DecodeOptions {
+  /// Track to decode; the first audio track when `None`.
+  final int? trackId;
+
+  const DecodeOptions({this.trackId});
+
+  static Future<DecodeOptions> default_() =>
+      RustLib.instance.api.crateApiTypesDecodeDecodeOptionsDefault();
+
+  @override
+  int get hashCode => trackId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecodeOptions &&
+          runtimeType == other.runtimeType &&
+          trackId == other.trackId;
+}
+
+class DecodeProgress {
+  final BigInt packets;
+  final BigInt bytesRead;
+  final BigInt? totalBytes;
+  /// Frames per channel decoded so far.
+  final BigInt decodedFrames;
+  /// Seconds of audio decoded so far.
+  final double decodedDuration;
+  /// Seconds of audio expected in total, from the container if it declares
+  /// a frame count, otherwise extrapolated from the bytes read.
+  final double? estimatedDuration;
+
+  const DecodeProgress({
+    required this.packets,
+    required this.bytesRead,
+    this.totalBytes,
+    required this.decodedFrames,
+    required this.decodedDuration,
+    this.estimatedDuration,
+  });
+
+  static Future<DecodeProgress> default_() =>
+      RustLib.instance.api.crateApiTypesDecodeDecodeProgressDefault();
+
+  @override
+  int get hashCode =>
+      packets.hashCode ^
+      bytesRead.hashCode ^
+      totalBytes.hashCode ^
+      decodedFrames.hashCode ^
+      decodedDuration.hashCode ^
+      estimatedDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecodeProgress &&
+          runtimeType == other.runtimeType &&
+          packets == other.packets &&
+          bytesRead == other.bytesRead &&
+          totalBytes == other.totalBytes &&
+          decodedFrames == other.decodedFrames &&
+          decodedDuration == other.decodedDuration &&
+          estimatedDuration == other.estimatedDuration;
+}
+
+class DecodeReport {
+  final List<DamagedRange> damaged;
+  /// Packets whose audio was replaced by silence.
+  final BigInt skippedPackets;
+  /// Times the codec had to be rebuilt mid-stream.
+  final int decoderResets;
+  /// The input ended before all of the declared audio was read.
+  final bool truncated;
+
+  const DecodeReport({
+    required this.damaged,
+    required this.skippedPackets,
+    required this.decoderResets,
+    required this.truncated,
+  });
+
+  static Future<DecodeReport> default_() =>
+      RustLib.instance.api.crateApiTypesDecodeDecodeReportDefault();
+
+  Future<bool> isClean() =>
+      RustLib.instance.api.crateApiTypesDecodeDecodeReportIsClean(that: this);
+
+  /// Records damage, merging it into the previous range when they touch and
+  /// share a reason so a run of bad packets shows up as one region.
+  Future<void> mark({
+    required BigInt startFrame,
+    required BigInt endFrame,
+    required int sampleRate,
+    required String reason,
+  }) => RustLib.instance.api.crateApiTypesDecodeDecodeReportMark(
+    that: this,
+    startFrame: startFrame,
+    endFrame: endFrame,
+    sampleRate: sampleRate,
+    reason: reason,
+  );
+
+  @override
+  int get hashCode =>
+      damaged.hashCode ^
+      skippedPackets.hashCode ^
+      decoderResets.hashCode ^
+      truncated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecodeReport &&
+          runtimeType == other.runtimeType &&
+          damaged == other.damaged &&
+          skippedPackets == other.skippedPackets &&
+          decoderResets == other.decoderResets &&
+          truncated == other.truncated;
+}
+
+enum Endianness { little, big }
+
+/// Layout of a headerless PCM dump, which has to be supplied by the caller.
+class RawPcmFormat {
+  final RawSampleFormat sampleFormat;
+  /// Ignored for single-byte formats.
+  final Endianness endianness;
+  /// Channels interleaved per frame.
+  final int channels;
+  final int sampleRate;
+  /// Bytes to skip before the first sample, e.g. a device-specific header.
+  final BigInt headerSkip;
+
+  const RawPcmFormat({
+    required this.sampleFormat,
+    required this.endianness,
+    required this.channels,
+    required this.sampleRate,
+    required this.headerSkip,
+  });
+
+  @override
+  int get hashCode =>
+      sampleFormat.hashCode ^
+      endianness.hashCode ^
+      channels.hashCode ^
+      sampleRate.hashCode ^
+      headerSkip.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RawPcmFormat &&
+          runtimeType == other.runtimeType &&
+          sampleFormat == other.sampleFormat &&
+          endianness == other.endianness &&
+          channels == other.channels &&
+          sampleRate == other.sampleRate &&
+          headerSkip == other.headerSkip;
+}
+
+enum RawSampleFormat {
+  u8,
+  s16,
+  s24,
+  s32,
+  f32,
+  /// G.711 mu-law, one byte per sample.
+  muLaw,
+  /// G.711 A-law, one byte per sample.
+  aLaw;
+
+  Future<BigInt> bytesPerSample() => RustLib.instance.api
+      .crateApiTypesDecodeRawSampleFormatBytesPerSample(that: this);
+}
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+enum ExportContainer { wav, flac }
+
+class ExportOptions {
+  final ExportContainer container;
+  final ExportSampleFormat sampleFormat;
+  /// Add TPDF dither when quantizing to an integer format.
+  final bool dither;
+
+  const ExportOptions({
+    required this.container,
+    required this.sampleFormat,
+    required this.dither,
+  });
+
+  @override
+  int get hashCode =>
+      container.hashCode ^ sampleFormat.hashCode ^ dither.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportOptions &&
+          runtimeType == other.runtimeType &&
+          container == other.container &&
+          sampleFormat == other.sampleFormat &&
+          dither == other.dither;
+}
+
+enum ExportSampleFormat {
+  i16,
+  i24,
+  /// WAV only; FLAC stores integers.
+  f32,
+}
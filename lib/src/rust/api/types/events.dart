@@ -5,18 +5,29 @@
 
 import '../../frb_generated.dart';
 import 'chart.dart';
+import 'decode.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'events.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
-
+/// `viewport` is the id of the viewport an event is for. `seq` numbers the
+/// views of that viewport a chart's points were computed for, in the order
+/// they were requested. A frame older than one already received is stale and
+/// can be dropped.
+///
+/// Edits name in `base_seq` the points they apply to. An edit whose
+/// `base_seq` is not the `seq` of the points held for the chart, because a
+/// frame was dropped, cannot be applied; ask for the whole chart again with
+/// `resend_chart` instead.
 @freezed
 sealed class ChartEvent with _$ChartEvent {
   const ChartEvent._();
 
-  const factory ChartEvent.addChart({required CommunicatorChart chart}) =
-      ChartEvent_AddChart;
+  const factory ChartEvent.addChart({
+    required String viewport,
+    required CommunicatorChart chart,
+    required BigInt seq,
+  }) = ChartEvent_AddChart;
   const factory ChartEvent.removeChart({
     required String key,
     required DataType dataType,
@@ -24,11 +35,67 @@ sealed class ChartEvent with _$ChartEvent {
   const factory ChartEvent.updateAllCharts({
     required List<CommunicatorChart> charts,
   }) = ChartEvent_UpdateAllCharts;
+  /// A whole chart at a fixed resolution, for the overview strip. Sent
+  /// only when it changes, not when the view moves.
+  const factory ChartEvent.updateOverview({
+    required String viewport,
+    required CommunicatorChart chart,
+  }) = ChartEvent_UpdateOverview;
   const factory ChartEvent.removeAllCharts() = ChartEvent_RemoveAllCharts;
-  const factory ChartEvent.updateMaxIndex({required double maxIndex}) =
-      ChartEvent_UpdateMaxIndex;
-  const factory ChartEvent.updateYRange({
-    required double minY,
-    required double maxY,
-  }) = ChartEvent_UpdateYRange;
+  const factory ChartEvent.updateMaxIndex({
+    required String viewport,
+    required double maxIndex,
+  }) = ChartEvent_UpdateMaxIndex;
+  /// The y axes of the viewport, each fitted to the points its charts
+  /// show of the current view. Replaces the axes sent before.
+  const factory ChartEvent.updateAxes({
+    required String viewport,
+    required List<ChartAxis> axes,
+  }) = ChartEvent_UpdateAxes;
+  const factory ChartEvent.updateRegions({
+    required String key,
+    required List<ChartRegion> regions,
+  }) = ChartEvent_UpdateRegions;
+  /// Points that follow the last one already sent for the chart.
+  const factory ChartEvent.appendPoints({
+    required String viewport,
+    required String key,
+    required DataType dataType,
+    required ChartPayload points,
+    required BigInt baseSeq,
+    required BigInt seq,
+  }) = ChartEvent_AppendPoints;
+  /// Replaces points `start..end` of those already sent for the chart.
+  const factory ChartEvent.replaceRange({
+    required String viewport,
+    required String key,
+    required DataType dataType,
+    required BigInt start,
+    required BigInt end,
+    required ChartPayload points,
+    required BigInt baseSeq,
+    required BigInt seq,
+  }) = ChartEvent_ReplaceRange;
+}
+
+@freezed
+sealed class DecodeEvent with _$DecodeEvent {
+  const DecodeEvent._();
+
+  const factory DecodeEvent.progress({
+    required String key,
+    required DecodeProgress progress,
+  }) = DecodeEvent_Progress;
+  const factory DecodeEvent.finished({
+    required String key,
+    required DecodeProgress progress,
+  }) = DecodeEvent_Finished;
+  const factory DecodeEvent.cancelled({
+    required String key,
+    required DecodeProgress progress,
+  }) = DecodeEvent_Cancelled;
+  const factory DecodeEvent.failed({
+    required String key,
+    required String message,
+  }) = DecodeEvent_Failed;
 }
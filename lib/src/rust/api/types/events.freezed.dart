@@ -55,16 +55,20 @@ extension ChartEventPatterns on ChartEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChartEvent_AddChart value)?  addChart,TResult Function( ChartEvent_RemoveChart value)?  removeChart,TResult Function( ChartEvent_UpdateAllCharts value)?  updateAllCharts,TResult Function( ChartEvent_RemoveAllCharts value)?  removeAllCharts,TResult Function( ChartEvent_UpdateMaxIndex value)?  updateMaxIndex,TResult Function( ChartEvent_UpdateYRange value)?  updateYRange,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChartEvent_AddChart value)?  addChart,TResult Function( ChartEvent_RemoveChart value)?  removeChart,TResult Function( ChartEvent_UpdateAllCharts value)?  updateAllCharts,TResult Function( ChartEvent_UpdateOverview value)?  updateOverview,TResult Function( ChartEvent_RemoveAllCharts value)?  removeAllCharts,TResult Function( ChartEvent_UpdateMaxIndex value)?  updateMaxIndex,TResult Function( ChartEvent_UpdateAxes value)?  updateAxes,TResult Function( ChartEvent_UpdateRegions value)?  updateRegions,TResult Function( ChartEvent_AppendPoints value)?  appendPoints,TResult Function( ChartEvent_ReplaceRange value)?  replaceRange,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case ChartEvent_AddChart() when addChart != null:
 return addChart(_that);case ChartEvent_RemoveChart() when removeChart != null:
 return removeChart(_that);case ChartEvent_UpdateAllCharts() when updateAllCharts != null:
-return updateAllCharts(_that);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
+return updateAllCharts(_that);case ChartEvent_UpdateOverview() when updateOverview != null:
+return updateOverview(_that);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
 return removeAllCharts(_that);case ChartEvent_UpdateMaxIndex() when updateMaxIndex != null:
-return updateMaxIndex(_that);case ChartEvent_UpdateYRange() when updateYRange != null:
-return updateYRange(_that);case _:
+return updateMaxIndex(_that);case ChartEvent_UpdateAxes() when updateAxes != null:
+return updateAxes(_that);case ChartEvent_UpdateRegions() when updateRegions != null:
+return updateRegions(_that);case ChartEvent_AppendPoints() when appendPoints != null:
+return appendPoints(_that);case ChartEvent_ReplaceRange() when replaceRange != null:
+return replaceRange(_that);case _:
   return orElse();
 
 }
@@ -82,16 +86,20 @@ return updateYRange(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChartEvent_AddChart value)  addChart,required TResult Function( ChartEvent_RemoveChart value)  removeChart,required TResult Function( ChartEvent_UpdateAllCharts value)  updateAllCharts,required TResult Function( ChartEvent_RemoveAllCharts value)  removeAllCharts,required TResult Function( ChartEvent_UpdateMaxIndex value)  updateMaxIndex,required TResult Function( ChartEvent_UpdateYRange value)  updateYRange,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChartEvent_AddChart value)  addChart,required TResult Function( ChartEvent_RemoveChart value)  removeChart,required TResult Function( ChartEvent_UpdateAllCharts value)  updateAllCharts,required TResult Function( ChartEvent_UpdateOverview value)  updateOverview,required TResult Function( ChartEvent_RemoveAllCharts value)  removeAllCharts,required TResult Function( ChartEvent_UpdateMaxIndex value)  updateMaxIndex,required TResult Function( ChartEvent_UpdateAxes value)  updateAxes,required TResult Function( ChartEvent_UpdateRegions value)  updateRegions,required TResult Function( ChartEvent_AppendPoints value)  appendPoints,required TResult Function( ChartEvent_ReplaceRange value)  replaceRange,}){
 final _that = this;
 switch (_that) {
 case ChartEvent_AddChart():
 return addChart(_that);case ChartEvent_RemoveChart():
 return removeChart(_that);case ChartEvent_UpdateAllCharts():
-return updateAllCharts(_that);case ChartEvent_RemoveAllCharts():
+return updateAllCharts(_that);case ChartEvent_UpdateOverview():
+return updateOverview(_that);case ChartEvent_RemoveAllCharts():
 return removeAllCharts(_that);case ChartEvent_UpdateMaxIndex():
-return updateMaxIndex(_that);case ChartEvent_UpdateYRange():
-return updateYRange(_that);}
+return updateMaxIndex(_that);case ChartEvent_UpdateAxes():
+return updateAxes(_that);case ChartEvent_UpdateRegions():
+return updateRegions(_that);case ChartEvent_AppendPoints():
+return appendPoints(_that);case ChartEvent_ReplaceRange():
+return replaceRange(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -105,16 +113,20 @@ return updateYRange(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChartEvent_AddChart value)?  addChart,TResult? Function( ChartEvent_RemoveChart value)?  removeChart,TResult? Function( ChartEvent_UpdateAllCharts value)?  updateAllCharts,TResult? Function( ChartEvent_RemoveAllCharts value)?  removeAllCharts,TResult? Function( ChartEvent_UpdateMaxIndex value)?  updateMaxIndex,TResult? Function( ChartEvent_UpdateYRange value)?  updateYRange,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChartEvent_AddChart value)?  addChart,TResult? Function( ChartEvent_RemoveChart value)?  removeChart,TResult? Function( ChartEvent_UpdateAllCharts value)?  updateAllCharts,TResult? Function( ChartEvent_UpdateOverview value)?  updateOverview,TResult? Function( ChartEvent_RemoveAllCharts value)?  removeAllCharts,TResult? Function( ChartEvent_UpdateMaxIndex value)?  updateMaxIndex,TResult? Function( ChartEvent_UpdateAxes value)?  updateAxes,TResult? Function( ChartEvent_UpdateRegions value)?  updateRegions,TResult? Function( ChartEvent_AppendPoints value)?  appendPoints,TResult? Function( ChartEvent_ReplaceRange value)?  replaceRange,}){
 final _that = this;
 switch (_that) {
 case ChartEvent_AddChart() when addChart != null:
 return addChart(_that);case ChartEvent_RemoveChart() when removeChart != null:
 return removeChart(_that);case ChartEvent_UpdateAllCharts() when updateAllCharts != null:
-return updateAllCharts(_that);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
+return updateAllCharts(_that);case ChartEvent_UpdateOverview() when updateOverview != null:
+return updateOverview(_that);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
 return removeAllCharts(_that);case ChartEvent_UpdateMaxIndex() when updateMaxIndex != null:
-return updateMaxIndex(_that);case ChartEvent_UpdateYRange() when updateYRange != null:
-return updateYRange(_that);case _:
+return updateMaxIndex(_that);case ChartEvent_UpdateAxes() when updateAxes != null:
+return updateAxes(_that);case ChartEvent_UpdateRegions() when updateRegions != null:
+return updateRegions(_that);case ChartEvent_AppendPoints() when appendPoints != null:
+return appendPoints(_that);case ChartEvent_ReplaceRange() when replaceRange != null:
+return replaceRange(_that);case _:
   return null;
 
 }
@@ -131,15 +143,19 @@ return updateYRange(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( CommunicatorChart chart)?  addChart,TResult Function( String key,  DataType dataType)?  removeChart,TResult Function( List<CommunicatorChart> charts)?  updateAllCharts,TResult Function()?  removeAllCharts,TResult Function( double maxIndex)?  updateMaxIndex,TResult Function( double minY,  double maxY)?  updateYRange,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String viewport,  CommunicatorChart chart,  BigInt seq)?  addChart,TResult Function( String key,  DataType dataType)?  removeChart,TResult Function( List<CommunicatorChart> charts)?  updateAllCharts,TResult Function( String viewport,  CommunicatorChart chart)?  updateOverview,TResult Function()?  removeAllCharts,TResult Function( String viewport,  double maxIndex)?  updateMaxIndex,TResult Function( String viewport,  List<ChartAxis> axes)?  updateAxes,TResult Function( String key,  List<ChartRegion> regions)?  updateRegions,TResult Function( String viewport,  String key,  DataType dataType,  ChartPayload points,  BigInt baseSeq,  BigInt seq)?  appendPoints,TResult Function( String viewport,  String key,  DataType dataType,  BigInt start,  BigInt end,  ChartPayload points,  BigInt baseSeq,  BigInt seq)?  replaceRange,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case ChartEvent_AddChart() when addChart != null:
-return addChart(_that.chart);case ChartEvent_RemoveChart() when removeChart != null:
+return addChart(_that.viewport,_that.chart,_that.seq);case ChartEvent_RemoveChart() when removeChart != null:
 return removeChart(_that.key,_that.dataType);case ChartEvent_UpdateAllCharts() when updateAllCharts != null:
-return updateAllCharts(_that.charts);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
+return updateAllCharts(_that.charts);case ChartEvent_UpdateOverview() when updateOverview != null:
+return updateOverview(_that.viewport,_that.chart);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
 return removeAllCharts();case ChartEvent_UpdateMaxIndex() when updateMaxIndex != null:
-return updateMaxIndex(_that.maxIndex);case ChartEvent_UpdateYRange() when updateYRange != null:
-return updateYRange(_that.minY,_that.maxY);case _:
+return updateMaxIndex(_that.viewport,_that.maxIndex);case ChartEvent_UpdateAxes() when updateAxes != null:
+return updateAxes(_that.viewport,_that.axes);case ChartEvent_UpdateRegions() when updateRegions != null:
+return updateRegions(_that.key,_that.regions);case ChartEvent_AppendPoints() when appendPoints != null:
+return appendPoints(_that.viewport,_that.key,_that.dataType,_that.points,_that.baseSeq,_that.seq);case ChartEvent_ReplaceRange() when replaceRange != null:
+return replaceRange(_that.viewport,_that.key,_that.dataType,_that.start,_that.end,_that.points,_that.baseSeq,_that.seq);case _:
   return orElse();
 
 }
@@ -157,15 +173,19 @@ return updateYRange(_that.minY,_that.maxY);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( CommunicatorChart chart)  addChart,required TResult Function( String key,  DataType dataType)  removeChart,required TResult Function( List<CommunicatorChart> charts)  updateAllCharts,required TResult Function()  removeAllCharts,required TResult Function( double maxIndex)  updateMaxIndex,required TResult Function( double minY,  double maxY)  updateYRange,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String viewport,  CommunicatorChart chart,  BigInt seq)  addChart,required TResult Function( String key,  DataType dataType)  removeChart,required TResult Function( List<CommunicatorChart> charts)  updateAllCharts,required TResult Function( String viewport,  CommunicatorChart chart)  updateOverview,required TResult Function()  removeAllCharts,required TResult Function( String viewport,  double maxIndex)  updateMaxIndex,required TResult Function( String viewport,  List<ChartAxis> axes)  updateAxes,required TResult Function( String key,  List<ChartRegion> regions)  updateRegions,required TResult Function( String viewport,  String key,  DataType dataType,  ChartPayload points,  BigInt baseSeq,  BigInt seq)  appendPoints,required TResult Function( String viewport,  String key,  DataType dataType,  BigInt start,  BigInt end,  ChartPayload points,  BigInt baseSeq,  BigInt seq)  replaceRange,}) {final _that = this;
 switch (_that) {
 case ChartEvent_AddChart():
-return addChart(_that.chart);case ChartEvent_RemoveChart():
+return addChart(_that.viewport,_that.chart,_that.seq);case ChartEvent_RemoveChart():
 return removeChart(_that.key,_that.dataType);case ChartEvent_UpdateAllCharts():
-return updateAllCharts(_that.charts);case ChartEvent_RemoveAllCharts():
+return updateAllCharts(_that.charts);case ChartEvent_UpdateOverview():
+return updateOverview(_that.viewport,_that.chart);case ChartEvent_RemoveAllCharts():
 return removeAllCharts();case ChartEvent_UpdateMaxIndex():
-return updateMaxIndex(_that.maxIndex);case ChartEvent_UpdateYRange():
-return updateYRange(_that.minY,_that.maxY);}
+return updateMaxIndex(_that.viewport,_that.maxIndex);case ChartEvent_UpdateAxes():
+return updateAxes(_that.viewport,_that.axes);case ChartEvent_UpdateRegions():
+return updateRegions(_that.key,_that.regions);case ChartEvent_AppendPoints():
+return appendPoints(_that.viewport,_that.key,_that.dataType,_that.points,_that.baseSeq,_that.seq);case ChartEvent_ReplaceRange():
+return replaceRange(_that.viewport,_that.key,_that.dataType,_that.start,_that.end,_that.points,_that.baseSeq,_that.seq);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -179,15 +199,19 @@ return updateYRange(_that.minY,_that.maxY);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( CommunicatorChart chart)?  addChart,TResult? Function( String key,  DataType dataType)?  removeChart,TResult? Function( List<CommunicatorChart> charts)?  updateAllCharts,TResult? Function()?  removeAllCharts,TResult? Function( double maxIndex)?  updateMaxIndex,TResult? Function( double minY,  double maxY)?  updateYRange,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String viewport,  CommunicatorChart chart,  BigInt seq)?  addChart,TResult? Function( String key,  DataType dataType)?  removeChart,TResult? Function( List<CommunicatorChart> charts)?  updateAllCharts,TResult? Function( String viewport,  CommunicatorChart chart)?  updateOverview,TResult? Function()?  removeAllCharts,TResult? Function( String viewport,  double maxIndex)?  updateMaxIndex,TResult? Function( String viewport,  List<ChartAxis> axes)?  updateAxes,TResult? Function( String key,  List<ChartRegion> regions)?  updateRegions,TResult? Function( String viewport,  String key,  DataType dataType,  ChartPayload points,  BigInt baseSeq,  BigInt seq)?  appendPoints,TResult? Function( String viewport,  String key,  DataType dataType,  BigInt start,  BigInt end,  ChartPayload points,  BigInt baseSeq,  BigInt seq)?  replaceRange,}) {final _that = this;
 switch (_that) {
 case ChartEvent_AddChart() when addChart != null:
-return addChart(_that.chart);case ChartEvent_RemoveChart() when removeChart != null:
+return addChart(_that.viewport,_that.chart,_that.seq);case ChartEvent_RemoveChart() when removeChart != null:
 return removeChart(_that.key,_that.dataType);case ChartEvent_UpdateAllCharts() when updateAllCharts != null:
-return updateAllCharts(_that.charts);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
+return updateAllCharts(_that.charts);case ChartEvent_UpdateOverview() when updateOverview != null:
+return updateOverview(_that.viewport,_that.chart);case ChartEvent_RemoveAllCharts() when removeAllCharts != null:
 return removeAllCharts();case ChartEvent_UpdateMaxIndex() when updateMaxIndex != null:
-return updateMaxIndex(_that.maxIndex);case ChartEvent_UpdateYRange() when updateYRange != null:
-return updateYRange(_that.minY,_that.maxY);case _:
+return updateMaxIndex(_that.viewport,_that.maxIndex);case ChartEvent_UpdateAxes() when updateAxes != null:
+return updateAxes(_that.viewport,_that.axes);case ChartEvent_UpdateRegions() when updateRegions != null:
+return updateRegions(_that.key,_that.regions);case ChartEvent_AppendPoints() when appendPoints != null:
+return appendPoints(_that.viewport,_that.key,_that.dataType,_that.points,_that.baseSeq,_that.seq);case ChartEvent_ReplaceRange() when replaceRange != null:
+return replaceRange(_that.viewport,_that.key,_that.dataType,_that.start,_that.end,_that.points,_that.baseSeq,_that.seq);case _:
   return null;
 
 }
@@ -199,10 +223,12 @@ return updateYRange(_that.minY,_that.maxY);case _:
 
 
 class ChartEvent_AddChart extends ChartEvent {
-  const ChartEvent_AddChart({required this.chart}): super._();
+  const ChartEvent_AddChart({required this.viewport, required this.chart, required this.seq}): super._();
   
 
+ final  String viewport;
  final  CommunicatorChart chart;
+ final  BigInt seq;
 
 /// Create a copy of ChartEvent
 /// with the given fields replaced by the non-null parameter values.
@@ -214,16 +240,16 @@ $ChartEvent_AddChartCopyWith<ChartEvent_AddChart> get copyWith => _$ChartEvent_A
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_AddChart&&(identical(other.chart, chart) || other.chart == chart));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_AddChart&&(identical(other.viewport, viewport) || other.viewport == viewport)&&(identical(other.chart, chart) || other.chart == chart)&&(identical(other.seq, seq) || other.seq == seq));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,chart);
+int get hashCode => Object.hash(runtimeType,viewport,chart,seq);
 
 @override
 String toString() {
-  return 'ChartEvent.addChart(chart: $chart)';
+  return 'ChartEvent.addChart(viewport: $viewport, chart: $chart, seq: $seq)';
 }
 
 
@@ -234,7 +260,7 @@ abstract mixin class $ChartEvent_AddChartCopyWith<$Res> implements $ChartEventCo
   factory $ChartEvent_AddChartCopyWith(ChartEvent_AddChart value, $Res Function(ChartEvent_AddChart) _then) = _$ChartEvent_AddChartCopyWithImpl;
 @useResult
 $Res call({
- CommunicatorChart chart
+ String viewport, CommunicatorChart chart, BigInt seq
 });
 
 
@@ -251,10 +277,12 @@ class _$ChartEvent_AddChartCopyWithImpl<$Res>
 
 /// Create a copy of ChartEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? chart = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? chart = null,Object? seq = null,}) {
   return _then(ChartEvent_AddChart(
-chart: null == chart ? _self.chart : chart // ignore: cast_nullable_to_non_nullable
-as CommunicatorChart,
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,chart: null == chart ? _self.chart : chart // ignore: cast_nullable_to_non_nullable
+as CommunicatorChart,seq: null == seq ? _self.seq : seq // ignore: cast_nullable_to_non_nullable
+as BigInt,
   ));
 }
 
@@ -399,6 +427,75 @@ as List<CommunicatorChart>,
 }
 
 
+}
+
+/// @nodoc
+/// A whole chart at a fixed resolution, for the overview strip. Sent
+/// only when it changes, not when the view moves.
+
+class ChartEvent_UpdateOverview extends ChartEvent {
+  const ChartEvent_UpdateOverview({required this.viewport, required this.chart}): super._();
+  
+
+ final  String viewport;
+ final  CommunicatorChart chart;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartEvent_UpdateOverviewCopyWith<ChartEvent_UpdateOverview> get copyWith => _$ChartEvent_UpdateOverviewCopyWithImpl<ChartEvent_UpdateOverview>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateOverview&&(identical(other.viewport, viewport) || other.viewport == viewport)&&(identical(other.chart, chart) || other.chart == chart));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,viewport,chart);
+
+@override
+String toString() {
+  return 'ChartEvent.updateOverview(viewport: $viewport, chart: $chart)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartEvent_UpdateOverviewCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
+  factory $ChartEvent_UpdateOverviewCopyWith(ChartEvent_UpdateOverview value, $Res Function(ChartEvent_UpdateOverview) _then) = _$ChartEvent_UpdateOverviewCopyWithImpl;
+@useResult
+$Res call({
+ String viewport, CommunicatorChart chart
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartEvent_UpdateOverviewCopyWithImpl<$Res>
+    implements $ChartEvent_UpdateOverviewCopyWith<$Res> {
+  _$ChartEvent_UpdateOverviewCopyWithImpl(this._self, this._then);
+
+  final ChartEvent_UpdateOverview _self;
+  final $Res Function(ChartEvent_UpdateOverview) _then;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? chart = null,}) {
+  return _then(ChartEvent_UpdateOverview(
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,chart: null == chart ? _self.chart : chart // ignore: cast_nullable_to_non_nullable
+as CommunicatorChart,
+  ));
+}
+
+
 }
 
 /// @nodoc
@@ -437,9 +534,10 @@ String toString() {
 
 
 class ChartEvent_UpdateMaxIndex extends ChartEvent {
-  const ChartEvent_UpdateMaxIndex({required this.maxIndex}): super._();
+  const ChartEvent_UpdateMaxIndex({required this.viewport, required this.maxIndex}): super._();
   
 
+ final  String viewport;
  final  double maxIndex;
 
 /// Create a copy of ChartEvent
@@ -452,16 +550,16 @@ $ChartEvent_UpdateMaxIndexCopyWith<ChartEvent_UpdateMaxIndex> get copyWith => _$
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateMaxIndex&&(identical(other.maxIndex, maxIndex) || other.maxIndex == maxIndex));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateMaxIndex&&(identical(other.viewport, viewport) || other.viewport == viewport)&&(identical(other.maxIndex, maxIndex) || other.maxIndex == maxIndex));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,maxIndex);
+int get hashCode => Object.hash(runtimeType,viewport,maxIndex);
 
 @override
 String toString() {
-  return 'ChartEvent.updateMaxIndex(maxIndex: $maxIndex)';
+  return 'ChartEvent.updateMaxIndex(viewport: $viewport, maxIndex: $maxIndex)';
 }
 
 
@@ -472,7 +570,7 @@ abstract mixin class $ChartEvent_UpdateMaxIndexCopyWith<$Res> implements $ChartE
   factory $ChartEvent_UpdateMaxIndexCopyWith(ChartEvent_UpdateMaxIndex value, $Res Function(ChartEvent_UpdateMaxIndex) _then) = _$ChartEvent_UpdateMaxIndexCopyWithImpl;
 @useResult
 $Res call({
- double maxIndex
+ String viewport, double maxIndex
 });
 
 
@@ -489,57 +587,139 @@ class _$ChartEvent_UpdateMaxIndexCopyWithImpl<$Res>
 
 /// Create a copy of ChartEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? maxIndex = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? maxIndex = null,}) {
   return _then(ChartEvent_UpdateMaxIndex(
-maxIndex: null == maxIndex ? _self.maxIndex : maxIndex // ignore: cast_nullable_to_non_nullable
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,maxIndex: null == maxIndex ? _self.maxIndex : maxIndex // ignore: cast_nullable_to_non_nullable
 as double,
   ));
 }
 
 
+}
+
+/// @nodoc
+/// The y axes of the viewport, each fitted to the points its charts
+/// show of the current view. Replaces the axes sent before.
+
+class ChartEvent_UpdateAxes extends ChartEvent {
+  const ChartEvent_UpdateAxes({required this.viewport, required final  List<ChartAxis> axes}): _axes = axes,super._();
+  
+
+ final  String viewport;
+ final  List<ChartAxis> _axes;
+ List<ChartAxis> get axes {
+  if (_axes is EqualUnmodifiableListView) return _axes;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_axes);
+}
+
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartEvent_UpdateAxesCopyWith<ChartEvent_UpdateAxes> get copyWith => _$ChartEvent_UpdateAxesCopyWithImpl<ChartEvent_UpdateAxes>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateAxes&&(identical(other.viewport, viewport) || other.viewport == viewport)&&const DeepCollectionEquality().equals(other._axes, _axes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,viewport,const DeepCollectionEquality().hash(_axes));
+
+@override
+String toString() {
+  return 'ChartEvent.updateAxes(viewport: $viewport, axes: $axes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartEvent_UpdateAxesCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
+  factory $ChartEvent_UpdateAxesCopyWith(ChartEvent_UpdateAxes value, $Res Function(ChartEvent_UpdateAxes) _then) = _$ChartEvent_UpdateAxesCopyWithImpl;
+@useResult
+$Res call({
+ String viewport, List<ChartAxis> axes
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChartEvent_UpdateAxesCopyWithImpl<$Res>
+    implements $ChartEvent_UpdateAxesCopyWith<$Res> {
+  _$ChartEvent_UpdateAxesCopyWithImpl(this._self, this._then);
+
+  final ChartEvent_UpdateAxes _self;
+  final $Res Function(ChartEvent_UpdateAxes) _then;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? axes = null,}) {
+  return _then(ChartEvent_UpdateAxes(
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,axes: null == axes ? _self._axes : axes // ignore: cast_nullable_to_non_nullable
+as List<ChartAxis>,
+  ));
+}
+
+
 }
 
 /// @nodoc
 
 
-class ChartEvent_UpdateYRange extends ChartEvent {
-  const ChartEvent_UpdateYRange({required this.minY, required this.maxY}): super._();
+class ChartEvent_UpdateRegions extends ChartEvent {
+  const ChartEvent_UpdateRegions({required this.key, required final  List<ChartRegion> regions}): _regions = regions,super._();
   
 
- final  double minY;
- final  double maxY;
+ final  String key;
+ final  List<ChartRegion> _regions;
+ List<ChartRegion> get regions {
+  if (_regions is EqualUnmodifiableListView) return _regions;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_regions);
+}
+
 
 /// Create a copy of ChartEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$ChartEvent_UpdateYRangeCopyWith<ChartEvent_UpdateYRange> get copyWith => _$ChartEvent_UpdateYRangeCopyWithImpl<ChartEvent_UpdateYRange>(this, _$identity);
+$ChartEvent_UpdateRegionsCopyWith<ChartEvent_UpdateRegions> get copyWith => _$ChartEvent_UpdateRegionsCopyWithImpl<ChartEvent_UpdateRegions>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateYRange&&(identical(other.minY, minY) || other.minY == minY)&&(identical(other.maxY, maxY) || other.maxY == maxY));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_UpdateRegions&&(identical(other.key, key) || other.key == key)&&const DeepCollectionEquality().equals(other._regions, _regions));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,minY,maxY);
+int get hashCode => Object.hash(runtimeType,key,const DeepCollectionEquality().hash(_regions));
 
 @override
 String toString() {
-  return 'ChartEvent.updateYRange(minY: $minY, maxY: $maxY)';
+  return 'ChartEvent.updateRegions(key: $key, regions: $regions)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $ChartEvent_UpdateYRangeCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
-  factory $ChartEvent_UpdateYRangeCopyWith(ChartEvent_UpdateYRange value, $Res Function(ChartEvent_UpdateYRange) _then) = _$ChartEvent_UpdateYRangeCopyWithImpl;
+abstract mixin class $ChartEvent_UpdateRegionsCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
+  factory $ChartEvent_UpdateRegionsCopyWith(ChartEvent_UpdateRegions value, $Res Function(ChartEvent_UpdateRegions) _then) = _$ChartEvent_UpdateRegionsCopyWithImpl;
 @useResult
 $Res call({
- double minY, double maxY
+ String key, List<ChartRegion> regions
 });
 
 
@@ -547,20 +727,669 @@ $Res call({
 
 }
 /// @nodoc
-class _$ChartEvent_UpdateYRangeCopyWithImpl<$Res>
-    implements $ChartEvent_UpdateYRangeCopyWith<$Res> {
-  _$ChartEvent_UpdateYRangeCopyWithImpl(this._self, this._then);
+class _$ChartEvent_UpdateRegionsCopyWithImpl<$Res>
+    implements $ChartEvent_UpdateRegionsCopyWith<$Res> {
+  _$ChartEvent_UpdateRegionsCopyWithImpl(this._self, this._then);
 
-  final ChartEvent_UpdateYRange _self;
-  final $Res Function(ChartEvent_UpdateYRange) _then;
+  final ChartEvent_UpdateRegions _self;
+  final $Res Function(ChartEvent_UpdateRegions) _then;
 
 /// Create a copy of ChartEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? minY = null,Object? maxY = null,}) {
-  return _then(ChartEvent_UpdateYRange(
-minY: null == minY ? _self.minY : minY // ignore: cast_nullable_to_non_nullable
-as double,maxY: null == maxY ? _self.maxY : maxY // ignore: cast_nullable_to_non_nullable
-as double,
+@pragma('vm:prefer-inline') $Res call({Object? key = null,Object? regions = null,}) {
+  return _then(ChartEvent_UpdateRegions(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,regions: null == regions ? _self._regions : regions // ignore: cast_nullable_to_non_nullable
+as List<ChartRegion>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+/// Points that follow the last one already sent for the chart.
+
+class ChartEvent_AppendPoints extends ChartEvent {
+  const ChartEvent_AppendPoints({required this.viewport, required this.key, required this.dataType, required this.points, required this.baseSeq, required this.seq}): super._();
+  
+
+ final  String viewport;
+ final  String key;
+ final  DataType dataType;
+ final  ChartPayload points;
+ final  BigInt baseSeq;
+ final  BigInt seq;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartEvent_AppendPointsCopyWith<ChartEvent_AppendPoints> get copyWith => _$ChartEvent_AppendPointsCopyWithImpl<ChartEvent_AppendPoints>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_AppendPoints&&(identical(other.viewport, viewport) || other.viewport == viewport)&&(identical(other.key, key) || other.key == key)&&(identical(other.dataType, dataType) || other.dataType == dataType)&&(identical(other.points, points) || other.points == points)&&(identical(other.baseSeq, baseSeq) || other.baseSeq == baseSeq)&&(identical(other.seq, seq) || other.seq == seq));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,viewport,key,dataType,points,baseSeq,seq);
+
+@override
+String toString() {
+  return 'ChartEvent.appendPoints(viewport: $viewport, key: $key, dataType: $dataType, points: $points, baseSeq: $baseSeq, seq: $seq)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartEvent_AppendPointsCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
+  factory $ChartEvent_AppendPointsCopyWith(ChartEvent_AppendPoints value, $Res Function(ChartEvent_AppendPoints) _then) = _$ChartEvent_AppendPointsCopyWithImpl;
+@useResult
+$Res call({
+ String viewport, String key, DataType dataType, ChartPayload points, BigInt baseSeq, BigInt seq
+});
+
+
+$ChartPayloadCopyWith<$Res> get points;
+
+}
+/// @nodoc
+class _$ChartEvent_AppendPointsCopyWithImpl<$Res>
+    implements $ChartEvent_AppendPointsCopyWith<$Res> {
+  _$ChartEvent_AppendPointsCopyWithImpl(this._self, this._then);
+
+  final ChartEvent_AppendPoints _self;
+  final $Res Function(ChartEvent_AppendPoints) _then;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? key = null,Object? dataType = null,Object? points = null,Object? baseSeq = null,Object? seq = null,}) {
+  return _then(ChartEvent_AppendPoints(
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,dataType: null == dataType ? _self.dataType : dataType // ignore: cast_nullable_to_non_nullable
+as DataType,points: null == points ? _self.points : points // ignore: cast_nullable_to_non_nullable
+as ChartPayload,baseSeq: null == baseSeq ? _self.baseSeq : baseSeq // ignore: cast_nullable_to_non_nullable
+as BigInt,seq: null == seq ? _self.seq : seq // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ChartPayloadCopyWith<$Res> get points {
+  
+  return $ChartPayloadCopyWith<$Res>(_self.points, (value) {
+    return _then(_self.copyWith(points: value));
+  });
+}
+}
+
+/// @nodoc
+/// Replaces points `start..end` of those already sent for the chart.
+
+class ChartEvent_ReplaceRange extends ChartEvent {
+  const ChartEvent_ReplaceRange({required this.viewport, required this.key, required this.dataType, required this.start, required this.end, required this.points, required this.baseSeq, required this.seq}): super._();
+  
+
+ final  String viewport;
+ final  String key;
+ final  DataType dataType;
+ final  BigInt start;
+ final  BigInt end;
+ final  ChartPayload points;
+ final  BigInt baseSeq;
+ final  BigInt seq;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChartEvent_ReplaceRangeCopyWith<ChartEvent_ReplaceRange> get copyWith => _$ChartEvent_ReplaceRangeCopyWithImpl<ChartEvent_ReplaceRange>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChartEvent_ReplaceRange&&(identical(other.viewport, viewport) || other.viewport == viewport)&&(identical(other.key, key) || other.key == key)&&(identical(other.dataType, dataType) || other.dataType == dataType)&&(identical(other.start, start) || other.start == start)&&(identical(other.end, end) || other.end == end)&&(identical(other.points, points) || other.points == points)&&(identical(other.baseSeq, baseSeq) || other.baseSeq == baseSeq)&&(identical(other.seq, seq) || other.seq == seq));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,viewport,key,dataType,start,end,points,baseSeq,seq);
+
+@override
+String toString() {
+  return 'ChartEvent.replaceRange(viewport: $viewport, key: $key, dataType: $dataType, start: $start, end: $end, points: $points, baseSeq: $baseSeq, seq: $seq)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChartEvent_ReplaceRangeCopyWith<$Res> implements $ChartEventCopyWith<$Res> {
+  factory $ChartEvent_ReplaceRangeCopyWith(ChartEvent_ReplaceRange value, $Res Function(ChartEvent_ReplaceRange) _then) = _$ChartEvent_ReplaceRangeCopyWithImpl;
+@useResult
+$Res call({
+ String viewport, String key, DataType dataType, BigInt start, BigInt end, ChartPayload points, BigInt baseSeq, BigInt seq
+});
+
+
+$ChartPayloadCopyWith<$Res> get points;
+
+}
+/// @nodoc
+class _$ChartEvent_ReplaceRangeCopyWithImpl<$Res>
+    implements $ChartEvent_ReplaceRangeCopyWith<$Res> {
+  _$ChartEvent_ReplaceRangeCopyWithImpl(this._self, this._then);
+
+  final ChartEvent_ReplaceRange _self;
+  final $Res Function(ChartEvent_ReplaceRange) _then;
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? viewport = null,Object? key = null,Object? dataType = null,Object? start = null,Object? end = null,Object? points = null,Object? baseSeq = null,Object? seq = null,}) {
+  return _then(ChartEvent_ReplaceRange(
+viewport: null == viewport ? _self.viewport : viewport // ignore: cast_nullable_to_non_nullable
+as String,key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,dataType: null == dataType ? _self.dataType : dataType // ignore: cast_nullable_to_non_nullable
+as DataType,start: null == start ? _self.start : start // ignore: cast_nullable_to_non_nullable
+as BigInt,end: null == end ? _self.end : end // ignore: cast_nullable_to_non_nullable
+as BigInt,points: null == points ? _self.points : points // ignore: cast_nullable_to_non_nullable
+as ChartPayload,baseSeq: null == baseSeq ? _self.baseSeq : baseSeq // ignore: cast_nullable_to_non_nullable
+as BigInt,seq: null == seq ? _self.seq : seq // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+/// Create a copy of ChartEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$ChartPayloadCopyWith<$Res> get points {
+  
+  return $ChartPayloadCopyWith<$Res>(_self.points, (value) {
+    return _then(_self.copyWith(points: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$DecodeEvent {
+
+ String get key;
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodeEventCopyWith<DecodeEvent> get copyWith => _$DecodeEventCopyWithImpl<DecodeEvent>(this as DecodeEvent, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodeEvent&&(identical(other.key, key) || other.key == key));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key);
+
+@override
+String toString() {
+  return 'DecodeEvent(key: $key)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodeEventCopyWith<$Res>  {
+  factory $DecodeEventCopyWith(DecodeEvent value, $Res Function(DecodeEvent) _then) = _$DecodeEventCopyWithImpl;
+@useResult
+$Res call({
+ String key
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodeEventCopyWithImpl<$Res>
+    implements $DecodeEventCopyWith<$Res> {
+  _$DecodeEventCopyWithImpl(this._self, this._then);
+
+  final DecodeEvent _self;
+  final $Res Function(DecodeEvent) _then;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? key = null,}) {
+  return _then(_self.copyWith(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DecodeEvent].
+extension DecodeEventPatterns on DecodeEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( DecodeEvent_Progress value)?  progress,TResult Function( DecodeEvent_Finished value)?  finished,TResult Function( DecodeEvent_Cancelled value)?  cancelled,TResult Function( DecodeEvent_Failed value)?  failed,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case DecodeEvent_Progress() when progress != null:
+return progress(_that);case DecodeEvent_Finished() when finished != null:
+return finished(_that);case DecodeEvent_Cancelled() when cancelled != null:
+return cancelled(_that);case DecodeEvent_Failed() when failed != null:
+return failed(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( DecodeEvent_Progress value)  progress,required TResult Function( DecodeEvent_Finished value)  finished,required TResult Function( DecodeEvent_Cancelled value)  cancelled,required TResult Function( DecodeEvent_Failed value)  failed,}){
+final _that = this;
+switch (_that) {
+case DecodeEvent_Progress():
+return progress(_that);case DecodeEvent_Finished():
+return finished(_that);case DecodeEvent_Cancelled():
+return cancelled(_that);case DecodeEvent_Failed():
+return failed(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( DecodeEvent_Progress value)?  progress,TResult? Function( DecodeEvent_Finished value)?  finished,TResult? Function( DecodeEvent_Cancelled value)?  cancelled,TResult? Function( DecodeEvent_Failed value)?  failed,}){
+final _that = this;
+switch (_that) {
+case DecodeEvent_Progress() when progress != null:
+return progress(_that);case DecodeEvent_Finished() when finished != null:
+return finished(_that);case DecodeEvent_Cancelled() when cancelled != null:
+return cancelled(_that);case DecodeEvent_Failed() when failed != null:
+return failed(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String key,  DecodeProgress progress)?  progress,TResult Function( String key,  DecodeProgress progress)?  finished,TResult Function( String key,  DecodeProgress progress)?  cancelled,TResult Function( String key,  String message)?  failed,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case DecodeEvent_Progress() when progress != null:
+return progress(_that.key,_that.progress);case DecodeEvent_Finished() when finished != null:
+return finished(_that.key,_that.progress);case DecodeEvent_Cancelled() when cancelled != null:
+return cancelled(_that.key,_that.progress);case DecodeEvent_Failed() when failed != null:
+return failed(_that.key,_that.message);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String key,  DecodeProgress progress)  progress,required TResult Function( String key,  DecodeProgress progress)  finished,required TResult Function( String key,  DecodeProgress progress)  cancelled,required TResult Function( String key,  String message)  failed,}) {final _that = this;
+switch (_that) {
+case DecodeEvent_Progress():
+return progress(_that.key,_that.progress);case DecodeEvent_Finished():
+return finished(_that.key,_that.progress);case DecodeEvent_Cancelled():
+return cancelled(_that.key,_that.progress);case DecodeEvent_Failed():
+return failed(_that.key,_that.message);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String key,  DecodeProgress progress)?  progress,TResult? Function( String key,  DecodeProgress progress)?  finished,TResult? Function( String key,  DecodeProgress progress)?  cancelled,TResult? Function( String key,  String message)?  failed,}) {final _that = this;
+switch (_that) {
+case DecodeEvent_Progress() when progress != null:
+return progress(_that.key,_that.progress);case DecodeEvent_Finished() when finished != null:
+return finished(_that.key,_that.progress);case DecodeEvent_Cancelled() when cancelled != null:
+return cancelled(_that.key,_that.progress);case DecodeEvent_Failed() when failed != null:
+return failed(_that.key,_that.message);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class DecodeEvent_Progress extends DecodeEvent {
+  const DecodeEvent_Progress({required this.key, required this.progress}): super._();
+  
+
+@override final  String key;
+ final  DecodeProgress progress;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodeEvent_ProgressCopyWith<DecodeEvent_Progress> get copyWith => _$DecodeEvent_ProgressCopyWithImpl<DecodeEvent_Progress>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodeEvent_Progress&&(identical(other.key, key) || other.key == key)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,progress);
+
+@override
+String toString() {
+  return 'DecodeEvent.progress(key: $key, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodeEvent_ProgressCopyWith<$Res> implements $DecodeEventCopyWith<$Res> {
+  factory $DecodeEvent_ProgressCopyWith(DecodeEvent_Progress value, $Res Function(DecodeEvent_Progress) _then) = _$DecodeEvent_ProgressCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, DecodeProgress progress
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodeEvent_ProgressCopyWithImpl<$Res>
+    implements $DecodeEvent_ProgressCopyWith<$Res> {
+  _$DecodeEvent_ProgressCopyWithImpl(this._self, this._then);
+
+  final DecodeEvent_Progress _self;
+  final $Res Function(DecodeEvent_Progress) _then;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? progress = null,}) {
+  return _then(DecodeEvent_Progress(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as DecodeProgress,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class DecodeEvent_Finished extends DecodeEvent {
+  const DecodeEvent_Finished({required this.key, required this.progress}): super._();
+  
+
+@override final  String key;
+ final  DecodeProgress progress;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodeEvent_FinishedCopyWith<DecodeEvent_Finished> get copyWith => _$DecodeEvent_FinishedCopyWithImpl<DecodeEvent_Finished>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodeEvent_Finished&&(identical(other.key, key) || other.key == key)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,progress);
+
+@override
+String toString() {
+  return 'DecodeEvent.finished(key: $key, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodeEvent_FinishedCopyWith<$Res> implements $DecodeEventCopyWith<$Res> {
+  factory $DecodeEvent_FinishedCopyWith(DecodeEvent_Finished value, $Res Function(DecodeEvent_Finished) _then) = _$DecodeEvent_FinishedCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, DecodeProgress progress
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodeEvent_FinishedCopyWithImpl<$Res>
+    implements $DecodeEvent_FinishedCopyWith<$Res> {
+  _$DecodeEvent_FinishedCopyWithImpl(this._self, this._then);
+
+  final DecodeEvent_Finished _self;
+  final $Res Function(DecodeEvent_Finished) _then;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? progress = null,}) {
+  return _then(DecodeEvent_Finished(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as DecodeProgress,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class DecodeEvent_Cancelled extends DecodeEvent {
+  const DecodeEvent_Cancelled({required this.key, required this.progress}): super._();
+  
+
+@override final  String key;
+ final  DecodeProgress progress;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodeEvent_CancelledCopyWith<DecodeEvent_Cancelled> get copyWith => _$DecodeEvent_CancelledCopyWithImpl<DecodeEvent_Cancelled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodeEvent_Cancelled&&(identical(other.key, key) || other.key == key)&&(identical(other.progress, progress) || other.progress == progress));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,progress);
+
+@override
+String toString() {
+  return 'DecodeEvent.cancelled(key: $key, progress: $progress)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodeEvent_CancelledCopyWith<$Res> implements $DecodeEventCopyWith<$Res> {
+  factory $DecodeEvent_CancelledCopyWith(DecodeEvent_Cancelled value, $Res Function(DecodeEvent_Cancelled) _then) = _$DecodeEvent_CancelledCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, DecodeProgress progress
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodeEvent_CancelledCopyWithImpl<$Res>
+    implements $DecodeEvent_CancelledCopyWith<$Res> {
+  _$DecodeEvent_CancelledCopyWithImpl(this._self, this._then);
+
+  final DecodeEvent_Cancelled _self;
+  final $Res Function(DecodeEvent_Cancelled) _then;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? progress = null,}) {
+  return _then(DecodeEvent_Cancelled(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,progress: null == progress ? _self.progress : progress // ignore: cast_nullable_to_non_nullable
+as DecodeProgress,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class DecodeEvent_Failed extends DecodeEvent {
+  const DecodeEvent_Failed({required this.key, required this.message}): super._();
+  
+
+@override final  String key;
+ final  String message;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodeEvent_FailedCopyWith<DecodeEvent_Failed> get copyWith => _$DecodeEvent_FailedCopyWithImpl<DecodeEvent_Failed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodeEvent_Failed&&(identical(other.key, key) || other.key == key)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,message);
+
+@override
+String toString() {
+  return 'DecodeEvent.failed(key: $key, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodeEvent_FailedCopyWith<$Res> implements $DecodeEventCopyWith<$Res> {
+  factory $DecodeEvent_FailedCopyWith(DecodeEvent_Failed value, $Res Function(DecodeEvent_Failed) _then) = _$DecodeEvent_FailedCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodeEvent_FailedCopyWithImpl<$Res>
+    implements $DecodeEvent_FailedCopyWith<$Res> {
+  _$DecodeEvent_FailedCopyWithImpl(this._self, this._then);
+
+  final DecodeEvent_Failed _self;
+  final $Res Function(DecodeEvent_Failed) _then;
+
+/// Create a copy of DecodeEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? message = null,}) {
+  return _then(DecodeEvent_Failed(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
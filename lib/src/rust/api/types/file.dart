@@ -5,17 +5,17 @@
 
 import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'file.freezed.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>
-abstract class ArcVecU8 implements RustOpaqueInterface {}
+/// Where a decoder reads the encoded audio from.
+@freezed
+sealed class AudioSource with _$AudioSource {
+  const AudioSource._();
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>
-abstract class File implements RustOpaqueInterface {
-  ArcVecU8 get bytes;
-
-  String get filePath;
-
-  set bytes(ArcVecU8 bytes);
-
-  set filePath(String filePath);
+  /// The whole file, already in memory.
+  const factory AudioSource.bytes(Uint8List field0) = AudioSource_Bytes;
+  /// A local file the decoder opens and streams itself, so large files never
+  /// have to cross the bridge or be held in memory.
+  const factory AudioSource.path(String field0) = AudioSource_Path;
 }
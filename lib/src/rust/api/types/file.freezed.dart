@@ -0,0 +1,307 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'file.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$AudioSource {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioSource);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'AudioSource()';
+}
+
+
+}
+
+/// @nodoc
+class $AudioSourceCopyWith<$Res>  {
+$AudioSourceCopyWith(AudioSource _, $Res Function(AudioSource) __);
+}
+
+
+/// Adds pattern-matching-related methods to [AudioSource].
+extension AudioSourcePatterns on AudioSource {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioSource_Bytes value)?  bytes,TResult Function( AudioSource_Path value)?  path,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case AudioSource_Bytes() when bytes != null:
+return bytes(_that);case AudioSource_Path() when path != null:
+return path(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioSource_Bytes value)  bytes,required TResult Function( AudioSource_Path value)  path,}){
+final _that = this;
+switch (_that) {
+case AudioSource_Bytes():
+return bytes(_that);case AudioSource_Path():
+return path(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioSource_Bytes value)?  bytes,TResult? Function( AudioSource_Path value)?  path,}){
+final _that = this;
+switch (_that) {
+case AudioSource_Bytes() when bytes != null:
+return bytes(_that);case AudioSource_Path() when path != null:
+return path(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Uint8List field0)?  bytes,TResult Function( String field0)?  path,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case AudioSource_Bytes() when bytes != null:
+return bytes(_that.field0);case AudioSource_Path() when path != null:
+return path(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Uint8List field0)  bytes,required TResult Function( String field0)  path,}) {final _that = this;
+switch (_that) {
+case AudioSource_Bytes():
+return bytes(_that.field0);case AudioSource_Path():
+return path(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Uint8List field0)?  bytes,TResult? Function( String field0)?  path,}) {final _that = this;
+switch (_that) {
+case AudioSource_Bytes() when bytes != null:
+return bytes(_that.field0);case AudioSource_Path() when path != null:
+return path(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+/// The whole file, already in memory.
+
+class AudioSource_Bytes extends AudioSource {
+  const AudioSource_Bytes(this.field0): super._();
+  
+
+ final  Uint8List field0;
+
+/// Create a copy of AudioSource
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioSource_BytesCopyWith<AudioSource_Bytes> get copyWith => _$AudioSource_BytesCopyWithImpl<AudioSource_Bytes>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioSource_Bytes&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'AudioSource.bytes(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioSource_BytesCopyWith<$Res> implements $AudioSourceCopyWith<$Res> {
+  factory $AudioSource_BytesCopyWith(AudioSource_Bytes value, $Res Function(AudioSource_Bytes) _then) = _$AudioSource_BytesCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioSource_BytesCopyWithImpl<$Res>
+    implements $AudioSource_BytesCopyWith<$Res> {
+  _$AudioSource_BytesCopyWithImpl(this._self, this._then);
+
+  final AudioSource_Bytes _self;
+  final $Res Function(AudioSource_Bytes) _then;
+
+/// Create a copy of AudioSource
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(AudioSource_Bytes(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+/// A local file the decoder opens and streams itself, so large files never
+/// have to cross the bridge or be held in memory.
+
+class AudioSource_Path extends AudioSource {
+  const AudioSource_Path(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of AudioSource
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioSource_PathCopyWith<AudioSource_Path> get copyWith => _$AudioSource_PathCopyWithImpl<AudioSource_Path>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioSource_Path&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'AudioSource.path(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioSource_PathCopyWith<$Res> implements $AudioSourceCopyWith<$Res> {
+  factory $AudioSource_PathCopyWith(AudioSource_Path value, $Res Function(AudioSource_Path) _then) = _$AudioSource_PathCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioSource_PathCopyWithImpl<$Res>
+    implements $AudioSource_PathCopyWith<$Res> {
+  _$AudioSource_PathCopyWithImpl(this._self, this._then);
+
+  final AudioSource_Path _self;
+  final $Res Function(AudioSource_Path) _then;
+
+/// Create a copy of AudioSource
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(AudioSource_Path(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'chart.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class AudioMemoryUsage {
+  final String key;
+  final BigInt bytes;
+
+  const AudioMemoryUsage({required this.key, required this.bytes});
+
+  @override
+  int get hashCode => key.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioMemoryUsage &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          bytes == other.bytes;
+}
+
+class ChartMemoryUsage {
+  final String key;
+  final DataType dataType;
+  final BigInt bytes;
+
+  const ChartMemoryUsage({
+    required this.key,
+    required this.dataType,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ dataType.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChartMemoryUsage &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          dataType == other.dataType &&
+          bytes == other.bytes;
+}
+
+/// Bytes currently held in memory, most recently used first.
+class MemoryUsage {
+  final List<AudioMemoryUsage> audio;
+  final List<ChartMemoryUsage> charts;
+  final BigInt audioBytes;
+  final BigInt chartBytes;
+  /// `None` when unlimited.
+  final BigInt? audioBudget;
+  final BigInt? chartBudget;
+
+  const MemoryUsage({
+    required this.audio,
+    required this.charts,
+    required this.audioBytes,
+    required this.chartBytes,
+    this.audioBudget,
+    this.chartBudget,
+  });
+
+  @override
+  int get hashCode =>
+      audio.hashCode ^
+      charts.hashCode ^
+      audioBytes.hashCode ^
+      chartBytes.hashCode ^
+      audioBudget.hashCode ^
+      chartBudget.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryUsage &&
+          runtimeType == other.runtimeType &&
+          audio == other.audio &&
+          charts == other.charts &&
+          audioBytes == other.audioBytes &&
+          chartBytes == other.chartBytes &&
+          audioBudget == other.audioBudget &&
+          chartBudget == other.chartBudget;
+}
@@ -4,14 +4,15 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
-import '../types/audio.dart';
-import '../types/error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-abstract class AudioStorage {
-  Future<Audio> load({required String key});
-
-  Future<void> remove({required String key});
-
-  Future<void> save({required String key, required Audio storageUnit});
+/// How decoded samples are kept in memory.
+enum SampleStorage {
+  /// 4 bytes per sample, lossless.
+  f32,
+  /// 2 bytes per sample, scaled to the peak so quiet files keep their
+  /// resolution.
+  i16,
+  /// 2 bytes per sample with about three significant digits at any level.
+  f16,
 }
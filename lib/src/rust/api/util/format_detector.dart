@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class DetectedFormat {
+  /// Container name, e.g. `"wav"`, `"ogg"` or `"mp4"`.
+  final String container;
+  /// Usual file extension for the container.
+  final String extension;
+  /// Codec when it can be told from the header alone.
+  final String? codec;
+
+  const DetectedFormat({
+    required this.container,
+    required this.extension,
+    this.codec,
+  });
+
+  @override
+  int get hashCode => container.hashCode ^ extension.hashCode ^ codec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DetectedFormat &&
+          runtimeType == other.runtimeType &&
+          container == other.container &&
+          extension == other.extension &&
+          codec == other.codec;
+}
@@ -1,11 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.12.0.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../../frb_generated.dart';
-import '../types/chart.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-Future<(double, double)> getMinMaxPar({required List<Point> points}) =>
-    RustLib.instance.api.crateApiUtilGetMinMaxGetMinMaxPar(points: points);
@@ -3,37 +3,25 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
-import 'api/communicator/stream_sink_communicator.dart';
 import 'api/core/engine.dart';
-import 'api/decoder/symphonia_decoder.dart';
 import 'api/events/communicator_events.dart';
-import 'api/sampling/equal_step.dart';
-import 'api/sampling/minmax.dart';
-import 'api/storage/kv_audio_storage.dart';
-import 'api/storage/kv_cached_chart_storage.dart';
-import 'api/traits/audio_decoder.dart';
-import 'api/traits/audio_storage.dart';
-import 'api/traits/cached_chart_storage.dart';
-import 'api/traits/communicator.dart';
-import 'api/traits/down_sample.dart';
-import 'api/traits/transform.dart';
-import 'api/transform/energy.dart';
-import 'api/transform/fft.dart';
-import 'api/transform/zero_crossing_rate.dart';
+import 'api/events/decode_events.dart';
 import 'api/types/audio.dart';
 import 'api/types/chart.dart';
 import 'api/types/config.dart';
+import 'api/types/decode.dart';
+import 'api/types/encode.dart';
 import 'api/types/error.dart';
 import 'api/types/events.dart';
 import 'api/types/file.dart';
-import 'api/util/format_getter.dart';
-import 'api/util/get_min_max.dart';
+import 'api/types/memory.dart';
+import 'api/types/samples.dart';
+import 'api/util/format_detector.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
-import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 /// Main entrypoint of the Rust API
@@ -89,7 +77,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -1868779041;
+  int get rustContentHash => -1188118069;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -101,370 +89,278 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  ArcVecF32 crateApiTypesAudioAudioDataAutoAccessorGetSamples({
-    required AudioData that,
-  });
-
-  void crateApiTypesAudioAudioDataAutoAccessorSetSamples({
-    required AudioData that,
-    required ArcVecF32 samples,
-  });
-
   Future<void> crateApiCoreEngineAudioProcessorEngineAdd({
     required AudioProcessorEngine that,
     required String filePath,
-    required String format,
     required List<int> audioData,
+    int? trackId,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineAddChart({
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineAddAllTracks({
     required AudioProcessorEngine that,
     required String filePath,
-    required DataType dataType,
+    required AudioSource source,
+    required CancellationToken token,
   });
 
-  Future<AudioProcessorEngine> crateApiCoreEngineAudioProcessorEngineNew({
-    required Config config,
-    required BoxAudioDecoder decoder,
-    required BoxAudioStorage storage,
-    required BoxCachedChartStorage cache,
-    required BoxCommunicator communicator,
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddCancellable({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required List<int> audioData,
+    int? trackId,
+    required CancellationToken token,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveAudio({
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineAddChannelCharts({
     required AudioProcessorEngine that,
     required String filePath,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveChart({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddChart({
     required AudioProcessorEngine that,
     required String filePath,
     required DataType dataType,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineReserveVisible({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddFromPath({
     required AudioProcessorEngine that,
-    required String chartName,
+    required String filePath,
+    int? trackId,
+    required CancellationToken token,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetConfig({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddRawPcm({
     required AudioProcessorEngine that,
-    required Config config,
+    required String filePath,
+    required AudioSource source,
+    required RawPcmFormat format,
+    required CancellationToken token,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNum({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddViewport({
     required AudioProcessorEngine that,
-    required BigInt pointsNum,
+    required String id,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetIndexRange({
+  Future<DetectedFormat> crateApiCoreEngineAudioProcessorEngineDetectFormat({
     required AudioProcessorEngine that,
-    required double start,
-    required double end,
+    required AudioSource source,
   });
 
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetSelectedAudio({
+  Future<Uint8List> crateApiCoreEngineAudioProcessorEngineExport({
     required AudioProcessorEngine that,
-    String? chartName,
-  });
-
-  Future<Chart> crateApiTypesAudioAudioAudioToChart({required Audio that});
-
-  AudioData crateApiTypesAudioAudioAutoAccessorGetData({required Audio that});
-
-  AudioInfo crateApiTypesAudioAudioAutoAccessorGetInfo({required Audio that});
-
-  void crateApiTypesAudioAudioAutoAccessorSetData({
-    required Audio that,
-    required AudioData data,
-  });
-
-  void crateApiTypesAudioAudioAutoAccessorSetInfo({
-    required Audio that,
-    required AudioInfo info,
+    required String key,
+    required ExportOptions options,
   });
 
-  Chart crateApiTypesChartChartWIthKeyAutoAccessorGetChart({
-    required ChartWIthKey that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineExportToPath({
+    required AudioProcessorEngine that,
+    required String key,
+    required String path,
+    required ExportOptions options,
   });
 
-  String crateApiTypesChartChartWIthKeyAutoAccessorGetKey({
-    required ChartWIthKey that,
+  Future<AudioInfo> crateApiCoreEngineAudioProcessorEngineGetAudioInfo({
+    required AudioProcessorEngine that,
+    required String filePath,
   });
 
-  void crateApiTypesChartChartWIthKeyAutoAccessorSetChart({
-    required ChartWIthKey that,
-    required Chart chart,
+  Future<DecodeReport> crateApiCoreEngineAudioProcessorEngineGetDecodeReport({
+    required AudioProcessorEngine that,
+    required String filePath,
   });
 
-  void crateApiTypesChartChartWIthKeyAutoAccessorSetKey({
-    required ChartWIthKey that,
-    required String key,
+  Future<List<TrackInfo>> crateApiCoreEngineAudioProcessorEngineListTracks({
+    required AudioProcessorEngine that,
+    required AudioSource source,
   });
 
-  DataType crateApiTypesChartChartAutoAccessorGetDataType({
-    required Chart that,
+  Future<MemoryUsage> crateApiCoreEngineAudioProcessorEngineMemoryUsage({
+    required AudioProcessorEngine that,
   });
 
-  double crateApiTypesChartChartAutoAccessorGetMaxY({required Chart that});
-
-  double crateApiTypesChartChartAutoAccessorGetMinY({required Chart that});
-
-  ArcVecPoint crateApiTypesChartChartAutoAccessorGetPoints({
-    required Chart that,
+  Future<AudioProcessorEngine> crateApiCoreEngineAudioProcessorEngineNew({
+    required Config config,
+    required BoxAudioDecoder decoder,
+    required BoxAudioStorage storage,
+    required BoxCachedChartStorage cache,
+    required BoxCommunicator communicator,
   });
 
-  ArcAtomicBool crateApiTypesChartChartAutoAccessorGetVisible({
-    required Chart that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveAudio({
+    required AudioProcessorEngine that,
+    required String filePath,
   });
 
-  void crateApiTypesChartChartAutoAccessorSetDataType({
-    required Chart that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveChart({
+    required AudioProcessorEngine that,
+    required String filePath,
     required DataType dataType,
   });
 
-  void crateApiTypesChartChartAutoAccessorSetMaxY({
-    required Chart that,
-    required double maxY,
-  });
-
-  void crateApiTypesChartChartAutoAccessorSetMinY({
-    required Chart that,
-    required double minY,
-  });
-
-  void crateApiTypesChartChartAutoAccessorSetPoints({
-    required Chart that,
-    required ArcVecPoint points,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveViewport({
+    required AudioProcessorEngine that,
+    required String id,
   });
 
-  void crateApiTypesChartChartAutoAccessorSetVisible({
-    required Chart that,
-    required ArcAtomicBool visible,
+  Future<void> crateApiCoreEngineAudioProcessorEngineResendChart({
+    required AudioProcessorEngine that,
+    required String viewport,
+    required String filePath,
+    required DataType dataType,
   });
 
-  Future<Chart> crateApiTypesChartChartGetRange({
-    required Chart that,
-    required double startX,
-    required double endX,
+  Future<void> crateApiCoreEngineAudioProcessorEngineReserveVisible({
+    required AudioProcessorEngine that,
+    required String chartName,
   });
 
-  ArcVecU8 crateApiTypesFileFileAutoAccessorGetBytes({required File that});
-
-  String crateApiTypesFileFileAutoAccessorGetFilePath({required File that});
-
-  void crateApiTypesFileFileAutoAccessorSetBytes({
-    required File that,
-    required ArcVecU8 bytes,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetChannelMix({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required ChannelMix mix,
   });
 
-  void crateApiTypesFileFileAutoAccessorSetFilePath({
-    required File that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetChartDownSampleMethod({
+    required AudioProcessorEngine that,
     required String filePath,
+    required DataType dataType,
+    DownSampleMethod? method,
   });
 
-  Future<Audio> crateApiStorageKvAudioStorageKvAudioStorageLoad({
-    required KvAudioStorage that,
-    required String key,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetConfig({
+    required AudioProcessorEngine that,
+    required Config config,
   });
 
-  Future<KvAudioStorage> crateApiStorageKvAudioStorageKvAudioStorageNew();
-
-  Future<void> crateApiStorageKvAudioStorageKvAudioStorageRemove({
-    required KvAudioStorage that,
-    required String key,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSampleMethod({
+    required AudioProcessorEngine that,
+    required DataType dataType,
+    required DownSampleMethod method,
   });
 
-  Future<void> crateApiStorageKvAudioStorageKvAudioStorageSave({
-    required KvAudioStorage that,
-    required String key,
-    required Audio storageUnit,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNum({
+    required AudioProcessorEngine that,
+    required BigInt pointsNum,
   });
 
-  Future<void> crateApiStorageKvCachedChartStorageKvCachedChartStorageAdd({
-    required KvCachedChartStorage that,
-    required String key,
-    required Chart chart,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetIndexRange({
+    required AudioProcessorEngine that,
+    required double start,
+    required double end,
   });
 
-  Config
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorGetConfig({
-    required KvCachedChartStorage that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetMemoryBudget({
+    required AudioProcessorEngine that,
+    BigInt? audioBytes,
+    BigInt? chartBytes,
   });
 
-  void
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorSetConfig({
-    required KvCachedChartStorage that,
-    required Config config,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetOverviewPointsNum({
+    required AudioProcessorEngine that,
+    required BigInt pointsNum,
   });
 
-  Future<Chart> crateApiStorageKvCachedChartStorageKvCachedChartStorageGet({
-    required KvCachedChartStorage that,
-    required String key,
-    required DataType dataType,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSampleStorage({
+    required AudioProcessorEngine that,
+    required SampleStorage storage,
   });
 
-  Future<List<ChartWIthKey>>
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageGetAllCache({
-    required KvCachedChartStorage that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSelectedAudio({
+    required AudioProcessorEngine that,
+    String? chartName,
   });
 
-  Future<KvCachedChartStorage>
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageNew();
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSincInterpolation({
+    required AudioProcessorEngine that,
+    required bool enabled,
+  });
 
-  Future<void> crateApiStorageKvCachedChartStorageKvCachedChartStorageRemove({
-    required KvCachedChartStorage that,
-    required String key,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportChartVisible({
+    required AudioProcessorEngine that,
+    required String id,
+    required String filePath,
     required DataType dataType,
+    required bool visible,
   });
 
-  Future<Config> crateApiTypesConfigConfigDefault();
-
-  Stream<ChartEvent> crateApiEventsCommunicatorEventsCreateChartEventStream();
-
-  Future<AudioProcessorEngine> crateApiCoreEngineCreateDefaultEngine({
-    required Config config,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportPointsNum({
+    required AudioProcessorEngine that,
+    required String id,
+    required BigInt pointsNum,
   });
 
-  Future<void> crateApiEventsCommunicatorEventsEmitChartEvent({
-    required ChartEvent event,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportRange({
+    required AudioProcessorEngine that,
+    required String id,
+    required double start,
+    required double end,
   });
 
-  Future<Chart> crateApiTransformEnergyEnergyCalculatorTransform({
-    required EnergyCalculator that,
-    required Audio data,
-    required Config config,
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineStoredAudioKeys({
+    required AudioProcessorEngine that,
   });
 
-  Future<Chart> crateApiSamplingEqualStepEqualStepDownSample({
-    required EqualStep that,
-    required Chart chart,
-    required BigInt targetPointsNum,
+  Future<TruePeak> crateApiCoreEngineAudioProcessorEngineTruePeak({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required double start,
+    required double end,
   });
 
-  Future<Chart> crateApiTransformFftFftTransformTransform({
-    required FftTransform that,
-    required Audio data,
-    required Config config,
+  Future<void> crateApiTypesDecodeCancellationTokenCancel({
+    required CancellationToken that,
   });
 
-  Future<(double, double)> crateApiUtilGetMinMaxGetMinMaxPar({
-    required List<Point> points,
+  Future<bool> crateApiTypesDecodeCancellationTokenIsCancelled({
+    required CancellationToken that,
   });
 
-  Future<Chart> crateApiSamplingMinmaxMinmaxDownSample({
-    required Minmax that,
-    required Chart chart,
-    required BigInt targetPointsNum,
-  });
+  Future<CancellationToken> crateApiTypesDecodeCancellationTokenNew();
 
-  Future<String> crateApiUtilFormatGetterSimpleFormatGetterGetFormat({
-    required SimpleFormatGetter that,
-    required String filePath,
-  });
+  Future<DecodeOptions> crateApiTypesDecodeDecodeOptionsDefault();
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorAddChart({
-    required StreamCommunicator that,
-    required String key,
-    required Chart chart,
-  });
+  Future<DecodeProgress> crateApiTypesDecodeDecodeProgressDefault();
 
-  Future<StreamCommunicator>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorNew();
+  Future<DecodeReport> crateApiTypesDecodeDecodeReportDefault();
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveAllCharts({
-    required StreamCommunicator that,
+  Future<bool> crateApiTypesDecodeDecodeReportIsClean({
+    required DecodeReport that,
   });
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveChart({
-    required StreamCommunicator that,
-    required String key,
-    required DataType dataType,
+  Future<void> crateApiTypesDecodeDecodeReportMark({
+    required DecodeReport that,
+    required BigInt startFrame,
+    required BigInt endFrame,
+    required int sampleRate,
+    required String reason,
   });
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateAllCharts({
-    required StreamCommunicator that,
-    required List<ChartWIthKey> charts,
+  Future<BigInt> crateApiTypesDecodeRawSampleFormatBytesPerSample({
+    required RawSampleFormat that,
   });
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateMaxIndex({
-    required StreamCommunicator that,
-    required double maxIndex,
-  });
+  Stream<ChartEvent> crateApiEventsCommunicatorEventsCreateChartEventStream();
 
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateYRange({
-    required StreamCommunicator that,
-    required double minY,
-    required double maxY,
-  });
+  Stream<DecodeEvent> crateApiEventsDecodeEventsCreateDecodeEventStream();
 
-  Future<Audio> crateApiDecoderSymphoniaDecoderSymphoniaDecoderDecode({
-    required SymphoniaDecoder that,
-    required String format,
-    required List<int> data,
+  Future<AudioProcessorEngine> crateApiCoreEngineCreateDefaultEngine({
+    required Config config,
   });
 
-  Future<SymphoniaDecoder> crateApiDecoderSymphoniaDecoderSymphoniaDecoderNew();
-
-  Future<Chart>
-  crateApiTransformZeroCrossingRateZeroCrossingRateCalculatorTransform({
-    required ZeroCrossingRateCalculator that,
-    required Audio data,
+  Future<AudioProcessorEngine> crateApiCoreEngineCreateEngine({
     required Config config,
+    required AudioStorageBackend storage,
+    required ChartCacheBackend cache,
   });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcAtomicBool;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcAtomicBool;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcAtomicBoolPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecPoint;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecPoint;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcVecPointPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecF32;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecF32;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcVecF32Ptr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecU8;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecU8;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ArcVecU8Ptr;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Audio;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Audio;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AudioPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AudioData;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AudioData;
+  Future<void> crateApiEventsCommunicatorEventsEmitChartEvent({
+    required ChartEvent event,
+  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AudioDataPtr;
+  Future<void> crateApiEventsDecodeEventsEmitDecodeEvent({
+    required DecodeEvent event,
+  });
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_AudioProcessorEngine;
@@ -511,43 +407,14 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_BoxCommunicatorPtr;
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Chart;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Chart;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ChartPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ChartWIthKey;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ChartWIthKey;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ChartWIthKeyPtr;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_File;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_File;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_FilePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_KvAudioStorage;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_KvAudioStorage;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_KvAudioStoragePtr;
-
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_KvCachedChartStorage;
+  get rust_arc_increment_strong_count_CancellationToken;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_KvCachedChartStorage;
+  get rust_arc_decrement_strong_count_CancellationToken;
 
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_KvCachedChartStoragePtr;
+  get rust_arc_decrement_strong_count_CancellationTokenPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -559,93 +426,116 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  ArcVecF32 crateApiTypesAudioAudioDataAutoAccessorGetSamples({
-    required AudioData that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineAdd({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required List<int> audioData,
+    int? trackId,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+          sse_encode_String(filePath, serializer);
+          sse_encode_list_prim_u_8_loose(audioData, serializer);
+          sse_encode_opt_box_autoadd_u_32(trackId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesAudioAudioDataAutoAccessorGetSamplesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddConstMeta,
+        argValues: [that, filePath, audioData, trackId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiTypesAudioAudioDataAutoAccessorGetSamplesConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineAddConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioData_auto_accessor_get_samples",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_add",
+        argNames: ["that", "filePath", "audioData", "trackId"],
       );
 
   @override
-  void crateApiTypesAudioAudioDataAutoAccessorSetSamples({
-    required AudioData that,
-    required ArcVecF32 samples,
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineAddAllTracks({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required AudioSource source,
+    required CancellationToken token,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-            samples,
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_audio_source(source, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+            token,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 2,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesAudioAudioDataAutoAccessorSetSamplesConstMeta,
-        argValues: [that, samples],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddAllTracksConstMeta,
+        argValues: [that, filePath, source, token],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiTypesAudioAudioDataAutoAccessorSetSamplesConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineAddAllTracksConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioData_auto_accessor_set_samples",
-        argNames: ["that", "samples"],
+        debugName: "AudioProcessorEngine_add_all_tracks",
+        argNames: ["that", "filePath", "source", "token"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineAdd({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddCancellable({
     required AudioProcessorEngine that,
     required String filePath,
-    required String format,
     required List<int> audioData,
+    int? trackId,
+    required CancellationToken token,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
           sse_encode_String(filePath, serializer);
-          sse_encode_String(format, serializer);
           sse_encode_list_prim_u_8_loose(audioData, serializer);
+          sse_encode_opt_box_autoadd_u_32(trackId, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+            token,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -657,35 +547,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddConstMeta,
-        argValues: [that, filePath, format, audioData],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineAddCancellableConstMeta,
+        argValues: [that, filePath, audioData, trackId, token],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineAddConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineAddCancellableConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_add",
-        argNames: ["that", "filePath", "format", "audioData"],
+        debugName: "AudioProcessorEngine_add_cancellable",
+        argNames: ["that", "filePath", "audioData", "trackId", "token"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineAddChart({
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineAddChannelCharts({
     required AudioProcessorEngine that,
     required String filePath,
-    required DataType dataType,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
           sse_encode_String(filePath, serializer);
-          sse_encode_data_type(dataType, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -694,51 +584,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddChartConstMeta,
-        argValues: [that, filePath, dataType],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineAddChannelChartsConstMeta,
+        argValues: [that, filePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineAddChartConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineAddChannelChartsConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_add_chart",
-        argNames: ["that", "filePath", "dataType"],
+        debugName: "AudioProcessorEngine_add_channel_charts",
+        argNames: ["that", "filePath"],
       );
 
   @override
-  Future<AudioProcessorEngine> crateApiCoreEngineAudioProcessorEngineNew({
-    required Config config,
-    required BoxAudioDecoder decoder,
-    required BoxAudioStorage storage,
-    required BoxCachedChartStorage cache,
-    required BoxCommunicator communicator,
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddChart({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required DataType dataType,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_config(config, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-            decoder,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-            storage,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-            cache,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-            communicator,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+            that,
             serializer,
           );
+          sse_encode_String(filePath, serializer);
+          sse_encode_data_type(dataType, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -747,27 +626,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineNewConstMeta,
-        argValues: [config, decoder, storage, cache, communicator],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddChartConstMeta,
+        argValues: [that, filePath, dataType],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineNewConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineAddChartConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_new",
-        argNames: ["config", "decoder", "storage", "cache", "communicator"],
+        debugName: "AudioProcessorEngine_add_chart",
+        argNames: ["that", "filePath", "dataType"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveAudio({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddFromPath({
     required AudioProcessorEngine that,
     required String filePath,
+    int? trackId,
+    required CancellationToken token,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -778,6 +658,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_String(filePath, serializer);
+          sse_encode_opt_box_autoadd_u_32(trackId, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+            token,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -789,25 +674,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineRemoveAudioConstMeta,
-        argValues: [that, filePath],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddFromPathConstMeta,
+        argValues: [that, filePath, trackId, token],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineRemoveAudioConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineAddFromPathConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_remove_audio",
-        argNames: ["that", "filePath"],
+        debugName: "AudioProcessorEngine_add_from_path",
+        argNames: ["that", "filePath", "trackId", "token"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveChart({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddRawPcm({
     required AudioProcessorEngine that,
     required String filePath,
-    required DataType dataType,
+    required AudioSource source,
+    required RawPcmFormat format,
+    required CancellationToken token,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -818,7 +705,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_String(filePath, serializer);
-          sse_encode_data_type(dataType, serializer);
+          sse_encode_box_autoadd_audio_source(source, serializer);
+          sse_encode_box_autoadd_raw_pcm_format(format, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+            token,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -830,34 +722,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineRemoveChartConstMeta,
-        argValues: [that, filePath, dataType],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddRawPcmConstMeta,
+        argValues: [that, filePath, source, format, token],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineRemoveChartConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineAddRawPcmConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_remove_chart",
-        argNames: ["that", "filePath", "dataType"],
+        debugName: "AudioProcessorEngine_add_raw_pcm",
+        argNames: ["that", "filePath", "source", "format", "token"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineReserveVisible({
+  Future<void> crateApiCoreEngineAudioProcessorEngineAddViewport({
     required AudioProcessorEngine that,
-    required String chartName,
+    required String id,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_String(chartName, serializer);
+          sse_encode_String(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -867,37 +758,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_app_error,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiCoreEngineAudioProcessorEngineReserveVisibleConstMeta,
-        argValues: [that, chartName],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineAddViewportConstMeta,
+        argValues: [that, id],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineReserveVisibleConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineAddViewportConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_reserve_visible",
-        argNames: ["that", "chartName"],
+        debugName: "AudioProcessorEngine_add_viewport",
+        argNames: ["that", "id"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetConfig({
+  Future<DetectedFormat> crateApiCoreEngineAudioProcessorEngineDetectFormat({
     required AudioProcessorEngine that,
-    required Config config,
+    required AudioSource source,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_box_autoadd_config(config, serializer);
+          sse_encode_box_autoadd_audio_source(source, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -906,36 +796,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_detected_format,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiCoreEngineAudioProcessorEngineSetConfigConstMeta,
-        argValues: [that, config],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineDetectFormatConstMeta,
+        argValues: [that, source],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineSetConfigConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineDetectFormatConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_set_config",
-        argNames: ["that", "config"],
+        debugName: "AudioProcessorEngine_detect_format",
+        argNames: ["that", "source"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNum({
+  Future<Uint8List> crateApiCoreEngineAudioProcessorEngineExport({
     required AudioProcessorEngine that,
-    required BigInt pointsNum,
+    required String key,
+    required ExportOptions options,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_usize(pointsNum, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -944,40 +837,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta:
-            kCrateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNumConstMeta,
-        argValues: [that, pointsNum],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineExportConstMeta,
+        argValues: [that, key, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNumConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineExportConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_set_down_sample_points_num",
-        argNames: ["that", "pointsNum"],
+        debugName: "AudioProcessorEngine_export",
+        argNames: ["that", "key", "options"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetIndexRange({
+  Future<void> crateApiCoreEngineAudioProcessorEngineExportToPath({
     required AudioProcessorEngine that,
-    required double start,
-    required double end,
+    required String key,
+    required String path,
+    required ExportOptions options,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_f_32(start, serializer);
-          sse_encode_f_32(end, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -987,37 +880,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta:
-            kCrateApiCoreEngineAudioProcessorEngineSetIndexRangeConstMeta,
-        argValues: [that, start, end],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineExportToPathConstMeta,
+        argValues: [that, key, path, options],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineSetIndexRangeConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineExportToPathConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_set_index_range",
-        argNames: ["that", "start", "end"],
+        debugName: "AudioProcessorEngine_export_to_path",
+        argNames: ["that", "key", "path", "options"],
       );
 
   @override
-  Future<void> crateApiCoreEngineAudioProcessorEngineSetSelectedAudio({
+  Future<AudioInfo> crateApiCoreEngineAudioProcessorEngineGetAudioInfo({
     required AudioProcessorEngine that,
-    String? chartName,
+    required String filePath,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_opt_String(chartName, serializer);
+          sse_encode_String(filePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1026,34 +918,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_audio_info,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta:
-            kCrateApiCoreEngineAudioProcessorEngineSetSelectedAudioConstMeta,
-        argValues: [that, chartName],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineGetAudioInfoConstMeta,
+        argValues: [that, filePath],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiCoreEngineAudioProcessorEngineSetSelectedAudioConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineGetAudioInfoConstMeta =>
       const TaskConstMeta(
-        debugName: "AudioProcessorEngine_set_selected_audio",
-        argNames: ["that", "chartName"],
+        debugName: "AudioProcessorEngine_get_audio_info",
+        argNames: ["that", "filePath"],
       );
 
   @override
-  Future<Chart> crateApiTypesAudioAudioAudioToChart({required Audio that}) {
+  Future<DecodeReport> crateApiCoreEngineAudioProcessorEngineGetDecodeReport({
+    required AudioProcessorEngine that,
+    required String filePath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
+          sse_encode_String(filePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1062,627 +957,781 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_decode_report,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesAudioAudioAudioToChartConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineGetDecodeReportConstMeta,
+        argValues: [that, filePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesAudioAudioAudioToChartConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineGetDecodeReportConstMeta =>
       const TaskConstMeta(
-        debugName: "Audio_audio_to_chart",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_get_decode_report",
+        argNames: ["that", "filePath"],
       );
 
   @override
-  AudioData crateApiTypesAudioAudioAutoAccessorGetData({required Audio that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<List<TrackInfo>> crateApiCoreEngineAudioProcessorEngineListTracks({
+    required AudioProcessorEngine that,
+    required AudioSource source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          sse_encode_box_autoadd_audio_source(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_track_info,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesAudioAudioAutoAccessorGetDataConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineListTracksConstMeta,
+        argValues: [that, source],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesAudioAudioAutoAccessorGetDataConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineListTracksConstMeta =>
       const TaskConstMeta(
-        debugName: "Audio_auto_accessor_get_data",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_list_tracks",
+        argNames: ["that", "source"],
       );
 
   @override
-  AudioInfo crateApiTypesAudioAudioAutoAccessorGetInfo({required Audio that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<MemoryUsage> crateApiCoreEngineAudioProcessorEngineMemoryUsage({
+    required AudioProcessorEngine that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_audio_info,
+          decodeSuccessData: sse_decode_memory_usage,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesAudioAudioAutoAccessorGetInfoConstMeta,
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineMemoryUsageConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesAudioAudioAutoAccessorGetInfoConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineMemoryUsageConstMeta =>
       const TaskConstMeta(
-        debugName: "Audio_auto_accessor_get_info",
+        debugName: "AudioProcessorEngine_memory_usage",
         argNames: ["that"],
       );
 
   @override
-  void crateApiTypesAudioAudioAutoAccessorSetData({
-    required Audio that,
-    required AudioData data,
+  Future<AudioProcessorEngine> crateApiCoreEngineAudioProcessorEngineNew({
+    required Config config,
+    required BoxAudioDecoder decoder,
+    required BoxAudioStorage storage,
+    required BoxCachedChartStorage cache,
+    required BoxCommunicator communicator,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-            that,
+          sse_encode_box_autoadd_config(config, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
+            decoder,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
+            storage,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
+            cache,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
+            communicator,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-            data,
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 16,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesAudioAudioAutoAccessorSetDataConstMeta,
-        argValues: [that, data],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineNewConstMeta,
+        argValues: [config, decoder, storage, cache, communicator],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesAudioAudioAutoAccessorSetDataConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineNewConstMeta =>
       const TaskConstMeta(
-        debugName: "Audio_auto_accessor_set_data",
-        argNames: ["that", "data"],
+        debugName: "AudioProcessorEngine_new",
+        argNames: ["config", "decoder", "storage", "cache", "communicator"],
       );
 
   @override
-  void crateApiTypesAudioAudioAutoAccessorSetInfo({
-    required Audio that,
-    required AudioInfo info,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveAudio({
+    required AudioProcessorEngine that,
+    required String filePath,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_audio_info(info, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesAudioAudioAutoAccessorSetInfoConstMeta,
-        argValues: [that, info],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineRemoveAudioConstMeta,
+        argValues: [that, filePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesAudioAudioAutoAccessorSetInfoConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineRemoveAudioConstMeta =>
       const TaskConstMeta(
-        debugName: "Audio_auto_accessor_set_info",
-        argNames: ["that", "info"],
+        debugName: "AudioProcessorEngine_remove_audio",
+        argNames: ["that", "filePath"],
       );
 
   @override
-  Chart crateApiTypesChartChartWIthKeyAutoAccessorGetChart({
-    required ChartWIthKey that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveChart({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required DataType dataType,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          sse_encode_String(filePath, serializer);
+          sse_encode_data_type(dataType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTypesChartChartWIthKeyAutoAccessorGetChartConstMeta,
-        argValues: [that],
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_app_error,
+        ),
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineRemoveChartConstMeta,
+        argValues: [that, filePath, dataType],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiTypesChartChartWIthKeyAutoAccessorGetChartConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineRemoveChartConstMeta =>
       const TaskConstMeta(
-        debugName: "ChartWIthKey_auto_accessor_get_chart",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_remove_chart",
+        argNames: ["that", "filePath", "dataType"],
       );
 
   @override
-  String crateApiTypesChartChartWIthKeyAutoAccessorGetKey({
-    required ChartWIthKey that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineRemoveViewport({
+    required AudioProcessorEngine that,
+    required String id,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesChartChartWIthKeyAutoAccessorGetKeyConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineRemoveViewportConstMeta,
+        argValues: [that, id],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiTypesChartChartWIthKeyAutoAccessorGetKeyConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineRemoveViewportConstMeta =>
       const TaskConstMeta(
-        debugName: "ChartWIthKey_auto_accessor_get_key",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_remove_viewport",
+        argNames: ["that", "id"],
       );
 
   @override
-  void crateApiTypesChartChartWIthKeyAutoAccessorSetChart({
-    required ChartWIthKey that,
-    required Chart chart,
+  Future<void> crateApiCoreEngineAudioProcessorEngineResendChart({
+    required AudioProcessorEngine that,
+    required String viewport,
+    required String filePath,
+    required DataType dataType,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-            chart,
+          sse_encode_String(viewport, serializer);
+          sse_encode_String(filePath, serializer);
+          sse_encode_data_type(dataType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 20,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesChartChartWIthKeyAutoAccessorSetChartConstMeta,
-        argValues: [that, chart],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineResendChartConstMeta,
+        argValues: [that, viewport, filePath, dataType],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiTypesChartChartWIthKeyAutoAccessorSetChartConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineResendChartConstMeta =>
       const TaskConstMeta(
-        debugName: "ChartWIthKey_auto_accessor_set_chart",
-        argNames: ["that", "chart"],
+        debugName: "AudioProcessorEngine_resend_chart",
+        argNames: ["that", "viewport", "filePath", "dataType"],
       );
 
   @override
-  void crateApiTypesChartChartWIthKeyAutoAccessorSetKey({
-    required ChartWIthKey that,
-    required String key,
+  Future<void> crateApiCoreEngineAudioProcessorEngineReserveVisible({
+    required AudioProcessorEngine that,
+    required String chartName,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_String(key, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          sse_encode_String(chartName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesChartChartWIthKeyAutoAccessorSetKeyConstMeta,
-        argValues: [that, key],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineReserveVisibleConstMeta,
+        argValues: [that, chartName],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiTypesChartChartWIthKeyAutoAccessorSetKeyConstMeta =>
+  get kCrateApiCoreEngineAudioProcessorEngineReserveVisibleConstMeta =>
       const TaskConstMeta(
-        debugName: "ChartWIthKey_auto_accessor_set_key",
-        argNames: ["that", "key"],
+        debugName: "AudioProcessorEngine_reserve_visible",
+        argNames: ["that", "chartName"],
       );
 
   @override
-  DataType crateApiTypesChartChartAutoAccessorGetDataType({
-    required Chart that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetChannelMix({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required ChannelMix mix,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_channel_mix(mix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_data_type,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorGetDataTypeConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetChannelMixConstMeta,
+        argValues: [that, filePath, mix],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorGetDataTypeConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetChannelMixConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_get_data_type",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_set_channel_mix",
+        argNames: ["that", "filePath", "mix"],
       );
 
   @override
-  double crateApiTypesChartChartAutoAccessorGetMaxY({required Chart that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetChartDownSampleMethod({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required DataType dataType,
+    DownSampleMethod? method,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          sse_encode_String(filePath, serializer);
+          sse_encode_data_type(dataType, serializer);
+          sse_encode_opt_box_autoadd_down_sample_method(method, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorGetMaxYConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetChartDownSampleMethodConstMeta,
+        argValues: [that, filePath, dataType, method],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorGetMaxYConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetChartDownSampleMethodConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_get_max_y",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_set_chart_down_sample_method",
+        argNames: ["that", "filePath", "dataType", "method"],
       );
 
   @override
-  double crateApiTypesChartChartAutoAccessorGetMinY({required Chart that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetConfig({
+    required AudioProcessorEngine that,
+    required Config config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          sse_encode_box_autoadd_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_f_32,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorGetMinYConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineSetConfigConstMeta,
+        argValues: [that, config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorGetMinYConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineSetConfigConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_get_min_y",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_set_config",
+        argNames: ["that", "config"],
       );
 
   @override
-  ArcVecPoint crateApiTypesChartChartAutoAccessorGetPoints({
-    required Chart that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSampleMethod({
+    required AudioProcessorEngine that,
+    required DataType dataType,
+    required DownSampleMethod method,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          sse_encode_data_type(dataType, serializer);
+          sse_encode_down_sample_method(method, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorGetPointsConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetDownSampleMethodConstMeta,
+        argValues: [that, dataType, method],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorGetPointsConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetDownSampleMethodConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_get_points",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_set_down_sample_method",
+        argNames: ["that", "dataType", "method"],
       );
 
   @override
-  ArcAtomicBool crateApiTypesChartChartAutoAccessorGetVisible({
-    required Chart that,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNum({
+    required AudioProcessorEngine that,
+    required BigInt pointsNum,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+          sse_encode_usize(pointsNum, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorGetVisibleConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNumConstMeta,
+        argValues: [that, pointsNum],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorGetVisibleConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetDownSamplePointsNumConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_get_visible",
-        argNames: ["that"],
+        debugName: "AudioProcessorEngine_set_down_sample_points_num",
+        argNames: ["that", "pointsNum"],
       );
 
   @override
-  void crateApiTypesChartChartAutoAccessorSetDataType({
-    required Chart that,
-    required DataType dataType,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetIndexRange({
+    required AudioProcessorEngine that,
+    required double start,
+    required double end,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_data_type(dataType, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          sse_encode_f_32(start, serializer);
+          sse_encode_f_32(end, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorSetDataTypeConstMeta,
-        argValues: [that, dataType],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetIndexRangeConstMeta,
+        argValues: [that, start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorSetDataTypeConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetIndexRangeConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_set_data_type",
-        argNames: ["that", "dataType"],
+        debugName: "AudioProcessorEngine_set_index_range",
+        argNames: ["that", "start", "end"],
       );
 
   @override
-  void crateApiTypesChartChartAutoAccessorSetMaxY({
-    required Chart that,
-    required double maxY,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetMemoryBudget({
+    required AudioProcessorEngine that,
+    BigInt? audioBytes,
+    BigInt? chartBytes,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_f_32(maxY, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+          sse_encode_opt_box_autoadd_u_64(audioBytes, serializer);
+          sse_encode_opt_box_autoadd_u_64(chartBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorSetMaxYConstMeta,
-        argValues: [that, maxY],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetMemoryBudgetConstMeta,
+        argValues: [that, audioBytes, chartBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorSetMaxYConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetMemoryBudgetConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_set_max_y",
-        argNames: ["that", "maxY"],
+        debugName: "AudioProcessorEngine_set_memory_budget",
+        argNames: ["that", "audioBytes", "chartBytes"],
       );
 
   @override
-  void crateApiTypesChartChartAutoAccessorSetMinY({
-    required Chart that,
-    required double minY,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetOverviewPointsNum({
+    required AudioProcessorEngine that,
+    required BigInt pointsNum,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_f_32(minY, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          sse_encode_usize(pointsNum, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorSetMinYConstMeta,
-        argValues: [that, minY],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetOverviewPointsNumConstMeta,
+        argValues: [that, pointsNum],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorSetMinYConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetOverviewPointsNumConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_set_min_y",
-        argNames: ["that", "minY"],
+        debugName: "AudioProcessorEngine_set_overview_points_num",
+        argNames: ["that", "pointsNum"],
       );
 
   @override
-  void crateApiTypesChartChartAutoAccessorSetPoints({
-    required Chart that,
-    required ArcVecPoint points,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSampleStorage({
+    required AudioProcessorEngine that,
+    required SampleStorage storage,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-            points,
+          sse_encode_sample_storage(storage, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 30,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorSetPointsConstMeta,
-        argValues: [that, points],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetSampleStorageConstMeta,
+        argValues: [that, storage],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorSetPointsConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetSampleStorageConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_set_points",
-        argNames: ["that", "points"],
+        debugName: "AudioProcessorEngine_set_sample_storage",
+        argNames: ["that", "storage"],
       );
 
   @override
-  void crateApiTypesChartChartAutoAccessorSetVisible({
-    required Chart that,
-    required ArcAtomicBool visible,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSelectedAudio({
+    required AudioProcessorEngine that,
+    String? chartName,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-            visible,
+          sse_encode_opt_String(chartName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 31,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartAutoAccessorSetVisibleConstMeta,
-        argValues: [that, visible],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetSelectedAudioConstMeta,
+        argValues: [that, chartName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartAutoAccessorSetVisibleConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetSelectedAudioConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_auto_accessor_set_visible",
-        argNames: ["that", "visible"],
+        debugName: "AudioProcessorEngine_set_selected_audio",
+        argNames: ["that", "chartName"],
       );
 
   @override
-  Future<Chart> crateApiTypesChartChartGetRange({
-    required Chart that,
-    required double startX,
-    required double endX,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetSincInterpolation({
+    required AudioProcessorEngine that,
+    required bool enabled,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_f_32(startX, serializer);
-          sse_encode_f_32(endX, serializer);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1691,597 +1740,524 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesChartChartGetRangeConstMeta,
-        argValues: [that, startX, endX],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetSincInterpolationConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesChartChartGetRangeConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetSincInterpolationConstMeta =>
       const TaskConstMeta(
-        debugName: "Chart_get_range",
-        argNames: ["that", "startX", "endX"],
+        debugName: "AudioProcessorEngine_set_sinc_interpolation",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  ArcVecU8 crateApiTypesFileFileAutoAccessorGetBytes({required File that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTypesFileFileAutoAccessorGetBytesConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTypesFileFileAutoAccessorGetBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "File_auto_accessor_get_bytes",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateApiTypesFileFileAutoAccessorGetFilePath({required File that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTypesFileFileAutoAccessorGetFilePathConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTypesFileFileAutoAccessorGetFilePathConstMeta =>
-      const TaskConstMeta(
-        debugName: "File_auto_accessor_get_file_path",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiTypesFileFileAutoAccessorSetBytes({
-    required File that,
-    required ArcVecU8 bytes,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportChartVisible({
+    required AudioProcessorEngine that,
+    required String id,
+    required String filePath,
+    required DataType dataType,
+    required bool visible,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-            bytes,
+          sse_encode_String(id, serializer);
+          sse_encode_String(filePath, serializer);
+          sse_encode_data_type(dataType, serializer);
+          sse_encode_bool(visible, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 33,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesFileFileAutoAccessorSetBytesConstMeta,
-        argValues: [that, bytes],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetViewportChartVisibleConstMeta,
+        argValues: [that, id, filePath, dataType, visible],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesFileFileAutoAccessorSetBytesConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetViewportChartVisibleConstMeta =>
       const TaskConstMeta(
-        debugName: "File_auto_accessor_set_bytes",
-        argNames: ["that", "bytes"],
+        debugName: "AudioProcessorEngine_set_viewport_chart_visible",
+        argNames: ["that", "id", "filePath", "dataType", "visible"],
       );
 
   @override
-  void crateApiTypesFileFileAutoAccessorSetFilePath({
-    required File that,
-    required String filePath,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportPointsNum({
+    required AudioProcessorEngine that,
+    required String id,
+    required BigInt pointsNum,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_String(filePath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+          sse_encode_String(id, serializer);
+          sse_encode_usize(pointsNum, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiTypesFileFileAutoAccessorSetFilePathConstMeta,
-        argValues: [that, filePath],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetViewportPointsNumConstMeta,
+        argValues: [that, id, pointsNum],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesFileFileAutoAccessorSetFilePathConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetViewportPointsNumConstMeta =>
       const TaskConstMeta(
-        debugName: "File_auto_accessor_set_file_path",
-        argNames: ["that", "filePath"],
+        debugName: "AudioProcessorEngine_set_viewport_points_num",
+        argNames: ["that", "id", "pointsNum"],
       );
 
   @override
-  Future<Audio> crateApiStorageKvAudioStorageKvAudioStorageLoad({
-    required KvAudioStorage that,
-    required String key,
+  Future<void> crateApiCoreEngineAudioProcessorEngineSetViewportRange({
+    required AudioProcessorEngine that,
+    required String id,
+    required double start,
+    required double end,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_String(key, serializer);
+          sse_encode_String(id, serializer);
+          sse_encode_f_32(start, serializer);
+          sse_encode_f_32(end, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiStorageKvAudioStorageKvAudioStorageLoadConstMeta,
-        argValues: [that, key],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineSetViewportRangeConstMeta,
+        argValues: [that, id, start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStorageKvAudioStorageKvAudioStorageLoadConstMeta =>
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineSetViewportRangeConstMeta =>
       const TaskConstMeta(
-        debugName: "KvAudioStorage_load",
-        argNames: ["that", "key"],
+        debugName: "AudioProcessorEngine_set_viewport_range",
+        argNames: ["that", "id", "start", "end"],
       );
 
   @override
-  Future<KvAudioStorage> crateApiStorageKvAudioStorageKvAudioStorageNew() {
+  Future<List<String>> crateApiCoreEngineAudioProcessorEngineStoredAudioKeys({
+    required AudioProcessorEngine that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiStorageKvAudioStorageKvAudioStorageNewConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiCoreEngineAudioProcessorEngineStoredAudioKeysConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStorageKvAudioStorageKvAudioStorageNewConstMeta =>
-      const TaskConstMeta(debugName: "KvAudioStorage_new", argNames: []);
+  TaskConstMeta
+  get kCrateApiCoreEngineAudioProcessorEngineStoredAudioKeysConstMeta =>
+      const TaskConstMeta(
+        debugName: "AudioProcessorEngine_stored_audio_keys",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiStorageKvAudioStorageKvAudioStorageRemove({
-    required KvAudioStorage that,
-    required String key,
+  Future<TruePeak> crateApiCoreEngineAudioProcessorEngineTruePeak({
+    required AudioProcessorEngine that,
+    required String filePath,
+    required double start,
+    required double end,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
             that,
             serializer,
           );
-          sse_encode_String(key, serializer);
+          sse_encode_String(filePath, serializer);
+          sse_encode_f_32(start, serializer);
+          sse_encode_f_32(end, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 37,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_true_peak,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta: kCrateApiStorageKvAudioStorageKvAudioStorageRemoveConstMeta,
-        argValues: [that, key],
+        constMeta: kCrateApiCoreEngineAudioProcessorEngineTruePeakConstMeta,
+        argValues: [that, filePath, start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvAudioStorageKvAudioStorageRemoveConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineAudioProcessorEngineTruePeakConstMeta =>
       const TaskConstMeta(
-        debugName: "KvAudioStorage_remove",
-        argNames: ["that", "key"],
+        debugName: "AudioProcessorEngine_true_peak",
+        argNames: ["that", "filePath", "start", "end"],
       );
 
   @override
-  Future<void> crateApiStorageKvAudioStorageKvAudioStorageSave({
-    required KvAudioStorage that,
-    required String key,
-    required Audio storageUnit,
+  Future<void> crateApiTypesDecodeCancellationTokenCancel({
+    required CancellationToken that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
             that,
             serializer,
           );
-          sse_encode_String(key, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-            storageUnit,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_app_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiStorageKvAudioStorageKvAudioStorageSaveConstMeta,
-        argValues: [that, key, storageUnit],
+        constMeta: kCrateApiTypesDecodeCancellationTokenCancelConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStorageKvAudioStorageKvAudioStorageSaveConstMeta =>
+  TaskConstMeta get kCrateApiTypesDecodeCancellationTokenCancelConstMeta =>
       const TaskConstMeta(
-        debugName: "KvAudioStorage_save",
-        argNames: ["that", "key", "storageUnit"],
+        debugName: "CancellationToken_cancel",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiStorageKvCachedChartStorageKvCachedChartStorageAdd({
-    required KvCachedChartStorage that,
-    required String key,
-    required Chart chart,
+  Future<bool> crateApiTypesDecodeCancellationTokenIsCancelled({
+    required CancellationToken that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
             that,
             serializer,
           );
-          sse_encode_String(key, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-            chart,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_app_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAddConstMeta,
-        argValues: [that, key, chart],
+        constMeta: kCrateApiTypesDecodeCancellationTokenIsCancelledConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAddConstMeta =>
+  TaskConstMeta get kCrateApiTypesDecodeCancellationTokenIsCancelledConstMeta =>
       const TaskConstMeta(
-        debugName: "KvCachedChartStorage_add",
-        argNames: ["that", "key", "chart"],
+        debugName: "CancellationToken_is_cancelled",
+        argNames: ["that"],
       );
 
   @override
-  Config
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorGetConfig({
-    required KvCachedChartStorage that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CancellationToken> crateApiTypesDecodeCancellationTokenNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-            that,
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 40,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_config,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorGetConfigConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTypesDecodeCancellationTokenNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorGetConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "KvCachedChartStorage_auto_accessor_get_config",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTypesDecodeCancellationTokenNewConstMeta =>
+      const TaskConstMeta(debugName: "CancellationToken_new", argNames: []);
 
   @override
-  void
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorSetConfig({
-    required KvCachedChartStorage that,
-    required Config config,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<DecodeOptions> crateApiTypesDecodeDecodeOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-            that,
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 41,
+            port: port_,
           );
-          sse_encode_config(config, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_decode_options,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorSetConfigConstMeta,
-        argValues: [that, config],
+        constMeta: kCrateApiTypesDecodeDecodeOptionsDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorSetConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "KvCachedChartStorage_auto_accessor_set_config",
-        argNames: ["that", "config"],
-      );
+  TaskConstMeta get kCrateApiTypesDecodeDecodeOptionsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "DecodeOptions_default", argNames: []);
 
   @override
-  Future<Chart> crateApiStorageKvCachedChartStorageKvCachedChartStorageGet({
-    required KvCachedChartStorage that,
-    required String key,
-    required DataType dataType,
-  }) {
+  Future<DecodeProgress> crateApiTypesDecodeDecodeProgressDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_data_type(dataType, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 42,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: sse_decode_app_error,
+          decodeSuccessData: sse_decode_decode_progress,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageGetConstMeta,
-        argValues: [that, key, dataType],
+        constMeta: kCrateApiTypesDecodeDecodeProgressDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageGetConstMeta =>
-      const TaskConstMeta(
-        debugName: "KvCachedChartStorage_get",
-        argNames: ["that", "key", "dataType"],
-      );
+  TaskConstMeta get kCrateApiTypesDecodeDecodeProgressDefaultConstMeta =>
+      const TaskConstMeta(debugName: "DecodeProgress_default", argNames: []);
 
   @override
-  Future<List<ChartWIthKey>>
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageGetAllCache({
-    required KvCachedChartStorage that,
-  }) {
+  Future<DecodeReport> crateApiTypesDecodeDecodeReportDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-            that,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 43,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey,
-          decodeErrorData: sse_decode_app_error,
+          decodeSuccessData: sse_decode_decode_report,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageGetAllCacheConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTypesDecodeDecodeReportDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageGetAllCacheConstMeta =>
-      const TaskConstMeta(
-        debugName: "KvCachedChartStorage_get_all_cache",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTypesDecodeDecodeReportDefaultConstMeta =>
+      const TaskConstMeta(debugName: "DecodeReport_default", argNames: []);
 
   @override
-  Future<KvCachedChartStorage>
-  crateApiStorageKvCachedChartStorageKvCachedChartStorageNew() {
+  Future<bool> crateApiTypesDecodeDecodeReportIsClean({
+    required DecodeReport that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_decode_report(that, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 44,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTypesDecodeDecodeReportIsCleanConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageNewConstMeta =>
-      const TaskConstMeta(debugName: "KvCachedChartStorage_new", argNames: []);
+  TaskConstMeta get kCrateApiTypesDecodeDecodeReportIsCleanConstMeta =>
+      const TaskConstMeta(
+        debugName: "DecodeReport_is_clean",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiStorageKvCachedChartStorageKvCachedChartStorageRemove({
-    required KvCachedChartStorage that,
-    required String key,
-    required DataType dataType,
+  Future<void> crateApiTypesDecodeDecodeReportMark({
+    required DecodeReport that,
+    required BigInt startFrame,
+    required BigInt endFrame,
+    required int sampleRate,
+    required String reason,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_data_type(dataType, serializer);
+          sse_encode_box_autoadd_decode_report(that, serializer);
+          sse_encode_u_64(startFrame, serializer);
+          sse_encode_u_64(endFrame, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          sse_encode_String(reason, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 45,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_app_error,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiStorageKvCachedChartStorageKvCachedChartStorageRemoveConstMeta,
-        argValues: [that, key, dataType],
+        constMeta: kCrateApiTypesDecodeDecodeReportMarkConstMeta,
+        argValues: [that, startFrame, endFrame, sampleRate, reason],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiStorageKvCachedChartStorageKvCachedChartStorageRemoveConstMeta =>
+  TaskConstMeta get kCrateApiTypesDecodeDecodeReportMarkConstMeta =>
       const TaskConstMeta(
-        debugName: "KvCachedChartStorage_remove",
-        argNames: ["that", "key", "dataType"],
+        debugName: "DecodeReport_mark",
+        argNames: ["that", "startFrame", "endFrame", "sampleRate", "reason"],
       );
 
   @override
-  Future<Config> crateApiTypesConfigConfigDefault() {
+  Future<BigInt> crateApiTypesDecodeRawSampleFormatBytesPerSample({
+    required RawSampleFormat that,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_raw_sample_format(that, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_config,
+          decodeSuccessData: sse_decode_usize,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTypesConfigConfigDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTypesDecodeRawSampleFormatBytesPerSampleConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTypesConfigConfigDefaultConstMeta =>
-      const TaskConstMeta(debugName: "config_default", argNames: []);
+  TaskConstMeta
+  get kCrateApiTypesDecodeRawSampleFormatBytesPerSampleConstMeta =>
+      const TaskConstMeta(
+        debugName: "RawSampleFormat_bytes_per_sample",
+        argNames: ["that"],
+      );
 
   @override
   Stream<ChartEvent> crateApiEventsCommunicatorEventsCreateChartEventStream() {
@@ -2295,7 +2271,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 66,
+              funcId: 47,
               port: port_,
             );
           },
@@ -2321,547 +2297,127 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<AudioProcessorEngine> crateApiCoreEngineCreateDefaultEngine({
-    required Config config,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_config(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 67,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine,
-          decodeErrorData: null,
+  Stream<DecodeEvent> crateApiEventsDecodeEventsCreateDecodeEventStream() {
+    final sink = RustStreamSink<DecodeEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_decode_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 48,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta:
+              kCrateApiEventsDecodeEventsCreateDecodeEventStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiCoreEngineCreateDefaultEngineConstMeta,
-        argValues: [config],
-        apiImpl: this,
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCoreEngineCreateDefaultEngineConstMeta =>
+  TaskConstMeta
+  get kCrateApiEventsDecodeEventsCreateDecodeEventStreamConstMeta =>
       const TaskConstMeta(
-        debugName: "create_default_engine",
-        argNames: ["config"],
+        debugName: "create_decode_event_stream",
+        argNames: ["sink"],
       );
 
   @override
-  Future<void> crateApiEventsCommunicatorEventsEmitChartEvent({
-    required ChartEvent event,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_chart_event(event, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 68,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEventsCommunicatorEventsEmitChartEventConstMeta,
-        argValues: [event],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEventsCommunicatorEventsEmitChartEventConstMeta =>
-      const TaskConstMeta(debugName: "emit_chart_event", argNames: ["event"]);
-
-  @override
-  Future<Chart> crateApiTransformEnergyEnergyCalculatorTransform({
-    required EnergyCalculator that,
-    required Audio data,
+  Future<AudioProcessorEngine> crateApiCoreEngineCreateDefaultEngine({
     required Config config,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_energy_calculator(that, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-            data,
-            serializer,
-          );
           sse_encode_box_autoadd_config(config, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: sse_decode_app_error,
-        ),
-        constMeta: kCrateApiTransformEnergyEnergyCalculatorTransformConstMeta,
-        argValues: [that, data, config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTransformEnergyEnergyCalculatorTransformConstMeta =>
-      const TaskConstMeta(
-        debugName: "energy_calculator_transform",
-        argNames: ["that", "data", "config"],
-      );
-
-  @override
-  Future<Chart> crateApiSamplingEqualStepEqualStepDownSample({
-    required EqualStep that,
-    required Chart chart,
-    required BigInt targetPointsNum,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_equal_step(that, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-            chart,
-            serializer,
-          );
-          sse_encode_usize(targetPointsNum, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 70,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSamplingEqualStepEqualStepDownSampleConstMeta,
-        argValues: [that, chart, targetPointsNum],
+        constMeta: kCrateApiCoreEngineCreateDefaultEngineConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSamplingEqualStepEqualStepDownSampleConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineCreateDefaultEngineConstMeta =>
       const TaskConstMeta(
-        debugName: "equal_step_down_sample",
-        argNames: ["that", "chart", "targetPointsNum"],
+        debugName: "create_default_engine",
+        argNames: ["config"],
       );
 
   @override
-  Future<Chart> crateApiTransformFftFftTransformTransform({
-    required FftTransform that,
-    required Audio data,
+  Future<AudioProcessorEngine> crateApiCoreEngineCreateEngine({
     required Config config,
+    required AudioStorageBackend storage,
+    required ChartCacheBackend cache,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_fft_transform(that, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-            data,
-            serializer,
-          );
           sse_encode_box_autoadd_config(config, serializer);
+          sse_encode_box_autoadd_audio_storage_backend(storage, serializer);
+          sse_encode_box_autoadd_chart_cache_backend(cache, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: sse_decode_app_error,
-        ),
-        constMeta: kCrateApiTransformFftFftTransformTransformConstMeta,
-        argValues: [that, data, config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTransformFftFftTransformTransformConstMeta =>
-      const TaskConstMeta(
-        debugName: "fft_transform_transform",
-        argNames: ["that", "data", "config"],
-      );
-
-  @override
-  Future<(double, double)> crateApiUtilGetMinMaxGetMinMaxPar({
-    required List<Point> points,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_point(points, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 72,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_record_f_32_f_32,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUtilGetMinMaxGetMinMaxParConstMeta,
-        argValues: [points],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUtilGetMinMaxGetMinMaxParConstMeta =>
-      const TaskConstMeta(debugName: "get_min_max_par", argNames: ["points"]);
-
-  @override
-  Future<Chart> crateApiSamplingMinmaxMinmaxDownSample({
-    required Minmax that,
-    required Chart chart,
-    required BigInt targetPointsNum,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_minmax(that, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-            chart,
-            serializer,
-          );
-          sse_encode_usize(targetPointsNum, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 73,
+            funcId: 50,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSamplingMinmaxMinmaxDownSampleConstMeta,
-        argValues: [that, chart, targetPointsNum],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSamplingMinmaxMinmaxDownSampleConstMeta =>
-      const TaskConstMeta(
-        debugName: "minmax_down_sample",
-        argNames: ["that", "chart", "targetPointsNum"],
-      );
-
-  @override
-  Future<String> crateApiUtilFormatGetterSimpleFormatGetterGetFormat({
-    required SimpleFormatGetter that,
-    required String filePath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_simple_format_getter(that, serializer);
-          sse_encode_String(filePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 74,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine,
           decodeErrorData: sse_decode_app_error,
         ),
-        constMeta:
-            kCrateApiUtilFormatGetterSimpleFormatGetterGetFormatConstMeta,
-        argValues: [that, filePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiUtilFormatGetterSimpleFormatGetterGetFormatConstMeta =>
-      const TaskConstMeta(
-        debugName: "simple_format_getter_get_format",
-        argNames: ["that", "filePath"],
-      );
-
-  @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorAddChart({
-    required StreamCommunicator that,
-    required String key,
-    required Chart chart,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          sse_encode_String(key, serializer);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-            chart,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 75,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorAddChartConstMeta,
-        argValues: [that, key, chart],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorAddChartConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_communicator_add_chart",
-        argNames: ["that", "key", "chart"],
-      );
-
-  @override
-  Future<StreamCommunicator>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorNew() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 76,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_stream_communicator,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorNewConstMeta =>
-      const TaskConstMeta(debugName: "stream_communicator_new", argNames: []);
-
-  @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveAllCharts({
-    required StreamCommunicator that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 77,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveAllChartsConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveAllChartsConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_communicator_remove_all_charts",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveChart({
-    required StreamCommunicator that,
-    required String key,
-    required DataType dataType,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          sse_encode_String(key, serializer);
-          sse_encode_data_type(dataType, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 78,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveChartConstMeta,
-        argValues: [that, key, dataType],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorRemoveChartConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_communicator_remove_chart",
-        argNames: ["that", "key", "dataType"],
-      );
-
-  @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateAllCharts({
-    required StreamCommunicator that,
-    required List<ChartWIthKey> charts,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-            charts,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 79,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateAllChartsConstMeta,
-        argValues: [that, charts],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateAllChartsConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_communicator_update_all_charts",
-        argNames: ["that", "charts"],
-      );
-
-  @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateMaxIndex({
-    required StreamCommunicator that,
-    required double maxIndex,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          sse_encode_f_32(maxIndex, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 80,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateMaxIndexConstMeta,
-        argValues: [that, maxIndex],
+        constMeta: kCrateApiCoreEngineCreateEngineConstMeta,
+        argValues: [config, storage, cache],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateMaxIndexConstMeta =>
+  TaskConstMeta get kCrateApiCoreEngineCreateEngineConstMeta =>
       const TaskConstMeta(
-        debugName: "stream_communicator_update_max_index",
-        argNames: ["that", "maxIndex"],
+        debugName: "create_engine",
+        argNames: ["config", "storage", "cache"],
       );
 
   @override
-  Future<void>
-  crateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateYRange({
-    required StreamCommunicator that,
-    required double minY,
-    required double maxY,
+  Future<void> crateApiEventsCommunicatorEventsEmitChartEvent({
+    required ChartEvent event,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_stream_communicator(that, serializer);
-          sse_encode_f_32(minY, serializer);
-          sse_encode_f_32(maxY, serializer);
+          sse_encode_box_autoadd_chart_event(event, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 51,
             port: port_,
           );
         },
@@ -2869,184 +2425,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateYRangeConstMeta,
-        argValues: [that, minY, maxY],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiCommunicatorStreamSinkCommunicatorStreamCommunicatorUpdateYRangeConstMeta =>
-      const TaskConstMeta(
-        debugName: "stream_communicator_update_y_range",
-        argNames: ["that", "minY", "maxY"],
-      );
-
-  @override
-  Future<Audio> crateApiDecoderSymphoniaDecoderSymphoniaDecoderDecode({
-    required SymphoniaDecoder that,
-    required String format,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_symphonia_decoder(that, serializer);
-          sse_encode_String(format, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 82,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio,
-          decodeErrorData: sse_decode_app_error,
-        ),
-        constMeta:
-            kCrateApiDecoderSymphoniaDecoderSymphoniaDecoderDecodeConstMeta,
-        argValues: [that, format, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiDecoderSymphoniaDecoderSymphoniaDecoderDecodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "symphonia_decoder_decode",
-        argNames: ["that", "format", "data"],
-      );
-
-  @override
-  Future<SymphoniaDecoder>
-  crateApiDecoderSymphoniaDecoderSymphoniaDecoderNew() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 83,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_symphonia_decoder,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDecoderSymphoniaDecoderSymphoniaDecoderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiEventsCommunicatorEventsEmitChartEventConstMeta,
+        argValues: [event],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDecoderSymphoniaDecoderSymphoniaDecoderNewConstMeta =>
-      const TaskConstMeta(debugName: "symphonia_decoder_new", argNames: []);
+  TaskConstMeta get kCrateApiEventsCommunicatorEventsEmitChartEventConstMeta =>
+      const TaskConstMeta(debugName: "emit_chart_event", argNames: ["event"]);
 
   @override
-  Future<Chart>
-  crateApiTransformZeroCrossingRateZeroCrossingRateCalculatorTransform({
-    required ZeroCrossingRateCalculator that,
-    required Audio data,
-    required Config config,
+  Future<void> crateApiEventsDecodeEventsEmitDecodeEvent({
+    required DecodeEvent event,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_zero_crossing_rate_calculator(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-            data,
-            serializer,
-          );
-          sse_encode_box_autoadd_config(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 84,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart,
-          decodeErrorData: sse_decode_app_error,
-        ),
-        constMeta:
-            kCrateApiTransformZeroCrossingRateZeroCrossingRateCalculatorTransformConstMeta,
-        argValues: [that, data, config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTransformZeroCrossingRateZeroCrossingRateCalculatorTransformConstMeta =>
-      const TaskConstMeta(
-        debugName: "zero_crossing_rate_calculator_transform",
-        argNames: ["that", "data", "config"],
-      );
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcAtomicBool => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcAtomicBool => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecPoint => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecPoint => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecF32 => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecF32 => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcVecU8 => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcVecU8 => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Audio => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Audio => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AudioData => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData;
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_decode_event(event, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiEventsDecodeEventsEmitDecodeEventConstMeta,
+        argValues: [event],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AudioData => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData;
+  TaskConstMeta get kCrateApiEventsDecodeEventsEmitDecodeEventConstMeta =>
+      const TaskConstMeta(debugName: "emit_decode_event", argNames: ["event"]);
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_AudioProcessorEngine => wire
@@ -3089,44 +2506,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync;
 
   RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Chart => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Chart => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ChartWIthKey => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ChartWIthKey => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_File => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_File => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_KvAudioStorage => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage;
+  get rust_arc_increment_strong_count_CancellationToken => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
 
   RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_KvAudioStorage => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_KvCachedChartStorage => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_KvCachedChartStorage => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage;
+  get rust_arc_decrement_strong_count_CancellationToken => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
 
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
@@ -3134,60 +2519,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(raw as String);
   }
 
-  @protected
-  ArcAtomicBool
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcAtomicBoolImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcVecPoint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecPointImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcVecF32
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecF32Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcVecU8
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecU8Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Audio
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AudioData
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioDataImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
   @protected
   AudioProcessorEngine
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
@@ -3234,71 +2565,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Chart
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ChartWIthKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  File
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FileImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  KvAudioStorage
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  KvCachedChartStorage
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  CancellationToken
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Audio
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AudioData
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioDataImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
   AudioProcessorEngine
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3306,62 +2583,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Chart
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ChartWIthKey
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  File
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FileImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  KvCachedChartStorage
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Audio
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AudioData
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  CancellationToken
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioDataImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
   AudioProcessorEngine
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3369,351 +2601,356 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Chart
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ChartWIthKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  File
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FileImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  KvAudioStorage
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  KvCachedChartStorage
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ArcAtomicBool
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
+  BoxAudioDecoder
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcAtomicBoolImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return BoxAudioDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  ArcVecPoint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
+  BoxAudioStorage
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecPointImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return BoxAudioStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  ArcVecF32
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
+  BoxCachedChartStorage
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecF32Impl.frbInternalDcoDecode(raw as List<dynamic>);
+    return BoxCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  ArcVecU8
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
+  BoxCommunicator
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcVecU8Impl.frbInternalDcoDecode(raw as List<dynamic>);
+    return BoxCommunicatorImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  Audio
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+  CancellationToken
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  AudioData
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  RustStreamSink<ChartEvent> dco_decode_StreamSink_chart_event_Sse(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioDataImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    throw UnimplementedError();
   }
 
   @protected
-  AudioProcessorEngine
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  RustStreamSink<DecodeEvent> dco_decode_StreamSink_decode_event_Sse(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioProcessorEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    throw UnimplementedError();
   }
 
   @protected
-  BoxAudioDecoder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    dynamic raw,
-  ) {
+  String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BoxAudioDecoderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw as String;
   }
 
   @protected
-  BoxAudioStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    dynamic raw,
-  ) {
+  AppError dco_decode_app_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BoxAudioStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    switch (raw[0]) {
+      case 0:
+        return AppError_Io(dco_decode_String(raw[1]));
+      case 1:
+        return AppError_Format(dco_decode_String(raw[1]));
+      case 2:
+        return AppError_Decode(dco_decode_String(raw[1]));
+      case 3:
+        return AppError_Storage(dco_decode_String(raw[1]));
+      case 4:
+        return AppError_Cache(dco_decode_String(raw[1]));
+      case 5:
+        return AppError_NotFound(dco_decode_String(raw[1]));
+      case 6:
+        return AppError_Generic(dco_decode_String(raw[1]));
+      case 7:
+        return AppError_ProcessingError(dco_decode_String(raw[1]));
+      case 8:
+        return AppError_InvalidChartName(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  BoxCachedChartStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-    dynamic raw,
-  ) {
+  AudioInfo dco_decode_audio_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BoxCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BoxCommunicator
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    dynamic raw,
-  ) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return AudioInfo(
+      sampleRate: dco_decode_u_32(arr[0]),
+      channels: dco_decode_u_32(arr[1]),
+      channelLayout: dco_decode_channel_layout(arr[2]),
+      codec: dco_decode_String(arr[3]),
+      container: dco_decode_String(arr[4]),
+      bitsPerSample: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      totalFrames: dco_decode_u_64(arr[6]),
+      duration: dco_decode_f_64(arr[7]),
+      encoderDelay: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      encoderPadding: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      tags: dco_decode_list_audio_tag(arr[10]),
+      decodeReport: dco_decode_decode_report(arr[11]),
+    );
+  }
+
+  @protected
+  AudioMemoryUsage dco_decode_audio_memory_usage(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BoxCommunicatorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AudioMemoryUsage(
+      key: dco_decode_String(arr[0]),
+      bytes: dco_decode_u_64(arr[1]),
+    );
   }
 
   @protected
-  Chart
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  ) {
+  AudioSource dco_decode_audio_source(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    switch (raw[0]) {
+      case 0:
+        return AudioSource_Bytes(dco_decode_list_prim_u_8_strict(raw[1]));
+      case 1:
+        return AudioSource_Path(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  ChartWIthKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  ) {
+  AudioStorageBackend dco_decode_audio_storage_backend(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    switch (raw[0]) {
+      case 0:
+        return AudioStorageBackend_Memory();
+      case 1:
+        return AudioStorageBackend_Disk(directory: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  File
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  ) {
+  AudioTag dco_decode_audio_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FileImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AudioTag(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
   }
 
   @protected
-  KvAudioStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    dynamic raw,
-  ) {
+  bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw as bool;
   }
 
   @protected
-  KvCachedChartStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  ) {
+  AudioSource dco_decode_box_autoadd_audio_source(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return dco_decode_audio_source(raw);
   }
 
   @protected
-  RustStreamSink<ChartEvent> dco_decode_StreamSink_chart_event_Sse(
+  AudioStorageBackend dco_decode_box_autoadd_audio_storage_backend(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_audio_storage_backend(raw);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
+  ChannelLayout dco_decode_box_autoadd_channel_layout(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+    return dco_decode_channel_layout(raw);
   }
 
   @protected
-  AudioDecoder dco_decode_TraitDef_AudioDecoder(dynamic raw) {
+  ChannelMix dco_decode_box_autoadd_channel_mix(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_channel_mix(raw);
   }
 
   @protected
-  AudioStorage dco_decode_TraitDef_AudioStorage(dynamic raw) {
+  ChartCacheBackend dco_decode_box_autoadd_chart_cache_backend(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_chart_cache_backend(raw);
   }
 
   @protected
-  CachedChartStorage dco_decode_TraitDef_CachedChartStorage(dynamic raw) {
+  ChartEvent dco_decode_box_autoadd_chart_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_chart_event(raw);
   }
 
   @protected
-  Communicator dco_decode_TraitDef_Communicator(dynamic raw) {
+  ChartPayload dco_decode_box_autoadd_chart_payload(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_chart_payload(raw);
   }
 
   @protected
-  DownSample dco_decode_TraitDef_DownSample(dynamic raw) {
+  CommunicatorChart dco_decode_box_autoadd_communicator_chart(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_communicator_chart(raw);
   }
 
   @protected
-  FormatGetter dco_decode_TraitDef_FormatGetter(dynamic raw) {
+  Config dco_decode_box_autoadd_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_config(raw);
   }
 
   @protected
-  SignalTransform dco_decode_TraitDef_SignalTransform(dynamic raw) {
+  DecodeEvent dco_decode_box_autoadd_decode_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    return dco_decode_decode_event(raw);
   }
 
   @protected
-  AppError dco_decode_app_error(dynamic raw) {
+  DecodeProgress dco_decode_box_autoadd_decode_progress(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return AppError_Io(dco_decode_String(raw[1]));
-      case 1:
-        return AppError_Format(dco_decode_String(raw[1]));
-      case 2:
-        return AppError_Decode(dco_decode_String(raw[1]));
-      case 3:
-        return AppError_Storage(dco_decode_String(raw[1]));
-      case 4:
-        return AppError_Cache(dco_decode_String(raw[1]));
-      case 5:
-        return AppError_NotFound(dco_decode_String(raw[1]));
-      case 6:
-        return AppError_Generic(dco_decode_String(raw[1]));
-      case 7:
-        return AppError_ProcessingError(dco_decode_String(raw[1]));
-      case 8:
-        return AppError_InvalidChartName(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+    return dco_decode_decode_progress(raw);
   }
 
   @protected
-  AudioInfo dco_decode_audio_info(dynamic raw) {
+  DecodeReport dco_decode_box_autoadd_decode_report(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return AudioInfo(sampleRate: dco_decode_u_32(arr[0]));
+    return dco_decode_decode_report(raw);
   }
 
   @protected
-  ChartEvent dco_decode_box_autoadd_chart_event(dynamic raw) {
+  DownSampleMethod dco_decode_box_autoadd_down_sample_method(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_chart_event(raw);
+    return dco_decode_down_sample_method(raw);
   }
 
   @protected
-  CommunicatorChart dco_decode_box_autoadd_communicator_chart(dynamic raw) {
+  ExportOptions dco_decode_box_autoadd_export_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_communicator_chart(raw);
+    return dco_decode_export_options(raw);
   }
 
   @protected
-  Config dco_decode_box_autoadd_config(dynamic raw) {
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_config(raw);
+    return raw as double;
   }
 
   @protected
-  EnergyCalculator dco_decode_box_autoadd_energy_calculator(dynamic raw) {
+  RawPcmFormat dco_decode_box_autoadd_raw_pcm_format(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_energy_calculator(raw);
+    return dco_decode_raw_pcm_format(raw);
   }
 
   @protected
-  EqualStep dco_decode_box_autoadd_equal_step(dynamic raw) {
+  RawSampleFormat dco_decode_box_autoadd_raw_sample_format(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_equal_step(raw);
+    return dco_decode_raw_sample_format(raw);
   }
 
   @protected
-  FftTransform dco_decode_box_autoadd_fft_transform(dynamic raw) {
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_fft_transform(raw);
+    return raw as int;
   }
 
   @protected
-  Minmax dco_decode_box_autoadd_minmax(dynamic raw) {
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_minmax(raw);
+    return dco_decode_u_64(raw);
   }
 
   @protected
-  SimpleFormatGetter dco_decode_box_autoadd_simple_format_getter(dynamic raw) {
+  ChannelLayout dco_decode_channel_layout(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_simple_format_getter(raw);
+    switch (raw[0]) {
+      case 0:
+        return ChannelLayout_Mono();
+      case 1:
+        return ChannelLayout_Stereo();
+      case 2:
+        return ChannelLayout_Multichannel(dco_decode_list_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  StreamCommunicator dco_decode_box_autoadd_stream_communicator(dynamic raw) {
+  ChannelMix dco_decode_channel_mix(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_stream_communicator(raw);
+    switch (raw[0]) {
+      case 0:
+        return ChannelMix_Mean();
+      case 1:
+        return ChannelMix_Left();
+      case 2:
+        return ChannelMix_Right();
+      case 3:
+        return ChannelMix_Mid();
+      case 4:
+        return ChannelMix_Side();
+      case 5:
+        return ChannelMix_Channel(dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  SymphoniaDecoder dco_decode_box_autoadd_symphonia_decoder(dynamic raw) {
+  ChartAxis dco_decode_chart_axis(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_symphonia_decoder(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ChartAxis(
+      dataType: dco_decode_data_type(arr[0]),
+      label: dco_decode_String(arr[1]),
+      unit: dco_decode_String(arr[2]),
+      minY: dco_decode_f_32(arr[3]),
+      maxY: dco_decode_f_32(arr[4]),
+      keys: dco_decode_list_String(arr[5]),
+    );
   }
 
   @protected
-  ZeroCrossingRateCalculator
-  dco_decode_box_autoadd_zero_crossing_rate_calculator(dynamic raw) {
+  ChartCacheBackend dco_decode_chart_cache_backend(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_zero_crossing_rate_calculator(raw);
+    switch (raw[0]) {
+      case 0:
+        return ChartCacheBackend_Memory();
+      case 1:
+        return ChartCacheBackend_Disk(directory: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
@@ -3722,7 +2959,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     switch (raw[0]) {
       case 0:
         return ChartEvent_AddChart(
-          chart: dco_decode_box_autoadd_communicator_chart(raw[1]),
+          viewport: dco_decode_String(raw[1]),
+          chart: dco_decode_box_autoadd_communicator_chart(raw[2]),
+          seq: dco_decode_u_64(raw[3]),
         );
       case 1:
         return ChartEvent_RemoveChart(
@@ -3734,13 +2973,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           charts: dco_decode_list_communicator_chart(raw[1]),
         );
       case 3:
-        return ChartEvent_RemoveAllCharts();
+        return ChartEvent_UpdateOverview(
+          viewport: dco_decode_String(raw[1]),
+          chart: dco_decode_box_autoadd_communicator_chart(raw[2]),
+        );
       case 4:
-        return ChartEvent_UpdateMaxIndex(maxIndex: dco_decode_f_32(raw[1]));
+        return ChartEvent_RemoveAllCharts();
       case 5:
-        return ChartEvent_UpdateYRange(
-          minY: dco_decode_f_32(raw[1]),
-          maxY: dco_decode_f_32(raw[2]),
+        return ChartEvent_UpdateMaxIndex(
+          viewport: dco_decode_String(raw[1]),
+          maxIndex: dco_decode_f_32(raw[2]),
+        );
+      case 6:
+        return ChartEvent_UpdateAxes(
+          viewport: dco_decode_String(raw[1]),
+          axes: dco_decode_list_chart_axis(raw[2]),
+        );
+      case 7:
+        return ChartEvent_UpdateRegions(
+          key: dco_decode_String(raw[1]),
+          regions: dco_decode_list_chart_region(raw[2]),
+        );
+      case 8:
+        return ChartEvent_AppendPoints(
+          viewport: dco_decode_String(raw[1]),
+          key: dco_decode_String(raw[2]),
+          dataType: dco_decode_data_type(raw[3]),
+          points: dco_decode_box_autoadd_chart_payload(raw[4]),
+          baseSeq: dco_decode_u_64(raw[5]),
+          seq: dco_decode_u_64(raw[6]),
+        );
+      case 9:
+        return ChartEvent_ReplaceRange(
+          viewport: dco_decode_String(raw[1]),
+          key: dco_decode_String(raw[2]),
+          dataType: dco_decode_data_type(raw[3]),
+          start: dco_decode_usize(raw[4]),
+          end: dco_decode_usize(raw[5]),
+          points: dco_decode_box_autoadd_chart_payload(raw[6]),
+          baseSeq: dco_decode_u_64(raw[7]),
+          seq: dco_decode_u_64(raw[8]),
         );
       default:
         throw Exception("unreachable");
@@ -3748,651 +3020,535 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  CommunicatorChart dco_decode_communicator_chart(dynamic raw) {
+  ChartMemoryUsage dco_decode_chart_memory_usage(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 3)
       throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return CommunicatorChart(
+    return ChartMemoryUsage(
       key: dco_decode_String(arr[0]),
       dataType: dco_decode_data_type(arr[1]),
-      chart: dco_decode_list_point(arr[2]),
+      bytes: dco_decode_u_64(arr[2]),
     );
   }
 
   @protected
-  Config dco_decode_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return Config(frameSize: dco_decode_usize(arr[0]));
-  }
-
-  @protected
-  DataType dco_decode_data_type(dynamic raw) {
+  ChartPayload dco_decode_chart_payload(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DataType.values[raw as int];
+    switch (raw[0]) {
+      case 0:
+        return ChartPayload_Points(
+          xs: dco_decode_list_prim_f_32_strict(raw[1]),
+          ys: dco_decode_list_prim_f_32_strict(raw[2]),
+        );
+      case 1:
+        return ChartPayload_Uniform(
+          x0: dco_decode_f_32(raw[1]),
+          dx: dco_decode_f_32(raw[2]),
+          ys: dco_decode_list_prim_f_32_strict(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  EnergyCalculator dco_decode_energy_calculator(dynamic raw) {
+  ChartRegion dco_decode_chart_region(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return EnergyCalculator();
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChartRegion(
+      startX: dco_decode_f_32(arr[0]),
+      endX: dco_decode_f_32(arr[1]),
+      label: dco_decode_String(arr[2]),
+    );
   }
 
   @protected
-  EqualStep dco_decode_equal_step(dynamic raw) {
+  CommunicatorChart dco_decode_communicator_chart(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return EqualStep();
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CommunicatorChart(
+      key: dco_decode_String(arr[0]),
+      dataType: dco_decode_data_type(arr[1]),
+      chart: dco_decode_chart_payload(arr[2]),
+    );
   }
 
   @protected
-  FftTransform dco_decode_fft_transform(dynamic raw) {
+  Config dco_decode_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return FftTransform();
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Config(frameSize: dco_decode_usize(arr[0]));
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
+  DamagedRange dco_decode_damaged_range(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DamagedRange(
+      startFrame: dco_decode_u_64(arr[0]),
+      endFrame: dco_decode_u_64(arr[1]),
+      start: dco_decode_f_64(arr[2]),
+      end: dco_decode_f_64(arr[3]),
+      reason: dco_decode_String(arr[4]),
+    );
   }
 
   @protected
-  List<ChartWIthKey>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  ) {
+  DataType dco_decode_data_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey,
-        )
-        .toList();
+    return DataType.values[raw as int];
   }
 
   @protected
-  List<CommunicatorChart> dco_decode_list_communicator_chart(dynamic raw) {
+  DecodeEvent dco_decode_decode_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_communicator_chart).toList();
+    switch (raw[0]) {
+      case 0:
+        return DecodeEvent_Progress(
+          key: dco_decode_String(raw[1]),
+          progress: dco_decode_box_autoadd_decode_progress(raw[2]),
+        );
+      case 1:
+        return DecodeEvent_Finished(
+          key: dco_decode_String(raw[1]),
+          progress: dco_decode_box_autoadd_decode_progress(raw[2]),
+        );
+      case 2:
+        return DecodeEvent_Cancelled(
+          key: dco_decode_String(raw[1]),
+          progress: dco_decode_box_autoadd_decode_progress(raw[2]),
+        );
+      case 3:
+        return DecodeEvent_Failed(
+          key: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
   }
 
   @protected
-  List<Point> dco_decode_list_point(dynamic raw) {
+  DecodeOptions dco_decode_decode_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_point).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return DecodeOptions(trackId: dco_decode_opt_box_autoadd_u_32(arr[0]));
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+  DecodeProgress dco_decode_decode_progress(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DecodeProgress(
+      packets: dco_decode_u_64(arr[0]),
+      bytesRead: dco_decode_u_64(arr[1]),
+      totalBytes: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      decodedFrames: dco_decode_u_64(arr[3]),
+      decodedDuration: dco_decode_f_64(arr[4]),
+      estimatedDuration: dco_decode_opt_box_autoadd_f_64(arr[5]),
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+  DecodeReport dco_decode_decode_report(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DecodeReport(
+      damaged: dco_decode_list_damaged_range(arr[0]),
+      skippedPackets: dco_decode_u_64(arr[1]),
+      decoderResets: dco_decode_u_32(arr[2]),
+      truncated: dco_decode_bool(arr[3]),
+    );
   }
 
   @protected
-  Minmax dco_decode_minmax(dynamic raw) {
+  DetectedFormat dco_decode_detected_format(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return Minmax();
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DetectedFormat(
+      container: dco_decode_String(arr[0]),
+      extension: dco_decode_String(arr[1]),
+      codec: dco_decode_opt_String(arr[2]),
+    );
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
+  DownSampleMethod dco_decode_down_sample_method(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+    return DownSampleMethod.values[raw as int];
   }
 
   @protected
-  Point dco_decode_point(dynamic raw) {
+  Endianness dco_decode_endianness(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return Point(x: dco_decode_f_32(arr[0]), y: dco_decode_f_32(arr[1]));
+    return Endianness.values[raw as int];
   }
 
   @protected
-  (double, double) dco_decode_record_f_32_f_32(dynamic raw) {
+  ExportContainer dco_decode_export_container(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_f_32(arr[0]), dco_decode_f_32(arr[1]));
+    return ExportContainer.values[raw as int];
   }
 
   @protected
-  SimpleFormatGetter dco_decode_simple_format_getter(dynamic raw) {
+  ExportOptions dco_decode_export_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return SimpleFormatGetter();
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ExportOptions(
+      container: dco_decode_export_container(arr[0]),
+      sampleFormat: dco_decode_export_sample_format(arr[1]),
+      dither: dco_decode_bool(arr[2]),
+    );
   }
 
   @protected
-  StreamCommunicator dco_decode_stream_communicator(dynamic raw) {
+  ExportSampleFormat dco_decode_export_sample_format(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return StreamCommunicator();
+    return ExportSampleFormat.values[raw as int];
   }
 
   @protected
-  SymphoniaDecoder dco_decode_symphonia_decoder(dynamic raw) {
+  double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return SymphoniaDecoder();
+    return raw as double;
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
+  double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return raw as double;
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
+  int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as int;
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
+  List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
+  List<AudioMemoryUsage> dco_decode_list_audio_memory_usage(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+    return (raw as List<dynamic>).map(dco_decode_audio_memory_usage).toList();
   }
 
   @protected
-  ZeroCrossingRateCalculator dco_decode_zero_crossing_rate_calculator(
-    dynamic raw,
-  ) {
+  List<AudioTag> dco_decode_list_audio_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return ZeroCrossingRateCalculator();
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  ArcAtomicBool
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcAtomicBoolImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ArcVecPoint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecPointImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    return (raw as List<dynamic>).map(dco_decode_audio_tag).toList();
   }
 
   @protected
-  ArcVecF32
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecF32Impl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<ChartAxis> dco_decode_list_chart_axis(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chart_axis).toList();
   }
 
   @protected
-  ArcVecU8
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecU8Impl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<ChartMemoryUsage> dco_decode_list_chart_memory_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chart_memory_usage).toList();
   }
 
   @protected
-  Audio
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<ChartRegion> dco_decode_list_chart_region(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chart_region).toList();
   }
 
   @protected
-  AudioData
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioDataImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<CommunicatorChart> dco_decode_list_communicator_chart(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_communicator_chart).toList();
   }
 
   @protected
-  AudioProcessorEngine
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioProcessorEngineImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<DamagedRange> dco_decode_list_damaged_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_damaged_range).toList();
   }
 
   @protected
-  BoxAudioDecoder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxAudioDecoderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
   }
 
   @protected
-  BoxAudioStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxAudioStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
   }
 
   @protected
-  BoxCachedChartStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxCachedChartStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
   }
 
   @protected
-  BoxCommunicator
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxCommunicatorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  List<TrackInfo> dco_decode_list_track_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_info).toList();
   }
 
   @protected
-  Chart
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+  MemoryUsage dco_decode_memory_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MemoryUsage(
+      audio: dco_decode_list_audio_memory_usage(arr[0]),
+      charts: dco_decode_list_chart_memory_usage(arr[1]),
+      audioBytes: dco_decode_u_64(arr[2]),
+      chartBytes: dco_decode_u_64(arr[3]),
+      audioBudget: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      chartBudget: dco_decode_opt_box_autoadd_u_64(arr[5]),
     );
   }
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
   }
 
   @protected
-  File
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return FileImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  ChannelLayout? dco_decode_opt_box_autoadd_channel_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_channel_layout(raw);
   }
 
   @protected
-  KvAudioStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  DownSampleMethod? dco_decode_opt_box_autoadd_down_sample_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_down_sample_method(raw);
   }
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
   }
 
   @protected
-  Audio
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
   }
 
   @protected
-  AudioData
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioDataImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
   @protected
-  AudioProcessorEngine
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioProcessorEngineImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+  RawPcmFormat dco_decode_raw_pcm_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RawPcmFormat(
+      sampleFormat: dco_decode_raw_sample_format(arr[0]),
+      endianness: dco_decode_endianness(arr[1]),
+      channels: dco_decode_u_32(arr[2]),
+      sampleRate: dco_decode_u_32(arr[3]),
+      headerSkip: dco_decode_u_64(arr[4]),
     );
   }
 
   @protected
-  Chart
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  RawSampleFormat dco_decode_raw_sample_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RawSampleFormat.values[raw as int];
   }
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  SampleStorage dco_decode_sample_storage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SampleStorage.values[raw as int];
   }
 
   @protected
-  File
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return FileImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+  TrackInfo dco_decode_track_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TrackInfo(
+      id: dco_decode_u_32(arr[0]),
+      codec: dco_decode_String(arr[1]),
+      language: dco_decode_opt_String(arr[2]),
+      sampleRate: dco_decode_u_32(arr[3]),
+      channels: dco_decode_u_32(arr[4]),
+      channelLayout: dco_decode_opt_box_autoadd_channel_layout(arr[5]),
+      totalFrames: dco_decode_opt_box_autoadd_u_64(arr[6]),
     );
   }
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  TruePeak dco_decode_true_peak(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TruePeak(x: dco_decode_f_32(arr[0]), value: dco_decode_f_32(arr[1]));
   }
 
   @protected
-  Audio
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
   }
 
   @protected
-  AudioData
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioDataImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
   }
 
   @protected
-  AudioProcessorEngine
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioProcessorEngineImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
   }
 
   @protected
-  Chart
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
   }
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
   }
 
   @protected
-  File
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    SseDeserializer deserializer,
-  ) {
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return FileImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
   }
 
   @protected
-  KvAudioStorage
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+  AudioProcessorEngine
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalSseDecode(
+    return AudioProcessorEngineImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  BoxAudioDecoder
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalSseDecode(
+    return BoxAudioDecoderImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  ArcAtomicBool
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
+  BoxAudioStorage
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcAtomicBoolImpl.frbInternalSseDecode(
+    return BoxAudioStorageImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  ArcVecPoint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
+  BoxCachedChartStorage
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecPointImpl.frbInternalSseDecode(
+    return BoxCachedChartStorageImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  ArcVecF32
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
+  BoxCommunicator
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecF32Impl.frbInternalSseDecode(
+    return BoxCommunicatorImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  ArcVecU8
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
+  CancellationToken
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcVecU8Impl.frbInternalSseDecode(
+    return CancellationTokenImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  Audio
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+  AudioProcessorEngine
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioImpl.frbInternalSseDecode(
+    return AudioProcessorEngineImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  AudioData
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  CancellationToken
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioDataImpl.frbInternalSseDecode(
+    return CancellationTokenImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
@@ -4448,78 +3604,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   @protected
   BoxCommunicator
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxCommunicatorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Chart
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  ChartWIthKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ChartWIthKeyImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  File
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return FileImpl.frbInternalSseDecode(
+    return BoxCommunicatorImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  KvAudioStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+  CancellationToken
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvAudioStorageImpl.frbInternalSseDecode(
+    return CancellationTokenImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  KvCachedChartStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  RustStreamSink<ChartEvent> sse_decode_StreamSink_chart_event_Sse(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return KvCachedChartStorageImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    throw UnimplementedError('Unreachable ()');
   }
 
   @protected
-  RustStreamSink<ChartEvent> sse_decode_StreamSink_chart_event_Sse(
+  RustStreamSink<DecodeEvent> sse_decode_StreamSink_decode_event_Sse(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4575,7 +3691,120 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   AudioInfo sse_decode_audio_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_sampleRate = sse_decode_u_32(deserializer);
-    return AudioInfo(sampleRate: var_sampleRate);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_channelLayout = sse_decode_channel_layout(deserializer);
+    var var_codec = sse_decode_String(deserializer);
+    var var_container = sse_decode_String(deserializer);
+    var var_bitsPerSample = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_totalFrames = sse_decode_u_64(deserializer);
+    var var_duration = sse_decode_f_64(deserializer);
+    var var_encoderDelay = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_encoderPadding = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_tags = sse_decode_list_audio_tag(deserializer);
+    var var_decodeReport = sse_decode_decode_report(deserializer);
+    return AudioInfo(
+      sampleRate: var_sampleRate,
+      channels: var_channels,
+      channelLayout: var_channelLayout,
+      codec: var_codec,
+      container: var_container,
+      bitsPerSample: var_bitsPerSample,
+      totalFrames: var_totalFrames,
+      duration: var_duration,
+      encoderDelay: var_encoderDelay,
+      encoderPadding: var_encoderPadding,
+      tags: var_tags,
+      decodeReport: var_decodeReport,
+    );
+  }
+
+  @protected
+  AudioMemoryUsage sse_decode_audio_memory_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    return AudioMemoryUsage(key: var_key, bytes: var_bytes);
+  }
+
+  @protected
+  AudioSource sse_decode_audio_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return AudioSource_Bytes(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return AudioSource_Path(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AudioStorageBackend sse_decode_audio_storage_backend(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return AudioStorageBackend_Memory();
+      case 1:
+        var var_directory = sse_decode_String(deserializer);
+        return AudioStorageBackend_Disk(directory: var_directory);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AudioTag sse_decode_audio_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return AudioTag(key: var_key, value: var_value);
+  }
+
+  @protected
+  AudioSource sse_decode_box_autoadd_audio_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_source(deserializer));
+  }
+
+  @protected
+  AudioStorageBackend sse_decode_box_autoadd_audio_storage_backend(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_storage_backend(deserializer));
+  }
+
+  @protected
+  ChannelLayout sse_decode_box_autoadd_channel_layout(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_channel_layout(deserializer));
+  }
+
+  @protected
+  ChannelMix sse_decode_box_autoadd_channel_mix(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_channel_mix(deserializer));
+  }
+
+  @protected
+  ChartCacheBackend sse_decode_box_autoadd_chart_cache_backend(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chart_cache_backend(deserializer));
   }
 
   @protected
@@ -4584,6 +3813,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_chart_event(deserializer));
   }
 
+  @protected
+  ChartPayload sse_decode_box_autoadd_chart_payload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chart_payload(deserializer));
+  }
+
   @protected
   CommunicatorChart sse_decode_box_autoadd_communicator_chart(
     SseDeserializer deserializer,
@@ -4599,64 +3836,156 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  EnergyCalculator sse_decode_box_autoadd_energy_calculator(
+  DecodeEvent sse_decode_box_autoadd_decode_event(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_energy_calculator(deserializer));
+    return (sse_decode_decode_event(deserializer));
   }
 
   @protected
-  EqualStep sse_decode_box_autoadd_equal_step(SseDeserializer deserializer) {
+  DecodeProgress sse_decode_box_autoadd_decode_progress(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_equal_step(deserializer));
+    return (sse_decode_decode_progress(deserializer));
   }
 
   @protected
-  FftTransform sse_decode_box_autoadd_fft_transform(
+  DecodeReport sse_decode_box_autoadd_decode_report(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_fft_transform(deserializer));
+    return (sse_decode_decode_report(deserializer));
   }
 
   @protected
-  Minmax sse_decode_box_autoadd_minmax(SseDeserializer deserializer) {
+  DownSampleMethod sse_decode_box_autoadd_down_sample_method(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_minmax(deserializer));
+    return (sse_decode_down_sample_method(deserializer));
   }
 
   @protected
-  SimpleFormatGetter sse_decode_box_autoadd_simple_format_getter(
+  ExportOptions sse_decode_box_autoadd_export_options(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_simple_format_getter(deserializer));
+    return (sse_decode_export_options(deserializer));
   }
 
   @protected
-  StreamCommunicator sse_decode_box_autoadd_stream_communicator(
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  RawPcmFormat sse_decode_box_autoadd_raw_pcm_format(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_stream_communicator(deserializer));
+    return (sse_decode_raw_pcm_format(deserializer));
   }
 
   @protected
-  SymphoniaDecoder sse_decode_box_autoadd_symphonia_decoder(
+  RawSampleFormat sse_decode_box_autoadd_raw_sample_format(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_symphonia_decoder(deserializer));
+    return (sse_decode_raw_sample_format(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  ChannelLayout sse_decode_channel_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ChannelLayout_Mono();
+      case 1:
+        return ChannelLayout_Stereo();
+      case 2:
+        var var_field0 = sse_decode_list_String(deserializer);
+        return ChannelLayout_Multichannel(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ChannelMix sse_decode_channel_mix(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ChannelMix_Mean();
+      case 1:
+        return ChannelMix_Left();
+      case 2:
+        return ChannelMix_Right();
+      case 3:
+        return ChannelMix_Mid();
+      case 4:
+        return ChannelMix_Side();
+      case 5:
+        var var_field0 = sse_decode_u_32(deserializer);
+        return ChannelMix_Channel(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ChartAxis sse_decode_chart_axis(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dataType = sse_decode_data_type(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_unit = sse_decode_String(deserializer);
+    var var_minY = sse_decode_f_32(deserializer);
+    var var_maxY = sse_decode_f_32(deserializer);
+    var var_keys = sse_decode_list_String(deserializer);
+    return ChartAxis(
+      dataType: var_dataType,
+      label: var_label,
+      unit: var_unit,
+      minY: var_minY,
+      maxY: var_maxY,
+      keys: var_keys,
+    );
   }
 
   @protected
-  ZeroCrossingRateCalculator
-  sse_decode_box_autoadd_zero_crossing_rate_calculator(
+  ChartCacheBackend sse_decode_chart_cache_backend(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_zero_crossing_rate_calculator(deserializer));
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ChartCacheBackend_Memory();
+      case 1:
+        var var_directory = sse_decode_String(deserializer);
+        return ChartCacheBackend_Disk(directory: var_directory);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
@@ -4666,8 +3995,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var tag_ = sse_decode_i_32(deserializer);
     switch (tag_) {
       case 0:
+        var var_viewport = sse_decode_String(deserializer);
         var var_chart = sse_decode_box_autoadd_communicator_chart(deserializer);
-        return ChartEvent_AddChart(chart: var_chart);
+        var var_seq = sse_decode_u_64(deserializer);
+        return ChartEvent_AddChart(
+          viewport: var_viewport,
+          chart: var_chart,
+          seq: var_seq,
+        );
       case 1:
         var var_key = sse_decode_String(deserializer);
         var var_dataType = sse_decode_data_type(deserializer);
@@ -4676,19 +4011,110 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_charts = sse_decode_list_communicator_chart(deserializer);
         return ChartEvent_UpdateAllCharts(charts: var_charts);
       case 3:
-        return ChartEvent_RemoveAllCharts();
+        var var_viewport = sse_decode_String(deserializer);
+        var var_chart = sse_decode_box_autoadd_communicator_chart(deserializer);
+        return ChartEvent_UpdateOverview(
+          viewport: var_viewport,
+          chart: var_chart,
+        );
       case 4:
-        var var_maxIndex = sse_decode_f_32(deserializer);
-        return ChartEvent_UpdateMaxIndex(maxIndex: var_maxIndex);
+        return ChartEvent_RemoveAllCharts();
       case 5:
-        var var_minY = sse_decode_f_32(deserializer);
-        var var_maxY = sse_decode_f_32(deserializer);
-        return ChartEvent_UpdateYRange(minY: var_minY, maxY: var_maxY);
+        var var_viewport = sse_decode_String(deserializer);
+        var var_maxIndex = sse_decode_f_32(deserializer);
+        return ChartEvent_UpdateMaxIndex(
+          viewport: var_viewport,
+          maxIndex: var_maxIndex,
+        );
+      case 6:
+        var var_viewport = sse_decode_String(deserializer);
+        var var_axes = sse_decode_list_chart_axis(deserializer);
+        return ChartEvent_UpdateAxes(viewport: var_viewport, axes: var_axes);
+      case 7:
+        var var_key = sse_decode_String(deserializer);
+        var var_regions = sse_decode_list_chart_region(deserializer);
+        return ChartEvent_UpdateRegions(key: var_key, regions: var_regions);
+      case 8:
+        var var_viewport = sse_decode_String(deserializer);
+        var var_key = sse_decode_String(deserializer);
+        var var_dataType = sse_decode_data_type(deserializer);
+        var var_points = sse_decode_box_autoadd_chart_payload(deserializer);
+        var var_baseSeq = sse_decode_u_64(deserializer);
+        var var_seq = sse_decode_u_64(deserializer);
+        return ChartEvent_AppendPoints(
+          viewport: var_viewport,
+          key: var_key,
+          dataType: var_dataType,
+          points: var_points,
+          baseSeq: var_baseSeq,
+          seq: var_seq,
+        );
+      case 9:
+        var var_viewport = sse_decode_String(deserializer);
+        var var_key = sse_decode_String(deserializer);
+        var var_dataType = sse_decode_data_type(deserializer);
+        var var_start = sse_decode_usize(deserializer);
+        var var_end = sse_decode_usize(deserializer);
+        var var_points = sse_decode_box_autoadd_chart_payload(deserializer);
+        var var_baseSeq = sse_decode_u_64(deserializer);
+        var var_seq = sse_decode_u_64(deserializer);
+        return ChartEvent_ReplaceRange(
+          viewport: var_viewport,
+          key: var_key,
+          dataType: var_dataType,
+          start: var_start,
+          end: var_end,
+          points: var_points,
+          baseSeq: var_baseSeq,
+          seq: var_seq,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ChartMemoryUsage sse_decode_chart_memory_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_dataType = sse_decode_data_type(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    return ChartMemoryUsage(
+      key: var_key,
+      dataType: var_dataType,
+      bytes: var_bytes,
+    );
+  }
+
+  @protected
+  ChartPayload sse_decode_chart_payload(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_xs = sse_decode_list_prim_f_32_strict(deserializer);
+        var var_ys = sse_decode_list_prim_f_32_strict(deserializer);
+        return ChartPayload_Points(xs: var_xs, ys: var_ys);
+      case 1:
+        var var_x0 = sse_decode_f_32(deserializer);
+        var var_dx = sse_decode_f_32(deserializer);
+        var var_ys = sse_decode_list_prim_f_32_strict(deserializer);
+        return ChartPayload_Uniform(x0: var_x0, dx: var_dx, ys: var_ys);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  ChartRegion sse_decode_chart_region(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startX = sse_decode_f_32(deserializer);
+    var var_endX = sse_decode_f_32(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    return ChartRegion(startX: var_startX, endX: var_endX, label: var_label);
+  }
+
   @protected
   CommunicatorChart sse_decode_communicator_chart(
     SseDeserializer deserializer,
@@ -4696,7 +4122,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_key = sse_decode_String(deserializer);
     var var_dataType = sse_decode_data_type(deserializer);
-    var var_chart = sse_decode_list_point(deserializer);
+    var var_chart = sse_decode_chart_payload(deserializer);
     return CommunicatorChart(
       key: var_key,
       dataType: var_dataType,
@@ -4705,64 +4131,250 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Config sse_decode_config(SseDeserializer deserializer) {
+  Config sse_decode_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frameSize = sse_decode_usize(deserializer);
+    return Config(frameSize: var_frameSize);
+  }
+
+  @protected
+  DamagedRange sse_decode_damaged_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startFrame = sse_decode_u_64(deserializer);
+    var var_endFrame = sse_decode_u_64(deserializer);
+    var var_start = sse_decode_f_64(deserializer);
+    var var_end = sse_decode_f_64(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return DamagedRange(
+      startFrame: var_startFrame,
+      endFrame: var_endFrame,
+      start: var_start,
+      end: var_end,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  DataType sse_decode_data_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DataType.values[inner];
+  }
+
+  @protected
+  DecodeEvent sse_decode_decode_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_key = sse_decode_String(deserializer);
+        var var_progress = sse_decode_box_autoadd_decode_progress(deserializer);
+        return DecodeEvent_Progress(key: var_key, progress: var_progress);
+      case 1:
+        var var_key = sse_decode_String(deserializer);
+        var var_progress = sse_decode_box_autoadd_decode_progress(deserializer);
+        return DecodeEvent_Finished(key: var_key, progress: var_progress);
+      case 2:
+        var var_key = sse_decode_String(deserializer);
+        var var_progress = sse_decode_box_autoadd_decode_progress(deserializer);
+        return DecodeEvent_Cancelled(key: var_key, progress: var_progress);
+      case 3:
+        var var_key = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return DecodeEvent_Failed(key: var_key, message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DecodeOptions sse_decode_decode_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_trackId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return DecodeOptions(trackId: var_trackId);
+  }
+
+  @protected
+  DecodeProgress sse_decode_decode_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_packets = sse_decode_u_64(deserializer);
+    var var_bytesRead = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_decodedFrames = sse_decode_u_64(deserializer);
+    var var_decodedDuration = sse_decode_f_64(deserializer);
+    var var_estimatedDuration = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return DecodeProgress(
+      packets: var_packets,
+      bytesRead: var_bytesRead,
+      totalBytes: var_totalBytes,
+      decodedFrames: var_decodedFrames,
+      decodedDuration: var_decodedDuration,
+      estimatedDuration: var_estimatedDuration,
+    );
+  }
+
+  @protected
+  DecodeReport sse_decode_decode_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_damaged = sse_decode_list_damaged_range(deserializer);
+    var var_skippedPackets = sse_decode_u_64(deserializer);
+    var var_decoderResets = sse_decode_u_32(deserializer);
+    var var_truncated = sse_decode_bool(deserializer);
+    return DecodeReport(
+      damaged: var_damaged,
+      skippedPackets: var_skippedPackets,
+      decoderResets: var_decoderResets,
+      truncated: var_truncated,
+    );
+  }
+
+  @protected
+  DetectedFormat sse_decode_detected_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_container = sse_decode_String(deserializer);
+    var var_extension = sse_decode_String(deserializer);
+    var var_codec = sse_decode_opt_String(deserializer);
+    return DetectedFormat(
+      container: var_container,
+      extension: var_extension,
+      codec: var_codec,
+    );
+  }
+
+  @protected
+  DownSampleMethod sse_decode_down_sample_method(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownSampleMethod.values[inner];
+  }
+
+  @protected
+  Endianness sse_decode_endianness(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Endianness.values[inner];
+  }
+
+  @protected
+  ExportContainer sse_decode_export_container(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ExportContainer.values[inner];
+  }
+
+  @protected
+  ExportOptions sse_decode_export_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_container = sse_decode_export_container(deserializer);
+    var var_sampleFormat = sse_decode_export_sample_format(deserializer);
+    var var_dither = sse_decode_bool(deserializer);
+    return ExportOptions(
+      container: var_container,
+      sampleFormat: var_sampleFormat,
+      dither: var_dither,
+    );
+  }
+
+  @protected
+  ExportSampleFormat sse_decode_export_sample_format(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_frameSize = sse_decode_usize(deserializer);
-    return Config(frameSize: var_frameSize);
+    var inner = sse_decode_i_32(deserializer);
+    return ExportSampleFormat.values[inner];
   }
 
   @protected
-  DataType sse_decode_data_type(SseDeserializer deserializer) {
+  double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return DataType.values[inner];
+    return deserializer.buffer.getFloat32();
   }
 
   @protected
-  EnergyCalculator sse_decode_energy_calculator(SseDeserializer deserializer) {
+  double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EnergyCalculator();
+    return deserializer.buffer.getFloat64();
   }
 
   @protected
-  EqualStep sse_decode_equal_step(SseDeserializer deserializer) {
+  int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EqualStep();
+    return deserializer.buffer.getInt32();
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  FftTransform sse_decode_fft_transform(SseDeserializer deserializer) {
+  List<AudioMemoryUsage> sse_decode_list_audio_memory_usage(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return FftTransform();
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioMemoryUsage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_memory_usage(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  List<AudioTag> sse_decode_list_audio_tag(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChartAxis> sse_decode_list_chart_axis(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChartAxis>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chart_axis(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  List<ChartWIthKey>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+  List<ChartMemoryUsage> sse_decode_list_chart_memory_usage(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ChartWIthKey>[];
+    var ans_ = <ChartMemoryUsage>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-          deserializer,
-        ),
-      );
+      ans_.add(sse_decode_chart_memory_usage(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChartRegion> sse_decode_list_chart_region(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChartRegion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chart_region(deserializer));
     }
     return ans_;
   }
@@ -4782,17 +4394,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<Point> sse_decode_list_point(SseDeserializer deserializer) {
+  List<DamagedRange> sse_decode_list_damaged_range(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Point>[];
+    var ans_ = <DamagedRange>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point(deserializer));
+      ans_.add(sse_decode_damaged_range(deserializer));
     }
     return ans_;
   }
 
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4808,9 +4429,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Minmax sse_decode_minmax(SseDeserializer deserializer) {
+  List<TrackInfo> sse_decode_list_track_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MemoryUsage sse_decode_memory_usage(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return Minmax();
+    var var_audio = sse_decode_list_audio_memory_usage(deserializer);
+    var var_charts = sse_decode_list_chart_memory_usage(deserializer);
+    var var_audioBytes = sse_decode_u_64(deserializer);
+    var var_chartBytes = sse_decode_u_64(deserializer);
+    var var_audioBudget = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_chartBudget = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return MemoryUsage(
+      audio: var_audio,
+      charts: var_charts,
+      audioBytes: var_audioBytes,
+      chartBytes: var_chartBytes,
+      audioBudget: var_audioBudget,
+      chartBudget: var_chartBudget,
+    );
   }
 
   @protected
@@ -4825,165 +4471,167 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Point sse_decode_point(SseDeserializer deserializer) {
+  ChannelLayout? sse_decode_opt_box_autoadd_channel_layout(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_x = sse_decode_f_32(deserializer);
-    var var_y = sse_decode_f_32(deserializer);
-    return Point(x: var_x, y: var_y);
-  }
 
-  @protected
-  (double, double) sse_decode_record_f_32_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_f_32(deserializer);
-    var var_field1 = sse_decode_f_32(deserializer);
-    return (var_field0, var_field1);
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_channel_layout(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  SimpleFormatGetter sse_decode_simple_format_getter(
+  DownSampleMethod? sse_decode_opt_box_autoadd_down_sample_method(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SimpleFormatGetter();
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_down_sample_method(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  StreamCommunicator sse_decode_stream_communicator(
-    SseDeserializer deserializer,
-  ) {
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return StreamCommunicator();
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  SymphoniaDecoder sse_decode_symphonia_decoder(SseDeserializer deserializer) {
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SymphoniaDecoder();
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  RawPcmFormat sse_decode_raw_pcm_format(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    var var_sampleFormat = sse_decode_raw_sample_format(deserializer);
+    var var_endianness = sse_decode_endianness(deserializer);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_headerSkip = sse_decode_u_64(deserializer);
+    return RawPcmFormat(
+      sampleFormat: var_sampleFormat,
+      endianness: var_endianness,
+      channels: var_channels,
+      sampleRate: var_sampleRate,
+      headerSkip: var_headerSkip,
+    );
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  RawSampleFormat sse_decode_raw_sample_format(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RawSampleFormat.values[inner];
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  SampleStorage sse_decode_sample_storage(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    var inner = sse_decode_i_32(deserializer);
+    return SampleStorage.values[inner];
   }
 
   @protected
-  ZeroCrossingRateCalculator sse_decode_zero_crossing_rate_calculator(
-    SseDeserializer deserializer,
-  ) {
+  TrackInfo sse_decode_track_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ZeroCrossingRateCalculator();
+    var var_id = sse_decode_u_32(deserializer);
+    var var_codec = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_channelLayout =
+        sse_decode_opt_box_autoadd_channel_layout(deserializer);
+    var var_totalFrames = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return TrackInfo(
+      id: var_id,
+      codec: var_codec,
+      language: var_language,
+      sampleRate: var_sampleRate,
+      channels: var_channels,
+      channelLayout: var_channelLayout,
+      totalFrames: var_totalFrames,
+    );
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  TruePeak sse_decode_true_peak(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    var var_x = sse_decode_f_32(deserializer);
+    var var_value = sse_decode_f_32(deserializer);
+    return TruePeak(x: var_x, value: var_value);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
+  int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ArcAtomicBool self,
-    SseSerializer serializer,
-  ) {
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcAtomicBoolImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ArcVecPoint self,
-    SseSerializer serializer,
-  ) {
+  int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecPointImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ArcVecF32 self,
-    SseSerializer serializer,
-  ) {
+  void sse_decode_unit(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecF32Impl).frbInternalSseEncode(move: true),
-      serializer,
-    );
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ArcVecU8 self,
-    SseSerializer serializer,
-  ) {
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecU8Impl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
-    SseSerializer serializer,
-  ) {
+  bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    return deserializer.buffer.getUint8() != 0;
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  void sse_encode_AnyhowException(
+    AnyhowException self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioDataImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
@@ -5053,720 +4701,813 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as ChartImpl).frbInternalSseEncode(move: true),
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: true),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+    AudioProcessorEngine self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as ChartWIthKeyImpl).frbInternalSseEncode(move: true),
+      (self as AudioProcessorEngineImpl).frbInternalSseEncode(move: false),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as FileImpl).frbInternalSseEncode(move: true),
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: false),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+    AudioProcessorEngine self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as KvAudioStorageImpl).frbInternalSseEncode(move: true),
+      (self as AudioProcessorEngineImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
+    BoxAudioDecoder self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as KvCachedChartStorageImpl).frbInternalSseEncode(move: true),
+      (self as BoxAudioDecoderImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
+    BoxAudioStorage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as AudioImpl).frbInternalSseEncode(move: false),
+      (self as BoxAudioStorageImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
+    BoxCachedChartStorage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as AudioDataImpl).frbInternalSseEncode(move: false),
+      (self as BoxCachedChartStorageImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
+    BoxCommunicator self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as AudioProcessorEngineImpl).frbInternalSseEncode(move: false),
+      (self as BoxCommunicatorImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_usize(
-      (self as ChartImpl).frbInternalSseEncode(move: false),
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: null),
       serializer,
     );
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  void sse_encode_StreamSink_chart_event_Sse(
+    RustStreamSink<ChartEvent> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ChartWIthKeyImpl).frbInternalSseEncode(move: false),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chart_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
       serializer,
     );
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  void sse_encode_StreamSink_decode_event_Sse(
+    RustStreamSink<DecodeEvent> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as FileImpl).frbInternalSseEncode(move: false),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_decode_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
       serializer,
     );
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_app_error(AppError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AppError_Io(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_Format(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_Decode(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_Storage(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_Cache(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_NotFound(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_Generic(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_ProcessingError(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case AppError_InvalidChartName(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_audio_info(AudioInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_channel_layout(self.channelLayout, serializer);
+    sse_encode_String(self.codec, serializer);
+    sse_encode_String(self.container, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitsPerSample, serializer);
+    sse_encode_u_64(self.totalFrames, serializer);
+    sse_encode_f_64(self.duration, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.encoderDelay, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.encoderPadding, serializer);
+    sse_encode_list_audio_tag(self.tags, serializer);
+    sse_encode_decode_report(self.decodeReport, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_memory_usage(
+    AudioMemoryUsage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as KvCachedChartStorageImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_String(self.key, serializer);
+    sse_encode_u_64(self.bytes, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_audio_source(AudioSource self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    switch (self) {
+      case AudioSource_Bytes(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+      case AudioSource_Path(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  void sse_encode_audio_storage_backend(
+    AudioStorageBackend self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioDataImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    switch (self) {
+      case AudioStorageBackend_Memory():
+        sse_encode_i_32(0, serializer);
+      case AudioStorageBackend_Disk(directory: final directory):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(directory, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_audio_tag(AudioTag self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioProcessorEngineImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  void sse_encode_box_autoadd_audio_source(
+    AudioSource self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ChartImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_audio_source(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  void sse_encode_box_autoadd_audio_storage_backend(
+    AudioStorageBackend self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ChartWIthKeyImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_audio_storage_backend(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  void sse_encode_box_autoadd_channel_layout(
+    ChannelLayout self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as FileImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_channel_layout(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
+  void sse_encode_box_autoadd_channel_mix(
+    ChannelMix self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as KvAudioStorageImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_channel_mix(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
+  void sse_encode_box_autoadd_chart_cache_backend(
+    ChartCacheBackend self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as KvCachedChartStorageImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_chart_cache_backend(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ArcAtomicBool self,
+  void sse_encode_box_autoadd_chart_event(
+    ChartEvent self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcAtomicBoolImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_chart_event(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ArcVecPoint self,
+  void sse_encode_box_autoadd_chart_payload(
+    ChartPayload self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecPointImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_chart_payload(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ArcVecF32 self,
+  void sse_encode_box_autoadd_communicator_chart(
+    CommunicatorChart self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecF32Impl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_communicator_chart(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ArcVecU8 self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcVecU8Impl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_config(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
+  void sse_encode_box_autoadd_decode_event(
+    DecodeEvent self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_decode_event(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  void sse_encode_box_autoadd_decode_progress(
+    DecodeProgress self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioDataImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_decode_progress(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
+  void sse_encode_box_autoadd_decode_report(
+    DecodeReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioProcessorEngineImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_decode_report(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    BoxAudioDecoder self,
+  void sse_encode_box_autoadd_down_sample_method(
+    DownSampleMethod self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as BoxAudioDecoderImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_down_sample_method(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    BoxAudioStorage self,
+  void sse_encode_box_autoadd_export_options(
+    ExportOptions self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as BoxAudioStorageImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_export_options(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-    BoxCachedChartStorage self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as BoxCachedChartStorageImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    BoxCommunicator self,
+  void sse_encode_box_autoadd_raw_pcm_format(
+    RawPcmFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as BoxCommunicatorImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_raw_pcm_format(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  void sse_encode_box_autoadd_raw_sample_format(
+    RawSampleFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ChartImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_raw_sample_format(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ChartWIthKeyImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as FileImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_channel_layout(ChannelLayout self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as KvAudioStorageImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    switch (self) {
+      case ChannelLayout_Mono():
+        sse_encode_i_32(0, serializer);
+      case ChannelLayout_Stereo():
+        sse_encode_i_32(1, serializer);
+      case ChannelLayout_Multichannel(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_list_String(field0, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_channel_mix(ChannelMix self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as KvCachedChartStorageImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    switch (self) {
+      case ChannelMix_Mean():
+        sse_encode_i_32(0, serializer);
+      case ChannelMix_Left():
+        sse_encode_i_32(1, serializer);
+      case ChannelMix_Right():
+        sse_encode_i_32(2, serializer);
+      case ChannelMix_Mid():
+        sse_encode_i_32(3, serializer);
+      case ChannelMix_Side():
+        sse_encode_i_32(4, serializer);
+      case ChannelMix_Channel(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_32(field0, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_chart_event_Sse(
-    RustStreamSink<ChartEvent> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_chart_axis(ChartAxis self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_chart_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_data_type(self.dataType, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.unit, serializer);
+    sse_encode_f_32(self.minY, serializer);
+    sse_encode_f_32(self.maxY, serializer);
+    sse_encode_list_String(self.keys, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_chart_cache_backend(
+    ChartCacheBackend self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    switch (self) {
+      case ChartCacheBackend_Memory():
+        sse_encode_i_32(0, serializer);
+      case ChartCacheBackend_Disk(directory: final directory):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(directory, serializer);
+    }
   }
 
   @protected
-  void sse_encode_app_error(AppError self, SseSerializer serializer) {
+  void sse_encode_chart_event(ChartEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case AppError_Io(field0: final field0):
+      case ChartEvent_AddChart(
+        viewport: final viewport,
+        chart: final chart,
+        seq: final seq,
+      ):
         sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_Format(field0: final field0):
+        sse_encode_String(viewport, serializer);
+        sse_encode_box_autoadd_communicator_chart(chart, serializer);
+        sse_encode_u_64(seq, serializer);
+      case ChartEvent_RemoveChart(key: final key, dataType: final dataType):
         sse_encode_i_32(1, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_Decode(field0: final field0):
+        sse_encode_String(key, serializer);
+        sse_encode_data_type(dataType, serializer);
+      case ChartEvent_UpdateAllCharts(charts: final charts):
         sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_Storage(field0: final field0):
+        sse_encode_list_communicator_chart(charts, serializer);
+      case ChartEvent_UpdateOverview(
+        viewport: final viewport,
+        chart: final chart,
+      ):
         sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_Cache(field0: final field0):
+        sse_encode_String(viewport, serializer);
+        sse_encode_box_autoadd_communicator_chart(chart, serializer);
+      case ChartEvent_RemoveAllCharts():
         sse_encode_i_32(4, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_NotFound(field0: final field0):
+      case ChartEvent_UpdateMaxIndex(
+        viewport: final viewport,
+        maxIndex: final maxIndex,
+      ):
         sse_encode_i_32(5, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_Generic(field0: final field0):
+        sse_encode_String(viewport, serializer);
+        sse_encode_f_32(maxIndex, serializer);
+      case ChartEvent_UpdateAxes(viewport: final viewport, axes: final axes):
         sse_encode_i_32(6, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_ProcessingError(field0: final field0):
+        sse_encode_String(viewport, serializer);
+        sse_encode_list_chart_axis(axes, serializer);
+      case ChartEvent_UpdateRegions(key: final key, regions: final regions):
         sse_encode_i_32(7, serializer);
-        sse_encode_String(field0, serializer);
-      case AppError_InvalidChartName(field0: final field0):
+        sse_encode_String(key, serializer);
+        sse_encode_list_chart_region(regions, serializer);
+      case ChartEvent_AppendPoints(
+        viewport: final viewport,
+        key: final key,
+        dataType: final dataType,
+        points: final points,
+        baseSeq: final baseSeq,
+        seq: final seq,
+      ):
         sse_encode_i_32(8, serializer);
-        sse_encode_String(field0, serializer);
+        sse_encode_String(viewport, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_data_type(dataType, serializer);
+        sse_encode_box_autoadd_chart_payload(points, serializer);
+        sse_encode_u_64(baseSeq, serializer);
+        sse_encode_u_64(seq, serializer);
+      case ChartEvent_ReplaceRange(
+        viewport: final viewport,
+        key: final key,
+        dataType: final dataType,
+        start: final start,
+        end: final end,
+        points: final points,
+        baseSeq: final baseSeq,
+        seq: final seq,
+      ):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(viewport, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_data_type(dataType, serializer);
+        sse_encode_usize(start, serializer);
+        sse_encode_usize(end, serializer);
+        sse_encode_box_autoadd_chart_payload(points, serializer);
+        sse_encode_u_64(baseSeq, serializer);
+        sse_encode_u_64(seq, serializer);
     }
   }
 
   @protected
-  void sse_encode_audio_info(AudioInfo self, SseSerializer serializer) {
+  void sse_encode_chart_memory_usage(
+    ChartMemoryUsage self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_data_type(self.dataType, serializer);
+    sse_encode_u_64(self.bytes, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_chart_event(
-    ChartEvent self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_chart_payload(ChartPayload self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_chart_event(self, serializer);
+    switch (self) {
+      case ChartPayload_Points(xs: final xs, ys: final ys):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_f_32_strict(xs, serializer);
+        sse_encode_list_prim_f_32_strict(ys, serializer);
+      case ChartPayload_Uniform(x0: final x0, dx: final dx, ys: final ys):
+        sse_encode_i_32(1, serializer);
+        sse_encode_f_32(x0, serializer);
+        sse_encode_f_32(dx, serializer);
+        sse_encode_list_prim_f_32_strict(ys, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_communicator_chart(
+  void sse_encode_chart_region(ChartRegion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.startX, serializer);
+    sse_encode_f_32(self.endX, serializer);
+    sse_encode_String(self.label, serializer);
+  }
+
+  @protected
+  void sse_encode_communicator_chart(
     CommunicatorChart self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_communicator_chart(self, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_data_type(self.dataType, serializer);
+    sse_encode_chart_payload(self.chart, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer) {
+  void sse_encode_config(Config self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.frameSize, serializer);
+  }
+
+  @protected
+  void sse_encode_damaged_range(DamagedRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.startFrame, serializer);
+    sse_encode_u_64(self.endFrame, serializer);
+    sse_encode_f_64(self.start, serializer);
+    sse_encode_f_64(self.end, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_data_type(DataType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_config(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_energy_calculator(
-    EnergyCalculator self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_decode_event(DecodeEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_energy_calculator(self, serializer);
+    switch (self) {
+      case DecodeEvent_Progress(key: final key, progress: final progress):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_box_autoadd_decode_progress(progress, serializer);
+      case DecodeEvent_Finished(key: final key, progress: final progress):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_box_autoadd_decode_progress(progress, serializer);
+      case DecodeEvent_Cancelled(key: final key, progress: final progress):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_box_autoadd_decode_progress(progress, serializer);
+      case DecodeEvent_Failed(key: final key, message: final message):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_String(message, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_equal_step(
-    EqualStep self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_decode_options(DecodeOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_equal_step(self, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackId, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_fft_transform(
-    FftTransform self,
+  void sse_encode_decode_progress(
+    DecodeProgress self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_fft_transform(self, serializer);
+    sse_encode_u_64(self.packets, serializer);
+    sse_encode_u_64(self.bytesRead, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.decodedFrames, serializer);
+    sse_encode_f_64(self.decodedDuration, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.estimatedDuration, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_minmax(Minmax self, SseSerializer serializer) {
+  void sse_encode_decode_report(DecodeReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_minmax(self, serializer);
+    sse_encode_list_damaged_range(self.damaged, serializer);
+    sse_encode_u_64(self.skippedPackets, serializer);
+    sse_encode_u_32(self.decoderResets, serializer);
+    sse_encode_bool(self.truncated, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_simple_format_getter(
-    SimpleFormatGetter self,
+  void sse_encode_detected_format(
+    DetectedFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_simple_format_getter(self, serializer);
+    sse_encode_String(self.container, serializer);
+    sse_encode_String(self.extension, serializer);
+    sse_encode_opt_String(self.codec, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_stream_communicator(
-    StreamCommunicator self,
+  void sse_encode_down_sample_method(
+    DownSampleMethod self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stream_communicator(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_symphonia_decoder(
-    SymphoniaDecoder self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_endianness(Endianness self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_symphonia_decoder(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_zero_crossing_rate_calculator(
-    ZeroCrossingRateCalculator self,
+  void sse_encode_export_container(
+    ExportContainer self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_zero_crossing_rate_calculator(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_chart_event(ChartEvent self, SseSerializer serializer) {
+  void sse_encode_export_options(ExportOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ChartEvent_AddChart(chart: final chart):
-        sse_encode_i_32(0, serializer);
-        sse_encode_box_autoadd_communicator_chart(chart, serializer);
-      case ChartEvent_RemoveChart(key: final key, dataType: final dataType):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(key, serializer);
-        sse_encode_data_type(dataType, serializer);
-      case ChartEvent_UpdateAllCharts(charts: final charts):
-        sse_encode_i_32(2, serializer);
-        sse_encode_list_communicator_chart(charts, serializer);
-      case ChartEvent_RemoveAllCharts():
-        sse_encode_i_32(3, serializer);
-      case ChartEvent_UpdateMaxIndex(maxIndex: final maxIndex):
-        sse_encode_i_32(4, serializer);
-        sse_encode_f_32(maxIndex, serializer);
-      case ChartEvent_UpdateYRange(minY: final minY, maxY: final maxY):
-        sse_encode_i_32(5, serializer);
-        sse_encode_f_32(minY, serializer);
-        sse_encode_f_32(maxY, serializer);
-    }
+    sse_encode_export_container(self.container, serializer);
+    sse_encode_export_sample_format(self.sampleFormat, serializer);
+    sse_encode_bool(self.dither, serializer);
   }
 
   @protected
-  void sse_encode_communicator_chart(
-    CommunicatorChart self,
+  void sse_encode_export_sample_format(
+    ExportSampleFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.key, serializer);
-    sse_encode_data_type(self.dataType, serializer);
-    sse_encode_list_point(self.chart, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_config(Config self, SseSerializer serializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.frameSize, serializer);
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  void sse_encode_data_type(DataType self, SseSerializer serializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void sse_encode_energy_calculator(
-    EnergyCalculator self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  void sse_encode_equal_step(EqualStep self, SseSerializer serializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_list_audio_memory_usage(
+    List<AudioMemoryUsage> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_memory_usage(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_fft_transform(FftTransform self, SseSerializer serializer) {
+  void sse_encode_list_audio_tag(
+    List<AudioTag> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_tag(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_list_chart_axis(
+    List<ChartAxis> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chart_axis(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    List<ChartWIthKey> self,
+  void sse_encode_list_chart_memory_usage(
+    List<ChartMemoryUsage> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-        item,
-        serializer,
-      );
+      sse_encode_chart_memory_usage(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chart_region(
+    List<ChartRegion> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chart_region(item, serializer);
     }
   }
 
@@ -5783,14 +5524,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_list_point(List<Point> self, SseSerializer serializer) {
+  void sse_encode_list_damaged_range(
+    List<DamagedRange> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_point(item, serializer);
+      sse_encode_damaged_range(item, serializer);
     }
   }
 
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
     List<int> self,
@@ -5814,8 +5568,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_minmax(Minmax self, SseSerializer serializer) {
+  void sse_encode_list_track_info(
+    List<TrackInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_memory_usage(MemoryUsage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_audio_memory_usage(self.audio, serializer);
+    sse_encode_list_chart_memory_usage(self.charts, serializer);
+    sse_encode_u_64(self.audioBytes, serializer);
+    sse_encode_u_64(self.chartBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.audioBudget, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.chartBudget, serializer);
   }
 
   @protected
@@ -5829,226 +5601,139 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_point(Point self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_channel_layout(
+    ChannelLayout? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.x, serializer);
-    sse_encode_f_32(self.y, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_channel_layout(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_record_f_32_f_32(
-    (double, double) self,
+  void sse_encode_opt_box_autoadd_down_sample_method(
+    DownSampleMethod? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.$1, serializer);
-    sse_encode_f_32(self.$2, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_down_sample_method(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_simple_format_getter(
-    SimpleFormatGetter self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_stream_communicator(
-    StreamCommunicator self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_raw_pcm_format(RawPcmFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_raw_sample_format(self.sampleFormat, serializer);
+    sse_encode_endianness(self.endianness, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_64(self.headerSkip, serializer);
   }
 
   @protected
-  void sse_encode_symphonia_decoder(
-    SymphoniaDecoder self,
+  void sse_encode_raw_sample_format(
+    RawSampleFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
+  void sse_encode_sample_storage(SampleStorage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_track_info(TrackInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.codec, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_opt_box_autoadd_channel_layout(self.channelLayout, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalFrames, serializer);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_true_peak(TruePeak self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.x, serializer);
+    sse_encode_f_32(self.value, serializer);
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_zero_crossing_rate_calculator(
-    ZeroCrossingRateCalculator self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    serializer.buffer.putUint8(self);
   }
-}
-
-@sealed
-class ArcAtomicBoolImpl extends RustOpaque implements ArcAtomicBool {
-  // Not to be used by end users
-  ArcAtomicBoolImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcAtomicBoolImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcAtomicBool,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcAtomicBool,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcAtomicBoolPtr,
-  );
-}
-
-@sealed
-class ArcVecF32Impl extends RustOpaque implements ArcVecF32 {
-  // Not to be used by end users
-  ArcVecF32Impl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcVecF32Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcVecF32,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecF32,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecF32Ptr,
-  );
-}
-
-@sealed
-class ArcVecPointImpl extends RustOpaque implements ArcVecPoint {
-  // Not to be used by end users
-  ArcVecPointImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcVecPointImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcVecPoint,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecPoint,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecPointPtr,
-  );
-}
-
-@sealed
-class ArcVecU8Impl extends RustOpaque implements ArcVecU8 {
-  // Not to be used by end users
-  ArcVecU8Impl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ArcVecU8Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ArcVecU8,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecU8,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ArcVecU8Ptr,
-  );
-}
-
-@sealed
-class AudioDataImpl extends RustOpaque implements AudioData {
-  // Not to be used by end users
-  AudioDataImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  AudioDataImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_AudioData,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AudioData,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AudioDataPtr,
-  );
-
-  ArcVecF32 get samples => RustLib.instance.api
-      .crateApiTypesAudioAudioDataAutoAccessorGetSamples(that: this);
-
-  set samples(ArcVecF32 samples) =>
-      RustLib.instance.api.crateApiTypesAudioAudioDataAutoAccessorSetSamples(
-        that: this,
-        samples: samples,
-      );
-}
-
-@sealed
-class AudioImpl extends RustOpaque implements Audio {
-  // Not to be used by end users
-  AudioImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
 
-  // Not to be used by end users
-  AudioImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Audio,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Audio,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AudioPtr,
-  );
-
-  Future<Chart> audioToChart() =>
-      RustLib.instance.api.crateApiTypesAudioAudioAudioToChart(that: this);
-
-  AudioData get data => RustLib.instance.api
-      .crateApiTypesAudioAudioAutoAccessorGetData(that: this);
-
-  AudioInfo get info => RustLib.instance.api
-      .crateApiTypesAudioAudioAutoAccessorGetInfo(that: this);
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
 
-  set data(AudioData data) => RustLib.instance.api
-      .crateApiTypesAudioAudioAutoAccessorSetData(that: this, data: data);
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
 
-  set info(AudioInfo info) => RustLib.instance.api
-      .crateApiTypesAudioAudioAutoAccessorSetInfo(that: this, info: info);
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
 }
 
 @sealed
@@ -6081,15 +5766,48 @@ class AudioProcessorEngineImpl extends RustOpaque
 
   Future<void> add({
     required String filePath,
-    required String format,
     required List<int> audioData,
+    int? trackId,
   }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineAdd(
     that: this,
     filePath: filePath,
-    format: format,
     audioData: audioData,
+    trackId: trackId,
+  );
+
+  Future<List<String>> addAllTracks({
+    required String filePath,
+    required AudioSource source,
+    required CancellationToken token,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineAddAllTracks(
+    that: this,
+    filePath: filePath,
+    source: source,
+    token: token,
   );
 
+  Future<void> addCancellable({
+    required String filePath,
+    required List<int> audioData,
+    int? trackId,
+    required CancellationToken token,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineAddCancellable(
+        that: this,
+        filePath: filePath,
+        audioData: audioData,
+        trackId: trackId,
+        token: token,
+      );
+
+  Future<List<String>> addChannelCharts({required String filePath}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineAddChannelCharts(
+        that: this,
+        filePath: filePath,
+      );
+
   Future<void> addChart({
     required String filePath,
     required DataType dataType,
@@ -6099,6 +5817,82 @@ class AudioProcessorEngineImpl extends RustOpaque
     dataType: dataType,
   );
 
+  Future<void> addFromPath({
+    required String filePath,
+    int? trackId,
+    required CancellationToken token,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineAddFromPath(
+    that: this,
+    filePath: filePath,
+    trackId: trackId,
+    token: token,
+  );
+
+  Future<void> addRawPcm({
+    required String filePath,
+    required AudioSource source,
+    required RawPcmFormat format,
+    required CancellationToken token,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineAddRawPcm(
+    that: this,
+    filePath: filePath,
+    source: source,
+    format: format,
+    token: token,
+  );
+
+  Future<void> addViewport({required String id}) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineAddViewport(that: this, id: id);
+
+  Future<DetectedFormat> detectFormat({required AudioSource source}) =>
+      RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineDetectFormat(
+        that: this,
+        source: source,
+      );
+
+  Future<Uint8List> export({
+    required String key,
+    required ExportOptions options,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineExport(
+    that: this,
+    key: key,
+    options: options,
+  );
+
+  Future<void> exportToPath({
+    required String key,
+    required String path,
+    required ExportOptions options,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineExportToPath(
+    that: this,
+    key: key,
+    path: path,
+    options: options,
+  );
+
+  Future<AudioInfo> getAudioInfo({required String filePath}) =>
+      RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineGetAudioInfo(
+        that: this,
+        filePath: filePath,
+      );
+
+  Future<DecodeReport> getDecodeReport({required String filePath}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineGetDecodeReport(
+        that: this,
+        filePath: filePath,
+      );
+
+  Future<List<TrackInfo>> listTracks({required AudioSource source}) =>
+      RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineListTracks(
+        that: this,
+        source: source,
+      );
+
+  Future<MemoryUsage> memoryUsage() => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineMemoryUsage(that: this);
+
   Future<void> removeAudio({required String filePath}) =>
       RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineRemoveAudio(
         that: this,
@@ -6114,18 +5908,64 @@ class AudioProcessorEngineImpl extends RustOpaque
     dataType: dataType,
   );
 
+  Future<void> removeViewport({required String id}) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineRemoveViewport(that: this, id: id);
+
+  Future<void> resendChart({
+    required String viewport,
+    required String filePath,
+    required DataType dataType,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineResendChart(
+    that: this,
+    viewport: viewport,
+    filePath: filePath,
+    dataType: dataType,
+  );
+
   Future<void> reserveVisible({required String chartName}) =>
       RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineReserveVisible(
         that: this,
         chartName: chartName,
       );
 
+  Future<void> setChannelMix({
+    required String filePath,
+    required ChannelMix mix,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetChannelMix(
+        that: this,
+        filePath: filePath,
+        mix: mix,
+      );
+
+  Future<void> setChartDownSampleMethod({
+    required String filePath,
+    required DataType dataType,
+    DownSampleMethod? method,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetChartDownSampleMethod(
+        that: this,
+        filePath: filePath,
+        dataType: dataType,
+        method: method,
+      );
+
   Future<void> setConfig({required Config config}) =>
       RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineSetConfig(
         that: this,
         config: config,
       );
 
+  Future<void> setDownSampleMethod({
+    required DataType dataType,
+    required DownSampleMethod method,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetDownSampleMethod(
+        that: this,
+        dataType: dataType,
+        method: method,
+      );
+
   Future<void> setDownSamplePointsNum({required BigInt pointsNum}) => RustLib
       .instance
       .api
@@ -6141,11 +5981,94 @@ class AudioProcessorEngineImpl extends RustOpaque
         end: end,
       );
 
+  Future<void> setMemoryBudget({BigInt? audioBytes, BigInt? chartBytes}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineSetMemoryBudget(
+        that: this,
+        audioBytes: audioBytes,
+        chartBytes: chartBytes,
+      );
+
+  Future<void> setOverviewPointsNum({required BigInt pointsNum}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineSetOverviewPointsNum(
+        that: this,
+        pointsNum: pointsNum,
+      );
+
+  Future<void> setSampleStorage({required SampleStorage storage}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineSetSampleStorage(
+        that: this,
+        storage: storage,
+      );
+
   Future<void> setSelectedAudio({String? chartName}) => RustLib.instance.api
       .crateApiCoreEngineAudioProcessorEngineSetSelectedAudio(
         that: this,
         chartName: chartName,
       );
+
+  Future<void> setSincInterpolation({required bool enabled}) => RustLib
+      .instance
+      .api
+      .crateApiCoreEngineAudioProcessorEngineSetSincInterpolation(
+        that: this,
+        enabled: enabled,
+      );
+
+  Future<void> setViewportChartVisible({
+    required String id,
+    required String filePath,
+    required DataType dataType,
+    required bool visible,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetViewportChartVisible(
+        that: this,
+        id: id,
+        filePath: filePath,
+        dataType: dataType,
+        visible: visible,
+      );
+
+  Future<void> setViewportPointsNum({
+    required String id,
+    required BigInt pointsNum,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetViewportPointsNum(
+        that: this,
+        id: id,
+        pointsNum: pointsNum,
+      );
+
+  Future<void> setViewportRange({
+    required String id,
+    required double start,
+    required double end,
+  }) => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineSetViewportRange(
+        that: this,
+        id: id,
+        start: start,
+        end: end,
+      );
+
+  Future<List<String>> storedAudioKeys() => RustLib.instance.api
+      .crateApiCoreEngineAudioProcessorEngineStoredAudioKeys(that: this);
+
+  Future<TruePeak> truePeak({
+    required String filePath,
+    required double start,
+    required double end,
+  }) => RustLib.instance.api.crateApiCoreEngineAudioProcessorEngineTruePeak(
+    that: this,
+    filePath: filePath,
+    start: start,
+    end: end,
+  );
 }
 
 @sealed
@@ -6238,237 +6161,31 @@ class BoxCommunicatorImpl extends RustOpaque implements BoxCommunicator {
 }
 
 @sealed
-class ChartImpl extends RustOpaque implements Chart {
-  // Not to be used by end users
-  ChartImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ChartImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Chart,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Chart,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ChartPtr,
-  );
-
-  DataType get dataType => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorGetDataType(that: this);
-
-  double get maxY => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorGetMaxY(that: this);
-
-  double get minY => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorGetMinY(that: this);
-
-  ArcVecPoint get points => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorGetPoints(that: this);
-
-  ArcAtomicBool get visible => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorGetVisible(that: this);
-
-  set dataType(DataType dataType) =>
-      RustLib.instance.api.crateApiTypesChartChartAutoAccessorSetDataType(
-        that: this,
-        dataType: dataType,
-      );
-
-  set maxY(double maxY) => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorSetMaxY(that: this, maxY: maxY);
-
-  set minY(double minY) => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorSetMinY(that: this, minY: minY);
-
-  set points(ArcVecPoint points) => RustLib.instance.api
-      .crateApiTypesChartChartAutoAccessorSetPoints(that: this, points: points);
-
-  set visible(ArcAtomicBool visible) =>
-      RustLib.instance.api.crateApiTypesChartChartAutoAccessorSetVisible(
-        that: this,
-        visible: visible,
-      );
-
-  Future<Chart> getRange({required double startX, required double endX}) =>
-      RustLib.instance.api.crateApiTypesChartChartGetRange(
-        that: this,
-        startX: startX,
-        endX: endX,
-      );
-}
-
-@sealed
-class ChartWIthKeyImpl extends RustOpaque implements ChartWIthKey {
-  // Not to be used by end users
-  ChartWIthKeyImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ChartWIthKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ChartWIthKey,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ChartWIthKey,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ChartWIthKeyPtr,
-  );
-
-  Chart get chart => RustLib.instance.api
-      .crateApiTypesChartChartWIthKeyAutoAccessorGetChart(that: this);
-
-  String get key => RustLib.instance.api
-      .crateApiTypesChartChartWIthKeyAutoAccessorGetKey(that: this);
-
-  set chart(Chart chart) =>
-      RustLib.instance.api.crateApiTypesChartChartWIthKeyAutoAccessorSetChart(
-        that: this,
-        chart: chart,
-      );
-
-  set key(String key) => RustLib.instance.api
-      .crateApiTypesChartChartWIthKeyAutoAccessorSetKey(that: this, key: key);
-}
-
-@sealed
-class FileImpl extends RustOpaque implements File {
-  // Not to be used by end users
-  FileImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  FileImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_File,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_File,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_FilePtr,
-  );
-
-  ArcVecU8 get bytes => RustLib.instance.api
-      .crateApiTypesFileFileAutoAccessorGetBytes(that: this);
-
-  String get filePath => RustLib.instance.api
-      .crateApiTypesFileFileAutoAccessorGetFilePath(that: this);
-
-  set bytes(ArcVecU8 bytes) => RustLib.instance.api
-      .crateApiTypesFileFileAutoAccessorSetBytes(that: this, bytes: bytes);
-
-  set filePath(String filePath) =>
-      RustLib.instance.api.crateApiTypesFileFileAutoAccessorSetFilePath(
-        that: this,
-        filePath: filePath,
-      );
-}
-
-@sealed
-class KvAudioStorageImpl extends RustOpaque implements KvAudioStorage {
-  // Not to be used by end users
-  KvAudioStorageImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  KvAudioStorageImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_KvAudioStorage,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_KvAudioStorage,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_KvAudioStoragePtr,
-  );
-
-  Future<Audio> load({required String key}) => RustLib.instance.api
-      .crateApiStorageKvAudioStorageKvAudioStorageLoad(that: this, key: key);
-
-  Future<void> remove({required String key}) => RustLib.instance.api
-      .crateApiStorageKvAudioStorageKvAudioStorageRemove(that: this, key: key);
-
-  Future<void> save({required String key, required Audio storageUnit}) =>
-      RustLib.instance.api.crateApiStorageKvAudioStorageKvAudioStorageSave(
-        that: this,
-        key: key,
-        storageUnit: storageUnit,
-      );
-}
-
-@sealed
-class KvCachedChartStorageImpl extends RustOpaque
-    implements KvCachedChartStorage {
+class CancellationTokenImpl extends RustOpaque implements CancellationToken {
   // Not to be used by end users
-  KvCachedChartStorageImpl.frbInternalDcoDecode(List<dynamic> wire)
+  CancellationTokenImpl.frbInternalDcoDecode(List<dynamic> wire)
     : super.frbInternalDcoDecode(wire, _kStaticData);
 
   // Not to be used by end users
-  KvCachedChartStorageImpl.frbInternalSseDecode(
+  CancellationTokenImpl.frbInternalSseDecode(
     BigInt ptr,
     int externalSizeOnNative,
   ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
   static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_increment_strong_count_KvCachedChartStorage,
-    rustArcDecrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_KvCachedChartStorage,
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_CancellationToken,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancellationToken,
     rustArcDecrementStrongCountPtr: RustLib
         .instance
         .api
-        .rust_arc_decrement_strong_count_KvCachedChartStoragePtr,
+        .rust_arc_decrement_strong_count_CancellationTokenPtr,
   );
 
-  Future<void> add({required String key, required Chart chart}) => RustLib
-      .instance
-      .api
-      .crateApiStorageKvCachedChartStorageKvCachedChartStorageAdd(
-        that: this,
-        key: key,
-        chart: chart,
-      );
-
-  Config get config => RustLib.instance.api
-      .crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorGetConfig(
-        that: this,
-      );
-
-  set config(Config config) => RustLib.instance.api
-      .crateApiStorageKvCachedChartStorageKvCachedChartStorageAutoAccessorSetConfig(
-        that: this,
-        config: config,
-      );
-
-  Future<Chart> get_({required String key, required DataType dataType}) =>
-      RustLib.instance.api
-          .crateApiStorageKvCachedChartStorageKvCachedChartStorageGet(
-            that: this,
-            key: key,
-            dataType: dataType,
-          );
-
-  Future<List<ChartWIthKey>> getAllCache() => RustLib.instance.api
-      .crateApiStorageKvCachedChartStorageKvCachedChartStorageGetAllCache(
-        that: this,
-      );
+  Future<void> cancel() => RustLib.instance.api
+      .crateApiTypesDecodeCancellationTokenCancel(that: this);
 
-  Future<void> remove({required String key, required DataType dataType}) =>
-      RustLib.instance.api
-          .crateApiStorageKvCachedChartStorageKvCachedChartStorageRemove(
-            that: this,
-            key: key,
-            dataType: dataType,
-          );
+  Future<bool> isCancelled() => RustLib.instance.api
+      .crateApiTypesDecodeCancellationTokenIsCancelled(that: this);
 }
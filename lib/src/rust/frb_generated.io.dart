@@ -3,36 +3,24 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
-import 'api/communicator/stream_sink_communicator.dart';
 import 'api/core/engine.dart';
-import 'api/decoder/symphonia_decoder.dart';
 import 'api/events/communicator_events.dart';
-import 'api/sampling/equal_step.dart';
-import 'api/sampling/minmax.dart';
-import 'api/storage/kv_audio_storage.dart';
-import 'api/storage/kv_cached_chart_storage.dart';
-import 'api/traits/audio_decoder.dart';
-import 'api/traits/audio_storage.dart';
-import 'api/traits/cached_chart_storage.dart';
-import 'api/traits/communicator.dart';
-import 'api/traits/down_sample.dart';
-import 'api/traits/transform.dart';
-import 'api/transform/energy.dart';
-import 'api/transform/fft.dart';
-import 'api/transform/zero_crossing_rate.dart';
+import 'api/events/decode_events.dart';
 import 'api/types/audio.dart';
 import 'api/types/chart.dart';
 import 'api/types/config.dart';
+import 'api/types/decode.dart';
+import 'api/types/encode.dart';
 import 'api/types/error.dart';
 import 'api/types/events.dart';
 import 'api/types/file.dart';
-import 'api/util/format_getter.dart';
-import 'api/util/get_min_max.dart';
+import 'api/types/memory.dart';
+import 'api/types/samples.dart';
+import 'api/util/format_detector.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
-import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
@@ -43,29 +31,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcAtomicBoolPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBoolPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcVecPointPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPointPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcVecF32Ptr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32Ptr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ArcVecU8Ptr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8Ptr;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AudioPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_AudioDataPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioDataPtr;
-
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_AudioProcessorEnginePtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEnginePtr;
@@ -86,63 +51,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_BoxCommunicatorPtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSyncPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ChartPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_ChartWIthKeyPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKeyPtr;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_FilePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFilePtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_KvAudioStoragePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStoragePtr;
-
   CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_KvCachedChartStoragePtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStoragePtr;
+  get rust_arc_decrement_strong_count_CancellationTokenPtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr;
 
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  ArcAtomicBool
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    dynamic raw,
-  );
-
-  @protected
-  ArcVecPoint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    dynamic raw,
-  );
-
-  @protected
-  ArcVecF32
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    dynamic raw,
-  );
-
-  @protected
-  ArcVecU8
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    dynamic raw,
-  );
-
-  @protected
-  Audio
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  );
-
-  @protected
-  AudioData
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    dynamic raw,
-  );
-
   @protected
   AudioProcessorEngine
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
@@ -174,326 +89,249 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  Chart
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+  CancellationToken
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   );
 
   @protected
-  ChartWIthKey
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+  AudioProcessorEngine
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     dynamic raw,
   );
 
   @protected
-  File
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  CancellationToken
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   );
 
   @protected
-  KvAudioStorage
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+  AudioProcessorEngine
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     dynamic raw,
   );
 
   @protected
-  KvCachedChartStorage
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  BoxAudioDecoder
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
     dynamic raw,
   );
 
   @protected
-  Audio
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+  BoxAudioStorage
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
     dynamic raw,
   );
 
   @protected
-  AudioData
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  BoxCachedChartStorage
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
     dynamic raw,
   );
 
   @protected
-  AudioProcessorEngine
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  BoxCommunicator
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
     dynamic raw,
   );
 
   @protected
-  Chart
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+  CancellationToken
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     dynamic raw,
   );
 
   @protected
-  ChartWIthKey
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  );
+  RustStreamSink<ChartEvent> dco_decode_StreamSink_chart_event_Sse(dynamic raw);
 
   @protected
-  File
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  RustStreamSink<DecodeEvent> dco_decode_StreamSink_decode_event_Sse(
     dynamic raw,
   );
 
   @protected
-  KvCachedChartStorage
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  );
+  String dco_decode_String(dynamic raw);
 
   @protected
-  Audio
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  );
+  AppError dco_decode_app_error(dynamic raw);
 
   @protected
-  AudioData
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    dynamic raw,
-  );
+  AudioInfo dco_decode_audio_info(dynamic raw);
 
   @protected
-  AudioProcessorEngine
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    dynamic raw,
-  );
+  AudioMemoryUsage dco_decode_audio_memory_usage(dynamic raw);
 
   @protected
-  Chart
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  );
+  AudioSource dco_decode_audio_source(dynamic raw);
 
   @protected
-  ChartWIthKey
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  );
+  AudioStorageBackend dco_decode_audio_storage_backend(dynamic raw);
 
   @protected
-  File
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  );
+  AudioTag dco_decode_audio_tag(dynamic raw);
 
   @protected
-  KvAudioStorage
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    dynamic raw,
-  );
+  bool dco_decode_bool(dynamic raw);
 
   @protected
-  KvCachedChartStorage
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  );
+  AudioSource dco_decode_box_autoadd_audio_source(dynamic raw);
 
   @protected
-  ArcAtomicBool
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    dynamic raw,
-  );
+  AudioStorageBackend dco_decode_box_autoadd_audio_storage_backend(dynamic raw);
 
   @protected
-  ArcVecPoint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    dynamic raw,
-  );
+  ChannelLayout dco_decode_box_autoadd_channel_layout(dynamic raw);
 
   @protected
-  ArcVecF32
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    dynamic raw,
-  );
+  ChannelMix dco_decode_box_autoadd_channel_mix(dynamic raw);
 
   @protected
-  ArcVecU8
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    dynamic raw,
-  );
+  ChartCacheBackend dco_decode_box_autoadd_chart_cache_backend(dynamic raw);
 
   @protected
-  Audio
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    dynamic raw,
-  );
+  ChartEvent dco_decode_box_autoadd_chart_event(dynamic raw);
 
   @protected
-  AudioData
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    dynamic raw,
-  );
+  ChartPayload dco_decode_box_autoadd_chart_payload(dynamic raw);
 
   @protected
-  AudioProcessorEngine
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    dynamic raw,
-  );
+  CommunicatorChart dco_decode_box_autoadd_communicator_chart(dynamic raw);
 
   @protected
-  BoxAudioDecoder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    dynamic raw,
-  );
+  Config dco_decode_box_autoadd_config(dynamic raw);
 
   @protected
-  BoxAudioStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    dynamic raw,
-  );
+  DecodeEvent dco_decode_box_autoadd_decode_event(dynamic raw);
 
   @protected
-  BoxCachedChartStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-    dynamic raw,
-  );
+  DecodeProgress dco_decode_box_autoadd_decode_progress(dynamic raw);
 
   @protected
-  BoxCommunicator
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    dynamic raw,
-  );
+  DecodeReport dco_decode_box_autoadd_decode_report(dynamic raw);
 
   @protected
-  Chart
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    dynamic raw,
-  );
+  DownSampleMethod dco_decode_box_autoadd_down_sample_method(dynamic raw);
 
   @protected
-  ChartWIthKey
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  );
+  ExportOptions dco_decode_box_autoadd_export_options(dynamic raw);
 
   @protected
-  File
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    dynamic raw,
-  );
+  double dco_decode_box_autoadd_f_64(dynamic raw);
 
   @protected
-  KvAudioStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    dynamic raw,
-  );
+  RawPcmFormat dco_decode_box_autoadd_raw_pcm_format(dynamic raw);
 
   @protected
-  KvCachedChartStorage
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    dynamic raw,
-  );
+  RawSampleFormat dco_decode_box_autoadd_raw_sample_format(dynamic raw);
 
   @protected
-  RustStreamSink<ChartEvent> dco_decode_StreamSink_chart_event_Sse(dynamic raw);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  String dco_decode_String(dynamic raw);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  ChannelLayout dco_decode_channel_layout(dynamic raw);
 
   @protected
-  AudioDecoder dco_decode_TraitDef_AudioDecoder(dynamic raw);
+  ChannelMix dco_decode_channel_mix(dynamic raw);
 
   @protected
-  AudioStorage dco_decode_TraitDef_AudioStorage(dynamic raw);
+  ChartAxis dco_decode_chart_axis(dynamic raw);
 
   @protected
-  CachedChartStorage dco_decode_TraitDef_CachedChartStorage(dynamic raw);
+  ChartCacheBackend dco_decode_chart_cache_backend(dynamic raw);
 
   @protected
-  Communicator dco_decode_TraitDef_Communicator(dynamic raw);
+  ChartEvent dco_decode_chart_event(dynamic raw);
 
   @protected
-  DownSample dco_decode_TraitDef_DownSample(dynamic raw);
+  ChartMemoryUsage dco_decode_chart_memory_usage(dynamic raw);
 
   @protected
-  FormatGetter dco_decode_TraitDef_FormatGetter(dynamic raw);
+  ChartPayload dco_decode_chart_payload(dynamic raw);
 
   @protected
-  SignalTransform dco_decode_TraitDef_SignalTransform(dynamic raw);
+  ChartRegion dco_decode_chart_region(dynamic raw);
 
   @protected
-  AppError dco_decode_app_error(dynamic raw);
+  CommunicatorChart dco_decode_communicator_chart(dynamic raw);
 
   @protected
-  AudioInfo dco_decode_audio_info(dynamic raw);
+  Config dco_decode_config(dynamic raw);
 
   @protected
-  ChartEvent dco_decode_box_autoadd_chart_event(dynamic raw);
+  DamagedRange dco_decode_damaged_range(dynamic raw);
 
   @protected
-  CommunicatorChart dco_decode_box_autoadd_communicator_chart(dynamic raw);
+  DataType dco_decode_data_type(dynamic raw);
 
   @protected
-  Config dco_decode_box_autoadd_config(dynamic raw);
+  DecodeEvent dco_decode_decode_event(dynamic raw);
 
   @protected
-  EnergyCalculator dco_decode_box_autoadd_energy_calculator(dynamic raw);
+  DecodeOptions dco_decode_decode_options(dynamic raw);
 
   @protected
-  EqualStep dco_decode_box_autoadd_equal_step(dynamic raw);
+  DecodeProgress dco_decode_decode_progress(dynamic raw);
 
   @protected
-  FftTransform dco_decode_box_autoadd_fft_transform(dynamic raw);
+  DecodeReport dco_decode_decode_report(dynamic raw);
 
   @protected
-  Minmax dco_decode_box_autoadd_minmax(dynamic raw);
+  DetectedFormat dco_decode_detected_format(dynamic raw);
 
   @protected
-  SimpleFormatGetter dco_decode_box_autoadd_simple_format_getter(dynamic raw);
+  DownSampleMethod dco_decode_down_sample_method(dynamic raw);
 
   @protected
-  StreamCommunicator dco_decode_box_autoadd_stream_communicator(dynamic raw);
+  Endianness dco_decode_endianness(dynamic raw);
 
   @protected
-  SymphoniaDecoder dco_decode_box_autoadd_symphonia_decoder(dynamic raw);
+  ExportContainer dco_decode_export_container(dynamic raw);
 
   @protected
-  ZeroCrossingRateCalculator
-  dco_decode_box_autoadd_zero_crossing_rate_calculator(dynamic raw);
+  ExportOptions dco_decode_export_options(dynamic raw);
 
   @protected
-  ChartEvent dco_decode_chart_event(dynamic raw);
+  ExportSampleFormat dco_decode_export_sample_format(dynamic raw);
 
   @protected
-  CommunicatorChart dco_decode_communicator_chart(dynamic raw);
+  double dco_decode_f_32(dynamic raw);
 
   @protected
-  Config dco_decode_config(dynamic raw);
+  double dco_decode_f_64(dynamic raw);
 
   @protected
-  DataType dco_decode_data_type(dynamic raw);
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  EnergyCalculator dco_decode_energy_calculator(dynamic raw);
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  EqualStep dco_decode_equal_step(dynamic raw);
+  List<AudioMemoryUsage> dco_decode_list_audio_memory_usage(dynamic raw);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  List<AudioTag> dco_decode_list_audio_tag(dynamic raw);
 
   @protected
-  FftTransform dco_decode_fft_transform(dynamic raw);
+  List<ChartAxis> dco_decode_list_chart_axis(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  List<ChartMemoryUsage> dco_decode_list_chart_memory_usage(dynamic raw);
 
   @protected
-  List<ChartWIthKey>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    dynamic raw,
-  );
+  List<ChartRegion> dco_decode_list_chart_region(dynamic raw);
 
   @protected
   List<CommunicatorChart> dco_decode_list_communicator_chart(dynamic raw);
 
   @protected
-  List<Point> dco_decode_list_point(dynamic raw);
+  List<DamagedRange> dco_decode_list_damaged_range(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
@@ -502,81 +340,61 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  Minmax dco_decode_minmax(dynamic raw);
+  List<TrackInfo> dco_decode_list_track_info(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  MemoryUsage dco_decode_memory_usage(dynamic raw);
 
   @protected
-  Point dco_decode_point(dynamic raw);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  (double, double) dco_decode_record_f_32_f_32(dynamic raw);
+  ChannelLayout? dco_decode_opt_box_autoadd_channel_layout(dynamic raw);
 
   @protected
-  SimpleFormatGetter dco_decode_simple_format_getter(dynamic raw);
+  DownSampleMethod? dco_decode_opt_box_autoadd_down_sample_method(dynamic raw);
 
   @protected
-  StreamCommunicator dco_decode_stream_communicator(dynamic raw);
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
-  SymphoniaDecoder dco_decode_symphonia_decoder(dynamic raw);
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  RawPcmFormat dco_decode_raw_pcm_format(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  RawSampleFormat dco_decode_raw_sample_format(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  SampleStorage dco_decode_sample_storage(dynamic raw);
 
   @protected
-  ZeroCrossingRateCalculator dco_decode_zero_crossing_rate_calculator(
-    dynamic raw,
-  );
+  TrackInfo dco_decode_track_info(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  TruePeak dco_decode_true_peak(dynamic raw);
 
   @protected
-  ArcAtomicBool
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_u_32(dynamic raw);
 
   @protected
-  ArcVecPoint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  ArcVecF32
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_u_8(dynamic raw);
 
   @protected
-  ArcVecU8
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    SseDeserializer deserializer,
-  );
+  void dco_decode_unit(dynamic raw);
 
   @protected
-  Audio
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_usize(dynamic raw);
 
   @protected
-  AudioData
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    SseDeserializer deserializer,
-  );
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
   AudioProcessorEngine
@@ -609,420 +427,355 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  Chart
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+  CancellationToken
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   );
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  File
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  AudioProcessorEngine
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     SseDeserializer deserializer,
   );
 
   @protected
-  KvAudioStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+  CancellationToken
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   );
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  AudioProcessorEngine
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     SseDeserializer deserializer,
   );
 
   @protected
-  Audio
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
+  BoxAudioDecoder
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
     SseDeserializer deserializer,
   );
 
   @protected
-  AudioData
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  BoxAudioStorage
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
     SseDeserializer deserializer,
   );
 
   @protected
-  AudioProcessorEngine
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  BoxCachedChartStorage
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
     SseDeserializer deserializer,
   );
 
   @protected
-  Chart
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+  BoxCommunicator
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
     SseDeserializer deserializer,
   );
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+  CancellationToken
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     SseDeserializer deserializer,
   );
 
   @protected
-  File
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  RustStreamSink<ChartEvent> sse_decode_StreamSink_chart_event_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  RustStreamSink<DecodeEvent> sse_decode_StreamSink_decode_event_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  Audio
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  );
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  AudioData
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    SseDeserializer deserializer,
-  );
+  AppError sse_decode_app_error(SseDeserializer deserializer);
 
   @protected
-  AudioProcessorEngine
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    SseDeserializer deserializer,
-  );
+  AudioInfo sse_decode_audio_info(SseDeserializer deserializer);
 
   @protected
-  Chart
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    SseDeserializer deserializer,
-  );
+  AudioMemoryUsage sse_decode_audio_memory_usage(SseDeserializer deserializer);
 
   @protected
-  ChartWIthKey
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    SseDeserializer deserializer,
-  );
+  AudioSource sse_decode_audio_source(SseDeserializer deserializer);
 
   @protected
-  File
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
+  AudioStorageBackend sse_decode_audio_storage_backend(
     SseDeserializer deserializer,
   );
 
   @protected
-  KvAudioStorage
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    SseDeserializer deserializer,
-  );
+  AudioTag sse_decode_audio_tag(SseDeserializer deserializer);
 
   @protected
-  KvCachedChartStorage
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    SseDeserializer deserializer,
-  );
+  AudioSource sse_decode_box_autoadd_audio_source(SseDeserializer deserializer);
 
   @protected
-  ArcAtomicBool
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
+  AudioStorageBackend sse_decode_box_autoadd_audio_storage_backend(
     SseDeserializer deserializer,
   );
 
   @protected
-  ArcVecPoint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
+  ChannelLayout sse_decode_box_autoadd_channel_layout(
     SseDeserializer deserializer,
   );
 
   @protected
-  ArcVecF32
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    SseDeserializer deserializer,
-  );
+  ChannelMix sse_decode_box_autoadd_channel_mix(SseDeserializer deserializer);
 
   @protected
-  ArcVecU8
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
+  ChartCacheBackend sse_decode_box_autoadd_chart_cache_backend(
     SseDeserializer deserializer,
   );
 
   @protected
-  Audio
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    SseDeserializer deserializer,
-  );
+  ChartEvent sse_decode_box_autoadd_chart_event(SseDeserializer deserializer);
 
   @protected
-  AudioData
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
+  ChartPayload sse_decode_box_autoadd_chart_payload(
     SseDeserializer deserializer,
   );
 
   @protected
-  AudioProcessorEngine
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+  CommunicatorChart sse_decode_box_autoadd_communicator_chart(
     SseDeserializer deserializer,
   );
 
   @protected
-  BoxAudioDecoder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    SseDeserializer deserializer,
-  );
+  Config sse_decode_box_autoadd_config(SseDeserializer deserializer);
 
   @protected
-  BoxAudioStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    SseDeserializer deserializer,
-  );
+  DecodeEvent sse_decode_box_autoadd_decode_event(SseDeserializer deserializer);
 
   @protected
-  BoxCachedChartStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
+  DecodeProgress sse_decode_box_autoadd_decode_progress(
     SseDeserializer deserializer,
   );
 
   @protected
-  BoxCommunicator
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
+  DecodeReport sse_decode_box_autoadd_decode_report(
     SseDeserializer deserializer,
   );
 
   @protected
-  Chart
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
+  DownSampleMethod sse_decode_box_autoadd_down_sample_method(
     SseDeserializer deserializer,
   );
 
   @protected
-  ChartWIthKey
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+  ExportOptions sse_decode_box_autoadd_export_options(
     SseDeserializer deserializer,
   );
 
   @protected
-  File
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    SseDeserializer deserializer,
-  );
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  KvAudioStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
+  RawPcmFormat sse_decode_box_autoadd_raw_pcm_format(
     SseDeserializer deserializer,
   );
 
   @protected
-  KvCachedChartStorage
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  RawSampleFormat sse_decode_box_autoadd_raw_sample_format(
     SseDeserializer deserializer,
   );
 
   @protected
-  RustStreamSink<ChartEvent> sse_decode_StreamSink_chart_event_Sse(
-    SseDeserializer deserializer,
-  );
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  AppError sse_decode_app_error(SseDeserializer deserializer);
+  ChannelLayout sse_decode_channel_layout(SseDeserializer deserializer);
 
   @protected
-  AudioInfo sse_decode_audio_info(SseDeserializer deserializer);
+  ChannelMix sse_decode_channel_mix(SseDeserializer deserializer);
 
   @protected
-  ChartEvent sse_decode_box_autoadd_chart_event(SseDeserializer deserializer);
+  ChartAxis sse_decode_chart_axis(SseDeserializer deserializer);
 
   @protected
-  CommunicatorChart sse_decode_box_autoadd_communicator_chart(
+  ChartCacheBackend sse_decode_chart_cache_backend(
     SseDeserializer deserializer,
   );
 
   @protected
-  Config sse_decode_box_autoadd_config(SseDeserializer deserializer);
+  ChartEvent sse_decode_chart_event(SseDeserializer deserializer);
 
   @protected
-  EnergyCalculator sse_decode_box_autoadd_energy_calculator(
-    SseDeserializer deserializer,
-  );
+  ChartMemoryUsage sse_decode_chart_memory_usage(SseDeserializer deserializer);
 
   @protected
-  EqualStep sse_decode_box_autoadd_equal_step(SseDeserializer deserializer);
+  ChartPayload sse_decode_chart_payload(SseDeserializer deserializer);
 
   @protected
-  FftTransform sse_decode_box_autoadd_fft_transform(
-    SseDeserializer deserializer,
-  );
+  ChartRegion sse_decode_chart_region(SseDeserializer deserializer);
 
   @protected
-  Minmax sse_decode_box_autoadd_minmax(SseDeserializer deserializer);
+  CommunicatorChart sse_decode_communicator_chart(SseDeserializer deserializer);
 
   @protected
-  SimpleFormatGetter sse_decode_box_autoadd_simple_format_getter(
-    SseDeserializer deserializer,
-  );
+  Config sse_decode_config(SseDeserializer deserializer);
 
   @protected
-  StreamCommunicator sse_decode_box_autoadd_stream_communicator(
-    SseDeserializer deserializer,
-  );
+  DamagedRange sse_decode_damaged_range(SseDeserializer deserializer);
 
   @protected
-  SymphoniaDecoder sse_decode_box_autoadd_symphonia_decoder(
-    SseDeserializer deserializer,
-  );
+  DataType sse_decode_data_type(SseDeserializer deserializer);
 
   @protected
-  ZeroCrossingRateCalculator
-  sse_decode_box_autoadd_zero_crossing_rate_calculator(
-    SseDeserializer deserializer,
-  );
+  DecodeEvent sse_decode_decode_event(SseDeserializer deserializer);
 
   @protected
-  ChartEvent sse_decode_chart_event(SseDeserializer deserializer);
+  DecodeOptions sse_decode_decode_options(SseDeserializer deserializer);
 
   @protected
-  CommunicatorChart sse_decode_communicator_chart(SseDeserializer deserializer);
+  DecodeProgress sse_decode_decode_progress(SseDeserializer deserializer);
 
   @protected
-  Config sse_decode_config(SseDeserializer deserializer);
+  DecodeReport sse_decode_decode_report(SseDeserializer deserializer);
 
   @protected
-  DataType sse_decode_data_type(SseDeserializer deserializer);
+  DetectedFormat sse_decode_detected_format(SseDeserializer deserializer);
+
+  @protected
+  DownSampleMethod sse_decode_down_sample_method(SseDeserializer deserializer);
 
   @protected
-  EnergyCalculator sse_decode_energy_calculator(SseDeserializer deserializer);
+  Endianness sse_decode_endianness(SseDeserializer deserializer);
 
   @protected
-  EqualStep sse_decode_equal_step(SseDeserializer deserializer);
+  ExportContainer sse_decode_export_container(SseDeserializer deserializer);
+
+  @protected
+  ExportOptions sse_decode_export_options(SseDeserializer deserializer);
+
+  @protected
+  ExportSampleFormat sse_decode_export_sample_format(
+    SseDeserializer deserializer,
+  );
 
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
   @protected
-  FftTransform sse_decode_fft_transform(SseDeserializer deserializer);
+  double sse_decode_f_64(SseDeserializer deserializer);
 
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  List<ChartWIthKey>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AudioMemoryUsage> sse_decode_list_audio_memory_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AudioTag> sse_decode_list_audio_tag(SseDeserializer deserializer);
+
+  @protected
+  List<ChartAxis> sse_decode_list_chart_axis(SseDeserializer deserializer);
+
+  @protected
+  List<ChartMemoryUsage> sse_decode_list_chart_memory_usage(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ChartRegion> sse_decode_list_chart_region(SseDeserializer deserializer);
+
   @protected
   List<CommunicatorChart> sse_decode_list_communicator_chart(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<Point> sse_decode_list_point(SseDeserializer deserializer);
+  List<DamagedRange> sse_decode_list_damaged_range(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
   @protected
-  Minmax sse_decode_minmax(SseDeserializer deserializer);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  List<TrackInfo> sse_decode_list_track_info(SseDeserializer deserializer);
 
   @protected
-  Point sse_decode_point(SseDeserializer deserializer);
+  MemoryUsage sse_decode_memory_usage(SseDeserializer deserializer);
 
   @protected
-  (double, double) sse_decode_record_f_32_f_32(SseDeserializer deserializer);
+  String? sse_decode_opt_String(SseDeserializer deserializer);
 
   @protected
-  SimpleFormatGetter sse_decode_simple_format_getter(
+  ChannelLayout? sse_decode_opt_box_autoadd_channel_layout(
     SseDeserializer deserializer,
   );
 
   @protected
-  StreamCommunicator sse_decode_stream_communicator(
+  DownSampleMethod? sse_decode_opt_box_autoadd_down_sample_method(
     SseDeserializer deserializer,
   );
 
   @protected
-  SymphoniaDecoder sse_decode_symphonia_decoder(SseDeserializer deserializer);
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  RawPcmFormat sse_decode_raw_pcm_format(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  RawSampleFormat sse_decode_raw_sample_format(SseDeserializer deserializer);
 
   @protected
-  ZeroCrossingRateCalculator sse_decode_zero_crossing_rate_calculator(
-    SseDeserializer deserializer,
-  );
+  SampleStorage sse_decode_sample_storage(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  TrackInfo sse_decode_track_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  TruePeak sse_decode_true_peak(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ArcAtomicBool self,
-    SseSerializer serializer,
-  );
+  int sse_decode_u_32(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ArcVecPoint self,
-    SseSerializer serializer,
-  );
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ArcVecF32 self,
-    SseSerializer serializer,
-  );
+  int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ArcVecU8 self,
-    SseSerializer serializer,
-  );
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
-    SseSerializer serializer,
-  );
+  BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
     SseSerializer serializer,
   );
 
@@ -1063,368 +816,334 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+    AudioProcessorEngine self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
+    AudioProcessorEngine self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
+    BoxAudioDecoder self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
+    BoxAudioStorage self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
+    BoxCachedChartStorage self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
+    BoxCommunicator self,
     SseSerializer serializer,
   );
 
   @protected
   void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  void sse_encode_StreamSink_chart_event_Sse(
+    RustStreamSink<ChartEvent> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  void sse_encode_StreamSink_decode_event_Sse(
+    RustStreamSink<DecodeEvent> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
-    SseSerializer serializer,
-  );
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
-    SseSerializer serializer,
-  );
+  void sse_encode_app_error(AppError self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
-    SseSerializer serializer,
-  );
+  void sse_encode_audio_info(AudioInfo self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
+  void sse_encode_audio_memory_usage(
+    AudioMemoryUsage self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
-    SseSerializer serializer,
-  );
+  void sse_encode_audio_source(AudioSource self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
+  void sse_encode_audio_storage_backend(
+    AudioStorageBackend self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
-    SseSerializer serializer,
-  );
+  void sse_encode_audio_tag(AudioTag self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
+  void sse_encode_box_autoadd_audio_source(
+    AudioSource self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
+  void sse_encode_box_autoadd_audio_storage_backend(
+    AudioStorageBackend self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ArcAtomicBool self,
+  void sse_encode_box_autoadd_channel_layout(
+    ChannelLayout self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ArcVecPoint self,
+  void sse_encode_box_autoadd_channel_mix(
+    ChannelMix self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ArcVecF32 self,
+  void sse_encode_box_autoadd_chart_cache_backend(
+    ChartCacheBackend self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ArcVecU8 self,
+  void sse_encode_box_autoadd_chart_event(
+    ChartEvent self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    Audio self,
+  void sse_encode_box_autoadd_chart_payload(
+    ChartPayload self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    AudioData self,
+  void sse_encode_box_autoadd_communicator_chart(
+    CommunicatorChart self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
-    AudioProcessorEngine self,
-    SseSerializer serializer,
-  );
+  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioDecoderSendSync(
-    BoxAudioDecoder self,
+  void sse_encode_box_autoadd_decode_event(
+    DecodeEvent self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAudioStorageSendSync(
-    BoxAudioStorage self,
+  void sse_encode_box_autoadd_decode_progress(
+    DecodeProgress self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCachedChartStorageSendSync(
-    BoxCachedChartStorage self,
+  void sse_encode_box_autoadd_decode_report(
+    DecodeReport self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynCommunicatorSendSync(
-    BoxCommunicator self,
+  void sse_encode_box_autoadd_down_sample_method(
+    DownSampleMethod self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    Chart self,
+  void sse_encode_box_autoadd_export_options(
+    ExportOptions self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ChartWIthKey self,
-    SseSerializer serializer,
-  );
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    File self,
+  void sse_encode_box_autoadd_raw_pcm_format(
+    RawPcmFormat self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    KvAudioStorage self,
+  void sse_encode_box_autoadd_raw_sample_format(
+    RawSampleFormat self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
-    KvCachedChartStorage self,
-    SseSerializer serializer,
-  );
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_StreamSink_chart_event_Sse(
-    RustStreamSink<ChartEvent> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_channel_layout(ChannelLayout self, SseSerializer serializer);
 
   @protected
-  void sse_encode_app_error(AppError self, SseSerializer serializer);
+  void sse_encode_channel_mix(ChannelMix self, SseSerializer serializer);
 
   @protected
-  void sse_encode_audio_info(AudioInfo self, SseSerializer serializer);
+  void sse_encode_chart_axis(ChartAxis self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_chart_event(
-    ChartEvent self,
+  void sse_encode_chart_cache_backend(
+    ChartCacheBackend self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_communicator_chart(
-    CommunicatorChart self,
+  void sse_encode_chart_event(ChartEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chart_memory_usage(
+    ChartMemoryUsage self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer);
+  void sse_encode_chart_payload(ChartPayload self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_energy_calculator(
-    EnergyCalculator self,
-    SseSerializer serializer,
-  );
+  void sse_encode_chart_region(ChartRegion self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_equal_step(
-    EqualStep self,
+  void sse_encode_communicator_chart(
+    CommunicatorChart self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_fft_transform(
-    FftTransform self,
-    SseSerializer serializer,
-  );
+  void sse_encode_config(Config self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_damaged_range(DamagedRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_data_type(DataType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_minmax(Minmax self, SseSerializer serializer);
+  void sse_encode_decode_event(DecodeEvent self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_simple_format_getter(
-    SimpleFormatGetter self,
+  void sse_encode_decode_options(DecodeOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_decode_progress(
+    DecodeProgress self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_stream_communicator(
-    StreamCommunicator self,
+  void sse_encode_decode_report(DecodeReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_detected_format(
+    DetectedFormat self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_symphonia_decoder(
-    SymphoniaDecoder self,
+  void sse_encode_down_sample_method(
+    DownSampleMethod self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_zero_crossing_rate_calculator(
-    ZeroCrossingRateCalculator self,
+  void sse_encode_endianness(Endianness self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_container(
+    ExportContainer self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_chart_event(ChartEvent self, SseSerializer serializer);
+  void sse_encode_export_options(ExportOptions self, SseSerializer serializer);
 
   @protected
-  void sse_encode_communicator_chart(
-    CommunicatorChart self,
+  void sse_encode_export_sample_format(
+    ExportSampleFormat self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_config(Config self, SseSerializer serializer);
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_data_type(DataType self, SseSerializer serializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_energy_calculator(
-    EnergyCalculator self,
-    SseSerializer serializer,
-  );
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_equal_step(EqualStep self, SseSerializer serializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_list_audio_memory_usage(
+    List<AudioMemoryUsage> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_fft_transform(FftTransform self, SseSerializer serializer);
+  void sse_encode_list_audio_tag(List<AudioTag> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_list_chart_axis(
+    List<ChartAxis> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    List<ChartWIthKey> self,
+  void sse_encode_list_chart_memory_usage(
+    List<ChartMemoryUsage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_chart_region(
+    List<ChartRegion> self,
     SseSerializer serializer,
   );
 
@@ -1435,7 +1154,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_list_point(List<Point> self, SseSerializer serializer);
+  void sse_encode_list_damaged_range(
+    List<DamagedRange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
@@ -1447,41 +1175,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_minmax(Minmax self, SseSerializer serializer);
+  void sse_encode_list_track_info(
+    List<TrackInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_memory_usage(MemoryUsage self, SseSerializer serializer);
 
   @protected
-  void sse_encode_point(Point self, SseSerializer serializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_record_f_32_f_32(
-    (double, double) self,
+  void sse_encode_opt_box_autoadd_channel_layout(
+    ChannelLayout? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_simple_format_getter(
-    SimpleFormatGetter self,
+  void sse_encode_opt_box_autoadd_down_sample_method(
+    DownSampleMethod? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_stream_communicator(
-    StreamCommunicator self,
-    SseSerializer serializer,
-  );
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_symphonia_decoder(
-    SymphoniaDecoder self,
+  void sse_encode_raw_pcm_format(RawPcmFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_raw_sample_format(
+    RawSampleFormat self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_sample_storage(SampleStorage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_track_info(TrackInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_true_peak(TruePeak self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -1491,12 +1240,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_zero_crossing_rate_calculator(
-    ZeroCrossingRateCalculator self,
-    SseSerializer serializer,
-  );
-
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 }
@@ -1515,210 +1258,6 @@ class RustLibWire implements BaseWire {
   RustLibWire(ffi.DynamicLibrary dynamicLibrary)
     : _lookup = dynamicLibrary.lookup;
 
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBoolPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBoolPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBoolPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBoolPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPointPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPointPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPointPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPointPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32 =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32 =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8 =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8 =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioDataPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioDataPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioDataPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioDataPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
     ffi.Pointer<ffi.Void> ptr,
@@ -1890,172 +1429,36 @@ class RustLibWire implements BaseWire {
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKeyPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKeyPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKeyPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKeyPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFilePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFilePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFilePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFilePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStoragePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStoragePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStoragePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStoragePtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStoragePtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage',
+        'frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken',
       );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStoragePtr
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStoragePtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage',
+        'frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken',
       );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStoragePtr
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
@@ -19,15 +19,14 @@ class AudioProcessorController {
   Future<void> addFile(
     String filePath,
     Uint8List fileData, {
-    String? format,
+    int? trackId,
   }) async {
     try {
       final audioEngine = await engine();
-      final actualFormat = format ?? filePath.split('.').last.toLowerCase();
       await audioEngine.add(
         filePath: filePath,
-        format: actualFormat,
         audioData: fileData,
+        trackId: trackId,
       );
     } catch (e, st) {
       _engineSignal.setError(e, st);
@@ -1,30 +1,116 @@
 import 'dart:async';
 import 'dart:collection';
+import 'dart:typed_data';
 
 import 'package:flutter/material.dart';
-import 'package:syncfusion_flutter_charts/charts.dart';
+import 'package:syncfusion_flutter_charts/charts.dart' hide ChartAxis;
 import 'package:vad/src/signals/audio_processor_signal.dart';
 import 'package:vad/src/rust/api/events/communicator_events.dart';
 import 'package:vad/src/rust/api/types/chart.dart';
 import 'package:vad/src/rust/api/types/events.dart';
 
+/// Id of the viewport the engine creates on its own.
+const mainViewport = 'main';
+
 class ChartWidget extends StatefulWidget {
-  const ChartWidget({super.key});
+  const ChartWidget({super.key, this.viewport = mainViewport});
+
+  /// Id of the engine viewport shown, whose events are the only ones
+  /// applied.
+  final String viewport;
 
   @override
   State<ChartWidget> createState() => _ChartWidgetState();
 }
 
+/// A chart as last received, with the `seq` of the frame that brought its
+/// points up to date.
+class _HeldChart {
+  _HeldChart(this.chart, this.seq);
+
+  CommunicatorChart chart;
+  BigInt seq;
+}
+
 class _ChartDataContainer {
-  final LinkedHashMap<String, List<CommunicatorChart>> seriesData =
-      LinkedHashMap();
+  final LinkedHashMap<String, List<_HeldChart>> seriesData = LinkedHashMap();
 
-  void addSeries(String key, CommunicatorChart chartData) {
-    seriesData.putIfAbsent(key, () => []).add(chartData);
+  void addSeries(String key, CommunicatorChart chartData, BigInt seq) {
+    final charts = seriesData.putIfAbsent(key, () => []);
+    final held = _HeldChart(chartData, seq);
+    final index = charts.indexWhere(
+      (c) => c.chart.dataType == chartData.dataType,
+    );
+    // A frame for an older view than the one held is stale.
+    if (index >= 0 && charts[index].seq > seq) return;
+    if (index < 0) {
+      charts.add(held);
+    } else {
+      charts[index] = held;
+    }
   }
 
-  void removeSeries(String key) {
-    seriesData.remove(key);
+  /// Returns false when the held points are not the ones the edit builds
+  /// on, leaving them untouched.
+  bool appendPoints(ChartEvent_AppendPoints event) {
+    final held = _getChart(event.key, event.dataType);
+    if (held == null) return false;
+    final length = held.chart.chart.length;
+    return _splice(
+      held,
+      length,
+      length,
+      event.points,
+      event.baseSeq,
+      event.seq,
+    );
+  }
+
+  /// Returns false when the held points are not the ones the edit builds
+  /// on, leaving them untouched.
+  bool replaceRange(ChartEvent_ReplaceRange event) {
+    final held = _getChart(event.key, event.dataType);
+    if (held == null) return false;
+    return _splice(
+      held,
+      event.start.toInt(),
+      event.end.toInt(),
+      event.points,
+      event.baseSeq,
+      event.seq,
+    );
+  }
+
+  bool _splice(
+    _HeldChart held,
+    int start,
+    int end,
+    ChartPayload points,
+    BigInt baseSeq,
+    BigInt seq,
+  ) {
+    if (held.seq != baseSeq) return false;
+    held.chart = CommunicatorChart(
+      key: held.chart.key,
+      dataType: held.chart.dataType,
+      chart: held.chart.chart.splice(start, end, points),
+    );
+    held.seq = seq;
+    return true;
+  }
+
+  _HeldChart? _getChart(String key, DataType dataType) {
+    for (final held in seriesData[key] ?? const <_HeldChart>[]) {
+      if (held.chart.dataType == dataType) return held;
+    }
+    return null;
+  }
+
+  void removeSeries(String key, DataType dataType) {
+    final charts = seriesData[key];
+    if (charts == null) return;
+    charts.removeWhere((held) => held.chart.dataType == dataType);
+    if (charts.isEmpty) seriesData.remove(key);
   }
 
   void clearAll() {
@@ -33,7 +119,78 @@ class _ChartDataContainer {
 
   List<String> getKeys() => seriesData.keys.toList();
 
-  List<CommunicatorChart>? getCharts(String key) => seriesData[key];
+  List<CommunicatorChart>? getCharts(String key) =>
+      seriesData[key]?.map((held) => held.chart).toList();
+}
+
+/// Reads the points of a payload in place, without building a point object
+/// per sample.
+extension _PayloadPoints on ChartPayload {
+  int get length => ys.length;
+
+  double xAt(int i) => switch (this) {
+    ChartPayload_Points(:final xs) => xs[i],
+    ChartPayload_Uniform(:final x0, :final dx) => x0 + i * dx,
+  };
+
+  double yAt(int i) => ys[i];
+
+  List<int> get indices => List<int>.generate(length, (i) => i);
+
+  /// The points with `start..end` replaced by those of `points`.
+  ChartPayload splice(int start, int end, ChartPayload points) {
+    final uniform = _spliceUniform(start, end, points);
+    if (uniform != null) return uniform;
+
+    final xs = Float32List(length - (end - start) + points.length);
+    final ys = Float32List(xs.length);
+    var n = 0;
+    void copy(ChartPayload from, int begin, int stop) {
+      for (var i = begin; i < stop; i++, n++) {
+        xs[n] = from.xAt(i);
+        ys[n] = from.yAt(i);
+      }
+    }
+
+    copy(this, 0, start);
+    copy(points, 0, points.length);
+    copy(this, end, length);
+    return ChartPayload.points(xs: xs, ys: ys);
+  }
+
+  /// [splice] for when this and `points` are uniform with the same step and
+  /// line up, so the result is too; null otherwise. Joins get half a step of
+  /// slack, as each `x0` was rounded to f32.
+  ChartPayload? _spliceUniform(int start, int end, ChartPayload points) {
+    final self = this;
+    if (self is! ChartPayload_Uniform) return null;
+    final dx = self.dx;
+    if (points case ChartPayload_Uniform(dx: final pointsDx) when pointsDx != dx) {
+      return null;
+    }
+    if (points is ChartPayload_Points && points.length > 0) return null;
+
+    bool joins(double? before, double after) =>
+        before == null || (after - before - dx).abs() <= dx / 2;
+    double? before = start > 0 ? xAt(start - 1) : null;
+    if (points.length > 0) {
+      if (!joins(before, points.xAt(0))) return null;
+      before = points.xAt(points.length - 1);
+    }
+    if (end < length && !joins(before, xAt(end))) return null;
+
+    final count = length - (end - start) + points.length;
+    final ys = Float32List(count)
+      ..setRange(0, start, self.ys)
+      ..setRange(start, start + points.length, points.ys)
+      ..setRange(start + points.length, count, self.ys, end);
+    final x0 = start > 0
+        ? self.x0
+        : points.length > 0
+        ? points.xAt(0)
+        : xAt(end);
+    return ChartPayload.uniform(x0: x0, dx: dx, ys: ys);
+  }
 }
 
 class _ChartWidgetState extends State<ChartWidget> {
@@ -43,48 +200,93 @@ class _ChartWidgetState extends State<ChartWidget> {
   late final _ChartDataContainer _chartDataContainer = _ChartDataContainer();
   double minXAxis = 0.0;
   double maxXAxis = 10000.0;
-  double minYAxis = -0.5;
-  double maxYAxis = 0.5;
+  List<ChartAxis> _axes = const [];
+  final LinkedHashMap<(String, DataType), CommunicatorChart> _overview =
+      LinkedHashMap();
+  final Map<String, List<ChartRegion>> _regions = {};
+  final _visibleRange = ValueNotifier<(double, double)>((0.0, 0.0));
 
   @override
   void dispose() {
     _chartEventSubscription?.cancel();
+    _visibleRange.dispose();
+    if (widget.viewport != mainViewport) {
+      audioProcessorEngine.engine().then(
+        (engine) => engine.removeViewport(id: widget.viewport),
+      );
+    }
     super.dispose();
   }
 
   @override
   void initState() {
     super.initState();
+    if (widget.viewport != mainViewport) {
+      audioProcessorEngine.engine().then(
+        (engine) => engine.addViewport(id: widget.viewport),
+      );
+    }
     _chartEventSubscription = createChartEventStream().listen(
       (event) {
+        final viewport = _viewportOf(event);
+        if (viewport != null && viewport != widget.viewport) return;
         switch (event) {
           case ChartEvent_AddChart():
             {
-              _chartDataContainer.addSeries(event.chart.key, event.chart);
+              _chartDataContainer.addSeries(
+                event.chart.key,
+                event.chart,
+                event.seq,
+              );
             }
           case ChartEvent_UpdateAllCharts():
             {
               _chartDataContainer.clearAll();
               for (final chart in event.charts) {
-                _chartDataContainer.addSeries(chart.key, chart);
+                _chartDataContainer.addSeries(chart.key, chart, BigInt.zero);
+              }
+            }
+          case ChartEvent_AppendPoints():
+            {
+              if (!_chartDataContainer.appendPoints(event)) {
+                _resendChart(event.viewport, event.key, event.dataType);
+              }
+            }
+          case ChartEvent_ReplaceRange():
+            {
+              if (!_chartDataContainer.replaceRange(event)) {
+                _resendChart(event.viewport, event.key, event.dataType);
               }
             }
           case ChartEvent_RemoveChart():
             {
-              _chartDataContainer.removeSeries(event.key);
+              _chartDataContainer.removeSeries(event.key, event.dataType);
+              _overview.remove((event.key, event.dataType));
+              if (event.dataType == DataType.audio) {
+                _regions.remove(event.key);
+              }
             }
           case ChartEvent_RemoveAllCharts():
             {
               _chartDataContainer.clearAll();
+              _overview.clear();
+              _regions.clear();
+            }
+          case ChartEvent_UpdateRegions():
+            {
+              _regions[event.key] = event.regions;
+            }
+          case ChartEvent_UpdateOverview():
+            {
+              _overview[(event.chart.key, event.chart.dataType)] = event.chart;
             }
           case ChartEvent_UpdateMaxIndex():
             {
               maxXAxis = event.maxIndex.toDouble();
             }
-          case ChartEvent_UpdateYRange():
+          case ChartEvent_UpdateAxes():
             {
-              minYAxis = event.minY.toDouble();
-              maxYAxis = event.maxY.toDouble();
+              _axes = event.axes;
             }
         }
         setState(() {});
@@ -94,6 +296,31 @@ class _ChartWidgetState extends State<ChartWidget> {
     );
   }
 
+  /// The viewport an event is for, or null if it concerns every viewport.
+  static String? _viewportOf(ChartEvent event) => switch (event) {
+    ChartEvent_AddChart(:final viewport) ||
+    ChartEvent_UpdateOverview(:final viewport) ||
+    ChartEvent_UpdateMaxIndex(:final viewport) ||
+    ChartEvent_UpdateAxes(:final viewport) ||
+    ChartEvent_AppendPoints(:final viewport) ||
+    ChartEvent_ReplaceRange(:final viewport) => viewport,
+    _ => null,
+  };
+
+  /// Asks for the whole chart again after an edit that could not be applied.
+  Future<void> _resendChart(
+    String viewport,
+    String key,
+    DataType dataType,
+  ) async {
+    final engine = await audioProcessorEngine.engine();
+    await engine.resendChart(
+      viewport: viewport,
+      filePath: key,
+      dataType: dataType,
+    );
+  }
+
   void _onSizeChanged(Size size) {
     if (size.width != _lastWidth) {
       _lastWidth = size.width;
@@ -103,7 +330,10 @@ class _ChartWidgetState extends State<ChartWidget> {
 
   Future<void> _updateEnginePoints(double width) async {
     final engine = await audioProcessorEngine.engine();
-    await engine.setDownSamplePointsNum(pointsNum: BigInt.from(width.toInt()));
+    await engine.setViewportPointsNum(
+      id: widget.viewport,
+      pointsNum: BigInt.from(width.toInt()),
+    );
   }
 
   @override
@@ -114,7 +344,7 @@ class _ChartWidgetState extends State<ChartWidget> {
         WidgetsBinding.instance.addPostFrameCallback(
           (_) => _onSizeChanged(constraints.biggest),
         );
-        return SizedBox(
+        final chart = SizedBox(
           key: _containerKey,
           height: 500,
           child: RepaintBoundary(
@@ -157,16 +387,32 @@ class _ChartWidgetState extends State<ChartWidget> {
                     borderColor: Colors.red,
                     borderWidth: 1,
                   ),
+                  for (final region in _regions.values.expand((r) => r))
+                    PlotBand(
+                      isVisible: true,
+                      start: region.startX,
+                      end: region.endX,
+                      text: region.label,
+                      color: Theme.of(context).colorScheme.error,
+                      opacity: 0.15,
+                    ),
                 ],
               ),
+              primaryYAxis: NumericAxis(isVisible: _axes.isEmpty),
+              axes: _buildYAxes(),
               onActualRangeChanged: (ActualRangeChangedArgs rangeChangedArgs) {
                 if (rangeChangedArgs.axisName == 'primaryXAxis') {
                   double minX = (rangeChangedArgs.visibleMin as num).toDouble();
                   double maxX = (rangeChangedArgs.visibleMax as num).toDouble();
 
                   WidgetsBinding.instance.addPostFrameCallback((_) async {
+                    _visibleRange.value = (minX, maxX);
                     final engine = await audioProcessorEngine.engine();
-                    await engine.setIndexRange(start: minX, end: maxX);
+                    await engine.setViewportRange(
+                      id: widget.viewport,
+                      start: minX,
+                      end: maxX,
+                    );
                   });
                 }
               },
@@ -183,10 +429,103 @@ class _ChartWidgetState extends State<ChartWidget> {
             ),
           ),
         );
+        return Column(
+          children: [
+            chart,
+            if (_overview.isNotEmpty) _buildOverviewStrip(context),
+          ],
+        );
       },
     );
   }
 
+  /// Every chart whole at a fixed resolution, with the range shown above
+  /// marked on it.
+  Widget _buildOverviewStrip(BuildContext context) {
+    final colorScheme = Theme.of(context).colorScheme;
+    final colors = _getChartColors();
+    final charts = _overview.values.toList();
+    return SizedBox(
+      height: 80,
+      child: RepaintBoundary(
+        child: ValueListenableBuilder(
+          valueListenable: _visibleRange,
+          builder: (context, visibleRange, _) {
+            return SfCartesianChart(
+              backgroundColor: colorScheme.surfaceContainerLow,
+              plotAreaBorderWidth: 0,
+              primaryXAxis: NumericAxis(
+                minimum: 0.0,
+                maximum: maxXAxis,
+                isVisible: false,
+                rangePadding: ChartRangePadding.none,
+                plotBands: [
+                  PlotBand(
+                    isVisible: true,
+                    start: visibleRange.$1,
+                    end: visibleRange.$2,
+                    color: colorScheme.primary,
+                    opacity: 0.2,
+                  ),
+                ],
+              ),
+              primaryYAxis: NumericAxis(isVisible: false),
+              // An axis per chart, so each fills the height of the strip.
+              axes: [
+                for (final (index, _) in charts.indexed)
+                  NumericAxis(name: 'overview$index', isVisible: false),
+              ],
+              series: [
+                for (final (index, overview) in charts.indexed)
+                  FastLineSeries<int, double>(
+                    dataSource: overview.chart.indices,
+                    xValueMapper: (int i, _) => overview.chart.xAt(i),
+                    yValueMapper: (int i, _) => overview.chart.yAt(i),
+                    yAxisName: 'overview$index',
+                    color: colors[index % colors.length],
+                    width: 0.4,
+                    animationDuration: 0,
+                  ),
+              ],
+            );
+          },
+        ),
+      ),
+    );
+  }
+
+  /// One y axis per axis of the viewport, named after its data type so the
+  /// series drawn against it can refer to it.
+  List<NumericAxis> _buildYAxes() {
+    return [
+      for (final (index, axis) in _axes.indexed)
+        NumericAxis(
+          name: axis.dataType.name,
+          title: AxisTitle(
+            text: axis.unit.isEmpty
+                ? axis.label
+                : '${axis.label} (${axis.unit})',
+          ),
+          minimum: axis.minY,
+          maximum: axis.maxY,
+          opposedPosition: index.isOdd,
+          rangePadding: ChartRangePadding.none,
+          majorGridLines: const MajorGridLines(width: 0),
+        ),
+    ];
+  }
+
+  /// The axis listing the chart among its keys, or null for the primary
+  /// axis until one does.
+  String? _yAxisNameOf(String key, DataType dataType) {
+    for (final axis in _axes) {
+      if (axis.dataType == dataType && axis.keys.contains(key)) {
+        return axis.dataType.name;
+      }
+    }
+    return null;
+  }
+
   List<CartesianSeries> _buildChartSeries() {
     final keys = _chartDataContainer.getKeys();
     final colors = _getChartColors();
@@ -199,15 +538,18 @@ class _ChartWidgetState extends State<ChartWidget> {
 
       for (final communicatorChart in charts) {
         final color = colors[seriesIndex % colors.length];
+        final payload = communicatorChart.chart;
+        final yAxisName = _yAxisNameOf(key, communicatorChart.dataType);
         switch (communicatorChart.dataType) {
           case DataType.zeroCrossingRate || DataType.energy:
             {
               seriesList.add(
-                StepLineSeries<Point, double>(
+                StepLineSeries<int, double>(
                   name: '$key ${communicatorChart.dataType.name}',
-                  dataSource: communicatorChart.chart,
-                  xValueMapper: (Point point, _) => point.x,
-                  yValueMapper: (Point point, _) => point.y,
+                  dataSource: payload.indices,
+                  xValueMapper: (int i, _) => payload.xAt(i),
+                  yValueMapper: (int i, _) => payload.yAt(i),
+                  yAxisName: yAxisName,
                   animationDuration: 0,
                   width: 0.4,
                 ),
@@ -216,11 +558,12 @@ class _ChartWidgetState extends State<ChartWidget> {
           default:
             {
               seriesList.add(
-                FastLineSeries<Point, double>(
+                FastLineSeries<int, double>(
                   name: '$key ${communicatorChart.dataType.name}',
-                  dataSource: communicatorChart.chart,
-                  xValueMapper: (Point point, _) => point.x,
-                  yValueMapper: (Point point, _) => point.y,
+                  dataSource: payload.indices,
+                  xValueMapper: (int i, _) => payload.xAt(i),
+                  yValueMapper: (int i, _) => payload.yAt(i),
+                  yAxisName: yAxisName,
                   color: color,
                   width: 0.4,
                   animationDuration: 0,
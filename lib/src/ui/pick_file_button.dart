@@ -27,12 +27,7 @@ class PickFileButton extends StatelessWidget {
             debugPrint(
               'Picked file: ${file.name}, size: ${await file.length()} bytes',
             );
-            final format = file.name.split('.').last.toLowerCase();
-            await audioProcessorEngine.addFile(
-              file.path,
-              bytes,
-              format: format,
-            );
+            await audioProcessorEngine.addFile(file.path, bytes);
             audioProcessorEngine.engine().then((engine) async {
               await engine.addChart(
                 filePath: file.path,
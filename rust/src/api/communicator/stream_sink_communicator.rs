@@ -2,12 +2,15 @@ use crate::api::{
     events::communicator_events::emit_chart_event,
     traits::communicator::Communicator,
     types::{
-        chart::{Chart, ChartWIthKey, CommunicatorChart, DataType},
+        chart::{
+            Chart, ChartAxis, ChartPayload, ChartRegion, ChartSeries, ChartWIthKey,
+            CommunicatorChart, DataType,
+        },
         events::ChartEvent,
     },
 };
 
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{Mutex, OnceLock};
 
 pub struct StreamCommunicator {}
 
@@ -18,13 +21,15 @@ impl StreamCommunicator {
 }
 
 impl Communicator for StreamCommunicator {
-    fn add_chart(&self, key: String, chart: Chart) {
+    fn add_chart(&self, viewport: String, key: String, chart: Chart, seq: u64) {
         emit_chart_event(ChartEvent::AddChart {
+            viewport,
             chart: CommunicatorChart {
                 key,
                 data_type: chart.data_type,
-                chart: Arc::try_unwrap(chart.points).unwrap_or_else(|v| (*v).clone()),
+                chart: ChartPayload::from_series(&chart.series),
             },
+            seq,
         });
     }
 
@@ -34,7 +39,7 @@ impl Communicator for StreamCommunicator {
             .map(|c| CommunicatorChart {
                 key: c.key,
                 data_type: c.chart.data_type,
-                chart: Arc::try_unwrap(c.chart.points).unwrap_or_else(|v| (*v).clone()),
+                chart: ChartPayload::from_series(&c.chart.series),
             })
             .collect();
         emit_chart_event(ChartEvent::UpdateAllCharts {
@@ -42,6 +47,17 @@ impl Communicator for StreamCommunicator {
         });
     }
 
+    fn update_overview(&self, viewport: String, key: String, chart: Chart) {
+        emit_chart_event(ChartEvent::UpdateOverview {
+            viewport,
+            chart: CommunicatorChart {
+                key,
+                data_type: chart.data_type,
+                chart: ChartPayload::from_series(&chart.series),
+            },
+        });
+    }
+
     fn remove_all_charts(&self) {
         emit_chart_event(ChartEvent::RemoveAllCharts {});
     }
@@ -50,11 +66,57 @@ impl Communicator for StreamCommunicator {
         emit_chart_event(ChartEvent::RemoveChart { key, data_type });
     }
 
-    fn update_max_index(&self, max_index: f32) {
-        emit_chart_event(ChartEvent::UpdateMaxIndex { max_index });
+    fn update_max_index(&self, viewport: String, max_index: f32) {
+        emit_chart_event(ChartEvent::UpdateMaxIndex {
+            viewport,
+            max_index,
+        });
     }
     
-    fn update_y_range(&self, min_y: f32, max_y: f32) {
-        emit_chart_event(ChartEvent::UpdateYRange { min_y, max_y });
+    fn update_axes(&self, viewport: String, axes: Vec<ChartAxis>) {
+        emit_chart_event(ChartEvent::UpdateAxes { viewport, axes });
+    }
+
+    fn update_regions(&self, key: String, regions: Vec<ChartRegion>) {
+        emit_chart_event(ChartEvent::UpdateRegions { key, regions });
+    }
+
+    fn append_points(
+        &self,
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        points: ChartSeries,
+        (base_seq, seq): (u64, u64),
+    ) {
+        emit_chart_event(ChartEvent::AppendPoints {
+            viewport,
+            key,
+            data_type,
+            points: ChartPayload::from_series(&points),
+            base_seq,
+            seq,
+        });
+    }
+
+    fn replace_range(
+        &self,
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        (start, end): (usize, usize),
+        points: ChartSeries,
+        (base_seq, seq): (u64, u64),
+    ) {
+        emit_chart_event(ChartEvent::ReplaceRange {
+            viewport,
+            key,
+            data_type,
+            start,
+            end,
+            points: ChartPayload::from_series(&points),
+            base_seq,
+            seq,
+        });
     }
 }
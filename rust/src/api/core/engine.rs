@@ -1,15 +1,34 @@
-use std::sync::atomic;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{
+    atomic::{self, AtomicU64},
+    Arc, Mutex, MutexGuard,
+};
 
-use log::info;
+use log::{info, warn};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::api::storage::{
+    disk_cached_chart_storage::DiskCachedChartStorage, file_audio_storage::FileAudioStorage,
+};
 use crate::api::{
     communicator,
-    decoder::symphonia_decoder::SymphoniaDecoder,
-    sampling::minmax::{self, Minmax},
+    decoder::{raw_pcm_decoder::RawPcmDecoder, symphonia_decoder::SymphoniaDecoder},
+    encoder::{flac_encoder::FlacEncoder, wav_encoder::WavEncoder},
+    events::decode_events::emit_decode_event,
+    sampling::{
+        equal_step::EqualStep,
+        lttb::Lttb,
+        m4::M4,
+        minmax::{self, Minmax},
+        sinc::SincInterpolation,
+    },
     storage::{kv_audio_storage::KvAudioStorage, kv_cached_chart_storage::KvCachedChartStorage},
     traits::{
-        audio_decoder::AudioDecoder, audio_storage::AudioStorage,
+        audio_decoder::{AudioDecoder, DecodeUpdate},
+        audio_encoder::AudioEncoder,
+        audio_storage::AudioStorage,
         cached_chart_storage::CachedChartStorage, communicator::Communicator,
         down_sample::DownSample, transform::SignalTransform,
     },
@@ -17,23 +36,169 @@ use crate::api::{
         energy::EnergyCalculator, fft::FftTransform, zero_crossing_rate::ZeroCrossingRateCalculator,
     },
     types::{
-        chart::{Chart, ChartWIthKey, DataType},
-        config::Config,
+        audio::{Audio, AudioData, AudioInfo, ChannelMix, TrackInfo},
+        chart::{
+            Chart, ChartAxis, ChartParams, ChartRegion, DataType, DownSampleMethod, Point, TruePeak,
+        },
+        config::{AudioStorageBackend, ChartCacheBackend, Config},
+        decode::{CancellationToken, DecodeOptions, DecodeProgress, DecodeReport, RawPcmFormat},
+        encode::{ExportContainer, ExportOptions},
         error::AppError,
+        events::DecodeEvent,
+        file::{AudioSource, SharedSource},
+        memory::MemoryUsage,
+        samples::SampleStorage,
+    },
+    util::{
+        block_on::block_on,
+        channel,
+        format_detector::{detect_format, DetectedFormat},
+        point_diff::{diff_points, PointEdit},
     },
 };
 
-pub struct AudioProcessorEngine {
-    config: Config,
-    decoder: Box<dyn AudioDecoder + Send + Sync>,
-    storage: Box<dyn AudioStorage + Send + Sync>,
-    cache: Box<dyn CachedChartStorage + Send + Sync>,
-    communicator: Box<dyn Communicator + Send + Sync>,
-    down_sample_points_num: usize,
+/// Interleaved samples decoded before the first partial chart is shown. Each
+/// later snapshot waits for twice as many, so copying stays linear overall.
+const SNAPSHOT_SAMPLES: usize = 1 << 20;
+
+/// The viewport the engine starts with. Charts are shown in it when added,
+/// and the calls that take no viewport id act on it.
+pub const MAIN_VIEWPORT: &str = "main";
+
+const DATA_TYPES: [DataType; 4] = [
+    DataType::Audio,
+    DataType::Spectrum,
+    DataType::Energy,
+    DataType::ZeroCrossingRate,
+];
+
+/// A chart the user has added. The chart itself may be evicted from the cache
+/// at any time and is recomputed from the stored audio when needed again.
+struct ActiveChart {
+    key: String,
+    data_type: DataType,
+}
+
+/// One view onto the active charts, such as a detail view or a comparison
+/// pane, with its own x range, resolution and charts shown.
+#[derive(Clone)]
+struct Viewport {
+    id: String,
     index_range: (f32, f32),
-    selected_audio: Option<String>,
+    points_num: usize,
     max_index: f32,
-    y_range: (f32, f32),
+    /// The y axes last sent, fitted to what the viewport shows.
+    axes: Arc<Mutex<Vec<ChartAxis>>>,
+    /// Charts added to this viewport, shown or hidden. Others are hidden.
+    charts: Vec<ViewportChart>,
+    /// Sequence number of the latest render, bumped for every render so an
+    /// outdated one stops early.
+    seq: Arc<AtomicU64>,
+}
+
+impl Viewport {
+    fn new(id: String) -> Self {
+        Self {
+            id,
+            index_range: (0.0, 0.0),
+            points_num: 500,
+            max_index: 10000.0,
+            axes: Arc::default(),
+            charts: Vec::new(),
+            seq: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    fn chart(&self, file_path: &str, data_type: DataType) -> Option<&ViewportChart> {
+        self.charts
+            .iter()
+            .find(|c| c.key == file_path && c.data_type == data_type)
+    }
+
+    fn visible_charts(&self) -> impl Iterator<Item = &ViewportChart> {
+        self.charts.iter().filter(|c| c.visible)
+    }
+}
+
+/// A chart added to a viewport, with what the viewport was last sent of it.
+#[derive(Clone)]
+struct ViewportChart {
+    key: String,
+    data_type: DataType,
+    visible: bool,
+    sent: Arc<Mutex<SentChart>>,
+}
+
+impl ViewportChart {
+    fn lock_sent(&self) -> MutexGuard<'_, SentChart> {
+        self.sent.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn mark_dirty(&self) {
+        let mut sent = self.lock_sent();
+        sent.view = None;
+        sent.overview = None;
+    }
+}
+
+/// What the client was last sent for a chart, so an update only carries what
+/// changed.
+#[derive(Default)]
+struct SentChart {
+    /// The view `points` were down-sampled for. `None` once the chart itself
+    /// may have changed, which makes it dirty whatever the view.
+    view: Option<ChartView>,
+    /// `None` until the chart is first sent.
+    points: Option<Vec<Point>>,
+    /// The sequence number `points` were sent with, which edits to them
+    /// name as their base.
+    seq: u64,
+    /// The view the overview was last sent for, with the same meaning of
+    /// `None` as `view`.
+    overview: Option<ChartView>,
+}
+
+/// Renders waiting for the render worker, at most one per viewport. Only the
+/// latest is kept, as it supersedes any asked for before it.
+#[derive(Default)]
+struct RenderQueue {
+    pending: Vec<RenderJob>,
+    /// Whether the worker is running, in which case it picks up `pending`.
+    running: bool,
+}
+
+/// The visible charts of a viewport with the views and params to render them
+/// for, as they were when the render was asked for.
+struct RenderJob {
+    viewport: String,
+    seq: u64,
+    current_seq: Arc<AtomicU64>,
+    axes: Arc<Mutex<Vec<ChartAxis>>>,
+    charts: Vec<(ViewportChart, ChartView)>,
+    config: Config,
+    channel_mixes: HashMap<String, ChannelMix>,
+}
+
+/// Everything besides the chart that its down-sampled points depend on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ChartView {
+    index_range: (f32, f32),
+    points_num: usize,
+    method: DownSampleMethod,
+    sinc: bool,
+}
+
+enum DecodeMessage {
+    Progress(DecodeProgress),
+    Snapshot(Audio),
+    Done(Result<Audio, AppError>),
+}
+
+/// Every call takes `&self` and locks the engine's state only for as long as
+/// it needs it, so a long decode does not hold up viewport changes or
+/// cancelling.
+pub struct AudioProcessorEngine {
+    core: Mutex<EngineCore>,
 }
 
 impl AudioProcessorEngine {
@@ -45,169 +210,1290 @@ impl AudioProcessorEngine {
         communicator: Box<dyn Communicator + Send + Sync>,
     ) -> Self {
         Self {
-            config,
+            core: Mutex::new(EngineCore::new(
+                config,
+                decoder,
+                storage,
+                cache,
+                communicator,
+            )),
+        }
+    }
+
+    fn core(&self) -> MutexGuard<'_, EngineCore> {
+        self.core.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sets the number of points in the overview of each chart.
+    pub async fn set_overview_points_num(&self, points_num: usize) {
+        self.core().set_overview_points_num(points_num)
+    }
+
+    /// Adds a viewport with no charts in it, keeping the one already there
+    /// if `id` is taken.
+    pub async fn add_viewport(&self, id: String) {
+        self.core().add_viewport(id)
+    }
+
+    /// Removes a viewport. The main one cannot be removed.
+    pub async fn remove_viewport(&self, id: String) -> Result<(), AppError> {
+        self.core().remove_viewport(id)
+    }
+
+    /// Coalesces with other changes to the same viewport, so only the latest
+    /// is rendered.
+    pub async fn set_viewport_range(
+        &self,
+        id: String,
+        start: f32,
+        end: f32,
+    ) -> Result<(), AppError> {
+        self.core().set_viewport_range(id, start, end)
+    }
+
+    /// Coalesces with other changes to the same viewport, so only the latest
+    /// is rendered.
+    pub async fn set_viewport_points_num(
+        &self,
+        id: String,
+        points_num: usize,
+    ) -> Result<(), AppError> {
+        self.core().set_viewport_points_num(id, points_num)
+    }
+
+    /// Shows or hides an added chart in a viewport.
+    pub async fn set_viewport_chart_visible(
+        &self,
+        id: String,
+        file_path: String,
+        data_type: DataType,
+        visible: bool,
+    ) -> Result<(), AppError> {
+        self.core()
+            .set_viewport_chart_visible(id, file_path, data_type, visible)
+    }
+
+    /// Sets the point budget of the main viewport.
+    pub async fn set_down_sample_points_num(&self, points_num: usize) {
+        self.core().set_down_sample_points_num(points_num)
+    }
+
+    /// Chooses how charts of `data_type` are reduced to the requested number
+    /// of points, such as LTTB for smooth feature curves.
+    pub async fn set_down_sample_method(&self, data_type: DataType, method: DownSampleMethod) {
+        self.core().set_down_sample_method(data_type, method)
+    }
+
+    /// Overrides the down-sampling of one chart; `None` goes back to the
+    /// method set for its data type.
+    pub async fn set_chart_down_sample_method(
+        &self,
+        file_path: String,
+        data_type: DataType,
+        method: Option<DownSampleMethod>,
+    ) {
+        self.core()
+            .set_chart_down_sample_method(file_path, data_type, method)
+    }
+
+    /// When on, a waveform zoomed in past one sample per point is drawn as
+    /// the smooth band-limited curve through its samples instead of straight
+    /// lines between them.
+    pub async fn set_sinc_interpolation(&self, enabled: bool) {
+        self.core().set_sinc_interpolation(enabled)
+    }
+
+    /// Sets the x range of the main viewport.
+    pub async fn set_index_range(&self, start: f32, end: f32) {
+        self.core().set_index_range(start, end)
+    }
+
+    /// Charts computed with the old config stay on screen until their
+    /// replacements, computed in the background, are pushed.
+    pub async fn set_config(&self, config: Config) {
+        self.core().set_config(config)
+    }
+
+    pub async fn add(
+        &self,
+        file_path: String,
+        audio_data: Vec<u8>,
+        track_id: Option<u32>,
+    ) -> Result<(), AppError> {
+        self.add_cancellable(file_path, audio_data, track_id, &CancellationToken::new())
+            .await
+    }
+
+    /// Decodes on a thread of its own, streaming [`DecodeEvent`]s and charting
+    /// the audio decoded so far while the rest is still being read. The
+    /// engine stays free for other calls meanwhile. Cancelling `token` keeps
+    /// whatever was decoded up to that point.
+    pub async fn add_cancellable(
+        &self,
+        file_path: String,
+        audio_data: Vec<u8>,
+        track_id: Option<u32>,
+        token: &CancellationToken,
+    ) -> Result<(), AppError> {
+        let options = DecodeOptions { track_id };
+        let decoder = self.core().decoder.clone();
+        self.ingest(
+            file_path,
             decoder,
-            storage,
-            cache,
-            communicator,
-            down_sample_points_num: 500,
-            index_range: (0.0, 0.0),
+            SharedSource::Bytes(audio_data.into()),
+            options,
+            token,
+        )
+            .await
+    }
+
+    /// Opens `file_path` on the Rust side and streams it into the decoder, so
+    /// the file is neither copied across the bridge nor held in memory whole.
+    pub async fn add_from_path(
+        &self,
+        file_path: String,
+        track_id: Option<u32>,
+        token: &CancellationToken,
+    ) -> Result<(), AppError> {
+        let source = SharedSource::Path(file_path.clone());
+        let options = DecodeOptions { track_id };
+        let decoder = self.core().decoder.clone();
+        self.ingest(file_path, decoder, source, options, token)
+            .await
+    }
+
+    /// Imports a headerless PCM dump with the [`RawPcmDecoder`] instead of the
+    /// engine's probing decoder.
+    pub async fn add_raw_pcm(
+        &self,
+        file_path: String,
+        source: AudioSource,
+        format: RawPcmFormat,
+        token: &CancellationToken,
+    ) -> Result<(), AppError> {
+        let decoder = Arc::new(RawPcmDecoder::new(format));
+        self.ingest(
+            file_path,
+            decoder,
+            source.into(),
+            DecodeOptions::default(),
+            token,
+        )
+        .await
+    }
+
+    /// Container and codec recognised from the content, regardless of the
+    /// file name.
+    pub async fn detect_format(&self, source: AudioSource) -> Result<DetectedFormat, AppError> {
+        detect_format(&source.into())
+    }
+
+    /// Audio tracks of a container, for picking one before calling `add`.
+    pub async fn list_tracks(&self, source: AudioSource) -> Result<Vec<TrackInfo>, AppError> {
+        let decoder = self.core().decoder.clone();
+        decoder.list_tracks(source.into())
+    }
+
+    /// Decodes every audio track of `source` under its own key,
+    /// `"{file_path}#track{id}"`, and returns the keys. If a track fails, the
+    /// ones already added are removed again and its error is returned.
+    pub async fn add_all_tracks(
+        &self,
+        file_path: String,
+        source: AudioSource,
+        token: &CancellationToken,
+    ) -> Result<Vec<String>, AppError> {
+        let decoder = self.core().decoder.clone();
+        let source = SharedSource::from(source);
+        let tracks = decoder.list_tracks(source.clone())?;
+        let mut keys = Vec::with_capacity(tracks.len());
+        for track in tracks {
+            if token.is_cancelled() {
+                break;
+            }
+            let key = format!("{}#track{}", file_path, track.id);
+            let options = DecodeOptions {
+                track_id: Some(track.id),
+            };
+            keys.push(key.clone());
+            if let Err(e) = self
+                .ingest(key, decoder.clone(), source.clone(), options, token)
+                .await
+            {
+                self.core().discard(&keys);
+                return Err(e);
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Decodes `source` on a dedicated thread and publishes each snapshot it
+    /// sends, locking the engine only to publish.
+    async fn ingest(
+        &self,
+        file_path: String,
+        decoder: Arc<dyn AudioDecoder + Send + Sync>,
+        source: SharedSource,
+        options: DecodeOptions,
+        token: &CancellationToken,
+    ) -> Result<(), AppError> {
+        info!("Adding audio file: {}, track: {:?}", file_path, options.track_id);
+        let worker_token = token.clone();
+        let (sender, mut receiver) = channel::channel();
+        spawn_dedicated(move || {
+            let mut next_snapshot = SNAPSHOT_SAMPLES;
+            let mut on_progress = |update: DecodeUpdate| {
+                sender.send(DecodeMessage::Progress(update.progress.clone()));
+                if update.samples.len() >= next_snapshot {
+                    next_snapshot = update.samples.len() * 2;
+                    sender.send(DecodeMessage::Snapshot(Audio {
+                        data: AudioData {
+                            samples: update.samples.to_vec().into(),
+                        },
+                        info: update.info.clone(),
+                        content_hash: None,
+                    }));
+                }
+            };
+            let result =
+                decoder.decode_incremental(source, options, &worker_token, &mut on_progress);
+            sender.send(DecodeMessage::Done(result));
+        });
+
+        let mut published = false;
+        let mut last_progress = None;
+        while let Some(message) = receiver.recv().await {
+            let result = match message {
+                DecodeMessage::Progress(progress) => {
+                    emit_decode_event(DecodeEvent::Progress {
+                        key: file_path.clone(),
+                        progress: progress.clone(),
+                    });
+                    last_progress = Some(progress);
+                    Ok(())
+                }
+                DecodeMessage::Snapshot(audio) => {
+                    let result = self.publish_audio(&file_path, audio, false, published).await;
+                    published = true;
+                    result
+                }
+                DecodeMessage::Done(Ok(audio)) => {
+                    let regions = damaged_regions(&audio.info.decode_report);
+                    let result = self.publish_audio(&file_path, audio, true, published).await;
+                    self.core()
+                        .communicator
+                        .update_regions(file_path.clone(), regions);
+                    published = true;
+                    result
+                }
+                DecodeMessage::Done(Err(e)) => Err(e),
+            };
+            if let Err(e) = result {
+                token.cancel();
+                emit_decode_event(DecodeEvent::Failed {
+                    key: file_path.clone(),
+                    message: e.to_string(),
+                });
+                return Err(e);
+            }
+        }
+
+        let progress = last_progress.unwrap_or_default();
+        emit_decode_event(if token.is_cancelled() {
+            DecodeEvent::Cancelled {
+                key: file_path,
+                progress,
+            }
+        } else {
+            DecodeEvent::Finished {
+                key: file_path,
+                progress,
+            }
+        });
+        Ok(())
+    }
+
+    /// Converts and charts a decoded snapshot without holding the engine, so
+    /// other calls go on while a long file decodes. Only the final audio is
+    /// hashed and stored; earlier snapshots are only shown.
+    async fn publish_audio(
+        &self,
+        file_path: &str,
+        audio: Audio,
+        last: bool,
+        replace: bool,
+    ) -> Result<(), AppError> {
+        let (sample_storage, params) = {
+            let core = self.core();
+            (
+                core.sample_storage,
+                core.chart_params(file_path, DataType::Audio),
+            )
+        };
+        let audio = Audio {
+            data: AudioData {
+                samples: audio.data.samples.convert(sample_storage),
+            },
+            info: audio.info,
+            content_hash: None,
+        };
+        let audio = if last { audio.with_content_hash() } else { audio };
+        let chart = compute_chart(audio.clone(), DataType::Audio, &params).await?;
+        self.core()
+            .publish_audio(file_path, last.then_some(audio), params, chart, replace)
+    }
+
+    /// Removes a stored file, the channels split from it by
+    /// [`Self::add_channel_charts`] and every chart of either.
+    pub async fn remove_audio(&self, file_path: String) -> Result<(), AppError> {
+        self.core().remove_audio(file_path)
+    }
+
+    /// Properties of a stored file for display, such as codec, duration and tags.
+    pub async fn get_audio_info(&self, file_path: String) -> Result<AudioInfo, AppError> {
+        self.core().get_audio_info(file_path)
+    }
+
+    /// Caps the memory used by decoded audio and by computed charts, evicting
+    /// the least recently used first. Evicted charts are recomputed from the
+    /// stored audio when shown again, and evicted audio is read back from
+    /// disk. Memory storage holds the only copy of the audio, so an audio
+    /// budget fails with it and neither budget is changed.
+    pub async fn set_memory_budget(
+        &self,
+        audio_bytes: Option<u64>,
+        chart_bytes: Option<u64>,
+    ) -> Result<(), AppError> {
+        self.core().set_memory_budget(audio_bytes, chart_bytes)
+    }
+
+    pub async fn memory_usage(&self) -> MemoryUsage {
+        self.core().memory_usage()
+    }
+
+    /// Keys of all stored audio. With disk storage this includes audio decoded
+    /// before a restart, which can be shown again with [`Self::add_chart`].
+    pub async fn stored_audio_keys(&self) -> Result<Vec<String>, AppError> {
+        self.core().stored_audio_keys()
+    }
+
+    /// Encodes any stored audio, e.g. a single channel or track, as WAV or FLAC.
+    pub async fn export(&self, key: String, options: ExportOptions) -> Result<Vec<u8>, AppError> {
+        let storage = self.core().storage.clone();
+        let audio = storage.load(key)?;
+        encoder_for(&options).encode_to_bytes(&audio)
+    }
+
+    pub async fn export_to_path(
+        &self,
+        key: String,
+        path: String,
+        options: ExportOptions,
+    ) -> Result<(), AppError> {
+        let storage = self.core().storage.clone();
+        let audio = storage.load(key)?;
+        let file = std::fs::File::create(&path)
+            .map_err(|e| AppError::Io(format!("Failed to create {}: {}", path, e)))?;
+        let mut writer = std::io::BufWriter::new(file);
+        encoder_for(&options).encode(&audio, &mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// What went wrong while decoding a file, if anything.
+    pub async fn get_decode_report(&self, file_path: String) -> Result<DecodeReport, AppError> {
+        self.core().get_decode_report(file_path)
+    }
+
+    /// Chooses how audio added from now on keeps its samples in memory and
+    /// on disk. Audio that is already stored keeps its format.
+    pub async fn set_sample_storage(&self, storage: SampleStorage) {
+        self.core().set_sample_storage(storage)
+    }
+
+    /// Selects which channel or downmix of a multichannel file is charted. The
+    /// charts already cached for it are recomputed in the background. Fails
+    /// for a channel the stored audio does not have.
+    pub async fn set_channel_mix(
+        &self,
+        file_path: String,
+        mix: ChannelMix,
+    ) -> Result<(), AppError> {
+        self.core().set_channel_mix(file_path, mix)
+    }
+
+    /// Stores every channel of a multichannel file as its own mono audio under
+    /// `"{file_path}#ch{n}"` and charts each of them.
+    pub async fn add_channel_charts(&self, file_path: String) -> Result<Vec<String>, AppError> {
+        self.core().add_channel_charts(file_path)
+    }
+
+    /// Adds a chart and shows it in the main viewport.
+    pub async fn add_chart(&self, file_path: String, data_type: DataType) -> Result<(), AppError> {
+        self.core().add_chart(file_path, data_type)
+    }
+
+    /// Removes a chart from every viewport and the cache.
+    pub async fn remove_chart(
+        &self,
+        file_path: String,
+        data_type: DataType,
+    ) -> Result<(), AppError> {
+        self.core().remove_chart(file_path, data_type)
+    }
+
+    pub async fn set_selected_audio(&self, chart_name: Option<String>) {
+        self.core().set_selected_audio(chart_name)
+    }
+
+    pub async fn reserve_visible(&self, chart_name: String) -> Result<(), AppError> {
+        self.core().reserve_visible(chart_name)
+    }
+
+    /// Sends the whole chart again on the next render of the viewport, for a
+    /// client that cannot apply an edit because it dropped the frame the edit
+    /// builds on.
+    pub async fn resend_chart(
+        &self,
+        viewport: String,
+        file_path: String,
+        data_type: DataType,
+    ) -> Result<(), AppError> {
+        self.core().resend_chart(viewport, file_path, data_type)
+    }
+
+    /// The true peak of the charted waveform of `file_path` between frames
+    /// `start` and `end`, with the frame it falls on.
+    pub async fn true_peak(
+        &self,
+        file_path: String,
+        start: f32,
+        end: f32,
+    ) -> Result<TruePeak, AppError> {
+        let chart = self.core().chart_for(&file_path, DataType::Audio)?;
+        SincInterpolation {}
+            .true_peak(&chart, start, end)
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "No samples of {} between {} and {}",
+                    file_path, start, end
+                ))
+            })
+    }
+}
+
+/// Everything the engine keeps between calls. Held only while a call reads
+/// or changes it, never while decoding.
+struct EngineCore {
+    config: Config,
+    decoder: Arc<dyn AudioDecoder + Send + Sync>,
+    storage: Arc<dyn AudioStorage + Send + Sync>,
+    cache: Arc<dyn CachedChartStorage + Send + Sync>,
+    communicator: Arc<dyn Communicator + Send + Sync>,
+    /// The main viewport comes first and is never removed.
+    viewports: Vec<Viewport>,
+    /// Points of the whole-file overview of each chart.
+    overview_points_num: usize,
+    selected_audio: Option<String>,
+    channel_mixes: HashMap<String, ChannelMix>,
+    down_sample_methods: HashMap<DataType, DownSampleMethod>,
+    /// Per chart overrides of `down_sample_methods`.
+    chart_down_sample_methods: HashMap<(String, DataType), DownSampleMethod>,
+    sinc_interpolation: bool,
+    sample_storage: SampleStorage,
+    active_charts: Vec<ActiveChart>,
+    /// Audio and chart budgets in bytes, `None` meaning unlimited.
+    memory_budget: (Option<u64>, Option<u64>),
+    /// Bumped whenever chart params change so an outdated refresh stops early.
+    refresh_generation: Arc<AtomicU64>,
+    /// Held by a refresh while it runs, so two never write to the cache at once.
+    refresh_lock: Arc<Mutex<()>>,
+    render_queue: Arc<Mutex<RenderQueue>>,
+}
+
+impl EngineCore {
+    fn new(
+        config: Config,
+        decoder: Box<dyn AudioDecoder + Send + Sync>,
+        storage: Box<dyn AudioStorage + Send + Sync>,
+        cache: Box<dyn CachedChartStorage + Send + Sync>,
+        communicator: Box<dyn Communicator + Send + Sync>,
+    ) -> Self {
+        Self {
+            config,
+            decoder: Arc::from(decoder),
+            storage: Arc::from(storage),
+            cache: Arc::from(cache),
+            communicator: Arc::from(communicator),
+            viewports: vec![Viewport::new(MAIN_VIEWPORT.to_string())],
+            overview_points_num: 500,
             selected_audio: None,
-            max_index: 10000.0,
-            y_range: (-0.5, 0.5),
+            channel_mixes: HashMap::new(),
+            down_sample_methods: HashMap::new(),
+            chart_down_sample_methods: HashMap::new(),
+            sinc_interpolation: false,
+            sample_storage: SampleStorage::default(),
+            active_charts: Vec::new(),
+            memory_budget: (None, None),
+            refresh_generation: Arc::new(AtomicU64::new(0)),
+            refresh_lock: Arc::new(Mutex::new(())),
+            render_queue: Arc::default(),
         }
     }
 
+    /// Updates every viewport to the charts it shows.
     fn update_all(&mut self) {
-        let all_charts = self.cache.get_all_cache();
-        self.y_range = (-0.5, 0.5);
-        self.max_index = 10000.0;
-        if let Ok(charts) = all_charts {
-            let visible_charts: Vec<ChartWIthKey> = charts
-                .iter()
-                .map(|c| {
-                    if c.chart.visible.load(atomic::Ordering::Relaxed) {
-                        self.y_range = (
-                            self.y_range.0.min(c.chart.min_y),
-                            self.y_range.1.max(c.chart.max_y),
-                        );
-
-                        self.update_max_index(&c.chart);
-                    }
-                    let visible_chart = c.chart.get_range(self.index_range.0, self.index_range.1);
-                    let downsampled_chart =
-                        Minmax {}.down_sample(visible_chart, self.down_sample_points_num);
-                    ChartWIthKey {
-                        key: c.key.clone(),
-                        chart: downsampled_chart,
-                    }
-                })
-                .collect();
+        for index in 0..self.viewports.len() {
+            self.update_max_index(index);
+        }
+        self.update_overviews();
+        for viewport in &self.viewports {
+            self.render(viewport);
+        }
+    }
 
-            self.communicator.update_all_charts(visible_charts);
-            self.communicator
-                .update_max_index(self.max_index);
+    /// Updates one viewport after a change to the charts it shows.
+    fn update_viewport(&mut self, index: usize) {
+        self.update_max_index(index);
+        self.update_overviews();
+        self.render(&self.viewports[index]);
+    }
+
+    /// Fits the x axis of a viewport to the charts visible in it. The y axes
+    /// follow the view, so they are fitted as it is rendered.
+    fn update_max_index(&mut self, index: usize) {
+        let mut max_index = 10000.0;
+        let visible_charts: Vec<(String, DataType)> = self.viewports[index]
+            .visible_charts()
+            .map(|c| (c.key.clone(), c.data_type))
+            .collect();
+
+        for (key, data_type) in visible_charts {
+            let chart = match self.chart_for(&key, data_type) {
+                Ok(chart) => chart,
+                Err(e) => {
+                    warn!("Failed to load {}: {}", key, e);
+                    continue;
+                }
+            };
+            max_index = self.max_index_with(max_index, &chart);
+        }
+
+        let viewport = &mut self.viewports[index];
+        if viewport.max_index != max_index {
+            viewport.max_index = max_index;
             self.communicator
-                .update_y_range(self.y_range.0, self.y_range.1);
+                .update_max_index(viewport.id.clone(), max_index);
+        }
+    }
+
+    /// Sends the overview of every visible chart whose overview is dirty,
+    /// which only happens when the chart, `max_index` or the overview
+    /// resolution change, never for a new index range.
+    fn update_overviews(&self) {
+        for viewport in &self.viewports {
+            for shown in viewport.visible_charts() {
+                let view = self.overview_view(viewport, &shown.key, shown.data_type);
+                if shown.lock_sent().overview == Some(view) {
+                    continue;
+                }
+                match self.chart_for(&shown.key, shown.data_type) {
+                    Ok(chart) => push_overview(
+                        self.communicator.as_ref(),
+                        &viewport.id,
+                        &shown.key,
+                        &chart,
+                        view,
+                        &shown.sent,
+                    ),
+                    Err(e) => warn!("Failed to load {}: {}", shown.key, e),
+                }
+            }
+        }
+    }
+
+    fn set_overview_points_num(&mut self, points_num: usize) {
+        self.overview_points_num = points_num;
+        self.update_overviews();
+    }
+
+    /// Sends every chart visible in `viewport` that is dirty, that is changed
+    /// or last sent for another view, as the changes from what the client
+    /// holds, on a worker thread, then the y axes fitted to them. Hidden
+    /// charts are left alone until shown again.
+    ///
+    /// Only the latest render of a viewport matters: one still waiting for
+    /// the worker is replaced by a newer one, and one running stops before
+    /// its next chart once a newer one is asked for, so a burst of gestures
+    /// renders only its last view.
+    fn render(&self, viewport: &Viewport) {
+        let job = RenderJob {
+            viewport: viewport.id.clone(),
+            seq: viewport.seq.fetch_add(1, atomic::Ordering::SeqCst) + 1,
+            current_seq: viewport.seq.clone(),
+            axes: viewport.axes.clone(),
+            charts: viewport
+                .visible_charts()
+                .map(|c| (c.clone(), self.view(viewport, &c.key, c.data_type)))
+                .collect(),
+            config: self.config.clone(),
+            channel_mixes: self.channel_mixes.clone(),
+        };
+        let mut queue = self.render_queue.lock().unwrap_or_else(|e| e.into_inner());
+        queue
+            .pending
+            .retain(|pending| pending.viewport != job.viewport);
+        queue.pending.push(job);
+        if queue.running {
+            return;
+        }
+        queue.running = true;
+        drop(queue);
+
+        let render_queue = self.render_queue.clone();
+        let storage = self.storage.clone();
+        let cache = self.cache.clone();
+        let communicator = self.communicator.clone();
+        rayon::spawn(move || loop {
+            let job = {
+                let mut queue = render_queue.lock().unwrap_or_else(|e| e.into_inner());
+                if queue.pending.is_empty() {
+                    queue.running = false;
+                    return;
+                }
+                queue.pending.remove(0)
+            };
+            let is_current = || job.current_seq.load(atomic::Ordering::SeqCst) == job.seq;
+            for (shown, view) in &job.charts {
+                if !is_current() {
+                    break;
+                }
+                if shown.lock_sent().view == Some(*view) {
+                    continue;
+                }
+                let params =
+                    chart_params(&job.config, &job.channel_mixes, &shown.key, shown.data_type);
+                let chart = cache
+                    .get(shown.key.clone(), shown.data_type, &params)
+                    .or_else(|_| {
+                        let audio = storage.load(shown.key.clone())?;
+                        let chart = block_on(compute_chart(audio, shown.data_type, &params))?;
+                        cache.add(shown.key.clone(), params.clone(), chart.clone())?;
+                        Ok::<_, AppError>(chart)
+                    });
+                let chart = match chart {
+                    Ok(chart) => chart,
+                    Err(e) => {
+                        warn!("Failed to load {}: {}", shown.key, e);
+                        continue;
+                    }
+                };
+                // Loading the chart may have taken a while. A view asked for
+                // meanwhile makes this one moot, and one asked for while
+                // down-sampling makes `push_chart` drop it.
+                if !is_current() {
+                    break;
+                }
+                let downsampled = down_sample_view(&chart, *view);
+                push_chart(
+                    communicator.as_ref(),
+                    &job.viewport,
+                    &shown.key,
+                    downsampled,
+                    *view,
+                    &shown.sent,
+                    (job.seq, &job.current_seq),
+                );
+            }
+            if is_current() {
+                push_axes(
+                    communicator.as_ref(),
+                    &job.viewport,
+                    job.charts.iter().map(|(shown, _)| shown),
+                    &job.axes,
+                );
+            }
+        });
+    }
+
+    fn add_viewport(&mut self, id: String) {
+        if self.viewport_index(&id).is_err() {
+            self.viewports.push(Viewport::new(id));
+        }
+    }
+
+    fn remove_viewport(&mut self, id: String) -> Result<(), AppError> {
+        if id == MAIN_VIEWPORT {
+            return Err(AppError::Generic(
+                "The main viewport cannot be removed".to_string(),
+            ));
         }
+        let index = self.viewport_index(&id)?;
+        self.viewports.remove(index);
+        Ok(())
+    }
+
+    fn set_viewport_range(&mut self, id: String, start: f32, end: f32) -> Result<(), AppError> {
+        let index = self.viewport_index(&id)?;
+        self.viewports[index].index_range = (start, end);
+        self.render(&self.viewports[index]);
+        Ok(())
+    }
+
+    fn set_viewport_points_num(&mut self, id: String, points_num: usize) -> Result<(), AppError> {
+        let index = self.viewport_index(&id)?;
+        self.viewports[index].points_num = points_num;
+        self.render(&self.viewports[index]);
+        Ok(())
+    }
+
+    fn set_viewport_chart_visible(
+        &mut self,
+        id: String,
+        file_path: String,
+        data_type: DataType,
+        visible: bool,
+    ) -> Result<(), AppError> {
+        let index = self.viewport_index(&id)?;
+        if self.active(&file_path, data_type).is_none() {
+            return Err(AppError::NotFound(format!(
+                "Chart not found: {} {:?}",
+                file_path, data_type
+            )));
+        }
+        let viewport = &mut self.viewports[index];
+        match viewport
+            .charts
+            .iter_mut()
+            .find(|c| c.key == file_path && c.data_type == data_type)
+        {
+            Some(shown) => shown.visible = visible,
+            None => viewport.charts.push(ViewportChart {
+                key: file_path,
+                data_type,
+                visible,
+                sent: Arc::default(),
+            }),
+        }
+        self.update_viewport(index);
+        Ok(())
+    }
+
+    fn resend_chart(
+        &mut self,
+        id: String,
+        file_path: String,
+        data_type: DataType,
+    ) -> Result<(), AppError> {
+        let index = self.viewport_index(&id)?;
+        let viewport = &self.viewports[index];
+        let shown = viewport.chart(&file_path, data_type).ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Chart not found in viewport {}: {} {:?}",
+                id, file_path, data_type
+            ))
+        })?;
+        let mut sent = shown.lock_sent();
+        sent.view = None;
+        sent.points = None;
+        drop(sent);
+        self.render(viewport);
+        Ok(())
+    }
+
+    fn viewport_index(&self, id: &str) -> Result<usize, AppError> {
+        self.viewports
+            .iter()
+            .position(|v| v.id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Viewport not found: {}", id)))
+    }
+
+    fn set_down_sample_points_num(&mut self, points_num: usize) {
+        self.viewports[0].points_num = points_num;
+        self.render(&self.viewports[0]);
+    }
+
+    fn set_down_sample_method(&mut self, data_type: DataType, method: DownSampleMethod) {
+        self.down_sample_methods.insert(data_type, method);
+        self.update_all();
     }
 
-    pub async fn set_down_sample_points_num(&mut self, points_num: usize) {
-        self.down_sample_points_num = points_num;
+    fn set_chart_down_sample_method(
+        &mut self,
+        file_path: String,
+        data_type: DataType,
+        method: Option<DownSampleMethod>,
+    ) {
+        match method {
+            Some(method) => {
+                self.chart_down_sample_methods
+                    .insert((file_path, data_type), method);
+            }
+            None => {
+                self.chart_down_sample_methods
+                    .remove(&(file_path, data_type));
+            }
+        }
         self.update_all();
     }
 
-    pub async fn set_index_range(&mut self, start: f32, end: f32) {
-        self.index_range = (start, end);
+    fn set_sinc_interpolation(&mut self, enabled: bool) {
+        self.sinc_interpolation = enabled;
         self.update_all();
     }
 
-    pub async fn set_config(&mut self, config: Config) {
+    fn set_index_range(&mut self, start: f32, end: f32) {
+        self.viewports[0].index_range = (start, end);
+        self.render(&self.viewports[0]);
+    }
+
+    /// Charts computed with the old config are recomputed and sent by the
+    /// refresh worker alone.
+    fn set_config(&mut self, config: Config) {
         self.config = config;
+        self.refresh_stale_charts();
+    }
+
+    /// Shows the waveform of a snapshot, replacing the chart if this key has
+    /// already been published by an earlier one, and stores `audio` if given.
+    /// A chart computed for params that have changed since is left out of
+    /// the cache, so it is recomputed when needed.
+    fn publish_audio(
+        &mut self,
+        file_path: &str,
+        audio: Option<Audio>,
+        params: ChartParams,
+        audio_chart: Chart,
+        replace: bool,
+    ) -> Result<(), AppError> {
+        if let Some(audio) = audio {
+            self.set_content_hash(file_path, &audio);
+            self.storage.save(file_path.to_string(), audio)?;
+        }
+        self.activate(file_path, DataType::Audio);
+        if params == self.chart_params(file_path, DataType::Audio) {
+            self.cache.add(file_path.to_string(), params, audio_chart)?;
+        }
+        if replace {
+            self.mark_dirty(file_path, DataType::Audio);
+        }
         self.update_all();
+        Ok(())
     }
 
-    pub async fn add(
+    /// Removes the audio along with the channels split from it and every
+    /// chart computed from either, telling the client to drop those shown.
+    fn remove_audio(&mut self, file_path: String) -> Result<(), AppError> {
+        let channel_prefix = format!("{}#ch", file_path);
+        let channel_keys: Vec<String> = self
+            .storage
+            .keys()?
+            .into_iter()
+            .filter(|key| {
+                key.strip_prefix(&channel_prefix)
+                    .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .collect();
+        for key in &channel_keys {
+            self.drop_charts_of(key);
+            self.storage.remove(key.clone())?;
+        }
+        self.drop_charts_of(&file_path);
+        self.storage.remove(file_path)?;
+        self.update_all();
+        Ok(())
+    }
+
+    /// Takes back the import of `keys`, whether or not their audio got as far
+    /// as being stored.
+    fn discard(&mut self, keys: &[String]) {
+        for key in keys {
+            self.drop_charts_of(key);
+            // A decode that failed before finishing stored nothing.
+            let _ = self.storage.remove(key.clone());
+        }
+        self.update_all();
+    }
+
+    /// Forgets every chart of `file_path`, active or only cached, and the
+    /// settings kept for it.
+    fn drop_charts_of(&mut self, file_path: &str) {
+        for data_type in DATA_TYPES {
+            if self.active(file_path, data_type).is_some() {
+                self.deactivate(file_path, data_type);
+                self.communicator
+                    .remove_chart(file_path.to_string(), data_type);
+            }
+            // Charts that were never computed are not cached.
+            let _ = self.cache.remove(file_path.to_string(), data_type);
+            self.chart_down_sample_methods
+                .remove(&(file_path.to_string(), data_type));
+        }
+        self.channel_mixes.remove(file_path);
+        if self.selected_audio.as_deref() == Some(file_path) {
+            self.selected_audio = None;
+        }
+    }
+
+    fn get_audio_info(&self, file_path: String) -> Result<AudioInfo, AppError> {
+        Ok(self.storage.load(file_path)?.info)
+    }
+
+    fn set_memory_budget(
         &mut self,
-        file_path: String,
-        format: String,
-        audio_data: Vec<u8>,
+        audio_bytes: Option<u64>,
+        chart_bytes: Option<u64>,
     ) -> Result<(), AppError> {
-        info!("Adding audio file: {}, format: {}", file_path, format);
-        let decoded_audio = self.decoder.decode(format, audio_data)?;
+        self.storage.set_memory_budget(audio_bytes)?;
+        self.cache.set_memory_budget(chart_bytes);
+        self.memory_budget = (audio_bytes, chart_bytes);
+        Ok(())
+    }
 
-        self.storage
-            .save(file_path.clone(), decoded_audio.clone())?;
+    fn memory_usage(&self) -> MemoryUsage {
+        let audio = self.storage.memory_usage();
+        let charts = self.cache.memory_usage();
+        MemoryUsage {
+            audio_bytes: audio.iter().map(|usage| usage.bytes).sum(),
+            chart_bytes: charts.iter().map(|usage| usage.bytes).sum(),
+            audio,
+            charts,
+            audio_budget: self.memory_budget.0,
+            chart_budget: self.memory_budget.1,
+        }
+    }
 
-        let audio_chart = decoded_audio.audio_to_chart().await;
-        self.y_range = (
-            self.y_range.0.min(audio_chart.min_y),
-            self.y_range.1.max(audio_chart.max_y),
-        );
-        self.update_max_index(&audio_chart);
-        self.communicator
-            .update_max_index(self.max_index);
-        self.communicator
-            .update_y_range(self.y_range.0, self.y_range.1);
+    fn stored_audio_keys(&self) -> Result<Vec<String>, AppError> {
+        self.storage.keys()
+    }
 
-        self.cache.add(file_path.clone(), audio_chart.clone())?;
+    fn get_decode_report(&self, file_path: String) -> Result<DecodeReport, AppError> {
+        Ok(self.storage.load(file_path)?.info.decode_report)
+    }
 
-        let visible_chart = audio_chart.get_range(self.index_range.0, self.index_range.1);
+    fn set_sample_storage(&mut self, storage: SampleStorage) {
+        self.sample_storage = storage;
+    }
 
-        let downsampled_chart = Minmax {}.down_sample(visible_chart, self.down_sample_points_num);
-        self.communicator.add_chart(file_path, downsampled_chart);
+    fn set_channel_mix(&mut self, file_path: String, mix: ChannelMix) -> Result<(), AppError> {
+        mix.check(self.storage.load(file_path.clone())?.info.channels)?;
+        self.channel_mixes.insert(file_path, mix);
+        self.refresh_stale_charts();
         Ok(())
     }
 
-    pub async fn remove_audio(&self, file_path: String) -> Result<(), AppError> {
-        self.storage.remove(file_path)
+    fn add_channel_charts(&mut self, file_path: String) -> Result<Vec<String>, AppError> {
+        let stored_audio = self.storage.load(file_path.clone())?;
+        let mut keys = Vec::with_capacity(stored_audio.info.channels as usize);
+        for channel in 0..stored_audio.info.channels {
+            let key = format!("{}#ch{}", file_path, channel + 1);
+            self.store(&key, stored_audio.mix(ChannelMix::Channel(channel))?)?;
+            self.add_chart(key.clone(), DataType::Audio)?;
+            keys.push(key);
+        }
+        Ok(keys)
     }
 
-    pub async fn add_chart(
-        &mut self,
-        file_path: String,
-        data_type: DataType,
-    ) -> Result<(), AppError> {
-        let target_chart = if let Ok(cached_data) = self.cache.get(file_path.clone(), data_type) {
-            cached_data
-        } else {
-            let stored_audio = self.storage.load(file_path.clone())?;
-            let chart = match data_type {
-                DataType::Audio => stored_audio.audio_to_chart().await,
-                DataType::Spectrum => {
-                    (FftTransform {})
-                        .transform(stored_audio, self.config.clone())
-                        .await?
-                }
-                DataType::Energy => {
-                    (EnergyCalculator {})
-                        .transform(stored_audio, self.config.clone())
-                        .await?
-                }
-                DataType::ZeroCrossingRate => {
-                    (ZeroCrossingRateCalculator {})
-                        .transform(stored_audio, self.config.clone())
-                        .await?
+    /// Saves `audio` with its content hash, which is computed only here, and
+    /// lets a persistent cache recognise it from an earlier session.
+    fn store(&self, file_path: &str, audio: Audio) -> Result<Audio, AppError> {
+        let audio = audio.with_content_hash();
+        self.set_content_hash(file_path, &audio);
+        self.storage.save(file_path.to_string(), audio.clone())?;
+        Ok(audio)
+    }
+
+    fn set_content_hash(&self, file_path: &str, audio: &Audio) {
+        if let Some(content_hash) = audio.content_hash {
+            self.cache
+                .set_content_hash(file_path.to_string(), content_hash);
+        }
+    }
+
+    fn chart_params(&self, file_path: &str, data_type: DataType) -> ChartParams {
+        chart_params(&self.config, &self.channel_mixes, file_path, data_type)
+    }
+
+    fn down_sample_method(&self, file_path: &str, data_type: DataType) -> DownSampleMethod {
+        down_sample_method(
+            &self.down_sample_methods,
+            &self.chart_down_sample_methods,
+            file_path,
+            data_type,
+        )
+    }
+
+    /// Recomputes every cached chart whose params no longer match the current
+    /// config and channel mixes on a worker thread, along with any visible
+    /// chart no longer cached, then pushes each visible one. A newer refresh
+    /// supersedes one still running.
+    ///
+    /// Pushes carry the viewport's sequence number from when the refresh was
+    /// asked for. A viewport that has been rendered for another view since
+    /// skips the push and leaves the chart dirty, so its latest render sends
+    /// it for the view actually shown.
+    fn refresh_stale_charts(&self) {
+        let generation = self
+            .refresh_generation
+            .fetch_add(1, atomic::Ordering::SeqCst)
+            + 1;
+        let current_generation = self.refresh_generation.clone();
+        let lock = self.refresh_lock.clone();
+        let storage = self.storage.clone();
+        let cache = self.cache.clone();
+        let communicator = self.communicator.clone();
+        let config = self.config.clone();
+        let channel_mixes = self.channel_mixes.clone();
+        let down_sample_methods = self.down_sample_methods.clone();
+        let chart_down_sample_methods = self.chart_down_sample_methods.clone();
+        let sinc_interpolation = self.sinc_interpolation;
+        let overview_points_num = self.overview_points_num;
+        let viewports: Vec<(Viewport, u64)> = self
+            .viewports
+            .iter()
+            .map(|viewport| {
+                let seq = viewport.seq.load(atomic::Ordering::SeqCst);
+                (viewport.clone(), seq)
+            })
+            .collect();
+
+        rayon::spawn(move || {
+            let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+            let is_current = || current_generation.load(atomic::Ordering::SeqCst) == generation;
+            let current = |key: &str, data_type: DataType| {
+                chart_params(&config, &channel_mixes, key, data_type)
+            };
+            let mut refreshed: Vec<(String, DataType)> = match cache.stale(&current) {
+                Ok(stale_charts) => stale_charts
+                    .into_iter()
+                    .map(|stale| (stale.key, stale.chart.data_type))
+                    .collect(),
+                Err(e) => {
+                    warn!("Failed to list stale charts: {}", e);
+                    return;
                 }
             };
-            self.cache.add(file_path.clone(), chart.clone())?;
-            info!("{:?} data length: {}", data_type, chart.points.len());
-            chart
-        };
-        self.update_max_index(&target_chart);
-        self.communicator
-            .update_max_index(self.max_index);
-        self.communicator
-            .update_y_range(self.y_range.0, self.y_range.1);
-        let visible_chart = target_chart.get_range(self.index_range.0, self.index_range.1);
-
-        let downsampled_chart = Minmax {}.down_sample(visible_chart, self.down_sample_points_num);
-        self.communicator.add_chart(file_path, downsampled_chart);
+            for (viewport, _) in &viewports {
+                for shown in viewport.visible_charts() {
+                    let chart = (shown.key.clone(), shown.data_type);
+                    let params = current(&shown.key, shown.data_type);
+                    let uncached = cache.get(chart.0.clone(), chart.1, &params).is_err();
+                    if uncached && !refreshed.contains(&chart) {
+                        refreshed.push(chart);
+                    }
+                }
+            }
+
+            for (key, data_type) in refreshed {
+                if !is_current() {
+                    return;
+                }
+                let params = current(&key, data_type);
+                let chart = match cache.get(key.clone(), data_type, &params) {
+                    Ok(chart) => Ok(chart),
+                    Err(_) => storage
+                        .load(key.clone())
+                        .and_then(|audio| block_on(compute_chart(audio, data_type, &params))),
+                };
+                let chart = match chart {
+                    Ok(chart) => chart,
+                    Err(e) => {
+                        warn!("Failed to recompute {:?} for {}: {}", data_type, key, e);
+                        continue;
+                    }
+                };
+                if !is_current() {
+                    return;
+                }
+                if let Err(e) = cache.add(key.clone(), params, chart.clone()) {
+                    warn!("Failed to cache {:?} for {}: {}", data_type, key, e);
+                    continue;
+                }
+                let method = down_sample_method(
+                    &down_sample_methods,
+                    &chart_down_sample_methods,
+                    &key,
+                    data_type,
+                );
+                for (viewport, seq) in &viewports {
+                    let Some(shown) = viewport.chart(&key, data_type) else {
+                        continue;
+                    };
+                    if !shown.visible {
+                        // Sent once it is shown again.
+                        shown.mark_dirty();
+                        continue;
+                    }
+                    let view = ChartView {
+                        index_range: viewport.index_range,
+                        points_num: viewport.points_num,
+                        method,
+                        sinc: sinc_interpolation,
+                    };
+                    let pushed = push_chart(
+                        communicator.as_ref(),
+                        &viewport.id,
+                        &key,
+                        down_sample_view(&chart, view),
+                        view,
+                        &shown.sent,
+                        (*seq, &viewport.seq),
+                    );
+                    if !pushed {
+                        continue;
+                    }
+                    let overview_view = ChartView {
+                        index_range: (0.0, viewport.max_index),
+                        points_num: overview_points_num,
+                        method,
+                        sinc: false,
+                    };
+                    push_overview(
+                        communicator.as_ref(),
+                        &viewport.id,
+                        &key,
+                        &chart,
+                        overview_view,
+                        &shown.sent,
+                    );
+                    push_axes(
+                        communicator.as_ref(),
+                        &viewport.id,
+                        viewport.visible_charts(),
+                        &viewport.axes,
+                    );
+                }
+            }
+        });
+    }
+
+    fn add_chart(&mut self, file_path: String, data_type: DataType) -> Result<(), AppError> {
+        self.activate(&file_path, data_type);
+        if let Err(e) = self.chart_for(&file_path, data_type) {
+            self.deactivate(&file_path, data_type);
+            return Err(e);
+        }
+        self.update_all();
         Ok(())
     }
 
-    pub async fn remove_chart(
+    fn remove_chart(&mut self, file_path: String, data_type: DataType) -> Result<(), AppError> {
+        self.deactivate(&file_path, data_type);
+        self.communicator.remove_chart(file_path.clone(), data_type);
+        let removed = self.cache.remove(file_path, data_type);
+        self.update_all();
+        removed
+    }
+
+    /// Registers a chart and shows it in the main viewport, if it is not
+    /// registered already.
+    fn activate(&mut self, file_path: &str, data_type: DataType) {
+        if self.active(file_path, data_type).is_some() {
+            return;
+        }
+        self.active_charts.push(ActiveChart {
+            key: file_path.to_string(),
+            data_type,
+        });
+        self.viewports[0].charts.push(ViewportChart {
+            key: file_path.to_string(),
+            data_type,
+            visible: true,
+            sent: Arc::default(),
+        });
+    }
+
+    /// Unregisters a chart and takes it out of every viewport.
+    fn deactivate(&mut self, file_path: &str, data_type: DataType) {
+        self.active_charts
+            .retain(|c| c.key != file_path || c.data_type != data_type);
+        for viewport in &mut self.viewports {
+            viewport
+                .charts
+                .retain(|c| c.key != file_path || c.data_type != data_type);
+        }
+    }
+
+    fn active(&self, file_path: &str, data_type: DataType) -> Option<&ActiveChart> {
+        self.active_charts
+            .iter()
+            .find(|c| c.key == file_path && c.data_type == data_type)
+    }
+
+    /// Makes a chart whose content changed be sent on the next update.
+    fn mark_dirty(&self, file_path: &str, data_type: DataType) {
+        for viewport in &self.viewports {
+            if let Some(shown) = viewport.chart(file_path, data_type) {
+                shown.mark_dirty();
+            }
+        }
+    }
+
+    fn view(&self, viewport: &Viewport, file_path: &str, data_type: DataType) -> ChartView {
+        ChartView {
+            index_range: viewport.index_range,
+            points_num: viewport.points_num,
+            method: self.down_sample_method(file_path, data_type),
+            sinc: self.sinc_interpolation,
+        }
+    }
+
+    /// The view of a chart's overview, which spans `0..max_index` of the
+    /// viewport.
+    fn overview_view(
         &self,
-        file_path: String,
+        viewport: &Viewport,
+        file_path: &str,
         data_type: DataType,
-    ) -> Result<(), AppError> {
-        self.cache.remove(file_path, data_type)
+    ) -> ChartView {
+        ChartView {
+            index_range: (0.0, viewport.max_index),
+            points_num: self.overview_points_num,
+            method: self.down_sample_method(file_path, data_type),
+            sinc: false,
+        }
+    }
+
+    /// The chart for the current params, from the cache if it is still there
+    /// and otherwise recomputed from the stored audio.
+    fn chart_for(&self, file_path: &str, data_type: DataType) -> Result<Chart, AppError> {
+        let params = self.chart_params(file_path, data_type);
+        let chart = match self.cache.get(file_path.to_string(), data_type, &params) {
+            Ok(cached_data) => cached_data,
+            Err(_) => {
+                let stored_audio = self.storage.load(file_path.to_string())?;
+                // A persistent cache may still hold the chart from an earlier session.
+                self.set_content_hash(file_path, &stored_audio);
+                match self.cache.get(file_path.to_string(), data_type, &params) {
+                    Ok(persisted) => persisted,
+                    Err(_) => {
+                        let chart = block_on(compute_chart(stored_audio, data_type, &params))?;
+                        self.cache
+                            .add(file_path.to_string(), params, chart.clone())?;
+                        info!("{:?} data length: {}", data_type, chart.series.len());
+                        chart
+                    }
+                }
+            }
+        };
+        Ok(chart)
     }
 
-    pub async fn set_selected_audio(&mut self, chart_name: Option<String>) {
+    fn set_selected_audio(&mut self, chart_name: Option<String>) {
         self.selected_audio = chart_name;
     }
 
-    fn update_max_index(&mut self, chart: &Chart) {
-        chart.points.last().map(|p| {
-            if p.x > self.max_index {
-                self.max_index =
-                    (p.x / self.config.frame_size as f32).ceil() * self.config.frame_size as f32;
+    /// `max_index` widened to the last point of `chart`, in whole frames.
+    fn max_index_with(&self, max_index: f32, chart: &Chart) -> f32 {
+        match chart.series.last() {
+            Some(p) if p.x > max_index => {
+                (p.x / self.config.frame_size as f32).ceil() * self.config.frame_size as f32
             }
-        });
+            _ => max_index,
+        }
     }
 
-    pub async fn reserve_visible(&mut self, chart_name: String) -> Result<(), AppError> {
+    fn reserve_visible(&mut self, chart_name: String) -> Result<(), AppError> {
         let (file_path, data_part) = chart_name.rsplit_once(' ').unwrap_or(("", &chart_name));
 
         let data_type = match data_part {
@@ -218,22 +1504,325 @@ impl AudioProcessorEngine {
             _ => return Err(AppError::InvalidChartName(chart_name)),
         };
 
-        let chart = self.cache.get(file_path.to_string(), data_type)?;
-        chart.visible.store(
-            !chart.visible.load(atomic::Ordering::Relaxed),
-            atomic::Ordering::Relaxed,
-        );
+        let chart = self.viewports[0]
+            .charts
+            .iter_mut()
+            .find(|c| c.key == file_path && c.data_type == data_type)
+            .ok_or_else(|| AppError::NotFound(format!("Chart not found: {}", chart_name)))?;
+        chart.visible = !chart.visible;
         info!(
             "Set visible: {}, {}, {}",
-            file_path,
-            data_part,
-            chart.visible.load(atomic::Ordering::Relaxed)
+            file_path, data_part, chart.visible
         );
-        self.update_all();
+        self.update_viewport(0);
         Ok(())
     }
 }
 
+fn chart_params(
+    config: &Config,
+    channel_mixes: &HashMap<String, ChannelMix>,
+    file_path: &str,
+    data_type: DataType,
+) -> ChartParams {
+    ChartParams {
+        config: (data_type != DataType::Audio).then(|| config.clone()),
+        channel_mix: channel_mixes.get(file_path).copied().unwrap_or_default(),
+    }
+}
+
+async fn compute_chart(
+    audio: Audio,
+    data_type: DataType,
+    params: &ChartParams,
+) -> Result<Chart, AppError> {
+    let audio = audio.mix(params.channel_mix)?;
+    let config = params.config.clone().unwrap_or_default();
+    match data_type {
+        DataType::Audio => Ok(audio.audio_to_chart().await),
+        DataType::Spectrum => (FftTransform {}).transform(audio, config).await,
+        DataType::Energy => (EnergyCalculator {}).transform(audio, config).await,
+        DataType::ZeroCrossingRate => {
+            (ZeroCrossingRateCalculator {})
+                .transform(audio, config)
+                .await
+        }
+    }
+}
+
+fn down_sample_method(
+    by_data_type: &HashMap<DataType, DownSampleMethod>,
+    by_chart: &HashMap<(String, DataType), DownSampleMethod>,
+    file_path: &str,
+    data_type: DataType,
+) -> DownSampleMethod {
+    by_chart
+        .get(&(file_path.to_string(), data_type))
+        .or_else(|| by_data_type.get(&data_type))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// The part of `chart` within `index_range`, reduced to about `points_num`
+/// points with `method` over buckets aligned to `index_range`. With `sinc`,
+/// a waveform with fewer samples in view than points is interpolated instead.
+fn down_sample(
+    chart: &Chart,
+    method: DownSampleMethod,
+    sinc: bool,
+    index_range: (f32, f32),
+    points_num: usize,
+) -> Chart {
+    let (start_x, end_x) = index_range;
+    if sinc && chart.data_type == DataType::Audio {
+        let interpolated =
+            SincInterpolation {}.interpolate_range(chart, start_x, end_x, points_num);
+        if let Some(interpolated) = interpolated {
+            return interpolated;
+        }
+    }
+    let sampler: Box<dyn DownSample> = match method {
+        DownSampleMethod::MinMax => {
+            return Minmax {}.down_sample_range(chart, start_x, end_x, points_num)
+        }
+        DownSampleMethod::EqualStep => Box::new(EqualStep {}),
+        DownSampleMethod::Lttb => Box::new(Lttb {}),
+        DownSampleMethod::M4 => Box::new(M4 {}),
+    };
+    sampler.down_sample(chart.get_range(start_x, end_x), index_range, points_num)
+}
+
+/// `chart` down-sampled for `view`.
+fn down_sample_view(chart: &Chart, view: ChartView) -> Chart {
+    down_sample(
+        chart,
+        view.method,
+        view.sinc,
+        view.index_range,
+        view.points_num,
+    )
+}
+
+/// Sends the client the edits from what it was last sent of a chart to
+/// `downsampled_chart`, the chart down-sampled for `view`, or the whole chart
+/// if it holds nothing to build on, tagged with the view's sequence number
+/// `seq`. The first edit builds on the points last sent and each further
+/// edit on the one before it.
+///
+/// Sends nothing and leaves the chart dirty if the viewport has moved on from
+/// `seq` to `current_seq`. That is checked while holding `sent`, so a push for
+/// an outdated view never lands after one for a newer view. Returns whether
+/// the chart was sent.
+fn push_chart(
+    communicator: &dyn Communicator,
+    viewport: &str,
+    key: &str,
+    downsampled_chart: Chart,
+    view: ChartView,
+    sent: &Mutex<SentChart>,
+    (seq, current_seq): (u64, &AtomicU64),
+) -> bool {
+    let mut sent = sent.lock().unwrap_or_else(|e| e.into_inner());
+    if current_seq.load(atomic::Ordering::SeqCst) != seq {
+        sent.view = None;
+        return false;
+    }
+    let data_type = downsampled_chart.data_type;
+    let points = downsampled_chart.series.clone().into_points();
+
+    let old = sent.points.as_deref().unwrap_or_default();
+    match sent.points.as_ref().and_then(|_| diff_points(old, &points)) {
+        Some(edits) => {
+            let mut len = old.len();
+            let mut base_seq = sent.seq;
+            for PointEdit {
+                start,
+                end,
+                new_start,
+                points,
+            } in edits
+            {
+                let appended = start == len && end == len;
+                len = len - (end - start) + points.len();
+                let points = downsampled_chart
+                    .series
+                    .slice(new_start, new_start + points.len());
+                if appended {
+                    communicator.append_points(
+                        viewport.to_string(),
+                        key.to_string(),
+                        data_type,
+                        points,
+                        (base_seq, seq),
+                    );
+                } else {
+                    communicator.replace_range(
+                        viewport.to_string(),
+                        key.to_string(),
+                        data_type,
+                        (start, end),
+                        points,
+                        (base_seq, seq),
+                    );
+                }
+                base_seq = seq;
+            }
+        }
+        None => communicator.add_chart(
+            viewport.to_string(),
+            key.to_string(),
+            downsampled_chart,
+            seq,
+        ),
+    }
+    sent.view = Some(view);
+    sent.points = Some(points);
+    sent.seq = seq;
+    true
+}
+
+/// Down-samples `chart` for the overview `view` and sends it whole.
+fn push_overview(
+    communicator: &dyn Communicator,
+    viewport: &str,
+    key: &str,
+    chart: &Chart,
+    view: ChartView,
+    sent: &Mutex<SentChart>,
+) {
+    let mut sent = sent.lock().unwrap_or_else(|e| e.into_inner());
+    let overview = down_sample_view(chart, view);
+    communicator.update_overview(viewport.to_string(), key.to_string(), overview);
+    sent.overview = Some(view);
+}
+
+/// Fits an axis per data type to the points the client holds of the charts
+/// in `charts`, and sends the axes if they differ from `sent_axes`.
+fn push_axes<'a>(
+    communicator: &dyn Communicator,
+    viewport: &str,
+    charts: impl IntoIterator<Item = &'a ViewportChart>,
+    sent_axes: &Mutex<Vec<ChartAxis>>,
+) {
+    let mut axes: Vec<ChartAxis> = Vec::new();
+    for shown in charts {
+        let extent = shown.lock_sent().points.as_deref().and_then(y_extent);
+        let Some((min_y, max_y)) = extent else {
+            continue;
+        };
+        match axes.iter_mut().find(|a| a.data_type == shown.data_type) {
+            Some(axis) => {
+                axis.min_y = axis.min_y.min(min_y);
+                axis.max_y = axis.max_y.max(max_y);
+                axis.keys.push(shown.key.clone());
+            }
+            None => {
+                let (label, unit) = shown.data_type.y_axis();
+                axes.push(ChartAxis {
+                    data_type: shown.data_type,
+                    label: label.to_string(),
+                    unit: unit.to_string(),
+                    min_y,
+                    max_y,
+                    keys: vec![shown.key.clone()],
+                });
+            }
+        }
+    }
+
+    let mut sent_axes = sent_axes.lock().unwrap_or_else(|e| e.into_inner());
+    if *sent_axes != axes {
+        communicator.update_axes(viewport.to_string(), axes.clone());
+        *sent_axes = axes;
+    }
+}
+
+/// Lowest and highest y of `points`, skipping gaps. A flat line is given
+/// some room around it so the axis keeps a height. `None` when there is no
+/// point to fit.
+fn y_extent(points: &[Point]) -> Option<(f32, f32)> {
+    let mut extent: Option<(f32, f32)> = None;
+    for y in points.iter().map(|p| p.y).filter(|y| y.is_finite()) {
+        extent = Some(match extent {
+            Some((min_y, max_y)) => (y.min(min_y), y.max(max_y)),
+            None => (y, y),
+        });
+    }
+    let (min_y, max_y) = extent?;
+    if min_y < max_y {
+        return Some((min_y, max_y));
+    }
+    let room = if min_y == 0.0 { 0.5 } else { min_y.abs() * 0.1 };
+    Some((min_y - room, max_y + room))
+}
+
+/// Runs a long job on a thread of its own, keeping the rayon pool free for
+/// the render and refresh workers. Wasm has no threads to spare, so the job
+/// goes to the pool there.
+fn spawn_dedicated(job: impl FnOnce() + Send + 'static) {
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(job);
+    #[cfg(target_arch = "wasm32")]
+    rayon::spawn(job);
+}
+
+fn encoder_for(options: &ExportOptions) -> Box<dyn AudioEncoder> {
+    match options.container {
+        ExportContainer::Wav => Box::new(WavEncoder::new(options.sample_format, options.dither)),
+        ExportContainer::Flac => Box::new(FlacEncoder::new(options.sample_format, options.dither)),
+    }
+}
+
+fn damaged_regions(report: &DecodeReport) -> Vec<ChartRegion> {
+    report
+        .damaged
+        .iter()
+        .map(|range| ChartRegion {
+            start_x: range.start_frame as f32,
+            end_x: range.end_frame as f32,
+            label: range.reason.clone(),
+        })
+        .collect()
+}
+
+/// Like [`create_default_engine`], with a choice of where decoded audio and
+/// computed charts are kept.
+pub async fn create_engine(
+    config: Config,
+    storage: AudioStorageBackend,
+    cache: ChartCacheBackend,
+) -> Result<AudioProcessorEngine, AppError> {
+    let storage: Box<dyn AudioStorage + Send + Sync> = match storage {
+        AudioStorageBackend::Memory => Box::new(KvAudioStorage::new()),
+        #[cfg(not(target_arch = "wasm32"))]
+        AudioStorageBackend::Disk { directory } => Box::new(FileAudioStorage::new(directory)?),
+        #[cfg(target_arch = "wasm32")]
+        AudioStorageBackend::Disk { .. } => {
+            return Err(AppError::Storage(
+                "Disk audio storage is not available on web".to_string(),
+            ))
+        }
+    };
+    let cache: Box<dyn CachedChartStorage + Send + Sync> = match cache {
+        ChartCacheBackend::Memory => Box::new(KvCachedChartStorage::new()),
+        #[cfg(not(target_arch = "wasm32"))]
+        ChartCacheBackend::Disk { directory } => Box::new(DiskCachedChartStorage::new(directory)?),
+        #[cfg(target_arch = "wasm32")]
+        ChartCacheBackend::Disk { .. } => {
+            return Err(AppError::Cache(
+                "Disk chart cache is not available on web".to_string(),
+            ))
+        }
+    };
+    Ok(AudioProcessorEngine::new(
+        config,
+        Box::new(SymphoniaDecoder::new()),
+        storage,
+        cache,
+        Box::new(communicator::stream_sink_communicator::StreamCommunicator::new()),
+    ))
+}
+
 pub async fn create_default_engine(config: Config) -> AudioProcessorEngine {
     AudioProcessorEngine::new(
         config,
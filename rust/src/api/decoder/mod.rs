@@ -1 +1,2 @@
-pub mod symphonia_decoder;
\ No newline at end of file
+pub mod symphonia_decoder;
+pub mod raw_pcm_decoder;
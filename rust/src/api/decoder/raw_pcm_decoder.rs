@@ -0,0 +1,261 @@
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+
+use crate::api::traits::audio_decoder::{AudioDecoder, DecodeUpdate};
+use crate::api::types::audio::{Audio, AudioData, AudioInfo, ChannelLayout, TrackInfo};
+use crate::api::types::decode::{
+    CancellationToken, DecodeOptions, DecodeProgress, DecodeReport, Endianness, RawPcmFormat,
+    RawSampleFormat,
+};
+use crate::api::types::error::AppError;
+use crate::api::types::file::SharedSource;
+
+/// Frames converted per read, and per progress update.
+const CHUNK_FRAMES: usize = 1 << 16;
+
+/// Decodes headerless PCM whose layout is given up front instead of probed.
+pub struct RawPcmDecoder {
+    format: RawPcmFormat,
+}
+
+impl RawPcmDecoder {
+    pub fn new(format: RawPcmFormat) -> Self {
+        Self { format }
+    }
+
+    fn validate(&self) -> Result<(), AppError> {
+        if self.format.channels == 0 || self.format.sample_rate == 0 {
+            return Err(AppError::Format(
+                "Raw PCM needs at least one channel and a sample rate".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn codec(&self) -> String {
+        let endian = match self.format.endianness {
+            Endianness::Little => "le",
+            Endianness::Big => "be",
+        };
+        match self.format.sample_format {
+            RawSampleFormat::U8 => "pcm_u8".to_string(),
+            RawSampleFormat::S16 => format!("pcm_s16{}", endian),
+            RawSampleFormat::S24 => format!("pcm_s24{}", endian),
+            RawSampleFormat::S32 => format!("pcm_s32{}", endian),
+            RawSampleFormat::F32 => format!("pcm_f32{}", endian),
+            RawSampleFormat::MuLaw => "pcm_mulaw".to_string(),
+            RawSampleFormat::ALaw => "pcm_alaw".to_string(),
+        }
+    }
+
+    fn convert(&self, bytes: &[u8], samples: &mut Vec<f32>) {
+        let little = self.format.endianness == Endianness::Little;
+        let width = self.format.sample_format.bytes_per_sample();
+        samples.extend(
+            bytes
+                .chunks_exact(width)
+                .map(|b| match self.format.sample_format {
+                    RawSampleFormat::U8 => (b[0] as f32 - 128.0) / 128.0,
+                    RawSampleFormat::S16 => {
+                        let v = if little {
+                            i16::from_le_bytes([b[0], b[1]])
+                        } else {
+                            i16::from_be_bytes([b[0], b[1]])
+                        };
+                        v as f32 / 32768.0
+                    }
+                    RawSampleFormat::S24 => {
+                        let (lo, mid, hi) = if little {
+                            (b[0], b[1], b[2])
+                        } else {
+                            (b[2], b[1], b[0])
+                        };
+                        let v = i32::from_le_bytes([0, lo, mid, hi]) >> 8;
+                        v as f32 / 8_388_608.0
+                    }
+                    RawSampleFormat::S32 => {
+                        let bytes = [b[0], b[1], b[2], b[3]];
+                        let v = if little {
+                            i32::from_le_bytes(bytes)
+                        } else {
+                            i32::from_be_bytes(bytes)
+                        };
+                        v as f32 / 2_147_483_648.0
+                    }
+                    RawSampleFormat::F32 => {
+                        let bytes = [b[0], b[1], b[2], b[3]];
+                        if little {
+                            f32::from_le_bytes(bytes)
+                        } else {
+                            f32::from_be_bytes(bytes)
+                        }
+                    }
+                    RawSampleFormat::MuLaw => mu_law_to_linear(b[0]) as f32 / 32768.0,
+                    RawSampleFormat::ALaw => a_law_to_linear(b[0]) as f32 / 32768.0,
+                }),
+        );
+    }
+}
+
+impl AudioDecoder for RawPcmDecoder {
+    fn list_tracks(&self, _source: SharedSource) -> Result<Vec<TrackInfo>, AppError> {
+        self.validate()?;
+        Ok(vec![TrackInfo {
+            id: 0,
+            codec: self.codec(),
+            language: None,
+            sample_rate: self.format.sample_rate,
+            channels: self.format.channels,
+            channel_layout: Some(ChannelLayout::from_count(self.format.channels)),
+            total_frames: None,
+        }])
+    }
+
+    fn decode_incremental(
+        &self,
+        source: SharedSource,
+        _options: DecodeOptions,
+        token: &CancellationToken,
+        on_progress: &mut dyn FnMut(DecodeUpdate),
+    ) -> Result<Audio, AppError> {
+        self.validate()?;
+        let (mut reader, total_bytes): (Box<dyn Read>, u64) = match source {
+            SharedSource::Bytes(data) => {
+                let len = data.len() as u64;
+                (Box::new(Cursor::new(data)), len)
+            }
+            SharedSource::Path(path) => {
+                let file = File::open(&path)
+                    .map_err(|e| AppError::Io(format!("Failed to open {}: {}", path, e)))?;
+                let len = file.metadata()?.len();
+                (Box::new(file), len)
+            }
+        };
+        let skipped = io::copy(
+            &mut (&mut reader).take(self.format.header_skip),
+            &mut io::sink(),
+        )?;
+
+        let sample_rate = self.format.sample_rate;
+        let channels = self.format.channels;
+        let frame_bytes = self.format.sample_format.bytes_per_sample() * channels as usize;
+        let payload_bytes = total_bytes.saturating_sub(skipped);
+        let mut info = AudioInfo {
+            sample_rate,
+            channels,
+            channel_layout: ChannelLayout::from_count(channels),
+            codec: self.codec(),
+            container: "raw".to_string(),
+            bits_per_sample: Some(self.format.sample_format.bytes_per_sample() as u32 * 8),
+            total_frames: 0,
+            duration: 0.0,
+            encoder_delay: None,
+            encoder_padding: None,
+            tags: Vec::new(),
+            decode_report: DecodeReport::default(),
+        };
+
+        let mut samples = Vec::with_capacity(
+            payload_bytes as usize / self.format.sample_format.bytes_per_sample(),
+        );
+        let mut chunk = vec![0u8; CHUNK_FRAMES * frame_bytes];
+        let mut bytes_read = skipped;
+        let mut chunks = 0u64;
+        let mut leftover = 0usize;
+        let estimated_duration = (payload_bytes / frame_bytes as u64) as f64 / sample_rate as f64;
+
+        while !token.is_cancelled() {
+            let filled = read_full(&mut reader, &mut chunk)?;
+            if filled == 0 {
+                break;
+            }
+            bytes_read += filled as u64;
+            chunks += 1;
+            let whole = filled - filled % frame_bytes;
+            leftover = filled - whole;
+            self.convert(&chunk[..whole], &mut samples);
+
+            let decoded_frames = (samples.len() / channels as usize) as u64;
+            on_progress(DecodeUpdate {
+                progress: DecodeProgress {
+                    packets: chunks,
+                    bytes_read,
+                    total_bytes: Some(total_bytes),
+                    decoded_frames,
+                    decoded_duration: decoded_frames as f64 / sample_rate as f64,
+                    estimated_duration: Some(estimated_duration),
+                },
+                info: &info,
+                samples: &samples,
+            });
+            if filled < chunk.len() {
+                break;
+            }
+        }
+
+        info.total_frames = (samples.len() / channels as usize) as u64;
+        info.duration = info.total_frames as f64 / sample_rate as f64;
+        if leftover > 0 {
+            info.decode_report.truncated = true;
+            info.decode_report.mark(
+                info.total_frames,
+                info.total_frames,
+                sample_rate,
+                &format!("{} trailing bytes do not form a whole frame", leftover),
+            );
+        }
+
+        Ok(Audio {
+            data: AudioData {
+                samples: samples.into(),
+            },
+            info,
+            content_hash: None,
+        })
+    }
+}
+
+/// Reads until `buf` is full or the input ends, returning the bytes read.
+fn read_full(reader: &mut dyn Read, buf: &mut [u8]) -> Result<usize, AppError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+/// G.711 mu-law expansion to 16-bit linear PCM.
+fn mu_law_to_linear(byte: u8) -> i16 {
+    let byte = !byte;
+    let sign = byte & 0x80;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = (byte & 0x0f) as i16;
+    let magnitude = (((mantissa << 3) + 0x84) << exponent) - 0x84;
+    if sign != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// G.711 A-law expansion to 16-bit linear PCM.
+fn a_law_to_linear(byte: u8) -> i16 {
+    let byte = byte ^ 0x55;
+    let sign = byte & 0x80;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = (byte & 0x0f) as i16;
+    let magnitude = match exponent {
+        0 => (mantissa << 4) + 8,
+        _ => ((mantissa << 4) + 0x108) << (exponent - 1),
+    };
+    if sign != 0 {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
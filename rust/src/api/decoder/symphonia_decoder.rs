@@ -1,13 +1,22 @@
-use std::io::Cursor;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use log::error;
-use symphonia::core::probe::Hint;
+use log::{error, warn};
+use symphonia::core::codecs::{CodecParameters, Decoder, DecoderOptions};
+use symphonia::core::errors::Error;
+use symphonia::core::probe::{Hint, ProbeResult};
 
-use crate::api::traits::audio_decoder::AudioDecoder;
-use crate::api::types::audio::{Audio, AudioData, AudioInfo};
+use crate::api::traits::audio_decoder::{AudioDecoder, DecodeUpdate};
+use crate::api::types::audio::{Audio, AudioData, AudioInfo, AudioTag, ChannelLayout, TrackInfo};
+use crate::api::types::decode::{CancellationToken, DecodeOptions, DecodeProgress, DecodeReport};
 use crate::api::types::error::AppError;
-use symphonia::core::audio::Signal;
+use crate::api::types::file::SharedSource;
+use crate::api::util::format_detector::detect_format;
+use symphonia::core::audio::{Channels, SampleBuffer};
+use symphonia::core::io::MediaSource;
+use symphonia::core::meta::MetadataRevision;
 
 pub struct SymphoniaDecoder {}
 
@@ -17,54 +26,161 @@ impl SymphoniaDecoder {
     }
 }
 
+/// Emit a progress update whenever this fraction of the input has been read.
+const PROGRESS_STEP: f64 = 0.01;
+/// Fallback cadence when the input length is unknown.
+const PROGRESS_PACKETS: u64 = 1024;
+/// Consecutive unreadable packets tolerated before the rest of the input is
+/// given up as lost.
+const MAX_READ_ERRORS: u32 = 64;
+
 impl AudioDecoder for SymphoniaDecoder {
-    fn decode(&self, format: String, data: Vec<u8>) -> Result<Audio, AppError> {
-        let source = Box::new(Cursor::new(data));
-        let mut hint = Hint::new();
-        hint.with_extension(&format);
-
-        let mss = symphonia::core::io::MediaSourceStream::new(source, Default::default());
-        let probed = symphonia::default::get_probe()
-            .format(
-                &hint,
-                mss,
-                &symphonia::core::formats::FormatOptions::default(),
-                &symphonia::core::meta::MetadataOptions::default(),
-            )
-            .map_err(|e| AppError::Format(format!("Unsupported format: {:?}", e)))?;
+    fn list_tracks(&self, source: SharedSource) -> Result<Vec<TrackInfo>, AppError> {
+        let (probed, _) = probe(source, Arc::new(AtomicU64::new(0)))?;
+        Ok(probed
+            .format
+            .tracks()
+            .iter()
+            .filter(|t| t.codec_params.sample_rate.is_some())
+            .map(|t| TrackInfo {
+                id: t.id,
+                codec: codec_name(&t.codec_params),
+                language: t.language.clone(),
+                sample_rate: t.codec_params.sample_rate.unwrap_or_default(),
+                channels: t.codec_params.channels.map_or(0, |c| c.count() as u32),
+                channel_layout: t.codec_params.channels.map(channel_layout),
+                total_frames: t.codec_params.n_frames,
+            })
+            .collect())
+    }
+
+    fn decode_incremental(
+        &self,
+        source: SharedSource,
+        options: DecodeOptions,
+        token: &CancellationToken,
+        on_progress: &mut dyn FnMut(DecodeUpdate),
+    ) -> Result<Audio, AppError> {
+        let container = detect_format(&source)
+            .map(|detected| detected.container)
+            .unwrap_or_else(|_| "unknown".to_string());
+        let bytes_read = Arc::new(AtomicU64::new(0));
+        let (probed, total_bytes) = probe(source, bytes_read.clone())?;
 
         let mut format_reader = probed.format;
+        let mut probed_metadata = probed.metadata;
 
-        let track = format_reader
-            .tracks()
-            .iter()
-            .find(|t| t.codec_params.sample_rate.is_some())
-            .ok_or_else(|| AppError::Decode("No audio track found".to_string()))?;
+        let track = match options.track_id {
+            Some(id) => format_reader
+                .tracks()
+                .iter()
+                .find(|t| t.id == id)
+                .ok_or_else(|| AppError::NotFound(format!("Track not found: {}", id)))?,
+            None => format_reader
+                .tracks()
+                .iter()
+                .find(|t| t.codec_params.sample_rate.is_some())
+                .ok_or_else(|| AppError::Decode("No audio track found".to_string()))?,
+        };
+        if track.codec_params.sample_rate.is_none() {
+            return Err(AppError::Decode(format!("Track {} is not audio", track.id)));
+        }
 
         let sample_rate = track.codec_params.sample_rate.unwrap();
-        let track_id = track.id;
+        let mut track_id = track.id;
+        let declared_frames = track.codec_params.n_frames;
+        let mut channels = track.codec_params.channels;
+        let mut info = AudioInfo {
+            sample_rate,
+            channels: channels.map_or(1, |c| c.count() as u32),
+            channel_layout: channels.map_or(ChannelLayout::Mono, channel_layout),
+            codec: codec_name(&track.codec_params),
+            container,
+            bits_per_sample: track.codec_params.bits_per_sample,
+            total_frames: 0,
+            duration: 0.0,
+            encoder_delay: track.codec_params.delay,
+            encoder_padding: track.codec_params.padding,
+            tags: Vec::new(),
+            decode_report: DecodeReport::default(),
+        };
 
-        let mut decoder = symphonia::default::get_codecs()
-            .make(
-                &track.codec_params,
-                &symphonia::core::codecs::DecoderOptions::default(),
-            )
-            .map_err(|e| AppError::Decode(format!("Decoder init failed: {:?}", e)))?;
+        let mut decoder = make_decoder(&track.codec_params)?;
+        let mut codec_params = track.codec_params.clone();
 
         let mut samples_f32: Vec<f32> = Vec::new();
+        let mut packets = 0u64;
+        let mut last_reported = 0u64;
+        let mut report = DecodeReport::default();
+        let mut packet_frames = 0u64;
+        let mut read_errors = 0u32;
 
-        loop {
+        let progress = |packets: u64, samples: &[f32], info: &AudioInfo| {
+            let bytes_read = bytes_read.load(Ordering::Relaxed);
+            let decoded_frames = (samples.len() / info.channels.max(1) as usize) as u64;
+            let decoded_duration = decoded_frames as f64 / sample_rate as f64;
+            let estimated_duration = match (declared_frames, total_bytes) {
+                (Some(frames), _) => Some(frames as f64 / sample_rate as f64),
+                (None, Some(total)) if bytes_read > 0 => {
+                    Some(decoded_duration * total as f64 / bytes_read as f64)
+                }
+                _ => None,
+            };
+            DecodeProgress {
+                packets,
+                bytes_read,
+                total_bytes,
+                decoded_frames,
+                decoded_duration,
+                estimated_duration,
+            }
+        };
+
+        while !token.is_cancelled() {
+            let frames_so_far = (samples_f32.len() / info.channels.max(1) as usize) as u64;
             let packet = match format_reader.next_packet() {
-                Ok(p) => p,
-                Err(symphonia::core::errors::Error::ResetRequired) => {
-                    error!("RESET_REQUIRED_ERROR");
+                Ok(p) => {
+                    read_errors = 0;
+                    p
+                }
+                Err(Error::ResetRequired) => {
+                    warn!("Decoder reset required at frame {}", frames_so_far);
+                    let track = format_reader
+                        .tracks()
+                        .iter()
+                        .find(|t| t.id == track_id)
+                        .or_else(|| {
+                            format_reader
+                                .tracks()
+                                .iter()
+                                .find(|t| t.codec_params.sample_rate.is_some())
+                        })
+                        .ok_or_else(|| {
+                            AppError::Decode("No audio track after reset".to_string())
+                        })?;
+                    track_id = track.id;
+                    decoder = make_decoder(&track.codec_params)?;
+                    codec_params = track.codec_params.clone();
+                    report.decoder_resets += 1;
                     continue;
                 }
-                Err(symphonia::core::errors::Error::IoError(_)) => {
+                Err(Error::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
                     break;
                 }
+                Err(Error::DecodeError(e)) if read_errors < MAX_READ_ERRORS => {
+                    read_errors += 1;
+                    warn!(
+                        "Skipping unreadable packet at frame {}: {}",
+                        frames_so_far, e
+                    );
+                    report.mark(frames_so_far, frames_so_far, sample_rate, e);
+                    continue;
+                }
                 Err(e) => {
-                    return Err(AppError::Decode(format!("Packet read error: {:?}", e)));
+                    error!("Stopped reading at frame {}: {:?}", frames_so_far, e);
+                    report.truncated = true;
+                    report.mark(frames_so_far, frames_so_far, sample_rate, &e.to_string());
+                    break;
                 }
             };
 
@@ -74,30 +190,335 @@ impl AudioDecoder for SymphoniaDecoder {
 
             match decoder.decode(&packet) {
                 Ok(decoded_buffer) => {
-                    let spec = decoded_buffer.spec();
+                    let spec = *decoded_buffer.spec();
                     let duration = decoded_buffer.capacity();
+                    if channels.is_none() {
+                        channels = Some(spec.channels);
+                        info.channels = spec.channels.count() as u32;
+                        info.channel_layout = channel_layout(spec.channels);
+                    }
+                    packet_frames = decoded_buffer.frames() as u64;
 
-                    let mut audio_buffer: symphonia::core::audio::AudioBuffer<f32> =
-                        symphonia::core::audio::AudioBuffer::new(duration as u64, *spec);
-                    decoded_buffer.convert(&mut audio_buffer);
-
-                    for channel in 0..spec.channels.count() {
-                        for sample in audio_buffer.chan(channel) {
-                            samples_f32.push(*sample);
+                    let mut sample_buffer = SampleBuffer::<f32>::new(duration as u64, spec);
+                    sample_buffer.copy_interleaved_ref(decoded_buffer);
+                    samples_f32.extend_from_slice(sample_buffer.samples());
+                }
+                Err(e) => {
+                    if let Error::ResetRequired = e {
+                        let track = format_reader.tracks().iter().find(|t| t.id == track_id);
+                        if let Some(track) = track {
+                            decoder = make_decoder(&track.codec_params)?;
+                            codec_params = track.codec_params.clone();
+                            report.decoder_resets += 1;
                         }
                     }
+                    let frames = packet_frames_of(packet.dur, &codec_params, sample_rate)
+                        .unwrap_or(packet_frames);
+                    warn!(
+                        "Zero-filling {} frames of a damaged packet at frame {}: {}",
+                        frames, frames_so_far, e
+                    );
+                    samples_f32.resize(
+                        samples_f32.len() + frames as usize * info.channels.max(1) as usize,
+                        0.0,
+                    );
+                    report.skipped_packets += 1;
+                    report.mark(
+                        frames_so_far,
+                        frames_so_far + frames,
+                        sample_rate,
+                        &e.to_string(),
+                    );
                 }
-                Err(e) => {
-                    return Err(AppError::Decode(format!("Decode error: {:?}", e)));
+            }
+
+            packets += 1;
+            let due = match total_bytes {
+                Some(total) if total > 0 => {
+                    let read = bytes_read.load(Ordering::Relaxed);
+                    read.saturating_sub(last_reported) as f64 >= total as f64 * PROGRESS_STEP
                 }
+                _ => packets - last_reported >= PROGRESS_PACKETS,
+            };
+            if due {
+                last_reported = match total_bytes {
+                    Some(total) if total > 0 => bytes_read.load(Ordering::Relaxed),
+                    _ => packets,
+                };
+                on_progress(DecodeUpdate {
+                    progress: progress(packets, &samples_f32, &info),
+                    info: &info,
+                    samples: &samples_f32,
+                });
+            }
+        }
+
+        if let Some(revision) = probed_metadata
+            .get()
+            .as_mut()
+            .and_then(|m| m.skip_to_latest())
+        {
+            collect_tags(revision, &mut info.tags);
+        }
+        if let Some(revision) = format_reader.metadata().skip_to_latest() {
+            collect_tags(revision, &mut info.tags);
+        }
+
+        info.total_frames = (samples_f32.len() / info.channels.max(1) as usize) as u64;
+        info.duration = info.total_frames as f64 / sample_rate as f64;
+        if let Some(declared) = declared_frames {
+            if !token.is_cancelled() && info.total_frames < declared {
+                report.truncated = true;
+                report.mark(info.total_frames, declared, sample_rate, "Truncated");
             }
         }
+        info.decode_report = report;
+        on_progress(DecodeUpdate {
+            progress: progress(packets, &samples_f32, &info),
+            info: &info,
+            samples: &samples_f32,
+        });
 
         Ok(Audio {
             data: AudioData {
-                samples: Arc::new(samples_f32),
+                samples: samples_f32.into(),
             },
-            info: AudioInfo { sample_rate },
+            info,
+            content_hash: None,
         })
     }
 }
+
+/// Opens `source` and probes its container by content, trying every
+/// registered reader. Also returns the input length so progress can be
+/// reported against it.
+fn probe(
+    source: SharedSource,
+    bytes_read: Arc<AtomicU64>,
+) -> Result<(ProbeResult, Option<u64>), AppError> {
+    let source: Box<dyn MediaSource> = match source {
+        SharedSource::Bytes(data) => Box::new(CountingSource::new(Cursor::new(data), bytes_read)),
+        SharedSource::Path(path) => {
+            let file = File::open(&path)
+                .map_err(|e| AppError::Io(format!("Failed to open {}: {}", path, e)))?;
+            Box::new(CountingSource::new(file, bytes_read))
+        }
+    };
+    let total_bytes = source.byte_len();
+
+    let mss = symphonia::core::io::MediaSourceStream::new(source, Default::default());
+    let probed = symphonia::default::get_probe()
+        .format(
+            &Hint::new(),
+            mss,
+            &symphonia::core::formats::FormatOptions::default(),
+            &symphonia::core::meta::MetadataOptions::default(),
+        )
+        .map_err(|e| AppError::Format(format!("Unsupported format: {:?}", e)))?;
+    Ok((probed, total_bytes))
+}
+
+fn make_decoder(codec_params: &CodecParameters) -> Result<Box<dyn Decoder>, AppError> {
+    symphonia::default::get_codecs()
+        .make(codec_params, &DecoderOptions::default())
+        .map_err(|e| AppError::Decode(format!("Decoder init failed: {:?}", e)))
+}
+
+fn codec_name(codec_params: &CodecParameters) -> String {
+    symphonia::default::get_codecs()
+        .get_codec(codec_params.codec)
+        .map(|d| d.short_name.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Wraps the input so the decoder can report how far into it the format
+/// reader has got.
+struct CountingSource<R> {
+    inner: R,
+    position: Arc<AtomicU64>,
+}
+
+impl<R> CountingSource<R> {
+    fn new(inner: R, position: Arc<AtomicU64>) -> Self {
+        Self { inner, position }
+    }
+}
+
+impl<R: Read> Read for CountingSource<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+impl<R: Seek> Seek for CountingSource<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = self.inner.seek(pos)?;
+        self.position.store(position, Ordering::Relaxed);
+        Ok(position)
+    }
+}
+
+impl<R: MediaSource> MediaSource for CountingSource<R> {
+    fn is_seekable(&self) -> bool {
+        self.inner.is_seekable()
+    }
+
+    fn byte_len(&self) -> Option<u64> {
+        self.inner.byte_len()
+    }
+}
+
+/// Frames a packet spans, from its duration in time-base units, or else
+/// the frames per packet the codec declares.
+fn packet_frames_of(dur: u64, params: &CodecParameters, sample_rate: u32) -> Option<u64> {
+    match params.time_base {
+        Some(time_base) if dur > 0 && time_base.denom > 0 => {
+            let scaled = dur as u128 * time_base.numer as u128 * sample_rate as u128;
+            let denom = time_base.denom as u128;
+            Some(((scaled + denom / 2) / denom) as u64)
+        }
+        _ => params.max_frames_per_packet,
+    }
+}
+
+fn collect_tags(revision: &MetadataRevision, tags: &mut Vec<AudioTag>) {
+    for tag in revision.tags() {
+        let key = tag
+            .std_key
+            .map(|k| format!("{:?}", k))
+            .unwrap_or_else(|| tag.key.clone());
+        tags.push(AudioTag {
+            key,
+            value: tag.value.to_string(),
+        });
+    }
+}
+
+fn channel_layout(channels: Channels) -> ChannelLayout {
+    match channels.count() {
+        1 => ChannelLayout::Mono,
+        2 => ChannelLayout::Stereo,
+        _ => ChannelLayout::Multichannel(
+            channels
+                .iter()
+                .enumerate()
+                .map(|(index, channel)| channel_name(channel, index))
+                .collect(),
+        ),
+    }
+}
+
+fn channel_name(channel: Channels, index: usize) -> String {
+    let name = match channel {
+        Channels::FRONT_LEFT => "FL",
+        Channels::FRONT_RIGHT => "FR",
+        Channels::FRONT_CENTRE => "FC",
+        Channels::LFE1 => "LFE",
+        Channels::REAR_LEFT => "RL",
+        Channels::REAR_RIGHT => "RR",
+        Channels::FRONT_LEFT_CENTRE => "FLC",
+        Channels::FRONT_RIGHT_CENTRE => "FRC",
+        Channels::REAR_CENTRE => "RC",
+        Channels::SIDE_LEFT => "SL",
+        Channels::SIDE_RIGHT => "SR",
+        Channels::LFE2 => "LFE2",
+        _ => return format!("CH{}", index + 1),
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::encoder::flac_encoder::FlacEncoder;
+    use crate::api::traits::audio_encoder::AudioEncoder;
+    use crate::api::types::encode::ExportSampleFormat;
+    use symphonia::core::checksum::Crc16Ansi;
+    use symphonia::core::io::Monitor;
+    use symphonia::core::units::TimeBase;
+
+    const SAMPLE_RATE: u32 = 44100;
+    const BLOCK: usize = 4096;
+
+    /// Three FLAC blocks of a mono sine, with the first subframe of the
+    /// middle block set to a reserved type so the codec rejects it.
+    fn flac_with_damaged_block() -> Vec<u8> {
+        let samples: Vec<f32> = (0..3 * BLOCK)
+            .map(|n| (n as f32 * 0.05).sin() * 0.5)
+            .collect();
+        let audio = Audio {
+            data: AudioData {
+                samples: samples.into(),
+            },
+            info: AudioInfo {
+                sample_rate: SAMPLE_RATE,
+                channels: 1,
+                channel_layout: ChannelLayout::Mono,
+                codec: String::new(),
+                container: String::new(),
+                bits_per_sample: None,
+                total_frames: 3 * BLOCK as u64,
+                duration: 0.0,
+                encoder_delay: None,
+                encoder_padding: None,
+                tags: Vec::new(),
+                decode_report: DecodeReport::default(),
+            },
+            content_hash: None,
+        };
+        let mut flac = FlacEncoder::new(ExportSampleFormat::I16, false)
+            .encode_to_bytes(&audio)
+            .unwrap();
+
+        // Sync code, block size and rate codes, mono 16-bit, frame number.
+        let frame_start = |number: u8| {
+            let header = [0xff, 0xf8, 0x70, 0x08, number];
+            flac.windows(header.len())
+                .position(|w| w == header)
+                .unwrap()
+        };
+        let (start, end) = (frame_start(1), frame_start(2));
+        // Two bytes of block size and the CRC-8 end the header.
+        // A subframe type of 0b000010 is reserved.
+        flac[start + 8] = 0b0000_0100;
+        // The reader drops frames failing their CRC-16, so it has to match
+        // for the damage to reach the codec.
+        let mut crc = Crc16Ansi::new(0);
+        crc.process_buf_bytes(&flac[start..end - 2]);
+        flac[end - 2..end].copy_from_slice(&crc.crc().to_be_bytes());
+        flac
+    }
+
+    #[test]
+    fn damaged_packet_is_zero_filled_for_its_duration() {
+        let audio = SymphoniaDecoder::new()
+            .decode(
+                SharedSource::Bytes(flac_with_damaged_block().into()),
+                DecodeOptions::default(),
+            )
+            .unwrap();
+
+        assert_eq!(audio.info.total_frames, 3 * BLOCK as u64);
+        let report = &audio.info.decode_report;
+        assert_eq!(report.skipped_packets, 1);
+        assert_eq!(report.damaged.len(), 1);
+        assert_eq!(report.damaged[0].start_frame, BLOCK as u64);
+        assert_eq!(report.damaged[0].end_frame, 2 * BLOCK as u64);
+        let samples = audio.data.samples.to_f32();
+        assert!(samples[BLOCK..2 * BLOCK].iter().all(|&s| s == 0.0));
+        assert!(samples[2 * BLOCK..].iter().any(|&s| s != 0.0));
+    }
+
+    #[test]
+    fn packet_duration_is_converted_from_the_time_base() {
+        let mut params = CodecParameters::new();
+        params.with_time_base(TimeBase::new(1, 1000));
+        assert_eq!(packet_frames_of(10, &params, SAMPLE_RATE), Some(441));
+        assert_eq!(packet_frames_of(0, &params, SAMPLE_RATE), None);
+
+        params.with_max_frames_per_packet(1152);
+        assert_eq!(packet_frames_of(0, &params, SAMPLE_RATE), Some(1152));
+    }
+}
@@ -0,0 +1,429 @@
+use std::io::SeekFrom;
+
+use crate::api::{
+    traits::audio_encoder::{AudioEncoder, EncoderOutput},
+    types::{audio::Audio, encode::ExportSampleFormat, error::AppError},
+    util::dither::Quantizer,
+};
+
+/// Frames per FLAC block, the reference encoder's default.
+const BLOCK_SIZE: usize = 4096;
+const MAX_FIXED_ORDER: usize = 4;
+
+/// Writes FLAC with fixed linear predictors and one Rice partition per
+/// subframe. Channels are coded independently and the STREAMINFO MD5 is left
+/// zero, which the format defines as "not computed".
+pub struct FlacEncoder {
+    sample_format: ExportSampleFormat,
+    dither: bool,
+}
+
+impl FlacEncoder {
+    pub fn new(sample_format: ExportSampleFormat, dither: bool) -> Self {
+        Self {
+            sample_format,
+            dither,
+        }
+    }
+}
+
+impl AudioEncoder for FlacEncoder {
+    fn encode(&self, audio: &Audio, writer: &mut dyn EncoderOutput) -> Result<(), AppError> {
+        let bits = match self.sample_format {
+            ExportSampleFormat::I16 => 16,
+            ExportSampleFormat::I24 => 24,
+            ExportSampleFormat::F32 => {
+                return Err(AppError::Format(
+                    "FLAC cannot store floating point samples".to_string(),
+                ))
+            }
+        };
+        let channels = audio.info.channels.max(1) as usize;
+        if channels > 8 {
+            return Err(AppError::Format(format!(
+                "FLAC supports at most 8 channels, got {}",
+                channels
+            )));
+        }
+        let sample_rate = audio.info.sample_rate;
+        if sample_rate == 0 || sample_rate >= 1 << 20 {
+            return Err(AppError::Format(format!(
+                "Sample rate {} cannot be stored in FLAC",
+                sample_rate
+            )));
+        }
+
+        let frames = audio.frames();
+        let block_size = BLOCK_SIZE.min(frames.max(16)) as u64;
+        // The frame sizes stay zero, meaning unknown, until every frame is
+        // written.
+        let mut streaminfo = BitWriter::new();
+        streaminfo.write(block_size, 16);
+        streaminfo.write(block_size, 16);
+        streaminfo.write(0, 24);
+        streaminfo.write(0, 24);
+        streaminfo.write(sample_rate as u64, 20);
+        streaminfo.write(channels as u64 - 1, 3);
+        streaminfo.write(bits as u64 - 1, 5);
+        streaminfo.write(frames as u64, 36);
+        let mut streaminfo = streaminfo.into_bytes();
+        streaminfo.extend_from_slice(&[0u8; 16]);
+
+        let start = writer.stream_position()?;
+        writer.write_all(b"fLaC")?;
+        // Last-metadata-block flag, block type 0 (STREAMINFO), 24-bit length.
+        writer.write_all(&[0x80, 0x00, 0x00, streaminfo.len() as u8])?;
+        writer.write_all(&streaminfo)?;
+
+        // Integers of the stored width or wider hold the samples exactly, so
+        // only a narrower format is dithered.
+        let dither = self.dither && audio.data.samples.storage().bits() > bits;
+        let mut quantizer = Quantizer::new(bits, dither);
+        let mut min_frame_len = u32::MAX;
+        let mut max_frame_len = 0u32;
+        for (frame_number, block_start) in (0..frames).step_by(BLOCK_SIZE).enumerate() {
+            let block_len = BLOCK_SIZE.min(frames - block_start);
+            let interleaved = quantizer.quantize(
+                &audio
+                    .data
+                    .samples
+                    .slice(block_start * channels, (block_start + block_len) * channels),
+            );
+            let block: Vec<Vec<i32>> = (0..channels)
+                .map(|c| {
+                    interleaved
+                        .iter()
+                        .skip(c)
+                        .step_by(channels)
+                        .copied()
+                        .collect()
+                })
+                .collect();
+            let frame = encode_frame(&block, frame_number as u64, bits);
+            min_frame_len = min_frame_len.min(frame.len() as u32);
+            max_frame_len = max_frame_len.max(frame.len() as u32);
+            writer.write_all(&frame)?;
+        }
+
+        if max_frame_len > 0 {
+            let end = writer.stream_position()?;
+            // The frame sizes follow the two block sizes in STREAMINFO.
+            writer.seek(SeekFrom::Start(start + 8 + 4))?;
+            writer.write_all(&min_frame_len.to_be_bytes()[1..])?;
+            writer.write_all(&max_frame_len.to_be_bytes()[1..])?;
+            writer.seek(SeekFrom::Start(end))?;
+        }
+        Ok(())
+    }
+}
+
+fn encode_frame(block: &[Vec<i32>], frame_number: u64, bits: u32) -> Vec<u8> {
+    let block_len = block[0].len();
+    let mut out = BitWriter::new();
+    // Sync code, reserved bit, fixed block size strategy.
+    out.write(0xfff8, 16);
+    // Block size as 16-bit (n - 1) at the end of the header; sample rate
+    // taken from STREAMINFO.
+    out.write(0b0111, 4);
+    out.write(0b0000, 4);
+    // Independent channels.
+    out.write(block.len() as u64 - 1, 4);
+    out.write(if bits == 16 { 0b100 } else { 0b110 }, 3);
+    out.write(0, 1);
+    write_utf8_number(&mut out, frame_number);
+    out.write(block_len as u64 - 1, 16);
+    let crc = crc8(out.bytes());
+    out.write(crc as u64, 8);
+
+    for channel in block {
+        encode_subframe(&mut out, channel, bits);
+    }
+    out.align();
+    let crc = crc16(out.bytes());
+    out.write(crc as u64, 16);
+    out.into_bytes()
+}
+
+fn encode_subframe(out: &mut BitWriter, samples: &[i32], bits: u32) {
+    if samples.iter().all(|&s| s == samples[0]) {
+        out.write(0, 1);
+        out.write(0b000000, 6);
+        out.write(0, 1);
+        out.write_signed(samples[0], bits);
+        return;
+    }
+
+    let verbatim_bits = samples.len() as u64 * bits as u64;
+    let best = (0..=MAX_FIXED_ORDER.min(samples.len() - 1))
+        .map(|order| {
+            let residual = fixed_residual(samples, order);
+            let (parameter, residual_bits) = rice_parameter(&residual);
+            let total = order as u64 * bits as u64 + 6 + residual_bits;
+            (order, residual, parameter, total)
+        })
+        .min_by_key(|(_, _, _, total)| *total);
+
+    match best {
+        Some((order, residual, parameter, total)) if total < verbatim_bits => {
+            out.write(0, 1);
+            out.write(0b001000 | order as u64, 6);
+            out.write(0, 1);
+            for &warm_up in &samples[..order] {
+                out.write_signed(warm_up, bits);
+            }
+            // RICE with 4-bit parameters when they fit, RICE2 otherwise.
+            let wide = parameter > 14;
+            out.write(wide as u64, 2);
+            out.write(0, 4);
+            out.write(parameter as u64, if wide { 5 } else { 4 });
+            for &r in &residual {
+                let folded = zigzag(r);
+                let quotient = folded >> parameter;
+                out.write_zeros(quotient);
+                out.write(1, 1);
+                out.write(folded & ((1 << parameter) - 1), parameter);
+            }
+        }
+        _ => {
+            out.write(0, 1);
+            out.write(0b000001, 6);
+            out.write(0, 1);
+            for &sample in samples {
+                out.write_signed(sample, bits);
+            }
+        }
+    }
+}
+
+/// Residual of the fixed polynomial predictor of `order`, skipping the
+/// warm-up samples.
+fn fixed_residual(samples: &[i32], order: usize) -> Vec<i64> {
+    let s = |i: usize| samples[i] as i64;
+    (order..samples.len())
+        .map(|i| match order {
+            0 => s(i),
+            1 => s(i) - s(i - 1),
+            2 => s(i) - 2 * s(i - 1) + s(i - 2),
+            3 => s(i) - 3 * s(i - 1) + 3 * s(i - 2) - s(i - 3),
+            _ => s(i) - 4 * s(i - 1) + 6 * s(i - 2) - 4 * s(i - 3) + s(i - 4),
+        })
+        .collect()
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Rice parameter with the fewest bits for `residual`, and that bit count.
+fn rice_parameter(residual: &[i64]) -> (u32, u64) {
+    let folded: Vec<u64> = residual.iter().map(|&r| zigzag(r)).collect();
+    let mean = folded.iter().sum::<u64>() / folded.len().max(1) as u64;
+    let guess = 64 - mean.leading_zeros();
+    let cost = |parameter: u32| -> u64 {
+        folded
+            .iter()
+            .map(|&u| (u >> parameter) + 1 + parameter as u64)
+            .sum()
+    };
+    (guess.saturating_sub(1)..=(guess + 1).min(30))
+        .map(|parameter| (parameter, cost(parameter)))
+        .min_by_key(|(_, bits)| *bits)
+        .unwrap_or((0, cost(0)))
+}
+
+/// Frame number in FLAC's extended UTF-8 coding.
+fn write_utf8_number(out: &mut BitWriter, value: u64) {
+    if value < 0x80 {
+        out.write(value, 8);
+        return;
+    }
+    let mut continuation = 1;
+    while value >= 1 << (5 * continuation + 6) {
+        continuation += 1;
+    }
+    let marker = (0xff00u64 >> (continuation + 1)) & 0xff;
+    out.write(marker | (value >> (6 * continuation)), 8);
+    for i in (0..continuation).rev() {
+        out.write(0x80 | ((value >> (6 * i)) & 0x3f), 8);
+    }
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |mut crc, &byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |mut crc, &byte| {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+/// MSB-first bit packer.
+struct BitWriter {
+    bytes: Vec<u8>,
+    accumulator: u64,
+    pending: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            accumulator: 0,
+            pending: 0,
+        }
+    }
+
+    fn write(&mut self, value: u64, bits: u32) {
+        let mut remaining = bits;
+        while remaining > 0 {
+            let take = remaining.min(32);
+            remaining -= take;
+            let chunk = (value >> remaining) & ((1u64 << take) - 1);
+            self.accumulator = (self.accumulator << take) | chunk;
+            self.pending += take;
+            while self.pending >= 8 {
+                self.pending -= 8;
+                self.bytes.push((self.accumulator >> self.pending) as u8);
+            }
+            self.accumulator &= (1u64 << self.pending) - 1;
+        }
+    }
+
+    fn write_signed(&mut self, value: i32, bits: u32) {
+        self.write(value as u64 & ((1u64 << bits) - 1), bits);
+    }
+
+    fn write_zeros(&mut self, mut count: u64) {
+        while count > 0 {
+            let take = count.min(32);
+            self.write(0, take as u32);
+            count -= take;
+        }
+    }
+
+    fn align(&mut self) {
+        if self.pending > 0 {
+            self.write(0, 8 - self.pending);
+        }
+    }
+
+    /// Whole bytes written so far.
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::decoder::symphonia_decoder::SymphoniaDecoder;
+    use crate::api::traits::audio_decoder::AudioDecoder;
+    use crate::api::types::{
+        audio::{AudioData, AudioInfo, ChannelLayout},
+        decode::{DecodeOptions, DecodeReport},
+        file::SharedSource,
+        samples::{SampleBuffer, SampleStorage},
+    };
+
+    /// A sine of `frames` per channel, each channel at its own frequency,
+    /// with a silent stretch so constant subframes are written too.
+    fn sine(channels: u32, frames: usize, storage: SampleStorage) -> Audio {
+        let samples: Vec<f32> = (0..frames * channels as usize)
+            .map(|i| {
+                let (frame, channel) = (i / channels as usize, i % channels as usize);
+                if (BLOCK_SIZE..2 * BLOCK_SIZE).contains(&frame) {
+                    return 0.0;
+                }
+                (frame as f32 * 0.01 * (channel + 1) as f32).sin() * 0.9
+            })
+            .collect();
+        Audio {
+            data: AudioData {
+                samples: SampleBuffer::from_f32(samples, storage),
+            },
+            info: AudioInfo {
+                sample_rate: 44100,
+                channels,
+                channel_layout: ChannelLayout::from_count(channels),
+                codec: String::new(),
+                container: String::new(),
+                bits_per_sample: None,
+                total_frames: frames as u64,
+                duration: 0.0,
+                encoder_delay: None,
+                encoder_padding: None,
+                tags: Vec::new(),
+                decode_report: DecodeReport::default(),
+            },
+            content_hash: None,
+        }
+    }
+
+    #[test]
+    fn decodes_to_the_samples_written() {
+        for (sample_format, channels) in
+            [(ExportSampleFormat::I16, 2), (ExportSampleFormat::I24, 3)]
+        {
+            let audio = sine(channels, 3 * BLOCK_SIZE + 123, SampleStorage::F32);
+            let flac = FlacEncoder::new(sample_format, false)
+                .encode_to_bytes(&audio)
+                .unwrap();
+            // STREAMINFO frame sizes are filled in once the frames are written.
+            let min_frame_len = u32::from_be_bytes([0, flac[12], flac[13], flac[14]]);
+            let max_frame_len = u32::from_be_bytes([0, flac[15], flac[16], flac[17]]);
+            assert!(0 < min_frame_len && min_frame_len <= max_frame_len);
+
+            let decoded = SymphoniaDecoder::new()
+                .decode(SharedSource::Bytes(flac.into()), DecodeOptions::default())
+                .unwrap();
+            assert_eq!(decoded.info.channels, channels);
+            assert_eq!(decoded.info.total_frames, audio.frames() as u64);
+            assert!(decoded.info.decode_report.is_clean());
+
+            let lsb = 1.0 / (1u32 << (sample_format.bits() - 1)) as f32;
+            let original = audio.data.samples.to_f32();
+            let decoded = decoded.data.samples.to_f32();
+            assert_eq!(decoded.len(), original.len());
+            for (a, b) in original.iter().zip(decoded.iter()) {
+                assert!((a - b).abs() <= lsb, "{:?}: {} vs {}", sample_format, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn dithers_only_when_bits_are_lost() {
+        let encode = |audio: &Audio, dither: bool| {
+            FlacEncoder::new(ExportSampleFormat::I24, dither)
+                .encode_to_bytes(audio)
+                .unwrap()
+        };
+        let stored_16 = sine(1, 5000, SampleStorage::I16);
+        assert_eq!(encode(&stored_16, true), encode(&stored_16, false));
+        let stored_32 = sine(1, 5000, SampleStorage::F32);
+        assert_ne!(encode(&stored_32, true), encode(&stored_32, false));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod flac_encoder;
+pub mod wav_encoder;
@@ -0,0 +1,256 @@
+use std::io::SeekFrom;
+
+use crate::api::{
+    traits::audio_encoder::{AudioEncoder, EncoderOutput},
+    types::{audio::Audio, encode::ExportSampleFormat, error::AppError},
+    util::dither::Quantizer,
+};
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xfffe;
+/// Frames quantized and written at a time.
+const BLOCK_FRAMES: usize = 4096;
+/// Tail of the KSDATAFORMAT_SUBTYPE GUIDs, after the two-byte format tag.
+const SUBFORMAT_GUID_TAIL: [u8; 14] = [
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+];
+
+pub struct WavEncoder {
+    sample_format: ExportSampleFormat,
+    dither: bool,
+}
+
+impl WavEncoder {
+    pub fn new(sample_format: ExportSampleFormat, dither: bool) -> Self {
+        Self {
+            sample_format,
+            dither,
+        }
+    }
+}
+
+impl AudioEncoder for WavEncoder {
+    fn encode(&self, audio: &Audio, writer: &mut dyn EncoderOutput) -> Result<(), AppError> {
+        let channels = audio.info.channels.max(1) as u16;
+        let bits = self.sample_format.bits() as u16;
+        let block_align = channels * bits / 8;
+        let frames = audio.frames() as u64;
+        let is_float = self.sample_format == ExportSampleFormat::F32;
+        // WAVE_FORMAT_EXTENSIBLE is required for more than two channels and
+        // recommended for anything beyond 16-bit integer samples.
+        let extensible = channels > 2 || bits > 16;
+        let format_tag = if is_float {
+            WAVE_FORMAT_IEEE_FLOAT
+        } else {
+            WAVE_FORMAT_PCM
+        };
+
+        let fmt_len: u32 = if extensible { 40 } else { 16 };
+        let fact_len: u32 = if is_float { 12 } else { 0 };
+        let data_len = frames * block_align as u64;
+        let riff_len = 4 + (8 + fmt_len as u64) + fact_len as u64 + 8 + data_len + data_len % 2;
+        if riff_len > u32::MAX as u64 {
+            return Err(AppError::ProcessingError(
+                "Audio is too long for a WAV file".to_string(),
+            ));
+        }
+
+        // The RIFF, fact and data sizes are left zero until the data is written.
+        let start = writer.stream_position()?;
+        let mut header = Vec::with_capacity(80);
+        header.extend_from_slice(b"RIFF");
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(b"WAVE");
+
+        header.extend_from_slice(b"fmt ");
+        header.extend_from_slice(&fmt_len.to_le_bytes());
+        header.extend_from_slice(
+            &(if extensible {
+                WAVE_FORMAT_EXTENSIBLE
+            } else {
+                format_tag
+            })
+            .to_le_bytes(),
+        );
+        header.extend_from_slice(&channels.to_le_bytes());
+        header.extend_from_slice(&audio.info.sample_rate.to_le_bytes());
+        header.extend_from_slice(&(audio.info.sample_rate * block_align as u32).to_le_bytes());
+        header.extend_from_slice(&block_align.to_le_bytes());
+        header.extend_from_slice(&bits.to_le_bytes());
+        if extensible {
+            header.extend_from_slice(&22u16.to_le_bytes());
+            header.extend_from_slice(&bits.to_le_bytes());
+            header.extend_from_slice(&channel_mask(channels).to_le_bytes());
+            header.extend_from_slice(&format_tag.to_le_bytes());
+            header.extend_from_slice(&SUBFORMAT_GUID_TAIL);
+        }
+
+        let fact_offset = header.len() as u64 + 8;
+        if is_float {
+            header.extend_from_slice(b"fact");
+            header.extend_from_slice(&4u32.to_le_bytes());
+            header.extend_from_slice(&0u32.to_le_bytes());
+        }
+
+        header.extend_from_slice(b"data");
+        let data_len_offset = header.len() as u64;
+        header.extend_from_slice(&0u32.to_le_bytes());
+        writer.write_all(&header)?;
+
+        let channels = channels as usize;
+        // Integer formats hold the stored samples exactly unless they are
+        // narrower, so only then is there anything to dither.
+        let dither = self.dither && audio.data.samples.storage().bits() > bits as u32;
+        let mut quantizer = Quantizer::new(bits as u32, dither);
+        let mut written = 0u64;
+        let mut data = Vec::with_capacity(BLOCK_FRAMES * block_align as usize);
+        for block_start in (0..frames as usize).step_by(BLOCK_FRAMES) {
+            let block_end = (block_start + BLOCK_FRAMES).min(frames as usize);
+            let samples = audio
+                .data
+                .samples
+                .slice(block_start * channels, block_end * channels);
+            data.clear();
+            match self.sample_format {
+                ExportSampleFormat::F32 => {
+                    for sample in samples.iter() {
+                        data.extend_from_slice(&sample.to_le_bytes());
+                    }
+                }
+                ExportSampleFormat::I16 => {
+                    for sample in quantizer.quantize(&samples) {
+                        data.extend_from_slice(&(sample as i16).to_le_bytes());
+                    }
+                }
+                ExportSampleFormat::I24 => {
+                    for sample in quantizer.quantize(&samples) {
+                        data.extend_from_slice(&sample.to_le_bytes()[..3]);
+                    }
+                }
+            }
+            writer.write_all(&data)?;
+            written += data.len() as u64;
+        }
+        // Chunks are word aligned, so an odd-sized data chunk gets a pad byte.
+        if written % 2 == 1 {
+            writer.write_all(&[0])?;
+        }
+
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(start + 4))?;
+        writer.write_all(&((end - start - 8) as u32).to_le_bytes())?;
+        if is_float {
+            writer.seek(SeekFrom::Start(start + fact_offset))?;
+            writer.write_all(&(frames as u32).to_le_bytes())?;
+        }
+        writer.seek(SeekFrom::Start(start + data_len_offset))?;
+        writer.write_all(&(written as u32).to_le_bytes())?;
+        writer.seek(SeekFrom::Start(end))?;
+        Ok(())
+    }
+}
+
+/// Default speaker mask for the first `channels` positions.
+fn channel_mask(channels: u16) -> u32 {
+    if channels >= 32 {
+        u32::MAX
+    } else {
+        (1u32 << channels) - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::decoder::symphonia_decoder::SymphoniaDecoder;
+    use crate::api::traits::audio_decoder::AudioDecoder;
+    use crate::api::types::{
+        audio::{AudioData, AudioInfo, ChannelLayout},
+        decode::{DecodeOptions, DecodeReport},
+        file::SharedSource,
+        samples::{SampleBuffer, SampleStorage},
+    };
+
+    /// A sine of `frames` per channel, each channel at its own frequency,
+    /// kept in `storage`.
+    fn sine(channels: u32, frames: usize, storage: SampleStorage) -> Audio {
+        let samples: Vec<f32> = (0..frames * channels as usize)
+            .map(|i| {
+                let (frame, channel) = (i / channels as usize, i % channels as usize);
+                (frame as f32 * 0.01 * (channel + 1) as f32).sin() * 0.9
+            })
+            .collect();
+        Audio {
+            data: AudioData {
+                samples: SampleBuffer::from_f32(samples, storage),
+            },
+            info: AudioInfo {
+                sample_rate: 48000,
+                channels,
+                channel_layout: ChannelLayout::from_count(channels),
+                codec: String::new(),
+                container: String::new(),
+                bits_per_sample: None,
+                total_frames: frames as u64,
+                duration: 0.0,
+                encoder_delay: None,
+                encoder_padding: None,
+                tags: Vec::new(),
+                decode_report: DecodeReport::default(),
+            },
+            content_hash: None,
+        }
+    }
+
+    fn round_trip(audio: &Audio, sample_format: ExportSampleFormat) -> Audio {
+        let wav = WavEncoder::new(sample_format, false)
+            .encode_to_bytes(audio)
+            .unwrap();
+        SymphoniaDecoder::new()
+            .decode(SharedSource::Bytes(wav.into()), DecodeOptions::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn decodes_to_the_samples_written() {
+        // Frames not a multiple of the block, and an odd data chunk for mono
+        // 24-bit.
+        for (sample_format, channels) in [
+            (ExportSampleFormat::I16, 2),
+            (ExportSampleFormat::I24, 1),
+            (ExportSampleFormat::I24, 3),
+            (ExportSampleFormat::F32, 2),
+        ] {
+            let audio = sine(channels, 10001, SampleStorage::F32);
+            let decoded = round_trip(&audio, sample_format);
+            assert_eq!(decoded.info.channels, channels);
+            assert_eq!(decoded.info.total_frames, 10001);
+            assert!(decoded.info.decode_report.is_clean());
+
+            let lsb = match sample_format {
+                ExportSampleFormat::F32 => 0.0,
+                format => 1.0 / (1u32 << (format.bits() - 1)) as f32,
+            };
+            let original = audio.data.samples.to_f32();
+            let decoded = decoded.data.samples.to_f32();
+            assert_eq!(decoded.len(), original.len());
+            for (a, b) in original.iter().zip(decoded.iter()) {
+                assert!((a - b).abs() <= lsb, "{:?}: {} vs {}", sample_format, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn dithers_only_when_bits_are_lost() {
+        let encode = |audio: &Audio, dither: bool| {
+            WavEncoder::new(ExportSampleFormat::I16, dither)
+                .encode_to_bytes(audio)
+                .unwrap()
+        };
+        let stored_16 = sine(2, 5000, SampleStorage::I16);
+        assert_eq!(encode(&stored_16, true), encode(&stored_16, false));
+        let stored_32 = sine(2, 5000, SampleStorage::F32);
+        assert_ne!(encode(&stored_32, true), encode(&stored_32, false));
+    }
+}
@@ -0,0 +1,15 @@
+use crate::api::types::events::DecodeEvent;
+use crate::frb_generated::StreamSink;
+use std::sync::OnceLock;
+
+static DECODE_SINK: OnceLock<StreamSink<DecodeEvent>> = OnceLock::new();
+
+pub fn create_decode_event_stream(sink: StreamSink<DecodeEvent>) {
+    let _ = DECODE_SINK.set(sink);
+}
+
+pub fn emit_decode_event(event: DecodeEvent) {
+    if let Some(sink) = DECODE_SINK.get() {
+        let _ = sink.add(event);
+    }
+}
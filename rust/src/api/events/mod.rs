@@ -1 +1,2 @@
-pub mod communicator_events; 
\ No newline at end of file
+pub mod communicator_events;
+pub mod decode_events;
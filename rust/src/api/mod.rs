@@ -1,6 +1,7 @@
 pub mod types;
 pub mod traits;
 pub mod decoder;
+pub mod encoder;
 pub mod storage;
 pub mod util;
 pub mod cache;
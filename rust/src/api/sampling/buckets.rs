@@ -0,0 +1,225 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::api::types::chart::{ChartSeries, Point};
+
+/// Equal-width x intervals covering the view, so bucket edges stay put while
+/// points come and go and sparse stretches show up as empty buckets instead
+/// of being merged with their neighbours. Edges fall on multiples of the
+/// width, so views of the same span share the buckets they overlap in and
+/// panning only changes the points at either end.
+pub struct XBuckets {
+    /// Bucket `b` starts at `origin + (first + b) * width`, kept in f64 so a
+    /// bucket's edges come out the same whichever view it is part of.
+    origin: f64,
+    first: f64,
+    width: f64,
+    count: usize,
+    /// Indices of the points being bucketed.
+    points: (usize, usize),
+}
+
+impl XBuckets {
+    /// `count` buckets covering `x_range` for points `start..end` of
+    /// `series`, widened to take in every one of them. A range that is empty
+    /// or not finite falls back to the span of the points.
+    pub fn new(
+        series: &ChartSeries,
+        (start, end): (usize, usize),
+        x_range: (f32, f32),
+        count: usize,
+    ) -> Self {
+        let (first_x, last_x) = match start < end {
+            true => (series.point(start).x, series.point(end - 1).x),
+            false => (0.0, 0.0),
+        };
+        let (mut start_x, mut end_x) = x_range;
+        if !(start_x.is_finite() && end_x.is_finite() && start_x < end_x) {
+            (start_x, end_x) = (first_x, last_x);
+        }
+        let start_x = start_x.min(first_x) as f64;
+        let end_x = end_x.max(last_x) as f64;
+        let count = count.max(1);
+
+        // One bucket of slack lets the first edge move back to a multiple of
+        // the width while the last bucket still reaches `end_x`.
+        let width = (end_x - start_x) / count.saturating_sub(1).max(1) as f64;
+        let (origin, first) = match count > 1 && width > 0.0 && width.is_finite() {
+            true => (0.0, (start_x / width).floor()),
+            false => (start_x, 0.0),
+        };
+        Self {
+            origin,
+            first,
+            width,
+            count,
+            points: (start, end),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn start_x(&self, bucket: usize) -> f32 {
+        (self.origin + (self.first + bucket as f64) * self.width) as f32
+    }
+
+    /// Indices of the points in `bucket`. Every point falls in exactly one
+    /// bucket, the last one taking everything up to the end.
+    pub fn bounds(&self, series: &ChartSeries, bucket: usize) -> (usize, usize) {
+        let (first, last) = self.points;
+        let start = match bucket {
+            0 => first,
+            _ => series.lower_bound(self.start_x(bucket)).clamp(first, last),
+        };
+        let end = match bucket + 1 == self.count {
+            true => last,
+            false => series
+                .lower_bound(self.start_x(bucket + 1))
+                .clamp(first, last),
+        };
+        (start, end.max(start))
+    }
+
+    /// Runs `pick` on the points of every non-empty bucket in parallel and
+    /// joins the results in order, with a [`gap`] wherever one or more
+    /// buckets between points are empty.
+    pub fn collect(
+        &self,
+        series: &ChartSeries,
+        pick: impl Fn(usize, usize) -> Vec<Point> + Sync,
+    ) -> Vec<Point> {
+        let picked: Vec<Option<Vec<Point>>> = (0..self.count)
+            .into_par_iter()
+            .map(|bucket| {
+                let (start, end) = self.bounds(series, bucket);
+                (start < end).then(|| pick(start, end))
+            })
+            .collect();
+
+        join_with_gaps(
+            picked
+                .into_iter()
+                .enumerate()
+                .map(|(bucket, points)| (self.start_x(bucket), points)),
+        )
+    }
+}
+
+/// Concatenates the points picked per bucket, given with the bucket's start,
+/// putting a [`gap`] where one or more empty buckets (`None`) separate points.
+pub fn join_with_gaps(picked: impl IntoIterator<Item = (f32, Option<Vec<Point>>)>) -> Vec<Point> {
+    let mut points = Vec::new();
+    let mut gap_at = None;
+    for (start_x, bucket_points) in picked {
+        match bucket_points {
+            Some(bucket_points) => {
+                if let Some(x) = gap_at.take() {
+                    points.push(gap(x));
+                }
+                points.extend(bucket_points);
+            }
+            None if !points.is_empty() && gap_at.is_none() => gap_at = Some(start_x),
+            None => {}
+        }
+    }
+    points
+}
+
+/// Marks a break in a line; the chart draws nothing between the points on
+/// either side.
+pub fn gap(x: f32) -> Point {
+    Point { x, y: f32::NAN }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// Points at x = 0..10 and 50..60, with nothing in between.
+    fn with_hole() -> ChartSeries {
+        let points = (0..10)
+            .chain(50..60)
+            .map(|x| Point {
+                x: x as f32,
+                y: x as f32,
+            })
+            .collect();
+        ChartSeries::Points(Arc::new(points))
+    }
+
+    /// The first point of every bucket.
+    fn firsts(series: &ChartSeries, buckets: &XBuckets) -> Vec<Point> {
+        buckets.collect(series, |start, _| vec![series.point(start)])
+    }
+
+    fn gaps(points: &[Point]) -> Vec<f32> {
+        points
+            .iter()
+            .filter(|p| p.y.is_nan())
+            .map(|p| p.x)
+            .collect()
+    }
+
+    #[test]
+    fn empty_buckets_between_points_make_one_gap() {
+        let series = with_hole();
+        let buckets = XBuckets::new(&series, (0, series.len()), (0.0, 59.0), 12);
+        let points = firsts(&series, &buckets);
+
+        let gaps = gaps(&points);
+        assert_eq!(gaps.len(), 1);
+        assert!(gaps[0] > 9.0 && gaps[0] < 50.0);
+        let gap = points.iter().position(|p| p.y.is_nan()).unwrap();
+        assert!(points[gap - 1].x < gaps[0] && gaps[0] <= points[gap + 1].x);
+    }
+
+    #[test]
+    fn empty_buckets_at_the_ends_make_no_gap() {
+        let points: Vec<Point> = (20..30)
+            .map(|x| Point {
+                x: x as f32,
+                y: 0.0,
+            })
+            .collect();
+        let series = ChartSeries::Points(Arc::new(points));
+        let buckets = XBuckets::new(&series, (0, series.len()), (-100.0, 200.0), 30);
+
+        assert!(gaps(&firsts(&series, &buckets)).is_empty());
+    }
+
+    #[test]
+    fn every_point_falls_in_one_bucket() {
+        let series = with_hole();
+        let buckets = XBuckets::new(&series, (0, series.len()), (0.0, 59.0), 12);
+        let covered: usize = (0..buckets.count())
+            .map(|bucket| {
+                let (start, end) = buckets.bounds(&series, bucket);
+                end - start
+            })
+            .sum();
+
+        assert_eq!(covered, series.len());
+    }
+
+    #[test]
+    fn gaps_join_at_the_first_empty_bucket() {
+        let point = |x: f32| Point { x, y: x };
+        let picked = [
+            (0.0, None),
+            (1.0, Some(vec![point(1.5)])),
+            (2.0, None),
+            (3.0, None),
+            (4.0, Some(vec![point(4.5), point(4.7)])),
+            (5.0, None),
+        ];
+        let points = join_with_gaps(picked);
+
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[0], point(1.5));
+        assert!(points[1].x == 2.0 && points[1].y.is_nan());
+        assert_eq!(points[2..], [point(4.5), point(4.7)]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::api::{
+    sampling::buckets::{join_with_gaps, XBuckets},
+    traits::down_sample::DownSample,
+    types::chart::{Chart, ChartSeries, Point},
+};
+use std::sync::Arc;
+
+/// Largest-Triangle-Three-Buckets: keeps the first and last point and, from
+/// each bucket in between, the point forming the largest triangle with the
+/// point kept before it and the mean of the next non-empty bucket. Follows
+/// the shape of smooth curves closely with one point per bucket.
+pub struct Lttb {}
+
+impl DownSample for Lttb {
+    fn down_sample(&self, chart: Chart, x_range: (f32, f32), target_points_num: usize) -> Chart {
+        let series = &chart.series;
+        let n = series.len();
+
+        if n <= target_points_num || target_points_num < 3 {
+            return chart;
+        }
+
+        // The first and last point are kept as they are, so only the points
+        // between them are picked from.
+        let buckets = XBuckets::new(series, (0, n), x_range, target_points_num - 2);
+        let grid: Vec<(usize, usize)> = (0..buckets.count())
+            .map(|bucket| buckets.bounds(series, bucket))
+            .collect();
+        let bounds: Vec<(usize, usize)> = grid
+            .iter()
+            .map(|&(start, end)| (start.max(1), end.min(n - 1)))
+            .collect();
+        let occupied: Vec<usize> = (0..buckets.count())
+            .filter(|&bucket| bounds[bucket].0 < bounds[bucket].1)
+            .collect();
+        let mean = |(start, end): (usize, usize)| {
+            let count = (end - start) as f32;
+            let (sum_x, sum_y) = (start..end).fold((0.0, 0.0), |(x, y), i| {
+                let p = series.point(i);
+                (x + p.x, y + p.y)
+            });
+            Point {
+                x: sum_x / count,
+                y: sum_y / count,
+            }
+        };
+
+        let first = series.point(0);
+        let last = series.point(n - 1);
+        let mut picked: Vec<Option<Vec<Point>>> = vec![None; buckets.count()];
+        let first_bucket = grid.iter().position(|&(_, end)| end > 0).unwrap_or(0);
+        picked[first_bucket] = Some(vec![first]);
+        let mut previous = first;
+        for (k, &bucket) in occupied.iter().enumerate() {
+            // The last bucket looks ahead to the last point alone.
+            let next = occupied.get(k + 1).map_or(last, |&next| mean(bounds[next]));
+
+            let (start, end) = bounds[bucket];
+            let mut selected = series.point(start);
+            let mut max_area = -1.0;
+            for i in start..end {
+                let p = series.point(i);
+                // Twice the triangle's area; only the comparison matters.
+                let area = ((previous.x - next.x) * (p.y - previous.y)
+                    - (previous.x - p.x) * (next.y - previous.y))
+                    .abs();
+                if area > max_area {
+                    max_area = area;
+                    selected = p;
+                }
+            }
+            picked[bucket].get_or_insert_with(Vec::new).push(selected);
+            previous = selected;
+        }
+        let last_bucket = grid.iter().rposition(|&(start, _)| start < n).unwrap_or(0);
+        picked[last_bucket].get_or_insert_with(Vec::new).push(last);
+
+        let sampled_points = join_with_gaps(
+            picked
+                .into_iter()
+                .enumerate()
+                .map(|(bucket, points)| (buckets.start_x(bucket), points)),
+        );
+
+        Chart {
+            data_type: chart.data_type,
+            series: ChartSeries::Points(Arc::new(sampled_points)),
+            min_y: chart.min_y,
+            max_y: chart.max_y,
+            visible: chart.visible,
+            pyramid: Arc::default(),
+        }
+    }
+}
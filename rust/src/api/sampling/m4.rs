@@ -0,0 +1,50 @@
+use crate::api::{
+    sampling::buckets::XBuckets,
+    traits::down_sample::DownSample,
+    types::chart::{Chart, ChartSeries},
+};
+use std::sync::Arc;
+
+/// M4: splits the visible x span into one column per group of four output
+/// points and keeps the first, lowest, highest and last point of each column,
+/// so a line drawn through them at that width looks the same as one through
+/// all points.
+pub struct M4 {}
+
+impl DownSample for M4 {
+    fn down_sample(&self, chart: Chart, x_range: (f32, f32), target_points_num: usize) -> Chart {
+        let series = &chart.series;
+        let n = series.len();
+
+        if n <= target_points_num || target_points_num < 4 {
+            return chart;
+        }
+
+        let columns = XBuckets::new(series, (0, n), x_range, target_points_num / 4);
+        let sampled_points = columns.collect(series, |start, end| {
+            let (mut min_idx, mut max_idx) = (start, start);
+            for idx in start + 1..end {
+                let y = series.point(idx).y;
+                if y < series.point(min_idx).y {
+                    min_idx = idx;
+                }
+                if y > series.point(max_idx).y {
+                    max_idx = idx;
+                }
+            }
+            let mut kept = vec![start, min_idx, max_idx, end - 1];
+            kept.sort_unstable();
+            kept.dedup();
+            kept.into_iter().map(|idx| series.point(idx)).collect()
+        });
+
+        Chart {
+            data_type: chart.data_type,
+            series: ChartSeries::Points(Arc::new(sampled_points)),
+            min_y: chart.min_y,
+            max_y: chart.max_y,
+            visible: chart.visible,
+            pyramid: Arc::default(),
+        }
+    }
+}
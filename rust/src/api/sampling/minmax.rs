@@ -1,62 +1,210 @@
-use rayon::{iter::ParallelIterator, slice::ParallelSlice};
-
-use crate::api::{traits::down_sample::DownSample, types::chart::Chart};
+use crate::api::{
+    sampling::buckets::XBuckets,
+    traits::down_sample::DownSample,
+    types::chart::{Chart, ChartSeries, Point},
+};
 use std::sync::Arc;
 
 pub struct Minmax {}
 
 impl DownSample for Minmax {
-    fn down_sample(&self, chart: Chart, target_points_num: usize) -> Chart {
-        let original_points = &chart.points;
-        let n = original_points.len();
-
-        if n <= target_points_num || target_points_num < 2 {
+    fn down_sample(&self, chart: Chart, x_range: (f32, f32), target_points_num: usize) -> Chart {
+        let series = &chart.series;
+        let points = min_max_points(
+            series,
+            (0, series.len()),
+            x_range,
+            target_points_num,
+            |start, end| scan_extremes(series, start, end),
+        );
+        let Some(points) = points else {
             return chart;
+        };
+
+        Chart {
+            data_type: chart.data_type,
+            series: ChartSeries::Points(Arc::new(points)),
+            min_y: chart.min_y,
+            max_y: chart.max_y,
+            visible: chart.visible,
+            pyramid: Arc::default(),
         }
+    }
+}
 
-        let num_buckets = target_points_num / 2;
-        let bucket_size = (n / num_buckets).max(1);
+impl Minmax {
+    /// Same result as
+    /// `down_sample(chart.get_range(start_x, end_x), (start_x, end_x), ..)`, but
+    /// read from the chart's min/max pyramid, so the cost follows the number
+    /// of points returned rather than the length of the range.
+    pub fn down_sample_range(
+        &self,
+        chart: &Chart,
+        start_x: f32,
+        end_x: f32,
+        target_points_num: usize,
+    ) -> Chart {
+        let series = &chart.series;
+        let (start, end) = series.index_range(start_x, end_x);
+        let points = min_max_points(
+            series,
+            (start, end),
+            (start_x, end_x),
+            target_points_num,
+            |first, last| chart.pyramid().extremes(series, first, last),
+        );
+        let series = match points {
+            Some(points) => ChartSeries::Points(Arc::new(points)),
+            None => series.slice(start, end),
+        };
 
-        let mut mid_points: Vec<_> = original_points
-            .par_chunks(bucket_size)
-            .flat_map(|chunk| {
-                if chunk.is_empty() {
-                    return vec![];
-                }
+        Chart {
+            data_type: chart.data_type,
+            series,
+            min_y: chart.min_y,
+            max_y: chart.max_y,
+            visible: Arc::clone(&chart.visible),
+            pyramid: Arc::default(),
+        }
+    }
+}
 
-                let mut min_idx = 0;
-                let mut max_idx = 0;
+/// Keeps the first and last of points `start..end` plus the lowest and
+/// highest of each x bucket over `x_range`, in order. `extremes` gives the
+/// indices of the first lowest and first highest point of a bucket. `None`
+/// when the points already fit.
+fn min_max_points(
+    series: &ChartSeries,
+    (start, end): (usize, usize),
+    x_range: (f32, f32),
+    target_points_num: usize,
+    extremes: impl Fn(usize, usize) -> (usize, usize) + Sync,
+) -> Option<Vec<Point>> {
+    let n = end - start;
 
-                for (idx, p) in chunk.iter().enumerate() {
-                    if p.y < chunk[min_idx].y {
-                        min_idx = idx;
-                    }
-                    if p.y > chunk[max_idx].y {
-                        max_idx = idx;
-                    }
-                }
+    if n <= target_points_num || target_points_num < 2 {
+        return None;
+    }
 
-                if min_idx < max_idx {
-                    vec![chunk[min_idx], chunk[max_idx]]
-                } else if min_idx > max_idx {
-                    vec![chunk[max_idx], chunk[min_idx]]
-                } else {
-                    vec![chunk[min_idx]]
-                }
-            })
-            .collect();
+    let buckets = XBuckets::new(series, (start, end), x_range, target_points_num / 2);
+    let mut mid_points = buckets.collect(series, |first, last| {
+        let (min_idx, max_idx) = extremes(first, last);
 
-        let mut final_points = Vec::with_capacity(mid_points.len() + 2);
-        final_points.push(original_points[0]);
-        final_points.append(&mut mid_points);
-        final_points.push(original_points[n - 1]);
+        if min_idx < max_idx {
+            vec![series.point(min_idx), series.point(max_idx)]
+        } else if min_idx > max_idx {
+            vec![series.point(max_idx), series.point(min_idx)]
+        } else {
+            vec![series.point(min_idx)]
+        }
+    });
 
+    let mut final_points = Vec::with_capacity(mid_points.len() + 2);
+    final_points.push(series.point(start));
+    final_points.append(&mut mid_points);
+    final_points.push(series.point(end - 1));
+    Some(final_points)
+}
+
+/// Indices of the first lowest and first highest y among points
+/// `start..end`, found by scanning them.
+fn scan_extremes(series: &ChartSeries, start: usize, end: usize) -> (usize, usize) {
+    let mut min = series.point(start).y;
+    let mut max = min;
+    let mut min_idx = start;
+    let mut max_idx = start;
+
+    for idx in start + 1..end {
+        let y = series.point(idx).y;
+        if y < min {
+            min = y;
+            min_idx = idx;
+        }
+        if y > max {
+            max = y;
+            max_idx = idx;
+        }
+    }
+    (min_idx, max_idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::api::types::chart::DataType;
+
+    /// A wobbling signal with lone NaN values, NaN at the start of some
+    /// base blocks, and a NaN run long enough to cover whole blocks.
+    fn values() -> Vec<f32> {
+        (0..10_000)
+            .map(|i| match i {
+                _ if i % 97 == 0 || i % 320 == 0 => f32::NAN,
+                4_000..4_200 => f32::NAN,
+                _ => ((i as f32) * 0.37).sin() * (1.0 + (i % 13) as f32),
+            })
+            .collect()
+    }
+
+    fn chart(series: ChartSeries) -> Chart {
         Chart {
-            data_type: chart.data_type,
-            points: Arc::new(final_points),
-            min_y: chart.min_y,
-            max_y: chart.max_y,
-            visible: chart.visible,
+            data_type: DataType::Audio,
+            series,
+            min_y: -14.0,
+            max_y: 14.0,
+            visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
+        }
+    }
+
+    /// Every point of `chart`, as bits so NaN compares equal to itself.
+    fn bits(chart: &Chart) -> Vec<(u32, u32)> {
+        (0..chart.series.len())
+            .map(|i| chart.series.point(i))
+            .map(|p| (p.x.to_bits(), p.y.to_bits()))
+            .collect()
+    }
+
+    #[test]
+    fn down_sample_range_matches_down_sample_over_get_range() {
+        let uniform = chart(ChartSeries::uniform(0.5, 0.25, values().into()));
+        let points = chart(ChartSeries::Points(Arc::new(
+            values()
+                .into_iter()
+                .enumerate()
+                .map(|(i, y)| Point {
+                    x: (i + i / 7) as f32,
+                    y,
+                })
+                .collect(),
+        )));
+
+        for chart in [uniform, points] {
+            let last_x = chart.series.point(chart.series.len() - 1).x;
+            let ranges = [
+                (0.0, last_x),
+                (3.3, 517.9),
+                (97.1, 1_100.6),
+                (950.2, last_x * 0.8),
+                (-40.0, 2_000.0),
+                (last_x * 0.5, last_x + 40.0),
+            ];
+            for (start_x, end_x) in ranges {
+                for target in [2, 3, 10, 64, 333, 5_000] {
+                    let expected = Minmax {}.down_sample(
+                        chart.get_range(start_x, end_x),
+                        (start_x, end_x),
+                        target,
+                    );
+                    let actual = Minmax {}.down_sample_range(&chart, start_x, end_x, target);
+                    assert_eq!(
+                        bits(&actual),
+                        bits(&expected),
+                        "range {start_x}..{end_x}, {target} points"
+                    );
+                }
+            }
         }
     }
 }
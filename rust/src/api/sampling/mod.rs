@@ -1,2 +1,6 @@
 pub mod minmax;
-pub mod equal_step;
\ No newline at end of file
+pub mod equal_step;
+pub mod lttb;
+pub mod m4;
+pub mod buckets;
+pub mod sinc;
\ No newline at end of file
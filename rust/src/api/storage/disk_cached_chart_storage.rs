@@ -0,0 +1,395 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use dashmap::{DashMap, DashSet};
+use log::warn;
+
+use crate::api::{
+    storage::kv_cached_chart_storage::KvCachedChartStorage,
+    traits::cached_chart_storage::CachedChartStorage,
+    types::{
+        chart::{Chart, ChartParams, ChartSeries, ChartWIthKey, DataType, Point},
+        error::AppError,
+        memory::ChartMemoryUsage,
+    },
+    util::{binary::*, hash::fnv1a64},
+};
+
+const MAGIC: &[u8; 8] = b"VADCHART";
+const VERSION: u32 = 2;
+const EXTENSION: &str = "vchart";
+
+/// Keeps charts in memory like [`KvCachedChartStorage`] and also writes the
+/// derived ones (spectrum, energy, zero crossing rate) to a directory, so a
+/// later session can read them back instead of recomputing.
+///
+/// Files are named after the content hash of the audio, the chart params and
+/// the data type, so a chart is only reused for the exact same input. Each
+/// file holds a header, the points and an FNV-1a checksum of both; files that
+/// fail any check are treated as missing. Evenly spaced charts store only
+/// their first x, the step and the y values.
+///
+/// The directory is listed once on creation, so looking up a chart that was
+/// never persisted does not touch the file system.
+pub struct DiskCachedChartStorage {
+    directory: PathBuf,
+    content_hashes: DashMap<String, u64>,
+    files: DashSet<PathBuf>,
+    memory: KvCachedChartStorage,
+}
+
+impl DiskCachedChartStorage {
+    pub fn new(directory: String) -> Result<Self, AppError> {
+        let directory = PathBuf::from(directory);
+        fs::create_dir_all(&directory).map_err(|e| {
+            AppError::Cache(format!("Failed to create {}: {}", directory.display(), e))
+        })?;
+        let files = DashSet::new();
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            match path.extension().and_then(|e| e.to_str()) {
+                Some(EXTENSION) => {
+                    files.insert(path);
+                }
+                // Left over from a write that never finished.
+                Some("part") => {
+                    let _ = fs::remove_file(&path);
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            directory,
+            content_hashes: DashMap::new(),
+            files,
+            memory: KvCachedChartStorage::new(),
+        })
+    }
+
+    /// File for a chart of `key`, or `None` when the chart is not persisted.
+    fn path_for(&self, key: &str, data_type: DataType, params: &ChartParams) -> Option<PathBuf> {
+        let type_name = match data_type {
+            // The waveform is just the stored samples and cheaper to rebuild
+            // than to read back.
+            DataType::Audio => return None,
+            DataType::Spectrum => "spectrum",
+            DataType::Energy => "energy",
+            DataType::ZeroCrossingRate => "zcr",
+        };
+        let content_hash = *self.content_hashes.get(key)?;
+        Some(self.directory.join(format!(
+            "{:016x}-{:016x}-{}.{}",
+            content_hash,
+            params_hash(params),
+            type_name,
+            EXTENSION
+        )))
+    }
+
+    fn write_file(&self, path: &Path, chart: &Chart) -> Result<(), AppError> {
+        let series = &chart.series;
+        let mut body = Vec::with_capacity(32 + series.len() * 8);
+        body.write_all(MAGIC)?;
+        write_u32(&mut body, VERSION)?;
+        write_u8(&mut body, data_type_code(chart.data_type))?;
+        body.write_all(&chart.min_y.to_le_bytes())?;
+        body.write_all(&chart.max_y.to_le_bytes())?;
+        match series {
+            ChartSeries::Points(points) => {
+                write_u8(&mut body, 0)?;
+                write_u64(&mut body, points.len() as u64)?;
+                for point in points.iter() {
+                    body.write_all(&point.x.to_le_bytes())?;
+                    body.write_all(&point.y.to_le_bytes())?;
+                }
+            }
+            ChartSeries::Uniform {
+                x0,
+                dx,
+                values,
+                start,
+                end,
+            } => {
+                write_u8(&mut body, 1)?;
+                body.write_all(&(x0 + *start as f32 * dx).to_le_bytes())?;
+                body.write_all(&dx.to_le_bytes())?;
+                write_u64(&mut body, (end - start) as u64)?;
+                write_f32s(&mut body, &values.slice(*start, *end))?;
+            }
+        }
+        let checksum = fnv1a64(&body);
+        write_u64(&mut body, checksum)?;
+
+        let partial = path.with_extension(format!("{}.part", EXTENSION));
+        fs::write(&partial, &body)?;
+        fs::rename(&partial, path)?;
+        Ok(())
+    }
+
+    fn read_file(&self, path: &Path, data_type: DataType) -> Result<Chart, AppError> {
+        let bytes = fs::read(path)?;
+        let corrupt = || AppError::Cache(format!("Corrupt chart cache file {}", path.display()));
+        if bytes.len() < MAGIC.len() + 8 {
+            return Err(corrupt());
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 8);
+        if fnv1a64(body) != read_u64(&mut &checksum[..])? {
+            return Err(corrupt());
+        }
+
+        let mut reader = body;
+        let mut magic = [0u8; 8];
+        std::io::Read::read_exact(&mut reader, &mut magic)?;
+        if &magic != MAGIC || read_u32(&mut reader)? != VERSION {
+            return Err(corrupt());
+        }
+        if read_u8(&mut reader)? != data_type_code(data_type) {
+            return Err(corrupt());
+        }
+        let min_y = f32::from_bits(read_u32(&mut reader)?);
+        let max_y = f32::from_bits(read_u32(&mut reader)?);
+        let series = match read_u8(&mut reader)? {
+            0 => {
+                let count = read_u64(&mut reader)? as usize;
+                if reader.len() != count * 8 {
+                    return Err(corrupt());
+                }
+                let values = read_f32s(&mut reader, count * 2)?;
+                let points = values
+                    .chunks_exact(2)
+                    .map(|pair| Point {
+                        x: pair[0],
+                        y: pair[1],
+                    })
+                    .collect();
+                ChartSeries::Points(Arc::new(points))
+            }
+            1 => {
+                let x0 = f32::from_bits(read_u32(&mut reader)?);
+                let dx = f32::from_bits(read_u32(&mut reader)?);
+                let count = read_u64(&mut reader)? as usize;
+                if reader.len() != count * 4 || dx <= 0.0 {
+                    return Err(corrupt());
+                }
+                let values = read_f32s(&mut reader, count)?;
+                ChartSeries::uniform(x0, dx, values.into())
+            }
+            _ => return Err(corrupt()),
+        };
+
+        Ok(Chart {
+            data_type,
+            series,
+            min_y,
+            max_y,
+            visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
+        })
+    }
+}
+
+impl CachedChartStorage for DiskCachedChartStorage {
+    fn add(&self, key: String, params: ChartParams, chart: Chart) -> Result<(), AppError> {
+        if let Some(path) = self.path_for(&key, chart.data_type, &params) {
+            // The chart is still usable from memory, so a failed write only
+            // costs a recompute next session.
+            match self.write_file(&path, &chart) {
+                Ok(()) => {
+                    self.files.insert(path);
+                }
+                Err(e) => warn!("Failed to persist chart {}: {}", path.display(), e),
+            }
+        }
+        self.memory.add(key, params, chart)
+    }
+
+    fn get(
+        &self,
+        key: String,
+        data_type: DataType,
+        params: &ChartParams,
+    ) -> Result<Chart, AppError> {
+        if let Ok(chart) = self.memory.get(key.clone(), data_type, params) {
+            return Ok(chart);
+        }
+
+        let path = self
+            .path_for(&key, data_type, params)
+            .ok_or_else(|| AppError::NotFound(format!("Chart key not found: {}", key)))?;
+        if !self.files.contains(&path) {
+            return Err(AppError::NotFound(format!(
+                "Chart with specified data type and params not found for key: {}",
+                key
+            )));
+        }
+        let chart = self.read_file(&path, data_type).inspect_err(|_| {
+            self.files.remove(&path);
+            let _ = fs::remove_file(&path);
+        })?;
+        self.memory.add(key, params.clone(), chart.clone())?;
+        Ok(chart)
+    }
+
+    fn get_all_cache(&self) -> Result<Vec<ChartWIthKey>, AppError> {
+        self.memory.get_all_cache()
+    }
+
+    /// Only forgets the chart in memory; the file stays for whenever the same
+    /// audio is charted with the same params again.
+    fn remove(&self, key: String, data_type: DataType) -> Result<(), AppError> {
+        self.memory.remove(key, data_type)
+    }
+
+    fn stale(
+        &self,
+        current: &dyn Fn(&str, DataType) -> ChartParams,
+    ) -> Result<Vec<ChartWIthKey>, AppError> {
+        self.memory.stale(current)
+    }
+
+    fn set_memory_budget(&self, budget: Option<u64>) {
+        self.memory.set_memory_budget(budget);
+    }
+
+    fn memory_usage(&self) -> Vec<ChartMemoryUsage> {
+        self.memory.memory_usage()
+    }
+
+    fn set_content_hash(&self, key: String, content_hash: u64) {
+        self.content_hashes.insert(key, content_hash);
+    }
+}
+
+/// Must cover every field of [`ChartParams`] so that changing any of them
+/// leads to a different file.
+fn params_hash(params: &ChartParams) -> u64 {
+    let frame_size = params.config.as_ref().map(|c| c.frame_size as u64);
+    fnv1a64(format!("{:?}:{:?}", frame_size, params.channel_mix).as_bytes())
+}
+
+fn data_type_code(data_type: DataType) -> u8 {
+    match data_type {
+        DataType::Audio => 0,
+        DataType::Spectrum => 1,
+        DataType::Energy => 2,
+        DataType::ZeroCrossingRate => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{audio::ChannelMix, config::Config};
+
+    fn directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("vad-chart-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// A storage over `directory` that knows "a.wav" by a fixed content hash.
+    fn storage(directory: &Path) -> DiskCachedChartStorage {
+        let storage =
+            DiskCachedChartStorage::new(directory.to_string_lossy().into_owned()).unwrap();
+        storage.set_content_hash("a.wav".to_string(), 0x1234);
+        storage
+    }
+
+    fn params() -> ChartParams {
+        ChartParams {
+            config: Some(Config::default()),
+            channel_mix: ChannelMix::Mean,
+        }
+    }
+
+    fn chart(data_type: DataType, series: ChartSeries) -> Chart {
+        Chart {
+            data_type,
+            series,
+            min_y: -3.0,
+            max_y: 3.5,
+            visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
+        }
+    }
+
+    fn bits(chart: &Chart) -> Vec<(u32, u32)> {
+        (0..chart.series.len())
+            .map(|i| chart.series.point(i))
+            .map(|p| (p.x.to_bits(), p.y.to_bits()))
+            .collect()
+    }
+
+    #[test]
+    fn charts_are_read_back_by_a_later_session() {
+        let directory = directory("round-trip");
+        let values: Vec<f32> = (0..500).map(|i| (i as f32 * 0.2).sin() * 3.0).collect();
+        let points = (0..300)
+            .map(|i| Point {
+                x: i as f32 * 1.5,
+                y: if i % 50 == 0 {
+                    f32::NAN
+                } else {
+                    i as f32 / 100.0
+                },
+            })
+            .collect();
+        let charts = [
+            chart(
+                DataType::Energy,
+                ChartSeries::uniform(0.25, 0.5, values.into()).slice(7, 480),
+            ),
+            chart(DataType::Spectrum, ChartSeries::Points(Arc::new(points))),
+        ];
+
+        let writer = storage(&directory);
+        for chart in &charts {
+            writer
+                .add("a.wav".to_string(), params(), chart.clone())
+                .unwrap();
+        }
+
+        let reader = storage(&directory);
+        for chart in &charts {
+            let read = reader
+                .get("a.wav".to_string(), chart.data_type, &params())
+                .unwrap();
+            assert_eq!(bits(&read), bits(chart));
+            assert_eq!((read.min_y, read.max_y), (chart.min_y, chart.max_y));
+        }
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn a_file_failing_its_checksum_is_dropped() {
+        let directory = directory("checksum");
+        let values: Vec<f32> = (0..100).map(|i| i as f32).collect();
+        storage(&directory)
+            .add(
+                "a.wav".to_string(),
+                params(),
+                chart(DataType::Energy, ChartSeries::from_samples(values.into())),
+            )
+            .unwrap();
+
+        let path = fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().and_then(|e| e.to_str()) == Some(EXTENSION))
+            .unwrap();
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[40] ^= 0x01;
+        fs::write(&path, &bytes).unwrap();
+
+        let reader = storage(&directory);
+        let result = reader.get("a.wav".to_string(), DataType::Energy, &params());
+        assert!(matches!(result, Err(AppError::Cache(_))));
+        assert!(!path.exists());
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,476 @@
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use dashmap::DashMap;
+
+use crate::api::{
+    traits::audio_storage::AudioStorage,
+    types::{
+        audio::{Audio, AudioData, AudioInfo, AudioTag, ChannelLayout},
+        decode::{DamagedRange, DecodeReport},
+        error::AppError,
+        memory::AudioMemoryUsage,
+        samples::{SampleBuffer, SampleStorage},
+    },
+    util::{binary::*, hash::fnv1a64, memory_ledger::MemoryLedger},
+};
+
+const MAGIC: &[u8; 8] = b"VADAUDIO";
+const VERSION: u32 = 3;
+const EXTENSION: &str = "vaudio";
+
+/// Keeps decoded audio in a directory so it survives restarts.
+///
+/// Each key gets one file named after the hash of the key. The file starts
+/// with a header holding the key, sample rate, channel count and the rest of
+/// [`AudioInfo`] and the sample format, followed by the interleaved samples
+/// little-endian in that format and an FNV-1a checksum of both. Files whose
+/// sizes or checksum do not add up are refused.
+/// Loaded audio stays in memory within the memory budget and is read back
+/// from disk after eviction.
+pub struct FileAudioStorage {
+    directory: PathBuf,
+    loaded: DashMap<String, Audio>,
+    ledger: Mutex<MemoryLedger<String>>,
+}
+
+impl FileAudioStorage {
+    pub fn new(directory: String) -> Result<Self, AppError> {
+        let directory = PathBuf::from(directory);
+        fs::create_dir_all(&directory).map_err(|e| {
+            AppError::Storage(format!("Failed to create {}: {}", directory.display(), e))
+        })?;
+        Ok(Self {
+            directory,
+            loaded: DashMap::new(),
+            ledger: Mutex::new(MemoryLedger::new()),
+        })
+    }
+
+    fn ledger(&self) -> MutexGuard<'_, MemoryLedger<String>> {
+        self.ledger.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:016x}.{}", fnv1a64(key.as_bytes()), EXTENSION))
+    }
+
+    /// Keeps `audio` in memory, dropping whatever no longer fits the budget.
+    fn keep_loaded(&self, key: String, audio: Audio) {
+        let bytes = audio.memory_bytes();
+        self.loaded.insert(key.clone(), audio);
+        let evicted = self.ledger().insert(key, bytes);
+        for key in evicted {
+            self.loaded.remove(&key);
+        }
+    }
+
+    fn read_file(&self, key: &str) -> Result<Audio, AppError> {
+        let path = self.path_for(key);
+        let file = File::open(&path)
+            .map_err(|_| AppError::NotFound(format!("Audio key not found: {}", key)))?;
+        let len = file.metadata()?.len();
+        let mut reader = CheckedReader::new(BufReader::new(file), len);
+        let header = read_header(&mut reader)?;
+        if header.key != key {
+            return Err(AppError::NotFound(format!("Audio key not found: {}", key)));
+        }
+        let width = match header.storage {
+            SampleStorage::F32 => 4,
+            SampleStorage::I16 | SampleStorage::F16 => 2,
+        };
+        // The samples and the checksum have to be all that is left.
+        if header
+            .sample_count
+            .checked_mul(width)
+            .and_then(|n| n.checked_add(8))
+            != Some(reader.remaining())
+        {
+            return Err(corrupt(&path));
+        }
+        let count = header.sample_count as usize;
+        let samples = match header.storage {
+            SampleStorage::F32 => SampleBuffer::F32(Arc::new(read_f32s(&mut reader, count)?)),
+            SampleStorage::I16 => SampleBuffer::I16 {
+                samples: Arc::new(
+                    read_u16s(&mut reader, count)?
+                        .into_iter()
+                        .map(|v| v as i16)
+                        .collect(),
+                ),
+                scale: header.scale,
+            },
+            SampleStorage::F16 => SampleBuffer::F16 {
+                samples: Arc::new(read_u16s(&mut reader, count)?),
+                scale: header.scale,
+            },
+        };
+        let checksum = reader.checksum();
+        if read_u64(&mut reader)? != checksum {
+            return Err(corrupt(&path));
+        }
+        // Hashed here rather than kept in the file, so it always matches the
+        // samples actually read back.
+        Ok(Audio {
+            data: AudioData { samples },
+            info: header.info,
+            content_hash: None,
+        }
+        .with_content_hash())
+    }
+
+    fn write_file(&self, key: &str, audio: &Audio) -> Result<(), AppError> {
+        let path = self.path_for(key);
+        let partial = path.with_extension(format!("{}.part", EXTENSION));
+        {
+            let mut writer = HashingWriter::new(BufWriter::new(File::create(&partial)?));
+            write_header(&mut writer, key, audio)?;
+            match &audio.data.samples {
+                SampleBuffer::F32(samples) => write_f32s(&mut writer, samples)?,
+                SampleBuffer::I16 { samples, .. } => {
+                    write_u16s(&mut writer, samples.iter().map(|&sample| sample as u16))?
+                }
+                SampleBuffer::F16 { samples, .. } => {
+                    write_u16s(&mut writer, samples.iter().copied())?
+                }
+            }
+            let checksum = writer.checksum();
+            let mut writer = writer.into_inner();
+            write_u64(&mut writer, checksum)?;
+            writer.flush()?;
+        }
+        // Renaming last means a crash mid-write never leaves a torn file behind.
+        fs::rename(&partial, &path)?;
+        Ok(())
+    }
+}
+
+impl AudioStorage for FileAudioStorage {
+    fn load(&self, key: String) -> Result<Audio, AppError> {
+        if let Some(audio) = self.loaded.get(&key).map(|v| v.clone()) {
+            self.ledger().touch(&key);
+            return Ok(audio);
+        }
+        let audio = self.read_file(&key)?;
+        self.keep_loaded(key, audio.clone());
+        Ok(audio)
+    }
+
+    fn remove(&self, key: String) -> Result<(), AppError> {
+        self.loaded.remove(&key);
+        self.ledger().remove(&key);
+        match fs::remove_file(self.path_for(&key)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(AppError::NotFound(format!(
+                "Audio key not found for removal: {}",
+                key
+            ))),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, key: String, storage_unit: Audio) -> Result<(), AppError> {
+        self.write_file(&key, &storage_unit)?;
+        self.keep_loaded(key, storage_unit);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, AppError> {
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+            let file = File::open(&path)?;
+            let len = file.metadata()?.len();
+            let mut reader = CheckedReader::new(BufReader::new(file), len);
+            // Files from another version or damaged ones are skipped rather
+            // than failing the whole listing.
+            if let Ok(header) = read_header(&mut reader) {
+                keys.push(header.key);
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn set_memory_budget(&self, budget: Option<u64>) -> Result<(), AppError> {
+        let evicted = self.ledger().set_budget(budget);
+        for key in evicted {
+            self.loaded.remove(&key);
+        }
+        Ok(())
+    }
+
+    fn memory_usage(&self) -> Vec<AudioMemoryUsage> {
+        self.ledger()
+            .usage()
+            .into_iter()
+            .map(|(key, bytes)| AudioMemoryUsage { key, bytes })
+            .collect()
+    }
+}
+
+struct Header {
+    key: String,
+    info: AudioInfo,
+    sample_count: u64,
+    storage: SampleStorage,
+    scale: f32,
+}
+
+fn write_header(writer: &mut impl Write, key: &str, audio: &Audio) -> Result<(), AppError> {
+    let info = &audio.info;
+    writer.write_all(MAGIC)?;
+    write_u32(writer, VERSION)?;
+    write_u32(writer, info.sample_rate)?;
+    write_u32(writer, info.channels)?;
+    write_u64(writer, audio.data.samples.len() as u64)?;
+    write_str(writer, key)?;
+    let (storage, scale) = match &audio.data.samples {
+        SampleBuffer::F32(_) => (0, 1.0),
+        SampleBuffer::I16 { scale, .. } => (1, *scale),
+        SampleBuffer::F16 { scale, .. } => (2, *scale),
+    };
+    write_u8(writer, storage)?;
+    write_u32(writer, scale.to_bits())?;
+
+    match &info.channel_layout {
+        ChannelLayout::Mono => write_u8(writer, 0)?,
+        ChannelLayout::Stereo => write_u8(writer, 1)?,
+        ChannelLayout::Multichannel(names) => {
+            write_u8(writer, 2)?;
+            write_u32(writer, names.len() as u32)?;
+            for name in names {
+                write_str(writer, name)?;
+            }
+        }
+    }
+    write_str(writer, &info.codec)?;
+    write_str(writer, &info.container)?;
+    write_opt_u32(writer, info.bits_per_sample)?;
+    write_u64(writer, info.total_frames)?;
+    write_f64(writer, info.duration)?;
+    write_opt_u32(writer, info.encoder_delay)?;
+    write_opt_u32(writer, info.encoder_padding)?;
+    write_u32(writer, info.tags.len() as u32)?;
+    for tag in &info.tags {
+        write_str(writer, &tag.key)?;
+        write_str(writer, &tag.value)?;
+    }
+
+    let report = &info.decode_report;
+    write_u32(writer, report.damaged.len() as u32)?;
+    for range in &report.damaged {
+        write_u64(writer, range.start_frame)?;
+        write_u64(writer, range.end_frame)?;
+        write_f64(writer, range.start)?;
+        write_f64(writer, range.end)?;
+        write_str(writer, &range.reason)?;
+    }
+    write_u64(writer, report.skipped_packets)?;
+    write_u32(writer, report.decoder_resets)?;
+    write_u8(writer, report.truncated as u8)
+}
+
+fn corrupt(path: &Path) -> AppError {
+    AppError::Format(format!("Corrupt stored audio file {}", path.display()))
+}
+
+fn read_header(reader: &mut CheckedReader<impl Read>) -> Result<Header, AppError> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(AppError::Format("Not a stored audio file".to_string()));
+    }
+    let version = read_u32(reader)?;
+    if version != VERSION {
+        return Err(AppError::Format(format!(
+            "Unsupported stored audio version: {}",
+            version
+        )));
+    }
+    let sample_rate = read_u32(reader)?;
+    let channels = read_u32(reader)?;
+    let sample_count = read_u64(reader)?;
+    let key = read_str(reader)?;
+    let storage = match read_u8(reader)? {
+        0 => SampleStorage::F32,
+        1 => SampleStorage::I16,
+        2 => SampleStorage::F16,
+        other => {
+            return Err(AppError::Format(format!(
+                "Unknown stored sample format: {}",
+                other
+            )))
+        }
+    };
+    let scale = f32::from_bits(read_u32(reader)?);
+
+    let channel_layout = match read_u8(reader)? {
+        0 => ChannelLayout::Mono,
+        1 => ChannelLayout::Stereo,
+        _ => {
+            let count = read_u32(reader)?;
+            // Every entry below is at least a length or count, so a count
+            // needing more bytes than are left is damage.
+            reader.expect(count as u64 * 4)?;
+            ChannelLayout::Multichannel(
+                (0..count)
+                    .map(|_| read_str(reader))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+    };
+    let codec = read_str(reader)?;
+    let container = read_str(reader)?;
+    let bits_per_sample = read_opt_u32(reader)?;
+    let total_frames = read_u64(reader)?;
+    let duration = read_f64(reader)?;
+    let encoder_delay = read_opt_u32(reader)?;
+    let encoder_padding = read_opt_u32(reader)?;
+    let tag_count = read_u32(reader)?;
+    reader.expect(tag_count as u64 * 8)?;
+    let tags = (0..tag_count)
+        .map(|_| {
+            Ok(AudioTag {
+                key: read_str(reader)?,
+                value: read_str(reader)?,
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    let damaged_count = read_u32(reader)?;
+    reader.expect(damaged_count as u64 * 36)?;
+    let damaged = (0..damaged_count)
+        .map(|_| {
+            Ok(DamagedRange {
+                start_frame: read_u64(reader)?,
+                end_frame: read_u64(reader)?,
+                start: read_f64(reader)?,
+                end: read_f64(reader)?,
+                reason: read_str(reader)?,
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+    let decode_report = DecodeReport {
+        damaged,
+        skipped_packets: read_u64(reader)?,
+        decoder_resets: read_u32(reader)?,
+        truncated: read_u8(reader)? != 0,
+    };
+
+    Ok(Header {
+        key,
+        info: AudioInfo {
+            sample_rate,
+            channels,
+            channel_layout,
+            codec,
+            container,
+            bits_per_sample,
+            total_frames,
+            duration,
+            encoder_delay,
+            encoder_padding,
+            tags,
+            decode_report,
+        },
+        sample_count,
+        storage,
+        scale,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage(name: &str) -> FileAudioStorage {
+        let directory =
+            std::env::temp_dir().join(format!("vad-file-audio-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        FileAudioStorage::new(directory.to_string_lossy().into_owned()).unwrap()
+    }
+
+    fn audio() -> Audio {
+        Audio {
+            data: AudioData {
+                samples: SampleBuffer::from_f32(
+                    (0..1000).map(|i| (i as f32 * 0.1).sin()).collect(),
+                    SampleStorage::I16,
+                ),
+            },
+            info: AudioInfo {
+                sample_rate: 8000,
+                channels: 2,
+                channel_layout: ChannelLayout::Stereo,
+                codec: "pcm_s16le".to_string(),
+                container: "wav".to_string(),
+                bits_per_sample: Some(16),
+                total_frames: 500,
+                duration: 500.0 / 8000.0,
+                encoder_delay: None,
+                encoder_padding: None,
+                tags: vec![AudioTag {
+                    key: "Title".to_string(),
+                    value: "Test".to_string(),
+                }],
+                decode_report: DecodeReport::default(),
+            },
+            content_hash: None,
+        }
+    }
+
+    /// Reads the file back from disk rather than from memory.
+    fn reload(storage: &FileAudioStorage, key: &str) -> Result<Audio, AppError> {
+        storage.set_memory_budget(Some(0)).unwrap();
+        storage.load(key.to_string())
+    }
+
+    #[test]
+    fn reads_back_what_was_written() {
+        let storage = storage("round-trip");
+        let audio = audio();
+        storage.save("a".to_string(), audio.clone()).unwrap();
+
+        let loaded = reload(&storage, "a").unwrap();
+        assert_eq!(loaded.data.samples.to_f32(), audio.data.samples.to_f32());
+        assert_eq!(loaded.info.tags[0].value, "Test");
+        assert_eq!(storage.keys().unwrap(), vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn refuses_damaged_files() {
+        let storage = storage("damaged");
+        storage.save("a".to_string(), audio()).unwrap();
+        let path = storage.path_for("a");
+        let intact = fs::read(&path).unwrap();
+
+        // A flipped bit in the samples fails the checksum.
+        let mut bytes = intact.clone();
+        let last_sample = bytes.len() - 9;
+        bytes[last_sample] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+        assert!(reload(&storage, "a").is_err());
+
+        // A huge sample count is refused before anything is allocated.
+        let mut bytes = intact.clone();
+        bytes[20..28].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert!(reload(&storage, "a").is_err());
+
+        // So is a string longer than the file.
+        let mut bytes = intact;
+        bytes[28..32].copy_from_slice(&u32::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert!(reload(&storage, "a").is_err());
+        assert!(storage.keys().unwrap().is_empty());
+    }
+}
@@ -1,31 +1,46 @@
+use std::sync::{Mutex, MutexGuard};
+
 use dashmap::DashMap;
 
 use crate::api::{
     traits::audio_storage::AudioStorage,
-    types::{audio::Audio, error::AppError},
+    types::{audio::Audio, error::AppError, memory::AudioMemoryUsage},
+    util::memory_ledger::MemoryLedger,
 };
 
+/// Audio has nowhere else to live, so it is never evicted and a memory
+/// budget is refused; the ledger only tracks usage.
 pub struct KvAudioStorage {
     dashmap: DashMap<String, Audio>,
+    ledger: Mutex<MemoryLedger<String>>,
 }
 
 impl KvAudioStorage {
     pub fn new() -> Self {
         Self {
             dashmap: DashMap::new(),
+            ledger: Mutex::new(MemoryLedger::new()),
         }
     }
+
+    fn ledger(&self) -> MutexGuard<'_, MemoryLedger<String>> {
+        self.ledger.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 impl AudioStorage for KvAudioStorage {
     fn load(&self, key: String) -> Result<Audio, AppError> {
-        self.dashmap
+        let audio = self
+            .dashmap
             .get(&key)
             .map(|v| v.clone())
-            .ok_or_else(|| AppError::NotFound(format!("Audio key not found: {}", key)))
+            .ok_or_else(|| AppError::NotFound(format!("Audio key not found: {}", key)))?;
+        self.ledger().touch(&key);
+        Ok(audio)
     }
 
     fn remove(&self, key: String) -> Result<(), AppError> {
+        self.ledger().remove(&key);
         if self.dashmap.remove(&key).is_some() {
             Ok(())
         } else {
@@ -37,7 +52,36 @@ impl AudioStorage for KvAudioStorage {
     }
 
     fn save(&self, key: String, storage_unit: Audio) -> Result<(), AppError> {
-        self.dashmap.insert(key, storage_unit);
+        let bytes = storage_unit.memory_bytes();
+        self.dashmap.insert(key.clone(), storage_unit);
+        // Without a budget nothing is ever evicted.
+        self.ledger().insert(key, bytes);
         Ok(())
     }
+
+    fn keys(&self) -> Result<Vec<String>, AppError> {
+        Ok(self
+            .dashmap
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect())
+    }
+
+    fn set_memory_budget(&self, budget: Option<u64>) -> Result<(), AppError> {
+        match budget {
+            None => Ok(()),
+            Some(_) => Err(AppError::Storage(
+                "Memory storage holds the only copy of the audio, so it cannot be capped"
+                    .to_string(),
+            )),
+        }
+    }
+
+    fn memory_usage(&self) -> Vec<AudioMemoryUsage> {
+        self.ledger()
+            .usage()
+            .into_iter()
+            .map(|(key, bytes)| AudioMemoryUsage { key, bytes })
+            .collect()
+    }
 }
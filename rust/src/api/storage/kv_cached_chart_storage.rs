@@ -1,17 +1,22 @@
+use std::sync::{Mutex, MutexGuard};
+
 use dashmap::DashMap;
 
 use crate::api::{
     traits::cached_chart_storage::CachedChartStorage,
     types::{
-        chart::{Chart, ChartWIthKey, DataType},
+        chart::{Chart, ChartParams, ChartWIthKey, DataType},
         config::Config,
         error::AppError,
+        memory::ChartMemoryUsage,
     },
+    util::memory_ledger::MemoryLedger,
 };
 
 pub struct KvCachedChartStorage {
     pub config: Config,
-    dashmap: DashMap<String, Vec<Chart>>,
+    dashmap: DashMap<String, Vec<(ChartParams, Chart)>>,
+    ledger: Mutex<MemoryLedger<(String, DataType)>>,
 }
 
 impl KvCachedChartStorage {
@@ -19,35 +24,88 @@ impl KvCachedChartStorage {
         Self {
             config: Config::default(),
             dashmap: DashMap::new(),
+            ledger: Mutex::new(MemoryLedger::new()),
+        }
+    }
+
+    fn ledger(&self) -> MutexGuard<'_, MemoryLedger<(String, DataType)>> {
+        self.ledger.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Measures every cached chart again, since a chart grows when its pyramid
+    /// is built on first use and when the stored audio lets go of samples they
+    /// share. Returns the charts evicted if they no longer fit.
+    fn recharge(&self) -> Vec<(String, DataType)> {
+        let sizes: Vec<_> = self
+            .dashmap
+            .iter()
+            .flat_map(|entry| {
+                entry
+                    .value()
+                    .iter()
+                    .map(|(_, chart)| {
+                        ((entry.key().clone(), chart.data_type), chart.memory_bytes())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let mut ledger = self.ledger();
+        sizes
+            .into_iter()
+            .flat_map(|(entry, bytes)| ledger.resize(&entry, bytes))
+            .collect()
+    }
+
+    fn evict(&self, charts: Vec<(String, DataType)>) {
+        for (key, data_type) in charts {
+            if let Some(mut cached_charts) = self.dashmap.get_mut(&key) {
+                cached_charts.retain(|(_, c)| c.data_type != data_type);
+            }
         }
     }
 }
 
 impl CachedChartStorage for KvCachedChartStorage {
-    fn add(&self, key: String, chart: Chart) -> Result<(), AppError> {
+    fn add(&self, key: String, params: ChartParams, chart: Chart) -> Result<(), AppError> {
+        let recharged = self.recharge();
+        self.evict(recharged);
+
+        let entry = (key.clone(), chart.data_type);
+        let bytes = chart.memory_bytes();
         if let Some(mut cached_charts) = self.dashmap.get_mut(&key) {
             if let Some(existing) = cached_charts
                 .iter_mut()
-                .find(|c| c.data_type == chart.data_type)
+                .find(|(_, c)| c.data_type == chart.data_type)
             {
-                *existing = chart;
+                *existing = (params, chart);
             } else {
-                cached_charts.push(chart);
+                cached_charts.push((params, chart));
             }
         } else {
-            self.dashmap.insert(key, vec![chart]);
+            self.dashmap.insert(key, vec![(params, chart)]);
         }
 
+        let evicted = self.ledger().insert(entry, bytes);
+        self.evict(evicted);
         Ok(())
     }
 
-    fn get(&self, key: String, data_type: DataType) -> Result<Chart, AppError> {
+    fn get(
+        &self,
+        key: String,
+        data_type: DataType,
+        params: &ChartParams,
+    ) -> Result<Chart, AppError> {
         if let Some(cached_charts) = self.dashmap.get(&key) {
-            if let Some(chart) = cached_charts.iter().find(|c| c.data_type == data_type) {
+            if let Some((_, chart)) = cached_charts
+                .iter()
+                .find(|(p, c)| c.data_type == data_type && p == params)
+            {
+                self.ledger().touch(&(key.clone(), data_type));
                 Ok(chart.clone())
             } else {
                 Err(AppError::NotFound(format!(
-                    "Chart with specified data type not found for key: {}",
+                    "Chart with specified data type and params not found for key: {}",
                     key
                 )))
             }
@@ -61,7 +119,7 @@ impl CachedChartStorage for KvCachedChartStorage {
 
         for entry in self.dashmap.iter() {
             let key = entry.key().clone();
-            for chart in entry.value().iter() {
+            for (_, chart) in entry.value().iter() {
                 all_charts.push(ChartWIthKey {
                     key: key.clone(),
                     chart: chart.clone(),
@@ -78,7 +136,8 @@ impl CachedChartStorage for KvCachedChartStorage {
         data_type: crate::api::types::chart::DataType,
     ) -> Result<(), AppError> {
         if let Some(mut cached_charts) = self.dashmap.get_mut(&key) {
-            cached_charts.retain(|c| c.data_type != data_type);
+            cached_charts.retain(|(_, c)| c.data_type != data_type);
+            self.ledger().remove(&(key, data_type));
             Ok(())
         } else {
             Err(AppError::NotFound(format!(
@@ -87,4 +146,106 @@ impl CachedChartStorage for KvCachedChartStorage {
             )))
         }
     }
+
+    fn stale(
+        &self,
+        current: &dyn Fn(&str, DataType) -> ChartParams,
+    ) -> Result<Vec<ChartWIthKey>, AppError> {
+        let mut stale_charts = Vec::new();
+
+        for entry in self.dashmap.iter() {
+            for (params, chart) in entry.value().iter() {
+                if *params != current(entry.key(), chart.data_type) {
+                    stale_charts.push(ChartWIthKey {
+                        key: entry.key().clone(),
+                        chart: chart.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(stale_charts)
+    }
+
+    fn set_memory_budget(&self, budget: Option<u64>) {
+        let recharged = self.recharge();
+        self.evict(recharged);
+        let evicted = self.ledger().set_budget(budget);
+        self.evict(evicted);
+    }
+
+    fn memory_usage(&self) -> Vec<ChartMemoryUsage> {
+        let recharged = self.recharge();
+        self.evict(recharged);
+        self.ledger()
+            .usage()
+            .into_iter()
+            .map(|((key, data_type), bytes)| ChartMemoryUsage {
+                key,
+                data_type,
+                bytes,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicBool, Arc};
+
+    use super::*;
+    use crate::api::types::{audio::ChannelMix, chart::ChartSeries, samples::SampleBuffer};
+
+    /// A waveform over `samples`, sharing their buffer.
+    fn waveform(samples: &SampleBuffer) -> Chart {
+        Chart {
+            data_type: DataType::Audio,
+            series: ChartSeries::from_samples(samples.clone()),
+            min_y: -1.0,
+            max_y: 1.0,
+            visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
+        }
+    }
+
+    fn bytes_used(storage: &KvCachedChartStorage) -> u64 {
+        storage.memory_usage().iter().map(|usage| usage.bytes).sum()
+    }
+
+    #[test]
+    fn shared_samples_are_charged_once_the_audio_lets_go() {
+        let storage = KvCachedChartStorage::new();
+        let samples = SampleBuffer::from(vec![0.0f32; 4096]);
+        let params = ChartParams {
+            config: None,
+            channel_mix: ChannelMix::Mean,
+        };
+        storage
+            .add("a.wav".to_string(), params, waveform(&samples))
+            .unwrap();
+        let shared = bytes_used(&storage);
+        assert!(shared < samples.memory_bytes());
+
+        drop(samples);
+        assert!(bytes_used(&storage) >= shared + 4096 * 4);
+    }
+
+    #[test]
+    fn a_pyramid_built_after_caching_is_charged() {
+        let storage = KvCachedChartStorage::new();
+        let samples = SampleBuffer::from(vec![0.0f32; 4096]);
+        let params = ChartParams {
+            config: None,
+            channel_mix: ChannelMix::Mean,
+        };
+        let chart = waveform(&samples);
+        storage
+            .add("a.wav".to_string(), params, chart.clone())
+            .unwrap();
+        let before = bytes_used(&storage);
+
+        let pyramid = chart.pyramid().memory_bytes();
+        assert!(pyramid > 0);
+        assert_eq!(bytes_used(&storage), before + pyramid);
+    }
 }
@@ -1,2 +1,6 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod disk_cached_chart_storage;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_audio_storage;
 pub mod kv_audio_storage;
 pub mod kv_cached_chart_storage;
\ No newline at end of file
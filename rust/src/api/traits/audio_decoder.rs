@@ -1,5 +1,33 @@
-use crate::api::types::{audio::Audio, error::AppError};
+use crate::api::types::{
+    audio::{Audio, AudioInfo, TrackInfo},
+    decode::{CancellationToken, DecodeOptions, DecodeProgress},
+    error::AppError,
+    file::SharedSource,
+};
+
+/// Snapshot handed to the progress callback while a decode is running.
+pub struct DecodeUpdate<'a> {
+    pub progress: DecodeProgress,
+    pub info: &'a AudioInfo,
+    /// Interleaved samples decoded so far.
+    pub samples: &'a [f32],
+}
 
 pub trait AudioDecoder {
-    fn decode(&self, format: String, data: Vec<u8>) -> Result<Audio, AppError>;
+    /// Lists the audio tracks of a container without decoding them.
+    fn list_tracks(&self, source: SharedSource) -> Result<Vec<TrackInfo>, AppError>;
+
+    fn decode(&self, source: SharedSource, options: DecodeOptions) -> Result<Audio, AppError> {
+        self.decode_incremental(source, options, &CancellationToken::new(), &mut |_| {})
+    }
+
+    /// Decodes `source` while periodically reporting progress. Once `token` is
+    /// cancelled the decoder stops and returns what it has decoded so far.
+    fn decode_incremental(
+        &self,
+        source: SharedSource,
+        options: DecodeOptions,
+        token: &CancellationToken,
+        on_progress: &mut dyn FnMut(DecodeUpdate),
+    ) -> Result<Audio, AppError>;
 }
@@ -0,0 +1,19 @@
+use std::io::{Cursor, Seek, Write};
+
+use crate::api::types::{audio::Audio, error::AppError};
+
+/// Where an encoder writes to. Encoders stream the audio block by block and
+/// seek back to fill in the sizes they only know at the end.
+pub trait EncoderOutput: Write + Seek {}
+
+impl<T: Write + Seek> EncoderOutput for T {}
+
+pub trait AudioEncoder {
+    fn encode(&self, audio: &Audio, writer: &mut dyn EncoderOutput) -> Result<(), AppError>;
+
+    fn encode_to_bytes(&self, audio: &Audio) -> Result<Vec<u8>, AppError> {
+        let mut bytes = Cursor::new(Vec::new());
+        self.encode(audio, &mut bytes)?;
+        Ok(bytes.into_inner())
+    }
+}
@@ -1,7 +1,14 @@
-use crate::api::types::{audio::Audio, error::AppError};
+use crate::api::types::{audio::Audio, error::AppError, memory::AudioMemoryUsage};
 
 pub trait AudioStorage {
     fn save(&self, key: String, storage_unit: Audio) -> Result<(), AppError>;
     fn load(&self, key: String) -> Result<Audio, AppError>;
     fn remove(&self, key: String) -> Result<(), AppError>;
+    /// Every key that can currently be loaded.
+    fn keys(&self) -> Result<Vec<String>, AppError>;
+    /// Caps the bytes of audio held in memory, evicting the least recently
+    /// used first. `None` removes the cap. Fails for a storage that cannot
+    /// load evicted audio back.
+    fn set_memory_budget(&self, budget: Option<u64>) -> Result<(), AppError>;
+    fn memory_usage(&self) -> Vec<AudioMemoryUsage>;
 }
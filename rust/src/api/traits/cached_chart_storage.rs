@@ -1,11 +1,36 @@
 use crate::api::types::{
-    chart::{Chart, ChartWIthKey, DataType},
+    chart::{Chart, ChartParams, ChartWIthKey, DataType},
     error::AppError,
+    memory::ChartMemoryUsage,
 };
 
 pub trait CachedChartStorage {
-    fn add(&self, key: String, chart: Chart) -> Result<(), AppError>;
-    fn get(&self, key: String, data_type: DataType) -> Result<Chart, AppError>;
+    /// Replaces the chart of the same data type cached for `key`, whatever
+    /// params that one was computed with.
+    fn add(&self, key: String, params: ChartParams, chart: Chart) -> Result<(), AppError>;
+    /// Only finds a chart computed with exactly `params`.
+    fn get(
+        &self,
+        key: String,
+        data_type: DataType,
+        params: &ChartParams,
+    ) -> Result<Chart, AppError>;
     fn get_all_cache(&self) -> Result<Vec<ChartWIthKey>, AppError>;
     fn remove(&self, key:  String, data_type: DataType) -> Result<(), AppError>;
+
+    /// Cached charts whose params differ from what `current` gives for their
+    /// key and data type, i.e. the ones that need to be recomputed.
+    fn stale(
+        &self,
+        current: &dyn Fn(&str, DataType) -> ChartParams,
+    ) -> Result<Vec<ChartWIthKey>, AppError>;
+
+    /// Caps the bytes of charts held in memory, evicting the least recently
+    /// used first. `None` removes the cap.
+    fn set_memory_budget(&self, budget: Option<u64>);
+    fn memory_usage(&self) -> Vec<ChartMemoryUsage>;
+
+    /// Hash of the audio that charts for `key` are computed from. Persistent
+    /// caches use it to find charts computed in an earlier session.
+    fn set_content_hash(&self, _key: String, _content_hash: u64) {}
 }
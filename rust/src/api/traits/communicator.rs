@@ -1,10 +1,34 @@
-use crate::api::types::chart::{Chart, ChartWIthKey, DataType};
+use crate::api::types::chart::{Chart, ChartAxis, ChartRegion, ChartSeries, ChartWIthKey, DataType};
 
 pub trait Communicator {
-    fn add_chart(&self, key: String, chart: Chart);
+    fn add_chart(&self, viewport: String, key: String, chart: Chart, seq: u64);
     fn remove_chart(&self, key: String, data_type: DataType);
     fn update_all_charts(&self,charts:Vec<ChartWIthKey>);
+    fn update_overview(&self, viewport: String, key: String, chart: Chart);
     fn remove_all_charts(&self);
-    fn update_max_index(&self, max_index: f32);
-    fn update_y_range(&self, min_y: f32, max_y: f32);
+    fn update_max_index(&self, viewport: String, max_index: f32);
+    fn update_axes(&self, viewport: String, axes: Vec<ChartAxis>);
+    fn update_regions(&self, key: String, regions: Vec<ChartRegion>);
+    /// `points` are a slice of the down-sampled chart, so the edits of a
+    /// uniform chart stay uniform. `seqs` is the sequence number of the points
+    /// edited, then that of the points after the edit.
+    fn append_points(
+        &self,
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        points: ChartSeries,
+        seqs: (u64, u64),
+    );
+    /// Replaces points `range.0..range.1` of those already sent. `points` and
+    /// `seqs` are as for [`Communicator::append_points`].
+    fn replace_range(
+        &self,
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        range: (usize, usize),
+        points: ChartSeries,
+        seqs: (u64, u64),
+    );
 }
@@ -1,5 +1,8 @@
 use crate::api::types::chart::Chart;
 
 pub trait DownSample {
-    fn down_sample(&self, chart:Chart, target_points_num: usize) -> Chart;
-}
\ No newline at end of file
+    /// Reduces `chart` to about `target_points_num` points. Buckets are laid
+    /// out over `x_range`, the visible part of the x axis, and empty ones
+    /// between points come out as NaN gaps.
+    fn down_sample(&self, chart:Chart, x_range: (f32, f32), target_points_num: usize) -> Chart;
+}
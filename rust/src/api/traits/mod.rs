@@ -1,4 +1,5 @@
 pub mod audio_decoder;
+pub mod audio_encoder;
 pub mod audio_storage;
 pub mod cached_chart_storage;
 pub mod transform;
@@ -1,46 +1,38 @@
 use std::sync::{Arc, atomic::AtomicBool};
 
-use rayon::{
-    iter::{IndexedParallelIterator, ParallelIterator},
-    slice::ParallelSlice,
-};
+use rayon::iter::ParallelIterator;
 
 use crate::api::{
     traits::transform::SignalTransform,
     types::{
         audio::Audio,
-        chart::{Chart, DataType, Point},
+        chart::{Chart, ChartSeries, DataType},
         config::Config,
         error::AppError,
+        samples::SampleBuffer,
     },
-    util::get_min_max::get_min_max_par,
 };
 
 pub struct EnergyCalculator {}
 
 impl SignalTransform for EnergyCalculator {
     async fn transform(&self, data: Audio, config: Config) -> Result<Chart, AppError> {
-        let points = data
+        let values: Vec<f32> = data
             .data
             .samples
             .par_chunks(config.frame_size)
-            .enumerate()
-            .map(|(index, chunk)| {
-                let energy: f32 = chunk.iter().map(|&sample| sample * sample).sum();
-                Point {
-                    x: (index * config.frame_size) as f32,
-                    y: energy,
-                }
-            })
-            .collect::<Vec<Point>>();
+            .map(|chunk| chunk.iter().map(|&sample| sample * sample).sum())
+            .collect();
 
-        let (min_y, max_y) = get_min_max_par(&points).await;
+        let values = SampleBuffer::from(values);
+        let (min_y, max_y) = values.min_max();
         Ok(Chart {
             data_type: DataType::Energy,
-            points: Arc::new(points),
+            series: ChartSeries::uniform(0.0, config.frame_size as f32, values),
             min_y,
             max_y,
             visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
         })
     }
 }
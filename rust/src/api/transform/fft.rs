@@ -7,7 +7,7 @@ use crate::api::{
     traits::transform::SignalTransform,
     types::{
         audio::Audio,
-        chart::{Chart, DataType, Point},
+        chart::{Chart, ChartSeries, DataType, Point},
         config::Config,
         error::AppError,
     },
@@ -24,10 +24,11 @@ impl SignalTransform for FftTransform {
         if n == 0 {
             return Ok(Chart {
                 data_type: DataType::Spectrum,
-                points: Arc::new(vec![]),
+                series: ChartSeries::Points(Arc::new(vec![])),
                 min_y: 0.0,
                 max_y: 0.0,
                 visible: Arc::new(AtomicBool::new(true)),
+                pyramid: Arc::default(),
             });
         }
 
@@ -49,7 +50,7 @@ impl SignalTransform for FftTransform {
             .flat_map_iter(|(chunk_index, chunk)| {
                 let mut buffer: Vec<Complex<f32>> = Vec::with_capacity(frame_size);
 
-                for &sample in chunk {
+                for &sample in chunk.iter() {
                     buffer.push(Complex {
                         re: sample,
                         im: 0.0,
@@ -75,10 +76,11 @@ impl SignalTransform for FftTransform {
         let (min_y, max_y) = get_min_max_par(&points).await;
         Ok(Chart {
             data_type: DataType::Spectrum,
-            points: Arc::new(points),
+            series: ChartSeries::Points(Arc::new(points)),
             min_y,
             max_y,
             visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
         })
     }
 }
@@ -1,31 +1,27 @@
 use std::sync::{Arc, atomic::AtomicBool};
 
-use rayon::{
-    iter::{IndexedParallelIterator, ParallelIterator},
-    slice::ParallelSlice,
-};
+use rayon::iter::ParallelIterator;
 
 use crate::api::{
     traits::transform::SignalTransform,
     types::{
         audio::Audio,
-        chart::{Chart, DataType, Point},
+        chart::{Chart, ChartSeries, DataType},
         config::Config,
         error::AppError,
+        samples::SampleBuffer,
     },
-    util::get_min_max::get_min_max_par,
 };
 
 pub struct ZeroCrossingRateCalculator {}
 
 impl SignalTransform for ZeroCrossingRateCalculator {
     async fn transform(&self, data: Audio, config: Config) -> Result<Chart, AppError> {
-        let points = data
+        let values: Vec<f32> = data
             .data
             .samples
             .par_chunks(config.frame_size)
-            .enumerate()
-            .map(|(index, chunk)| {
+            .map(|chunk| {
                 let mut zero_crossings = 0;
                 for i in 1..chunk.len() {
                     if (chunk[i - 1] >= 0.0 && chunk[i] < 0.0)
@@ -34,20 +30,19 @@ impl SignalTransform for ZeroCrossingRateCalculator {
                         zero_crossings += 1;
                     }
                 }
-                Point {
-                    x: (index * config.frame_size) as f32,
-                    y: zero_crossings as f32,
-                }
+                zero_crossings as f32
             })
-            .collect::<Vec<Point>>();
+            .collect();
 
-        let (min_y, max_y) = get_min_max_par(&points).await;
+        let values = SampleBuffer::from(values);
+        let (min_y, max_y) = values.min_max();
         Ok(Chart {
             data_type: DataType::ZeroCrossingRate,
-            points: Arc::new(points),
+            series: ChartSeries::uniform(0.0, config.frame_size as f32, values),
             min_y,
             max_y,
             visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
         })
     }
 }
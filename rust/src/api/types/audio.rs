@@ -1,44 +1,219 @@
 use std::sync::{Arc, atomic::AtomicBool};
 
-use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::ParallelIterator;
 
-use crate::api::{types::chart::{Chart, DataType, Point}, util::get_min_max::{self, get_min_max_par}};
+use crate::api::types::{
+    chart::{Chart, ChartSeries, DataType},
+    decode::{DamagedRange, DecodeReport},
+    error::AppError,
+    samples::SampleBuffer,
+};
+use crate::api::util::hash::fnv1a64;
 
+/// Frames reduced per task by [`Audio::mix`].
+const MIX_BLOCK_FRAMES: usize = 4096;
+
+/// Interleaved samples: frame `i` of channel `c` lives at `i * channels + c`.
 #[derive(Clone)]
 pub struct AudioData {
-    pub samples: Arc<Vec<f32>>,
+    pub samples: SampleBuffer,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChannelLayout {
+    Mono,
+    Stereo,
+    /// Speaker positions in channel order, e.g. `["FL", "FR", "FC", "LFE"]`.
+    Multichannel(Vec<String>),
+}
+
+impl ChannelLayout {
+    pub fn from_count(channels: u32) -> Self {
+        match channels {
+            1 => ChannelLayout::Mono,
+            2 => ChannelLayout::Stereo,
+            n => ChannelLayout::Multichannel((1..=n).map(|i| format!("CH{}", i)).collect()),
+        }
+    }
+}
+
+/// How a multichannel [`Audio`] is reduced to the single signal that gets charted.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ChannelMix {
+    #[default]
+    Mean,
+    Left,
+    Right,
+    /// `(L + R) / 2`
+    Mid,
+    /// `(L - R) / 2`
+    Side,
+    /// A single channel by zero-based index.
+    Channel(u32),
+}
+
+impl ChannelMix {
+    /// Fails for a [`ChannelMix::Channel`] beyond the `channels` of the audio
+    /// it is meant for.
+    pub fn check(&self, channels: u32) -> Result<(), AppError> {
+        match *self {
+            ChannelMix::Channel(index) if index >= channels.max(1) => {
+                Err(AppError::NotFound(format!(
+                    "Channel {} not found in audio with {} channels",
+                    index, channels
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioTag {
+    pub key: String,
+    pub value: String,
+}
+
+/// An audio track as listed by the container, before it is decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackInfo {
+    pub id: u32,
+    pub codec: String,
+    /// ISO 639 language code, if the container declares one.
+    pub language: Option<String>,
+    pub sample_rate: u32,
+    /// Zero when the container leaves it to the codec.
+    pub channels: u32,
+    pub channel_layout: Option<ChannelLayout>,
+    pub total_frames: Option<u64>,
 }
 
 #[derive(Clone)]
 pub struct AudioInfo {
     pub sample_rate: u32,
+    pub channels: u32,
+    pub channel_layout: ChannelLayout,
+    /// Short codec name as reported by Symphonia, e.g. `"pcm_s16le"` or `"mp3"`.
+    pub codec: String,
+    pub container: String,
+    pub bits_per_sample: Option<u32>,
+    /// Frames per channel actually decoded.
+    pub total_frames: u64,
+    /// Duration in seconds derived from `total_frames`.
+    pub duration: f64,
+    /// Priming frames the encoder put in front of the audio.
+    pub encoder_delay: Option<u32>,
+    /// Padding frames the encoder appended to the audio.
+    pub encoder_padding: Option<u32>,
+    pub tags: Vec<AudioTag>,
+    /// Damage found while decoding; clean for intact input.
+    pub decode_report: DecodeReport,
 }
 
 #[derive(Clone)]
 pub struct Audio {
     pub data: AudioData,
     pub info: AudioInfo,
+    /// Hash of the samples and their format, set once when the audio is
+    /// stored so a persistent chart cache can recognise it in a later session.
+    pub content_hash: Option<u64>,
 }
 
 impl Audio {
-    pub async fn audio_to_chart(&self) -> Chart {
-        let points: Vec<Point> = self
+    pub fn frames(&self) -> usize {
+        self.data.samples.len() / self.info.channels.max(1) as usize
+    }
+
+    /// Bytes held by this audio: the samples at their capacity plus the
+    /// strings and lists in its info.
+    pub fn memory_bytes(&self) -> u64 {
+        let info = &self.info;
+        let layout = match &info.channel_layout {
+            ChannelLayout::Multichannel(names) => names.iter().map(String::capacity).sum(),
+            _ => 0,
+        };
+        let tags: usize = info
+            .tags
+            .iter()
+            .map(|tag| std::mem::size_of::<AudioTag>() + tag.key.capacity() + tag.value.capacity())
+            .sum();
+        let damaged: usize = info
+            .decode_report
+            .damaged
+            .iter()
+            .map(|range| std::mem::size_of::<DamagedRange>() + range.reason.capacity())
+            .sum();
+        (std::mem::size_of::<Audio>()
+            + self.data.samples.memory_bytes() as usize
+            + info.codec.capacity()
+            + info.container.capacity()
+            + layout
+            + tags
+            + damaged) as u64
+    }
+
+    /// The audio with its [`Audio::content_hash`] computed.
+    pub fn with_content_hash(self) -> Audio {
+        let seed = fnv1a64(format!("{}:{}", self.info.sample_rate, self.info.channels).as_bytes());
+        let content_hash = self.data.samples.content_hash(seed);
+        Audio {
+            content_hash: Some(content_hash),
+            ..self
+        }
+    }
+
+    /// Reduces the audio to a mono signal according to `mix`.
+    ///
+    /// Mono audio is returned as is without copying the samples. Fails for a
+    /// channel the audio does not have.
+    pub fn mix(&self, mix: ChannelMix) -> Result<Audio, AppError> {
+        mix.check(self.info.channels)?;
+        let channels = self.info.channels.max(1) as usize;
+        if channels == 1 {
+            return Ok(self.clone());
+        }
+
+        let last = channels - 1;
+        let reduce = |frame: &[f32]| match mix {
+            ChannelMix::Mean => frame.iter().sum::<f32>() / channels as f32,
+            ChannelMix::Left => frame[0],
+            ChannelMix::Right => frame[1.min(last)],
+            ChannelMix::Mid => (frame[0] + frame[1]) * 0.5,
+            ChannelMix::Side => (frame[0] - frame[1]) * 0.5,
+            ChannelMix::Channel(index) => frame[index as usize],
+        };
+        // Whole frames per block, so compact samples are only expanded a
+        // block at a time.
+        let samples: Vec<f32> = self
             .data
             .samples
-            .par_iter()
-            .enumerate()
-            .map(|(i, &sample)| Point {
-                x: i as f32,
-                y: sample,
-            })
+            .par_chunks(channels * MIX_BLOCK_FRAMES)
+            .flat_map_iter(|block| block.chunks(channels).map(reduce).collect::<Vec<_>>())
             .collect();
-        let (min_y, max_y) =  get_min_max_par(&points).await;
+        let samples = SampleBuffer::from_f32(samples, self.data.samples.storage());
+
+        Ok(Audio {
+            data: AudioData { samples },
+            info: AudioInfo {
+                channels: 1,
+                channel_layout: ChannelLayout::Mono,
+                ..self.info.clone()
+            },
+            content_hash: None,
+        })
+    }
+
+    /// Charts the samples as they are stored, so multichannel audio should be
+    /// reduced with [`Audio::mix`] first.
+    pub async fn audio_to_chart(&self) -> Chart {
+        let (min_y, max_y) = self.data.samples.min_max();
         Chart {
             data_type: DataType::Audio,
-            points: Arc::new(points),
+            series: ChartSeries::from_samples(self.data.samples.clone()),
             min_y,
             max_y,
             visible: Arc::new(AtomicBool::new(true)),
+            pyramid: Arc::default(),
         }
     }
 }
@@ -1,5 +1,10 @@
 use std::sync::atomic::{self, AtomicBool};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+
+use crate::api::{
+    types::{audio::ChannelMix, config::Config, samples::SampleBuffer},
+    util::min_max_pyramid::MinMaxPyramid,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point {
@@ -7,7 +12,18 @@ pub struct Point {
     pub y: f32,
 }
 
+/// The highest magnitude of a waveform reconstructed between its samples,
+/// and where it is.
 #[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TruePeak {
+    /// Position in the chart's x units, the frame index for a waveform. Falls
+    /// between two samples for an inter-sample peak.
+    pub x: f32,
+    /// The reconstructed value at `x`, whose magnitude is the true peak.
+    pub value: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DataType {
     Audio,
     Spectrum,
@@ -15,13 +31,209 @@ pub enum DataType {
     ZeroCrossingRate,
 }
 
+impl DataType {
+    /// Label and unit of the y axis charts of this type are drawn against.
+    pub fn y_axis(&self) -> (&'static str, &'static str) {
+        match self {
+            DataType::Audio => ("Amplitude", "FS"),
+            DataType::Spectrum => ("Magnitude", "FS"),
+            // Sum of squared samples over a frame.
+            DataType::Energy => ("Energy", "FS²"),
+            DataType::ZeroCrossingRate => ("Zero crossings", "per frame"),
+        }
+    }
+}
+
+/// How a chart is reduced to the requested number of points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DownSampleMethod {
+    /// Lowest and highest point per bucket; keeps every peak of a waveform.
+    #[default]
+    MinMax,
+    /// Every n-th point.
+    EqualStep,
+    /// Largest-Triangle-Three-Buckets; follows smooth curves closely.
+    Lttb,
+    /// First, lowest, highest and last point per column of the x span.
+    M4,
+}
+
+/// Everything besides the audio itself that a chart was computed from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartParams {
+    /// `None` for the waveform, which does not depend on the config.
+    pub config: Option<Config>,
+    pub channel_mix: ChannelMix,
+}
+
+/// The points of a chart, either stored as they are or as evenly spaced
+/// values whose x is implied by their index.
+#[derive(Clone, Debug)]
+pub enum ChartSeries {
+    /// For irregular series; x must not decrease.
+    Points(Arc<Vec<Point>>),
+    /// Point `i` is `(x0 + (start + i) * dx, values[start + i])` for
+    /// `i < end - start`, with `dx > 0`. Slicing only moves `start` and `end`,
+    /// so ranges share the values of the whole series.
+    Uniform {
+        x0: f32,
+        dx: f32,
+        values: SampleBuffer,
+        start: usize,
+        end: usize,
+    },
+}
+
+impl ChartSeries {
+    pub fn uniform(x0: f32, dx: f32, values: SampleBuffer) -> Self {
+        let end = values.len();
+        ChartSeries::Uniform {
+            x0,
+            dx,
+            values,
+            start: 0,
+            end,
+        }
+    }
+
+    /// A waveform, where x is the sample index.
+    pub fn from_samples(buffer: SampleBuffer) -> Self {
+        ChartSeries::uniform(0.0, 1.0, buffer)
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            ChartSeries::Points(points) => points.len(),
+            ChartSeries::Uniform { start, end, .. } => end - start,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn point(&self, index: usize) -> Point {
+        match self {
+            ChartSeries::Points(points) => points[index],
+            ChartSeries::Uniform {
+                x0,
+                dx,
+                values,
+                start,
+                ..
+            } => Point {
+                x: x0 + (start + index) as f32 * dx,
+                y: values.get(start + index),
+            },
+        }
+    }
+
+    pub fn first(&self) -> Option<Point> {
+        (!self.is_empty()).then(|| self.point(0))
+    }
+
+    pub fn last(&self) -> Option<Point> {
+        (!self.is_empty()).then(|| self.point(self.len() - 1))
+    }
+
+    /// The points as a plain list, without copying when they already are one.
+    pub fn into_points(self) -> Vec<Point> {
+        match self {
+            ChartSeries::Points(points) => Arc::try_unwrap(points).unwrap_or_else(|v| (*v).clone()),
+            series => (0..series.len()).map(|i| series.point(i)).collect(),
+        }
+    }
+
+    /// Points with `start_x <= x <= end_x`. When no point is at or after
+    /// `start_x` the range starts at the first point, and when none is at or
+    /// before `end_x` it runs to the last.
+    pub fn range(&self, start_x: f32, end_x: f32) -> Self {
+        let (start, end) = self.index_range(start_x, end_x);
+        self.slice(start, end)
+    }
+
+    /// Indices `start..end` of the points [`ChartSeries::range`] keeps.
+    pub fn index_range(&self, start_x: f32, end_x: f32) -> (usize, usize) {
+        use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator};
+
+        let first = match self {
+            ChartSeries::Points(points) => points.par_iter().position_first(|p| p.x >= start_x),
+            ChartSeries::Uniform { .. } => Some(self.partition(start_x, |x| x < start_x)),
+        };
+        let last = match self {
+            ChartSeries::Points(points) => points.par_iter().position_last(|p| p.x <= end_x),
+            ChartSeries::Uniform { .. } => self.partition(end_x, |x| x <= end_x).checked_sub(1),
+        };
+
+        let len = self.len();
+        let first = first.filter(|&i| i < len).unwrap_or(0);
+        let last = last.map(|i| i + 1).unwrap_or(len).clamp(first, len);
+        (first, last)
+    }
+
+    /// Number of leading points with `x < bound`, found by bisection for
+    /// stored points and arithmetically for uniform ones.
+    pub fn lower_bound(&self, bound: f32) -> usize {
+        match self {
+            ChartSeries::Points(points) => points.partition_point(|p| p.x < bound),
+            ChartSeries::Uniform { .. } => self.partition(bound, |x| x < bound),
+        }
+    }
+
+    /// Points `start..end` of this series.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        match self {
+            ChartSeries::Points(points) => {
+                ChartSeries::Points(Arc::new(points[start..end].to_vec()))
+            }
+            ChartSeries::Uniform {
+                x0,
+                dx,
+                values,
+                start: offset,
+                ..
+            } => ChartSeries::Uniform {
+                x0: *x0,
+                dx: *dx,
+                values: values.clone(),
+                start: offset + start,
+                end: offset + end,
+            },
+        }
+    }
+
+    /// Number of leading points whose x satisfies `below`, which must hold
+    /// for a prefix of the series. Estimated from `x0` and `dx`, then
+    /// stepped so it agrees with [`ChartSeries::point`] despite rounding.
+    fn partition(&self, bound: f32, below: impl Fn(f32) -> bool) -> usize {
+        let ChartSeries::Uniform { x0, dx, start, .. } = self else {
+            unreachable!("only uniform series are partitioned arithmetically");
+        };
+        let len = self.len();
+        let x = |i: usize| x0 + (start + i) as f32 * dx;
+        // A NaN estimate casts to 0, where the steps below settle it.
+        let estimate = ((bound - x0) / dx).ceil() - *start as f32;
+        let mut index = estimate.clamp(0.0, len as f32) as usize;
+        while index > 0 && !below(x(index - 1)) {
+            index -= 1;
+        }
+        while index < len && below(x(index)) {
+            index += 1;
+        }
+        index
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Chart {
     pub data_type: DataType,
-    pub points: Arc<Vec<Point>>,
+    pub series: ChartSeries,
     pub min_y: f32,
     pub max_y: f32,
     pub visible: Arc<AtomicBool>,
+    /// Built on first use and shared by clones, so every copy of a cached
+    /// chart reuses it.
+    pub pyramid: Arc<OnceLock<MinMaxPyramid>>,
 }
 
 // impl PartialEq for Chart {
@@ -43,34 +255,106 @@ pub struct Chart {
 pub struct CommunicatorChart {
     pub key: String,
     pub data_type: DataType,
-    pub chart: Vec<Point>,
+    pub chart: ChartPayload,
+}
+
+/// Points as plain f32 buffers, which reach Dart as `Float32List`s rather
+/// than a list of point objects.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChartPayload {
+    /// Point `i` is `(xs[i], ys[i])`.
+    Points { xs: Vec<f32>, ys: Vec<f32> },
+    /// Point `i` is `(x0 + i * dx, ys[i])`; no x is sent at all.
+    Uniform { x0: f32, dx: f32, ys: Vec<f32> },
+}
+
+impl ChartPayload {
+    pub fn from_points(points: &[Point]) -> Self {
+        let (xs, ys) = points.iter().map(|p| (p.x, p.y)).unzip();
+        ChartPayload::Points { xs, ys }
+    }
+
+    /// Keeps a uniform series uniform, so only its values are sent.
+    pub fn from_series(series: &ChartSeries) -> Self {
+        match series {
+            ChartSeries::Points(points) => ChartPayload::from_points(points),
+            ChartSeries::Uniform {
+                x0,
+                dx,
+                values,
+                start,
+                end,
+            } => ChartPayload::Uniform {
+                x0: x0 + *start as f32 * dx,
+                dx: *dx,
+                ys: values.slice(*start, *end).into_owned(),
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            ChartPayload::Points { ys, .. } | ChartPayload::Uniform { ys, .. } => ys.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A highlighted span of the x axis, such as audio damaged during decoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartRegion {
+    pub start_x: f32,
+    pub end_x: f32,
+    pub label: String,
+}
+
+/// A y axis of a viewport. Charts of one data type share an axis, so values
+/// in the same unit stay comparable while other types get their own scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartAxis {
+    pub data_type: DataType,
+    pub label: String,
+    pub unit: String,
+    pub min_y: f32,
+    pub max_y: f32,
+    /// Keys of the charts drawn against this axis.
+    pub keys: Vec<String>,
 }
 
 impl Chart {
-    pub fn get_range(&self, start_x: f32, end_x: f32) -> Self {
-        use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator};
+    /// Bytes held by this chart, including its pyramid once built. Samples
+    /// shared with anything else, such as the stored audio, are left to their
+    /// other owner and counted here only once this chart is the last one
+    /// holding them. Both change after the chart is cached, so the chart cache
+    /// measures its charts again before enforcing its budget.
+    pub fn memory_bytes(&self) -> u64 {
+        let series = match &self.series {
+            ChartSeries::Points(points) => {
+                (points.capacity() * std::mem::size_of::<Point>()) as u64
+            }
+            ChartSeries::Uniform { values, .. } if values.is_unique() => values.memory_bytes(),
+            ChartSeries::Uniform { .. } => 0,
+        };
+        let pyramid = self.pyramid.get().map_or(0, MinMaxPyramid::memory_bytes);
+        std::mem::size_of::<Chart>() as u64 + series + pyramid
+    }
 
-        let start = self
-            .points
-            .par_iter()
-            .position_first(|p| p.x >= start_x)
-            .unwrap_or(0);
-        let end = self
-            .points
-            .par_iter()
-            .position_last(|p| p.x <= end_x)
-            .map(|i| i + 1)
-            .unwrap_or(self.points.len());
-
-        let start = start.min(self.points.len());
-        let end = end.clamp(start, self.points.len());
+    pub fn pyramid(&self) -> &MinMaxPyramid {
+        self.pyramid
+            .get_or_init(|| MinMaxPyramid::build(&self.series))
+    }
 
+    pub fn get_range(&self, start_x: f32, end_x: f32) -> Self {
         Self {
             data_type: self.data_type,
-            points: Arc::new(self.points[start..end].to_vec()),
+            series: self.series.range(start_x, end_x),
             min_y: self.min_y,
             max_y: self.max_y,
             visible: Arc::clone(&self.visible),
+            pyramid: Arc::default(),
         }
     }
 }
@@ -1,4 +1,27 @@
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Config {
     pub frame_size: usize,
 }
+
+/// Where the engine keeps decoded audio.
+#[derive(Clone, Debug, Default)]
+pub enum AudioStorageBackend {
+    /// Lost when the app exits.
+    #[default]
+    Memory,
+    /// Kept in `directory` and available again after a restart. Not
+    /// available on web.
+    Disk { directory: String },
+}
+
+/// Where the engine keeps computed charts.
+#[derive(Clone, Debug, Default)]
+pub enum ChartCacheBackend {
+    /// Recomputed after every restart.
+    #[default]
+    Memory,
+    /// Spectrum and feature charts are also written to `directory` and read
+    /// back when the same audio is charted with the same config. Not
+    /// available on web.
+    Disk { directory: String },
+}
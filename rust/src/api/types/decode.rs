@@ -0,0 +1,141 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Shared flag that lets the caller stop a running decode.
+///
+/// Clones observe the same flag, so the Dart side can keep one handle and
+/// cancel while the engine holds another.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DecodeOptions {
+    /// Track to decode; the first audio track when `None`.
+    pub track_id: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RawSampleFormat {
+    U8,
+    S16,
+    S24,
+    S32,
+    F32,
+    /// G.711 mu-law, one byte per sample.
+    MuLaw,
+    /// G.711 A-law, one byte per sample.
+    ALaw,
+}
+
+impl RawSampleFormat {
+    pub fn bytes_per_sample(&self) -> usize {
+        match self {
+            RawSampleFormat::U8 | RawSampleFormat::MuLaw | RawSampleFormat::ALaw => 1,
+            RawSampleFormat::S16 => 2,
+            RawSampleFormat::S24 => 3,
+            RawSampleFormat::S32 | RawSampleFormat::F32 => 4,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+/// Layout of a headerless PCM dump, which has to be supplied by the caller.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawPcmFormat {
+    pub sample_format: RawSampleFormat,
+    /// Ignored for single-byte formats.
+    pub endianness: Endianness,
+    /// Channels interleaved per frame.
+    pub channels: u32,
+    pub sample_rate: u32,
+    /// Bytes to skip before the first sample, e.g. a device-specific header.
+    pub header_skip: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecodeProgress {
+    pub packets: u64,
+    pub bytes_read: u64,
+    pub total_bytes: Option<u64>,
+    /// Frames per channel decoded so far.
+    pub decoded_frames: u64,
+    /// Seconds of audio decoded so far.
+    pub decoded_duration: f64,
+    /// Seconds of audio expected in total, from the container if it declares
+    /// a frame count, otherwise extrapolated from the bytes read.
+    pub estimated_duration: Option<f64>,
+}
+
+/// A stretch of output that was not decoded from the input. Zero-filled
+/// packets span their duration; a discontinuity where the reader skipped
+/// unreadable data has `start_frame == end_frame`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DamagedRange {
+    pub start_frame: u64,
+    pub end_frame: u64,
+    /// Seconds from the start of the decoded audio.
+    pub start: f64,
+    pub end: f64,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecodeReport {
+    pub damaged: Vec<DamagedRange>,
+    /// Packets whose audio was replaced by silence.
+    pub skipped_packets: u64,
+    /// Times the codec had to be rebuilt mid-stream.
+    pub decoder_resets: u32,
+    /// The input ended before all of the declared audio was read.
+    pub truncated: bool,
+}
+
+impl DecodeReport {
+    pub fn is_clean(&self) -> bool {
+        self.damaged.is_empty() && self.decoder_resets == 0 && !self.truncated
+    }
+
+    /// Records damage, merging it into the previous range when they touch and
+    /// share a reason so a run of bad packets shows up as one region.
+    pub fn mark(&mut self, start_frame: u64, end_frame: u64, sample_rate: u32, reason: &str) {
+        let seconds = |frame: u64| frame as f64 / sample_rate.max(1) as f64;
+        if let Some(last) = self.damaged.last_mut() {
+            if last.end_frame == start_frame && last.reason == reason {
+                last.end_frame = end_frame;
+                last.end = seconds(end_frame);
+                return;
+            }
+        }
+        self.damaged.push(DamagedRange {
+            start_frame,
+            end_frame,
+            start: seconds(start_frame),
+            end: seconds(end_frame),
+            reason: reason.to_string(),
+        });
+    }
+}
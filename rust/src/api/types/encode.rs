@@ -0,0 +1,31 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportContainer {
+    Wav,
+    Flac,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportSampleFormat {
+    I16,
+    I24,
+    /// WAV only; FLAC stores integers.
+    F32,
+}
+
+impl ExportSampleFormat {
+    pub fn bits(&self) -> u32 {
+        match self {
+            ExportSampleFormat::I16 => 16,
+            ExportSampleFormat::I24 => 24,
+            ExportSampleFormat::F32 => 32,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportOptions {
+    pub container: ExportContainer,
+    pub sample_format: ExportSampleFormat,
+    /// Add TPDF dither when quantizing to an integer format.
+    pub dither: bool,
+}
@@ -1,9 +1,23 @@
-use crate::api::types::chart::{CommunicatorChart, DataType};
+use crate::api::types::{
+    chart::{ChartAxis, ChartPayload, ChartRegion, CommunicatorChart, DataType},
+    decode::DecodeProgress,
+};
 
+/// `viewport` is the id of the viewport an event is for. `seq` numbers the
+/// views of that viewport a chart's points were computed for, in the order
+/// they were requested. A frame older than one already received is stale and
+/// can be dropped.
+///
+/// Edits name in `base_seq` the points they apply to. An edit whose
+/// `base_seq` is not the `seq` of the points held for the chart, because a
+/// frame was dropped, cannot be applied; ask for the whole chart again with
+/// `resend_chart` instead.
 #[derive(Clone, Debug)]
 pub enum ChartEvent {
     AddChart {
+        viewport: String,
         chart: CommunicatorChart,
+        seq: u64,
     },
     RemoveChart {
         key: String,
@@ -12,12 +26,65 @@ pub enum ChartEvent {
     UpdateAllCharts{
         charts: Vec<CommunicatorChart>,
     },
+    /// A whole chart at a fixed resolution, for the overview strip. Sent
+    /// only when it changes, not when the view moves.
+    UpdateOverview {
+        viewport: String,
+        chart: CommunicatorChart,
+    },
     RemoveAllCharts,
     UpdateMaxIndex{
+        viewport: String,
         max_index:f32,
     },
-    UpdateYRange{
-        min_y:f32,
-        max_y:f32,
+    /// The y axes of the viewport, each fitted to the points its charts
+    /// show of the current view. Replaces the axes sent before.
+    UpdateAxes {
+        viewport: String,
+        axes: Vec<ChartAxis>,
+    },
+    UpdateRegions {
+        key: String,
+        regions: Vec<ChartRegion>,
+    },
+    /// Points that follow the last one already sent for the chart.
+    AppendPoints {
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        points: ChartPayload,
+        base_seq: u64,
+        seq: u64,
+    },
+    /// Replaces points `start..end` of those already sent for the chart.
+    ReplaceRange {
+        viewport: String,
+        key: String,
+        data_type: DataType,
+        start: usize,
+        end: usize,
+        points: ChartPayload,
+        base_seq: u64,
+        seq: u64,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub enum DecodeEvent {
+    Progress {
+        key: String,
+        progress: DecodeProgress,
+    },
+    Finished {
+        key: String,
+        progress: DecodeProgress,
+    },
+    Cancelled {
+        key: String,
+        progress: DecodeProgress,
+    },
+    Failed {
+        key: String,
+        message: String,
     },
 }
@@ -4,3 +4,30 @@ pub struct File {
     pub file_path: String,
     pub bytes: Arc<Vec<u8>>,
 }
+
+/// Where a decoder reads the encoded audio from.
+#[derive(Clone)]
+pub enum AudioSource {
+    /// The whole file, already in memory.
+    Bytes(Vec<u8>),
+    /// A local file the decoder opens and streams itself, so large files never
+    /// have to cross the bridge or be held in memory.
+    Path(String),
+}
+
+/// An [`AudioSource`] with the bytes shared rather than owned, so the same
+/// file can be decoded more than once, e.g. once per track, without copying.
+#[derive(Clone)]
+pub enum SharedSource {
+    Bytes(Arc<[u8]>),
+    Path(String),
+}
+
+impl From<AudioSource> for SharedSource {
+    fn from(source: AudioSource) -> Self {
+        match source {
+            AudioSource::Bytes(data) => SharedSource::Bytes(data.into()),
+            AudioSource::Path(path) => SharedSource::Path(path),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::api::types::chart::DataType;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioMemoryUsage {
+    pub key: String,
+    pub bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartMemoryUsage {
+    pub key: String,
+    pub data_type: DataType,
+    pub bytes: u64,
+}
+
+/// Bytes currently held in memory, most recently used first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryUsage {
+    pub audio: Vec<AudioMemoryUsage>,
+    pub charts: Vec<ChartMemoryUsage>,
+    pub audio_bytes: u64,
+    pub chart_bytes: u64,
+    /// `None` when unlimited.
+    pub audio_budget: Option<u64>,
+    pub chart_budget: Option<u64>,
+}
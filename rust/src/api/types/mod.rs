@@ -1,6 +1,10 @@
 pub mod audio;
 pub mod chart;
 pub mod config;
+pub mod decode;
+pub mod encode;
 pub mod error;
 pub mod events;
 pub mod file;
+pub mod memory;
+pub mod samples;
@@ -0,0 +1,219 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::api::util::{
+    half::{f16_bits_to_f32, f32_to_f16_bits},
+    hash::hash_words,
+};
+
+/// How decoded samples are kept in memory.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SampleStorage {
+    /// 4 bytes per sample, lossless.
+    #[default]
+    F32,
+    /// 2 bytes per sample, scaled to the peak so quiet files keep their
+    /// resolution.
+    I16,
+    /// 2 bytes per sample with about three significant digits at any level.
+    F16,
+}
+
+/// Sample values in one of the [`SampleStorage`] formats. Readers get `f32`
+/// either way; compact formats are converted as they are read.
+#[derive(Clone, Debug)]
+pub enum SampleBuffer {
+    F32(Arc<Vec<f32>>),
+    /// Sample `i` is `samples[i] as f32 * scale`.
+    I16 {
+        samples: Arc<Vec<i16>>,
+        scale: f32,
+    },
+    /// Half precision bit patterns; sample `i` is their value times `scale`.
+    F16 {
+        samples: Arc<Vec<u16>>,
+        scale: f32,
+    },
+}
+
+impl SampleStorage {
+    /// Bits each sample is kept in.
+    pub fn bits(&self) -> u32 {
+        match self {
+            SampleStorage::F32 => 32,
+            SampleStorage::I16 | SampleStorage::F16 => 16,
+        }
+    }
+}
+
+impl SampleBuffer {
+    pub fn from_f32(samples: Vec<f32>, storage: SampleStorage) -> Self {
+        let peak = || {
+            samples
+                .iter()
+                .filter(|sample| sample.is_finite())
+                .fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+        };
+        match storage {
+            SampleStorage::F32 => SampleBuffer::F32(Arc::new(samples)),
+            SampleStorage::I16 => {
+                let scale = peak().max(f32::MIN_POSITIVE) / 32767.0;
+                let samples = samples
+                    .iter()
+                    .map(|&sample| match sample.is_finite() {
+                        true => (sample / scale).round().clamp(-32767.0, 32767.0) as i16,
+                        false => 0,
+                    })
+                    .collect();
+                SampleBuffer::I16 {
+                    samples: Arc::new(samples),
+                    scale,
+                }
+            }
+            SampleStorage::F16 => {
+                // Only needed for values past the half precision range.
+                let scale = (peak() / 65504.0).max(1.0);
+                let samples = samples
+                    .iter()
+                    .map(|&sample| f32_to_f16_bits(sample / scale))
+                    .collect();
+                SampleBuffer::F16 {
+                    samples: Arc::new(samples),
+                    scale,
+                }
+            }
+        }
+    }
+
+    pub fn storage(&self) -> SampleStorage {
+        match self {
+            SampleBuffer::F32(_) => SampleStorage::F32,
+            SampleBuffer::I16 { .. } => SampleStorage::I16,
+            SampleBuffer::F16 { .. } => SampleStorage::F16,
+        }
+    }
+
+    /// The same samples in `storage`, shared rather than copied if they
+    /// already are.
+    pub fn convert(&self, storage: SampleStorage) -> Self {
+        if self.storage() == storage {
+            return self.clone();
+        }
+        SampleBuffer::from_f32(self.to_f32().into_owned(), storage)
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            SampleBuffer::F32(samples) => samples.len(),
+            SampleBuffer::I16 { samples, .. } => samples.len(),
+            SampleBuffer::F16 { samples, .. } => samples.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> f32 {
+        match self {
+            SampleBuffer::F32(samples) => samples[index],
+            SampleBuffer::I16 { samples, scale } => samples[index] as f32 * scale,
+            SampleBuffer::F16 { samples, scale } => f16_bits_to_f32(samples[index]) * scale,
+        }
+    }
+
+    /// Samples `start..end` as `f32`, borrowed when no conversion is needed.
+    pub fn slice(&self, start: usize, end: usize) -> Cow<'_, [f32]> {
+        match self {
+            SampleBuffer::F32(samples) => Cow::Borrowed(&samples[start..end]),
+            SampleBuffer::I16 { samples, scale } => Cow::Owned(
+                samples[start..end]
+                    .iter()
+                    .map(|&sample| sample as f32 * scale)
+                    .collect(),
+            ),
+            SampleBuffer::F16 { samples, scale } => Cow::Owned(
+                samples[start..end]
+                    .iter()
+                    .map(|&bits| f16_bits_to_f32(bits) * scale)
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn to_f32(&self) -> Cow<'_, [f32]> {
+        self.slice(0, self.len())
+    }
+
+    /// Parallel counterpart of `slice::par_chunks`, converting each chunk on
+    /// its own so a compact buffer is never expanded whole.
+    pub fn par_chunks(
+        &self,
+        chunk_size: usize,
+    ) -> impl IndexedParallelIterator<Item = Cow<'_, [f32]>> {
+        let len = self.len();
+        (0..len.div_ceil(chunk_size))
+            .into_par_iter()
+            .map(move |index| {
+                let start = index * chunk_size;
+                self.slice(start, (start + chunk_size).min(len))
+            })
+    }
+
+    /// `(min, max)` of the samples, or `(f32::MAX, f32::MIN)` when empty.
+    pub fn min_max(&self) -> (f32, f32) {
+        self.par_chunks(1 << 16)
+            .map(|chunk| {
+                chunk.iter().fold((f32::MAX, f32::MIN), |(min, max), &y| {
+                    (min.min(y), max.max(y))
+                })
+            })
+            .reduce(
+                || (f32::MAX, f32::MIN),
+                |(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)),
+            )
+    }
+
+    /// Hash of the stored representation, so the same audio kept in two
+    /// formats hashes differently.
+    pub fn content_hash(&self, seed: u64) -> u64 {
+        match self {
+            SampleBuffer::F32(samples) => {
+                hash_words(seed, samples.iter().map(|sample| sample.to_bits() as u64))
+            }
+            SampleBuffer::I16 { samples, scale } => hash_words(
+                seed ^ scale.to_bits() as u64,
+                samples.iter().map(|&sample| sample as u16 as u64),
+            ),
+            SampleBuffer::F16 { samples, scale } => hash_words(
+                !seed ^ scale.to_bits() as u64,
+                samples.iter().map(|&bits| bits as u64),
+            ),
+        }
+    }
+
+    pub fn memory_bytes(&self) -> u64 {
+        let bytes = match self {
+            SampleBuffer::F32(samples) => samples.capacity() * std::mem::size_of::<f32>(),
+            SampleBuffer::I16 { samples, .. } => samples.capacity() * std::mem::size_of::<i16>(),
+            SampleBuffer::F16 { samples, .. } => samples.capacity() * std::mem::size_of::<u16>(),
+        };
+        bytes as u64
+    }
+
+    /// Whether no other buffer shares these samples.
+    pub fn is_unique(&self) -> bool {
+        match self {
+            SampleBuffer::F32(samples) => Arc::strong_count(samples) == 1,
+            SampleBuffer::I16 { samples, .. } => Arc::strong_count(samples) == 1,
+            SampleBuffer::F16 { samples, .. } => Arc::strong_count(samples) == 1,
+        }
+    }
+}
+
+impl From<Vec<f32>> for SampleBuffer {
+    fn from(samples: Vec<f32>) -> Self {
+        SampleBuffer::F32(Arc::new(samples))
+    }
+}
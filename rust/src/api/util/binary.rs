@@ -0,0 +1,237 @@
+//! Little-endian primitives for the on-disk storage formats.
+
+use std::io::{self, Read, Write};
+
+use crate::api::{types::error::AppError, util::hash::Fnv1a64};
+
+/// Samples converted per write or read.
+const CHUNK_VALUES: usize = 1 << 16;
+/// Most values reserved up front when reading, however many a file claims;
+/// larger buffers grow as the values are actually read.
+const MAX_RESERVED_VALUES: usize = 1 << 24;
+
+/// Passes writes through while hashing them, so a streamed file can end with
+/// a checksum of everything before it.
+pub struct HashingWriter<W> {
+    inner: W,
+    hasher: Fnv1a64,
+}
+
+impl<W: Write> HashingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Fnv1a64::new(),
+        }
+    }
+
+    pub fn checksum(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads a file of known length while hashing it. Lengths and counts taken
+/// from the file are checked against what is left of it before anything is
+/// allocated for them, so a damaged header fails instead of exhausting
+/// memory.
+pub struct CheckedReader<R> {
+    inner: R,
+    remaining: u64,
+    hasher: Fnv1a64,
+}
+
+impl<R: Read> CheckedReader<R> {
+    pub fn new(inner: R, len: u64) -> Self {
+        Self {
+            inner,
+            remaining: len,
+            hasher: Fnv1a64::new(),
+        }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Fails unless at least `len` bytes are left.
+    pub fn expect(&self, len: u64) -> Result<(), AppError> {
+        if len > self.remaining {
+            return Err(AppError::Format(format!(
+                "Expected {} more bytes, only {} left",
+                len, self.remaining
+            )));
+        }
+        Ok(())
+    }
+
+    /// Hash of everything read so far.
+    pub fn checksum(&self) -> u64 {
+        self.hasher.finish()
+    }
+}
+
+impl<R: Read> Read for CheckedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf
+            .len()
+            .min(self.remaining.try_into().unwrap_or(usize::MAX));
+        let read = self.inner.read(&mut buf[..len])?;
+        self.hasher.update(&buf[..read]);
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+pub fn write_u8(writer: &mut impl Write, value: u8) -> Result<(), AppError> {
+    writer.write_all(&[value])?;
+    Ok(())
+}
+
+pub fn write_u32(writer: &mut impl Write, value: u32) -> Result<(), AppError> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+pub fn write_u64(writer: &mut impl Write, value: u64) -> Result<(), AppError> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+pub fn write_f64(writer: &mut impl Write, value: f64) -> Result<(), AppError> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+pub fn write_str(writer: &mut impl Write, value: &str) -> Result<(), AppError> {
+    write_u32(writer, value.len() as u32)?;
+    writer.write_all(value.as_bytes())?;
+    Ok(())
+}
+
+/// A presence byte followed by the value when there is one.
+pub fn write_opt_u32(writer: &mut impl Write, value: Option<u32>) -> Result<(), AppError> {
+    match value {
+        Some(value) => {
+            write_u8(writer, 1)?;
+            write_u32(writer, value)
+        }
+        None => write_u8(writer, 0),
+    }
+}
+
+/// Writes samples in bounded chunks so large buffers are not copied at once.
+pub fn write_f32s(writer: &mut impl Write, values: &[f32]) -> Result<(), AppError> {
+    let mut bytes = Vec::with_capacity(CHUNK_VALUES.min(values.len()) * 4);
+    for chunk in values.chunks(CHUNK_VALUES) {
+        bytes.clear();
+        bytes.extend(chunk.iter().flat_map(|value| value.to_le_bytes()));
+        writer.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+pub fn write_u16s(
+    writer: &mut impl Write,
+    values: impl Iterator<Item = u16>,
+) -> Result<(), AppError> {
+    let mut values = values.peekable();
+    let mut bytes = Vec::with_capacity(CHUNK_VALUES * 2);
+    while values.peek().is_some() {
+        bytes.clear();
+        bytes.extend(
+            values
+                .by_ref()
+                .take(CHUNK_VALUES)
+                .flat_map(|value| value.to_le_bytes()),
+        );
+        writer.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+pub fn read_u8(reader: &mut impl Read) -> Result<u8, AppError> {
+    let mut bytes = [0u8; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+pub fn read_u32(reader: &mut impl Read) -> Result<u32, AppError> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub fn read_u64(reader: &mut impl Read) -> Result<u64, AppError> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn read_f64(reader: &mut impl Read) -> Result<f64, AppError> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+pub fn read_str(reader: &mut CheckedReader<impl Read>) -> Result<String, AppError> {
+    let len = read_u32(reader)?;
+    reader.expect(len as u64)?;
+    let mut bytes = vec![0u8; len as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|e| AppError::Format(format!("Invalid string: {}", e)))
+}
+
+pub fn read_opt_u32(reader: &mut impl Read) -> Result<Option<u32>, AppError> {
+    match read_u8(reader)? {
+        0 => Ok(None),
+        _ => Ok(Some(read_u32(reader)?)),
+    }
+}
+
+pub fn read_f32s(reader: &mut impl Read, count: usize) -> Result<Vec<f32>, AppError> {
+    let mut values = Vec::with_capacity(count.min(MAX_RESERVED_VALUES));
+    let mut bytes = vec![0u8; CHUNK_VALUES.min(count) * 4];
+    while values.len() < count {
+        let take = (count - values.len()).min(CHUNK_VALUES);
+        let chunk = &mut bytes[..take * 4];
+        reader.read_exact(chunk)?;
+        values.extend(
+            chunk
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+        );
+    }
+    Ok(values)
+}
+
+pub fn read_u16s(reader: &mut impl Read, count: usize) -> Result<Vec<u16>, AppError> {
+    let mut values = Vec::with_capacity(count.min(MAX_RESERVED_VALUES));
+    let mut bytes = vec![0u8; CHUNK_VALUES.min(count) * 2];
+    while values.len() < count {
+        let take = (count - values.len()).min(CHUNK_VALUES);
+        let chunk = &mut bytes[..take * 2];
+        reader.read_exact(chunk)?;
+        values.extend(
+            chunk
+                .chunks_exact(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]])),
+        );
+    }
+    Ok(values)
+}
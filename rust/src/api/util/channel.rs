@@ -0,0 +1,93 @@
+use std::{
+    collections::VecDeque,
+    future::poll_fn,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+/// A channel from worker threads to async code, whose receiver waits without
+/// blocking the thread it is polled on.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        queue: VecDeque::new(),
+        waker: None,
+        senders: 1,
+    }));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    senders: usize,
+}
+
+pub struct Sender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.queue.push_back(value);
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.senders -= 1;
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    /// The next value sent, or `None` once every sender is gone and all
+    /// values have been received.
+    pub async fn recv(&mut self) -> Option<T> {
+        poll_fn(|context| {
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            match shared.queue.pop_front() {
+                Some(value) => Poll::Ready(Some(value)),
+                None if shared.senders == 0 => Poll::Ready(None),
+                None => {
+                    shared.waker = Some(context.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
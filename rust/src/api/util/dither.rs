@@ -0,0 +1,55 @@
+/// Quantizes `[-1.0, 1.0]` samples to signed integers of `bits` width,
+/// optionally with triangular (TPDF) dither of one LSB peak. Blocks fed in
+/// turn come out the same as the whole signal would at once.
+pub struct Quantizer {
+    scale: f64,
+    dither: bool,
+    rng: XorShift,
+}
+
+impl Quantizer {
+    pub fn new(bits: u32, dither: bool) -> Self {
+        Self {
+            scale: (1i64 << (bits - 1)) as f64,
+            dither,
+            rng: XorShift::new(0x9e37_79b9_7f4a_7c15),
+        }
+    }
+
+    pub fn quantize(&mut self, samples: &[f32]) -> Vec<i32> {
+        let max = self.scale - 1.0;
+        samples
+            .iter()
+            .map(|&sample| {
+                let noise = if self.dither {
+                    self.rng.next_unit() - self.rng.next_unit()
+                } else {
+                    0.0
+                };
+                (sample as f64 * self.scale + noise)
+                    .round()
+                    .clamp(-self.scale, max) as i32
+            })
+            .collect()
+    }
+}
+
+/// Small deterministic generator; dither only needs decorrelated noise, and
+/// a fixed seed keeps exports reproducible.
+struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Uniform in `[0.0, 1.0)`.
+    fn next_unit(&mut self) -> f64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
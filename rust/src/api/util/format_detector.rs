@@ -0,0 +1,185 @@
+use std::fs::File;
+use std::io::{Cursor, Read};
+
+use symphonia::core::formats::{FormatOptions, FormatReader};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::default::formats::{
+    AdtsReader, AiffReader, CafReader, FlacReader, IsoMp4Reader, MkvReader, MpaReader, OggReader,
+    WavReader,
+};
+
+use crate::api::types::{error::AppError, file::SharedSource};
+
+/// Bytes read from the start of a source for detection.
+const HEADER_LEN: usize = 64 * 1024;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedFormat {
+    /// Container name, e.g. `"wav"`, `"ogg"` or `"mp4"`.
+    pub container: String,
+    /// Usual file extension for the container.
+    pub extension: String,
+    /// Codec when it can be told from the header alone.
+    pub codec: Option<String>,
+}
+
+impl DetectedFormat {
+    fn new(container: &str, extension: &str, codec: Option<&str>) -> Self {
+        Self {
+            container: container.to_string(),
+            extension: extension.to_string(),
+            codec: codec.map(str::to_string),
+        }
+    }
+}
+
+pub trait FormatDetector {
+    fn detect(&self, header: &[u8]) -> Option<DetectedFormat>;
+}
+
+/// Recognises containers by their magic bytes.
+pub struct MagicFormatDetector {}
+
+impl FormatDetector for MagicFormatDetector {
+    fn detect(&self, header: &[u8]) -> Option<DetectedFormat> {
+        let at =
+            |offset: usize, magic: &[u8]| header.get(offset..offset + magic.len()) == Some(magic);
+
+        if (at(0, b"RIFF") || at(0, b"RF64") || at(0, b"BW64")) && at(8, b"WAVE") {
+            return Some(DetectedFormat::new("wav", "wav", wav_codec(header)));
+        }
+        if at(0, b"FORM") && (at(8, b"AIFF") || at(8, b"AIFC")) {
+            return Some(DetectedFormat::new("aiff", "aiff", None));
+        }
+        if at(0, b"fLaC") {
+            return Some(DetectedFormat::new("flac", "flac", Some("flac")));
+        }
+        if at(0, b"OggS") {
+            let codec = if at(28, b"\x01vorbis") {
+                Some("vorbis")
+            } else if at(28, b"OpusHead") {
+                Some("opus")
+            } else if at(28, b"\x7fFLAC") {
+                Some("flac")
+            } else {
+                None
+            };
+            return Some(DetectedFormat::new("ogg", "ogg", codec));
+        }
+        if at(0, b"caff") {
+            return Some(DetectedFormat::new("caf", "caf", None));
+        }
+        if at(4, b"ftyp") {
+            return Some(DetectedFormat::new("mp4", "m4a", None));
+        }
+        if at(0, &[0x1a, 0x45, 0xdf, 0xa3]) {
+            let is_webm = header[..header.len().min(64)]
+                .windows(4)
+                .any(|w| w == b"webm");
+            return Some(if is_webm {
+                DetectedFormat::new("webm", "webm", None)
+            } else {
+                DetectedFormat::new("mkv", "mkv", None)
+            });
+        }
+        if at(0, b"ID3") {
+            return Some(DetectedFormat::new("mpa", "mp3", Some("mp3")));
+        }
+        match header {
+            [0xff, b1, ..] if b1 & 0xf6 == 0xf0 => {
+                Some(DetectedFormat::new("adts", "aac", Some("aac")))
+            }
+            [0xff, b1, ..] if b1 & 0xe0 == 0xe0 => {
+                let codec = match (b1 >> 1) & 0x03 {
+                    0x01 => "mp3",
+                    0x02 => "mp2",
+                    _ => "mp1",
+                };
+                Some(DetectedFormat::new("mpa", codec, Some(codec)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Codec from the format tag of a canonical `fmt ` chunk.
+fn wav_codec(header: &[u8]) -> Option<&'static str> {
+    if header.get(12..16) != Some(b"fmt ") {
+        return None;
+    }
+    let tag = u16::from_le_bytes([*header.get(20)?, *header.get(21)?]);
+    match tag {
+        0x0001 => Some("pcm"),
+        0x0003 => Some("pcm_float"),
+        0x0006 => Some("pcm_alaw"),
+        0x0007 => Some("pcm_mulaw"),
+        0x0055 => Some("mp3"),
+        _ => None,
+    }
+}
+
+/// Tries every Symphonia format reader on the header in turn, for streams
+/// whose magic bytes are missing or unfamiliar.
+pub struct ProbingFormatDetector {}
+
+impl FormatDetector for ProbingFormatDetector {
+    fn detect(&self, header: &[u8]) -> Option<DetectedFormat> {
+        try_reader::<WavReader>(header, "wav", "wav")
+            .or_else(|| try_reader::<AiffReader>(header, "aiff", "aiff"))
+            .or_else(|| try_reader::<FlacReader>(header, "flac", "flac"))
+            .or_else(|| try_reader::<OggReader>(header, "ogg", "ogg"))
+            .or_else(|| try_reader::<CafReader>(header, "caf", "caf"))
+            .or_else(|| try_reader::<IsoMp4Reader>(header, "mp4", "m4a"))
+            .or_else(|| try_reader::<MkvReader>(header, "mkv", "mkv"))
+            .or_else(|| try_reader::<AdtsReader>(header, "adts", "aac"))
+            .or_else(|| try_reader::<MpaReader>(header, "mpa", "mp3"))
+    }
+}
+
+fn try_reader<R: FormatReader>(
+    header: &[u8],
+    container: &str,
+    extension: &str,
+) -> Option<DetectedFormat> {
+    let source = Box::new(Cursor::new(header.to_vec()));
+    let mss = MediaSourceStream::new(source, Default::default());
+    let reader = R::try_new(mss, &FormatOptions::default()).ok()?;
+    let codec = reader
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.sample_rate.is_some())
+        .and_then(|t| symphonia::default::get_codecs().get_codec(t.codec_params.codec))
+        .map(|d| d.short_name);
+    Some(DetectedFormat::new(container, extension, codec))
+}
+
+/// Magic bytes first, then every reader. When the magic alone does not tell
+/// the codec, it is taken from the reader for the same container.
+pub fn detect_format(source: &SharedSource) -> Result<DetectedFormat, AppError> {
+    let header = read_header(source)?;
+    let probe = || ProbingFormatDetector {}.detect(&header);
+    match (MagicFormatDetector {}).detect(&header) {
+        Some(mut magic) => {
+            if magic.codec.is_none() {
+                magic.codec = probe()
+                    .filter(|probed| probed.container == magic.container)
+                    .and_then(|probed| probed.codec);
+            }
+            Ok(magic)
+        }
+        None => probe().ok_or_else(|| AppError::Format("Unrecognized audio format".to_string())),
+    }
+}
+
+pub fn read_header(source: &SharedSource) -> Result<Vec<u8>, AppError> {
+    match source {
+        SharedSource::Bytes(data) => Ok(data[..data.len().min(HEADER_LEN)].to_vec()),
+        SharedSource::Path(path) => {
+            let file = File::open(path)
+                .map_err(|e| AppError::Io(format!("Failed to open {}: {}", path, e)))?;
+            let mut header = Vec::with_capacity(HEADER_LEN);
+            file.take(HEADER_LEN as u64).read_to_end(&mut header)?;
+            Ok(header)
+        }
+    }
+}
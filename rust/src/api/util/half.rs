@@ -0,0 +1,140 @@
+//! IEEE 754 half precision conversions, rounding to nearest even.
+
+pub fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        let nan = if mantissa != 0 { 0x0200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if half_exponent <= 0 {
+        // Subnormal in half precision, or too small to represent at all.
+        if half_exponent < -10 {
+            return sign;
+        }
+        let full = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        return sign | round_shift(full, shift) as u16;
+    }
+    // A carry out of the mantissa correctly bumps the exponent, up to infinity.
+    let out = ((half_exponent as u32) << 10) + round_shift(mantissa, 13);
+    sign | out as u16
+}
+
+pub fn f16_bits_to_f32(bits: u16) -> f32 {
+    let negative = bits & 0x8000 != 0;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x03ff) as u32;
+    let sign = (negative as u32) << 31;
+
+    let out = match exponent {
+        0 => {
+            let value = mantissa as f32 / (1 << 24) as f32;
+            return if negative { -value } else { value };
+        }
+        0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 112) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(out)
+}
+
+fn round_shift(value: u32, shift: u32) -> u32 {
+    let halfway = 1 << (shift - 1);
+    let truncated = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_known_values() {
+        for (value, bits) in [
+            (0.0, 0x0000),
+            (-0.0, 0x8000),
+            (1.0, 0x3c00),
+            (-2.0, 0xc000),
+            (0.5, 0x3800),
+            (65504.0, 0x7bff),
+            // Smallest normal, then the largest and smallest subnormals.
+            (2f32.powi(-14), 0x0400),
+            (1023.0 * 2f32.powi(-24), 0x03ff),
+            (2f32.powi(-24), 0x0001),
+            (-(2f32.powi(-24)), 0x8001),
+        ] {
+            assert_eq!(f32_to_f16_bits(value), bits, "{}", value);
+            assert_eq!(
+                f16_bits_to_f32(bits).to_bits(),
+                value.to_bits(),
+                "{:#06x}",
+                bits
+            );
+        }
+    }
+
+    #[test]
+    fn overflows_to_infinity() {
+        assert_eq!(f32_to_f16_bits(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_f16_bits(f32::NEG_INFINITY), 0xfc00);
+        assert_eq!(f32_to_f16_bits(1.0e6), 0x7c00);
+        assert_eq!(f32_to_f16_bits(-1.0e6), 0xfc00);
+        // Halfway between the largest finite half and the next step rounds
+        // up, out of range; just below it stays finite.
+        assert_eq!(f32_to_f16_bits(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16_bits(65519.99), 0x7bff);
+        assert_eq!(f16_bits_to_f32(0x7c00), f32::INFINITY);
+        assert_eq!(f16_bits_to_f32(0xfc00), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn keeps_nan() {
+        let bits = f32_to_f16_bits(f32::NAN);
+        assert_eq!(bits & 0x7c00, 0x7c00);
+        assert_ne!(bits & 0x03ff, 0);
+        assert!(f16_bits_to_f32(bits).is_nan());
+        assert!(f16_bits_to_f32(0x7e00).is_nan());
+        assert!(f16_bits_to_f32(0xfc01).is_nan());
+    }
+
+    #[test]
+    fn rounds_to_nearest_even() {
+        let ulp = 2f32.powi(-10);
+        // Ties go to the even mantissa, either way.
+        assert_eq!(f32_to_f16_bits(1.0 + ulp / 2.0), 0x3c00);
+        assert_eq!(f32_to_f16_bits(1.0 + 3.0 * ulp / 2.0), 0x3c02);
+        // Anything past the tie rounds up.
+        assert_eq!(f32_to_f16_bits(1.0 + ulp / 2.0 + 2f32.powi(-20)), 0x3c01);
+        // A carry out of the mantissa moves to the next exponent.
+        assert_eq!(f32_to_f16_bits(2.0 - 2f32.powi(-12)), 0x4000);
+        // The same among subnormals: half the smallest one is a tie with
+        // zero, a little more rounds up to it.
+        assert_eq!(f32_to_f16_bits(2f32.powi(-25)), 0x0000);
+        assert_eq!(f32_to_f16_bits(3.0 * 2f32.powi(-26)), 0x0001);
+        assert_eq!(f32_to_f16_bits(3.0 * 2f32.powi(-25)), 0x0002);
+        assert_eq!(f32_to_f16_bits(2f32.powi(-26)), 0x0000);
+    }
+
+    #[test]
+    fn every_half_survives_a_round_trip() {
+        for bits in 0..=u16::MAX {
+            let value = f16_bits_to_f32(bits);
+            if value.is_nan() {
+                continue;
+            }
+            assert_eq!(f32_to_f16_bits(value), bits, "{:#06x}", bits);
+        }
+    }
+}
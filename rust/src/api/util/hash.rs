@@ -0,0 +1,46 @@
+/// 64-bit FNV-1a. Not cryptographic; used to derive file names and detect
+/// changed content.
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a64::new();
+    hasher.update(bytes);
+    hasher.finish()
+}
+
+/// [`fnv1a64`] over bytes that arrive in pieces, such as a file streamed to
+/// or from disk.
+pub struct Fnv1a64 {
+    hash: u64,
+}
+
+impl Fnv1a64 {
+    pub fn new() -> Self {
+        Self {
+            hash: 0xcbf2_9ce4_8422_2325,
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.hash ^= byte as u64;
+            self.hash = self.hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl Default for Fnv1a64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// FNV-1a over whole words rather than bytes, which is several times fewer
+/// steps for the long sample buffers it is used on.
+pub fn hash_words(seed: u64, words: impl Iterator<Item = u64>) -> u64 {
+    words.fold(seed, |hash, word| {
+        (hash ^ word).wrapping_mul(0x0100_0000_01b3)
+    })
+}
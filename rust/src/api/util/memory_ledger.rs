@@ -0,0 +1,119 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Byte accounting for an in-memory store, with least-recently-used eviction
+/// once the total goes over budget.
+///
+/// The ledger only tracks keys; the store removes whatever keys it returns.
+pub struct MemoryLedger<K> {
+    budget: Option<u64>,
+    entries: HashMap<K, LedgerEntry>,
+    total: u64,
+    clock: u64,
+}
+
+struct LedgerEntry {
+    bytes: u64,
+    last_used: u64,
+}
+
+impl<K: Clone + Eq + Hash> Default for MemoryLedger<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash> MemoryLedger<K> {
+    pub fn new() -> Self {
+        Self {
+            budget: None,
+            entries: HashMap::new(),
+            total: 0,
+            clock: 0,
+        }
+    }
+
+    pub fn budget(&self) -> Option<u64> {
+        self.budget
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the keys evicted to fit the new budget.
+    pub fn set_budget(&mut self, budget: Option<u64>) -> Vec<K> {
+        self.budget = budget;
+        self.evict(None)
+    }
+
+    /// Records `key` as just used with a size of `bytes`, replacing its old
+    /// size. Returns the keys evicted to make room; `key` itself is kept even
+    /// when it alone is over budget, since the caller is about to use it.
+    pub fn insert(&mut self, key: K, bytes: u64) -> Vec<K> {
+        self.clock += 1;
+        let entry = LedgerEntry {
+            bytes,
+            last_used: self.clock,
+        };
+        if let Some(previous) = self.entries.insert(key.clone(), entry) {
+            self.total -= previous.bytes;
+        }
+        self.total += bytes;
+        self.evict(Some(&key))
+    }
+
+    /// Replaces the size of a tracked `key` without counting it as used, for
+    /// entries whose size changes while they sit in the store. Returns the
+    /// keys evicted if the total no longer fits, which may include `key`.
+    pub fn resize(&mut self, key: &K, bytes: u64) -> Vec<K> {
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.total = self.total - entry.bytes + bytes;
+            entry.bytes = bytes;
+        }
+        self.evict(None)
+    }
+
+    pub fn touch(&mut self, key: &K) {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.last_used = self.clock;
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.total -= entry.bytes;
+        }
+    }
+
+    /// Every tracked key with its size, most recently used first.
+    pub fn usage(&self) -> Vec<(K, u64)> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.last_used));
+        entries
+            .into_iter()
+            .map(|(key, entry)| (key.clone(), entry.bytes))
+            .collect()
+    }
+
+    fn evict(&mut self, keep: Option<&K>) -> Vec<K> {
+        let mut evicted = Vec::new();
+        let Some(budget) = self.budget else {
+            return evicted;
+        };
+        while self.total > budget {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(key, _)| Some(*key) != keep)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            let Some(oldest) = oldest else {
+                break;
+            };
+            self.remove(&oldest);
+            evicted.push(oldest);
+        }
+        evicted
+    }
+}
@@ -0,0 +1,142 @@
+use rayon::{
+    iter::{IntoParallelIterator, ParallelIterator},
+    slice::ParallelSlice,
+};
+
+use crate::api::types::chart::ChartSeries;
+
+/// Points summarised by the finest level. Anything shorter is scanned.
+const BASE_BLOCK: usize = 32;
+
+/// The lowest and highest y of a block of points and where they first occur.
+/// NaN values are skipped; a block of only NaN has NaN for both.
+#[derive(Clone, Copy, Debug)]
+struct Extremes {
+    min: f32,
+    min_index: usize,
+    max: f32,
+    max_index: usize,
+}
+
+impl Extremes {
+    const EMPTY: Extremes = Extremes {
+        min: f32::NAN,
+        min_index: 0,
+        max: f32::NAN,
+        max_index: 0,
+    };
+
+    fn point(y: f32, index: usize) -> Self {
+        Extremes {
+            min: y,
+            min_index: index,
+            max: y,
+            max_index: index,
+        }
+    }
+
+    /// Merges `self` with the block right after it, keeping the earlier
+    /// index on ties.
+    fn then(self, next: Extremes) -> Self {
+        let (min, min_index) = if self.min.is_nan() || next.min < self.min {
+            (next.min, next.min_index)
+        } else {
+            (self.min, self.min_index)
+        };
+        let (max, max_index) = if self.max.is_nan() || next.max > self.max {
+            (next.max, next.max_index)
+        } else {
+            (self.max, self.max_index)
+        };
+        Extremes {
+            min,
+            min_index,
+            max,
+            max_index,
+        }
+    }
+}
+
+/// Min/max summaries of a series at block sizes of 32, 64, 128 and so on,
+/// so the extremes of any index range take a bounded number of lookups.
+///
+/// Level `k` holds one entry per aligned block of `32 << k` points. A range
+/// is covered by the largest aligned blocks that fit, with at most a few
+/// dozen points at its ends scanned directly.
+#[derive(Debug)]
+pub struct MinMaxPyramid {
+    levels: Vec<Vec<Extremes>>,
+}
+
+impl MinMaxPyramid {
+    pub fn build(series: &ChartSeries) -> Self {
+        let len = series.len();
+        let base: Vec<Extremes> = (0..len / BASE_BLOCK)
+            .into_par_iter()
+            .map(|block| {
+                let start = block * BASE_BLOCK;
+                (start..start + BASE_BLOCK).fold(Extremes::EMPTY, |acc, index| {
+                    acc.then(Extremes::point(series.point(index).y, index))
+                })
+            })
+            .collect();
+
+        let mut levels = vec![base];
+        while levels.last().is_some_and(|level| level.len() >= 2) {
+            let below = levels.last().unwrap();
+            let next = below
+                .par_chunks_exact(2)
+                .map(|pair| pair[0].then(pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// Indices of the first lowest and first highest y among points
+    /// `start..end`, matching a left to right scan that starts from the first
+    /// point and only moves on a strictly lower or higher value.
+    pub fn extremes(&self, series: &ChartSeries, start: usize, end: usize) -> (usize, usize) {
+        // A scan starting on NaN never moves, as nothing compares below it.
+        if series.point(start).y.is_nan() {
+            return (start, start);
+        }
+
+        let mut acc = Extremes::EMPTY;
+        let mut index = start;
+        while index < end {
+            match self.largest_block(index, end) {
+                Some((level, size)) => {
+                    acc = acc.then(self.levels[level][index / size]);
+                    index += size;
+                }
+                None => {
+                    acc = acc.then(Extremes::point(series.point(index).y, index));
+                    index += 1;
+                }
+            }
+        }
+        (acc.min_index, acc.max_index)
+    }
+
+    /// The coarsest level with a block starting at `index` and ending by
+    /// `end`, with its block size.
+    fn largest_block(&self, index: usize, end: usize) -> Option<(usize, usize)> {
+        let mut found = None;
+        for (level, blocks) in self.levels.iter().enumerate() {
+            let size = BASE_BLOCK << level;
+            if !index.is_multiple_of(size) || index + size > end || index / size >= blocks.len() {
+                break;
+            }
+            found = Some((level, size));
+        }
+        found
+    }
+
+    pub fn memory_bytes(&self) -> u64 {
+        self.levels
+            .iter()
+            .map(|level| (level.capacity() * std::mem::size_of::<Extremes>()) as u64)
+            .sum()
+    }
+}
@@ -1,2 +1,11 @@
-pub mod format_getter;
-pub mod get_min_max;
\ No newline at end of file
+pub mod binary;
+pub mod block_on;
+pub mod channel;
+pub mod dither;
+pub mod format_detector;
+pub mod get_min_max;
+pub mod half;
+pub mod hash;
+pub mod memory_ledger;
+pub mod min_max_pyramid;
+pub mod point_diff;
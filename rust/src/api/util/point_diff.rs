@@ -0,0 +1,179 @@
+use crate::api::types::chart::Point;
+
+/// Replaces points `start..end` of a list with `points`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointEdit {
+    pub start: usize,
+    pub end: usize,
+    /// Index of the first of `points` in the new list.
+    pub new_start: usize,
+    pub points: Vec<Point>,
+}
+
+/// Edits that turn `old` into `new`, each applying to the list the ones
+/// before it left. The longest run of points both share stays in place, so
+/// panning only sends the ends that came into or went out of view. `None`
+/// when they share no point, so `new` is best sent whole; empty when they
+/// are the same.
+///
+/// Both lists must be ordered by x, as down-sampled charts are.
+pub fn diff_points(old: &[Point], new: &[Point]) -> Option<Vec<PointEdit>> {
+    let (old_start, new_start, len) = longest_shared_run(old, new)?;
+
+    let mut edits = Vec::with_capacity(2);
+    // The tail comes first so the head's indices are still those of `old`.
+    let tail = PointEdit {
+        start: old_start + len,
+        end: old.len(),
+        new_start: new_start + len,
+        points: new[new_start + len..].to_vec(),
+    };
+    let head = PointEdit {
+        start: 0,
+        end: old_start,
+        new_start: 0,
+        points: new[..new_start].to_vec(),
+    };
+    for edit in [tail, head] {
+        if edit.start < edit.end || !edit.points.is_empty() {
+            edits.push(edit);
+        }
+    }
+    Some(edits)
+}
+
+/// Start in `old`, start in `new` and length of the longest run of points
+/// the two have in common, found by walking both in x order.
+fn longest_shared_run(old: &[Point], new: &[Point]) -> Option<(usize, usize, usize)> {
+    let mut best: Option<(usize, usize, usize)> = None;
+    let mut run: Option<(usize, usize, usize)> = None;
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i].x < new[j].x {
+            i += 1;
+            continue;
+        }
+        if old[i].x > new[j].x {
+            j += 1;
+            continue;
+        }
+        if same(old[i], new[j]) {
+            run = match run {
+                Some((s, t, len)) if s + len == i && t + len == j => Some((s, t, len + 1)),
+                _ => Some((i, j, 1)),
+            };
+            if run.map(|r| r.2) > best.map(|b| b.2) {
+                best = run;
+            }
+        }
+        i += 1;
+        j += 1;
+    }
+    best
+}
+
+/// Bitwise equality, so the NaN of a gap matches itself.
+fn same(a: Point, b: Point) -> bool {
+    a.x.to_bits() == b.x.to_bits() && a.y.to_bits() == b.y.to_bits()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points at x = `xs` with y derived from x.
+    fn points(xs: std::ops::Range<usize>) -> Vec<Point> {
+        xs.map(|x| Point {
+            x: x as f32,
+            y: (x as f32 * 0.5).sin(),
+        })
+        .collect()
+    }
+
+    /// Applies `edits` to `old` in order, as the client does.
+    fn apply(old: &[Point], edits: &[PointEdit]) -> Vec<Point> {
+        let mut points = old.to_vec();
+        for edit in edits {
+            points.splice(edit.start..edit.end, edit.points.iter().copied());
+        }
+        points
+    }
+
+    #[test]
+    fn points_added_at_the_end_are_appended() {
+        let old = points(0..10);
+        let new = points(0..12);
+        let edits = diff_points(&old, &new).unwrap();
+
+        assert_eq!(
+            edits,
+            vec![PointEdit {
+                start: 10,
+                end: 10,
+                new_start: 10,
+                points: new[10..].to_vec(),
+            }]
+        );
+        assert_eq!(apply(&old, &edits), new);
+    }
+
+    #[test]
+    fn panning_replaces_only_the_ends() {
+        let old = points(0..10);
+        let new = points(3..13);
+        let edits = diff_points(&old, &new).unwrap();
+
+        assert_eq!(
+            edits,
+            vec![
+                PointEdit {
+                    start: 10,
+                    end: 10,
+                    new_start: 7,
+                    points: points(10..13),
+                },
+                PointEdit {
+                    start: 0,
+                    end: 3,
+                    new_start: 0,
+                    points: Vec::new(),
+                },
+            ]
+        );
+        assert_eq!(apply(&old, &edits), new);
+    }
+
+    #[test]
+    fn a_changed_point_is_replaced_with_the_rest_after_it() {
+        let old = points(0..10);
+        let mut new = old.clone();
+        new[5].y = 7.0;
+        let edits = diff_points(&old, &new).unwrap();
+
+        assert_eq!(
+            edits,
+            vec![PointEdit {
+                start: 5,
+                end: 10,
+                new_start: 5,
+                points: new[5..].to_vec(),
+            }]
+        );
+        assert_eq!(apply(&old, &edits), new);
+    }
+
+    #[test]
+    fn lists_sharing_no_point_are_sent_whole() {
+        assert_eq!(diff_points(&points(0..10), &points(20..30)), None);
+        assert_eq!(diff_points(&[], &points(0..3)), None);
+    }
+
+    #[test]
+    fn same_points_need_no_edit_even_with_gaps() {
+        let mut old = points(0..10);
+        old[4].y = f32::NAN;
+        let new = old.clone();
+
+        assert_eq!(diff_points(&old, &new), Some(Vec::new()));
+    }
+}
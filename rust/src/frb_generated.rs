@@ -27,18 +27,11 @@
 // Section: imports
 
 use crate::api::core::engine::*;
-use crate::api::storage::kv_audio_storage::*;
-use crate::api::storage::kv_cached_chart_storage::*;
 use crate::api::traits::audio_decoder::AudioDecoder;
 use crate::api::traits::audio_storage::AudioStorage;
 use crate::api::traits::cached_chart_storage::CachedChartStorage;
 use crate::api::traits::communicator::Communicator;
-use crate::api::traits::down_sample::DownSample;
-use crate::api::traits::transform::SignalTransform;
-use crate::api::types::audio::*;
-use crate::api::types::chart::*;
-use crate::api::types::file::*;
-use crate::api::util::format_getter::FormatGetter;
+use crate::api::types::decode::*;
 use crate::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
@@ -52,7 +45,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1868779041;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1188118069;
 
 // Section: executor
 
@@ -60,103 +53,6 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__types__audio__AudioData_auto_accessor_get_samples_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioData_auto_accessor_get_samples",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.samples.clone())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__audio__AudioData_auto_accessor_set_samples_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioData_auto_accessor_set_samples",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>,
-            >>::sse_decode(&mut deserializer);
-            let api_samples = <Arc<Vec<f32>>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.samples = api_samples;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
 fn wire__crate__api__core__engine__AudioProcessorEngine_add_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -183,8 +79,8 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
             let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_format = <String>::sse_decode(&mut deserializer);
             let api_audio_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_track_id = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::types::error::AppError>(
@@ -193,24 +89,24 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_impl(
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
+                                    &api_that, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
+                        let api_that_guard = api_that_guard.unwrap();
                         let output_ok = crate::api::core::engine::AudioProcessorEngine::add(
-                            &mut *api_that_guard,
+                            &*api_that_guard,
                             api_file_path,
-                            api_format,
                             api_audio_data,
+                            api_track_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -221,7 +117,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_all_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -229,7 +125,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_add_chart",
+            debug_name: "AudioProcessorEngine_add_all_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -247,34 +143,50 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
             let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::types::file::AudioSource>::sse_decode(&mut deserializer);
+            let api_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
                         let mut api_that_guard = None;
+                        let mut api_token_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_that, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_token, 1, false,
+                                    ),
+                                ],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_token_guard =
+                                        Some(api_token.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::core::engine::AudioProcessorEngine::add_chart(
-                            &mut *api_that_guard,
-                            api_file_path,
-                            api_data_type,
-                        )
-                        .await?;
+                        let api_that_guard = api_that_guard.unwrap();
+                        let api_token_guard = api_token_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::add_all_tracks(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_source,
+                                &*api_token_guard,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -283,15 +195,15 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_new_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_cancellable_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_new",
+            debug_name: "AudioProcessorEngine_add_cancellable",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -305,31 +217,65 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
-            let api_decoder = <Box<dyn AudioDecoder + Send + Sync>>::sse_decode(&mut deserializer);
-            let api_storage = <Box<dyn AudioStorage + Send + Sync>>::sse_decode(&mut deserializer);
-            let api_cache =
-                <Box<dyn CachedChartStorage + Send + Sync>>::sse_decode(&mut deserializer);
-            let api_communicator =
-                <Box<dyn Communicator + Send + Sync>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_audio_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_track_id = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::core::engine::AudioProcessorEngine::new(
-                            api_config,
-                            api_decoder,
-                            api_storage,
-                            api_cache,
-                            api_communicator,
-                        ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let mut api_token_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_that, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_token, 1, false,
+                                    ),
+                                ],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_token_guard =
+                                        Some(api_token.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let api_token_guard = api_token_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::add_cancellable(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_audio_data,
+                                api_track_id,
+                                &*api_token_guard,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_channel_charts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -337,7 +283,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_remove_audio",
+            debug_name: "AudioProcessorEngine_add_channel_charts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -377,7 +323,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::core::engine::AudioProcessorEngine::remove_audio(
+                            crate::api::core::engine::AudioProcessorEngine::add_channel_charts(
                                 &*api_that_guard,
                                 api_file_path,
                             )
@@ -390,7 +336,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -398,7 +344,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_remove_chart",
+            debug_name: "AudioProcessorEngine_add_chart",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -438,13 +384,12 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::core::engine::AudioProcessorEngine::remove_chart(
-                                &*api_that_guard,
-                                api_file_path,
-                                api_data_type,
-                            )
-                            .await?;
+                        let output_ok = crate::api::core::engine::AudioProcessorEngine::add_chart(
+                            &*api_that_guard,
+                            api_file_path,
+                            api_data_type,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -453,7 +398,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_from_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -461,7 +406,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_reserve_visible",
+            debug_name: "AudioProcessorEngine_add_from_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -478,32 +423,49 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_chart_name = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_track_id = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
                         let mut api_that_guard = None;
+                        let mut api_token_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_that, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_token, 1, false,
+                                    ),
+                                ],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_token_guard =
+                                        Some(api_token.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
+                        let api_that_guard = api_that_guard.unwrap();
+                        let api_token_guard = api_token_guard.unwrap();
                         let output_ok =
-                            crate::api::core::engine::AudioProcessorEngine::reserve_visible(
-                                &mut *api_that_guard,
-                                api_chart_name,
+                            crate::api::core::engine::AudioProcessorEngine::add_from_path(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_track_id,
+                                &*api_token_guard,
                             )
                             .await?;
                         Ok(output_ok)
@@ -514,7 +476,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_raw_pcm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -522,7 +484,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_set_config",
+            debug_name: "AudioProcessorEngine_add_raw_pcm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -539,35 +501,54 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::types::file::AudioSource>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::types::decode::RawPcmFormat>::sse_decode(&mut deserializer);
+            let api_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
                         let mut api_that_guard = None;
+                        let mut api_token_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
+                                vec![
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_that, 0, false,
+                                    ),
+                                    flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                        &api_token, 1, false,
+                                    ),
+                                ],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                1 => {
+                                    api_token_guard =
+                                        Some(api_token.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::core::engine::AudioProcessorEngine::set_config(
-                                &mut *api_that_guard,
-                                api_config,
+                        let api_that_guard = api_that_guard.unwrap();
+                        let api_token_guard = api_token_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::add_raw_pcm(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_source,
+                                api_format,
+                                &*api_token_guard,
                             )
-                            .await;
-                        })?;
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -576,32 +557,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_points_num_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioProcessorEngine_set_down_sample_points_num", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
-let api_points_num = <usize>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
-                    transform_result_sse::<_, ()>((move || async move {
-                        let mut api_that_guard = None;
-let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]);
-        for i in decode_indices_ {
-            match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_async_ref_mut().await),
-                _ => unreachable!(),
-            }
-        }
-        let mut api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok({ crate::api::core::engine::AudioProcessorEngine::set_down_sample_points_num(&mut *api_that_guard, api_points_num).await; })?;   Ok(output_ok)
-                    })().await)
-                } })
-}
-fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_add_viewport_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -609,7 +565,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_set_index_range",
+            debug_name: "AudioProcessorEngine_add_viewport",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -626,8 +582,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_start = <f32>::sse_decode(&mut deserializer);
-            let api_end = <f32>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
@@ -636,24 +591,23 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
+                                    &api_that, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
+                        let api_that_guard = api_that_guard.unwrap();
                         let output_ok = Result::<_, ()>::Ok({
-                            crate::api::core::engine::AudioProcessorEngine::set_index_range(
-                                &mut *api_that_guard,
-                                api_start,
-                                api_end,
+                            crate::api::core::engine::AudioProcessorEngine::add_viewport(
+                                &*api_that_guard,
+                                api_id,
                             )
                             .await;
                         })?;
@@ -665,7 +619,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
         },
     )
 }
-fn wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_detect_format_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -673,7 +627,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AudioProcessorEngine_set_selected_audio",
+            debug_name: "AudioProcessorEngine_detect_format",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -690,35 +644,34 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_chart_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::types::file::AudioSource>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
                             flutter_rust_bridge::for_generated::lockable_compute_decode_order(
                                 vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
+                                    &api_that, 0, false,
                                 )],
                             );
                         for i in decode_indices_ {
                             match i {
                                 0 => {
                                     api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref_mut().await)
+                                        Some(api_that.lockable_decode_async_ref().await)
                                 }
                                 _ => unreachable!(),
                             }
                         }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::core::engine::AudioProcessorEngine::set_selected_audio(
-                                &mut *api_that_guard,
-                                api_chart_name,
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::detect_format(
+                                &*api_that_guard,
+                                api_source,
                             )
-                            .await;
-                        })?;
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -727,7 +680,7 @@ fn wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
         },
     )
 }
-fn wire__crate__api__types__audio__Audio_audio_to_chart_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_export_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -735,7 +688,7 @@ fn wire__crate__api__types__audio__Audio_audio_to_chart_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Audio_audio_to_chart",
+            debug_name: "AudioProcessorEngine_export",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -750,11 +703,14 @@ fn wire__crate__api__types__audio__Audio_audio_to_chart_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::types::encode::ExportOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -773,9 +729,12 @@ fn wire__crate__api__types__audio__Audio_audio_to_chart_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::types::audio::Audio::audio_to_chart(&*api_that_guard).await,
-                        )?;
+                        let output_ok = crate::api::core::engine::AudioProcessorEngine::export(
+                            &*api_that_guard,
+                            api_key,
+                            api_options,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -784,16 +743,17 @@ fn wire__crate__api__types__audio__Audio_audio_to_chart_impl(
         },
     )
 }
-fn wire__crate__api__types__audio__Audio_auto_accessor_get_data_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_export_to_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Audio_auto_accessor_get_data",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_export_to_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -806,86 +766,60 @@ fn wire__crate__api__types__audio__Audio_auto_accessor_get_data_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::types::encode::ExportOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.data.clone())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__audio__Audio_auto_accessor_get_info_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Audio_auto_accessor_get_info",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::export_to_path(
+                                &*api_that_guard,
+                                api_key,
+                                api_path,
+                                api_options,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.info.clone())?;
-                Ok(output_ok)
-            })())
+            }
         },
     )
 }
-fn wire__crate__api__types__audio__Audio_auto_accessor_set_data_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_get_audio_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Audio_auto_accessor_set_data",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_get_audio_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -898,45 +832,55 @@ fn wire__crate__api__types__audio__Audio_auto_accessor_set_data_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_data = <AudioData>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.data = api_data;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::get_audio_info(
+                                &*api_that_guard,
+                                api_file_path,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__audio__Audio_auto_accessor_set_info_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_get_decode_report_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Audio_auto_accessor_set_info",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_get_decode_report",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -949,45 +893,55 @@ fn wire__crate__api__types__audio__Audio_auto_accessor_set_info_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_info = <crate::api::types::audio::AudioInfo>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.info = api_info;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::get_decode_report(
+                                &*api_that_guard,
+                                api_file_path,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_chart_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_list_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ChartWIthKey_auto_accessor_get_chart",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_list_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1000,40 +954,55 @@ fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_chart_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::types::file::AudioSource>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.chart.clone())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::list_tracks(
+                                &*api_that_guard,
+                                api_source,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_key_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_memory_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ChartWIthKey_auto_accessor_get_key",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_memory_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1046,40 +1015,54 @@ fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_key_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.key.clone())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::core::engine::AudioProcessorEngine::memory_usage(
+                                &*api_that_guard,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_chart_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ChartWIthKey_auto_accessor_set_chart",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1091,46 +1074,41 @@ fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_chart_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>,
-            >>::sse_decode(&mut deserializer);
-            let api_chart = <Chart>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+            let api_decoder = <Box<dyn AudioDecoder + Send + Sync>>::sse_decode(&mut deserializer);
+            let api_storage = <Box<dyn AudioStorage + Send + Sync>>::sse_decode(&mut deserializer);
+            let api_cache =
+                <Box<dyn CachedChartStorage + Send + Sync>>::sse_decode(&mut deserializer);
+            let api_communicator =
+                <Box<dyn Communicator + Send + Sync>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.chart = api_chart;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::core::engine::AudioProcessorEngine::new(
+                            api_config,
+                            api_decoder,
+                            api_storage,
+                            api_cache,
+                            api_communicator,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_key_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ChartWIthKey_auto_accessor_set_key",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_remove_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1143,91 +1121,55 @@ fn wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_key_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.key = api_key;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__chart__Chart_auto_accessor_get_data_type_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_get_data_type",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::remove_audio(
+                                &*api_that_guard,
+                                api_file_path,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.data_type.clone())?;
-                Ok(output_ok)
-            })())
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_get_max_y_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_get_max_y",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_remove_chart",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1240,86 +1182,57 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_get_max_y_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.max_y.clone())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__chart__Chart_auto_accessor_get_min_y_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_get_min_y",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::remove_chart(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_data_type,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.min_y.clone())?;
-                Ok(output_ok)
-            })())
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_get_points_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_remove_viewport_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_get_points",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_remove_viewport",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1332,40 +1245,55 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_get_points_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.points.clone())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::remove_viewport(
+                                &*api_that_guard,
+                                api_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_get_visible_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_resend_chart_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_get_visible",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_resend_chart",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1378,40 +1306,59 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_get_visible_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_viewport = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.visible.clone())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::resend_chart(
+                                &*api_that_guard,
+                                api_viewport,
+                                api_file_path,
+                                api_data_type,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_set_data_type_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_set_data_type",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_reserve_visible",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1424,45 +1371,55 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_set_data_type_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+            let api_chart_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.data_type = api_data_type;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::reserve_visible(
+                                &*api_that_guard,
+                                api_chart_name,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_set_max_y_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_channel_mix_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_set_max_y",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_channel_mix",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1475,45 +1432,86 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_set_max_y_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_max_y = <f32>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_mix = <crate::api::types::audio::ChannelMix>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.max_y = api_max_y;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__chart__Chart_auto_accessor_set_min_y_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::set_channel_mix(
+                                &*api_that_guard,
+                                api_file_path,
+                                api_mix,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_chart_down_sample_method_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "AudioProcessorEngine_set_chart_down_sample_method", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
+let api_file_path = <String>::sse_decode(&mut deserializer);
+let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+let api_method = <Option<crate::api::types::chart::DownSampleMethod>>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| async move {
+transform_result_sse::<_, ()>((move || async move {
+let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+for i in decode_indices_ { match i {
+0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await),
+_ => unreachable!(),
+} }
+let api_that_guard = api_that_guard.unwrap();
+let output_ok = Result::<_, ()>::Ok({ crate::api::core::engine::AudioProcessorEngine::set_chart_down_sample_method(&*api_that_guard, api_file_path, api_data_type, api_method).await; })?;
+Ok(output_ok)
+})().await)
+}
+})
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_set_min_y",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1526,45 +1524,56 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_set_min_y_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_min_y = <f32>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.min_y = api_min_y;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_config(
+                                &*api_that_guard,
+                                api_config,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_set_points_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_method_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_set_points",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_down_sample_method",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1577,45 +1586,86 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_set_points_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_points = <Arc<Vec<Point>>>::sse_decode(&mut deserializer);
+            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+            let api_method =
+                <crate::api::types::chart::DownSampleMethod>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.points = api_points;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_down_sample_method(
+                                &*api_that_guard,
+                                api_data_type,
+                                api_method,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_auto_accessor_set_visible_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_points_num_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "AudioProcessorEngine_set_down_sample_points_num", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
+let api_points_num = <usize>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| async move {
+transform_result_sse::<_, ()>((move || async move {
+let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+for i in decode_indices_ { match i {
+0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await),
+_ => unreachable!(),
+} }
+let api_that_guard = api_that_guard.unwrap();
+let output_ok = Result::<_, ()>::Ok({ crate::api::core::engine::AudioProcessorEngine::set_down_sample_points_num(&*api_that_guard, api_points_num).await; })?;
+Ok(output_ok)
+})().await)
+}
+})
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_auto_accessor_set_visible",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_index_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1628,44 +1678,56 @@ fn wire__crate__api__types__chart__Chart_auto_accessor_set_visible_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_visible = <Arc<AtomicBool>>::sse_decode(&mut deserializer);
+            let api_start = <f32>::sse_decode(&mut deserializer);
+            let api_end = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.visible = api_visible;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_index_range(
+                                &*api_that_guard,
+                                api_start,
+                                api_end,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__chart__Chart_get_range_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_memory_budget_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Chart_get_range",
+            debug_name: "AudioProcessorEngine_set_memory_budget",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1680,49 +1742,84 @@ fn wire__crate__api__types__chart__Chart_get_range_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_start_x = <f32>::sse_decode(&mut deserializer);
-            let api_end_x = <f32>::sse_decode(&mut deserializer);
+            let api_audio_bytes = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_chart_bytes = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::types::chart::Chart::get_range(
-                            &*api_that_guard,
-                            api_start_x,
-                            api_end_x,
-                        ))?;
-                    Ok(output_ok)
-                })())
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::set_memory_budget(
+                                &*api_that_guard,
+                                api_audio_bytes,
+                                api_chart_bytes,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__types__file__File_auto_accessor_get_bytes_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_overview_points_num_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "AudioProcessorEngine_set_overview_points_num", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
+let api_points_num = <usize>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| async move {
+transform_result_sse::<_, ()>((move || async move {
+let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+for i in decode_indices_ { match i {
+0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await),
+_ => unreachable!(),
+} }
+let api_that_guard = api_that_guard.unwrap();
+let output_ok = Result::<_, ()>::Ok({ crate::api::core::engine::AudioProcessorEngine::set_overview_points_num(&*api_that_guard, api_points_num).await; })?;
+Ok(output_ok)
+})().await)
+}
+})
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_sample_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "File_auto_accessor_get_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_sample_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1735,86 +1832,57 @@ fn wire__crate__api__types__file__File_auto_accessor_get_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
+            let api_storage =
+                <crate::api::types::samples::SampleStorage>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.bytes.clone())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__types__file__File_auto_accessor_get_file_path_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "File_auto_accessor_get_file_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_sample_storage(
+                                &*api_that_guard,
+                                api_storage,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.file_path.clone())?;
-                Ok(output_ok)
-            })())
+            }
         },
     )
 }
-fn wire__crate__api__types__file__File_auto_accessor_set_bytes_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "File_auto_accessor_set_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_selected_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1827,45 +1895,56 @@ fn wire__crate__api__types__file__File_auto_accessor_set_bytes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_bytes = <Arc<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_chart_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.bytes = api_bytes;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_selected_audio(
+                                &*api_that_guard,
+                                api_chart_name,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__types__file__File_auto_accessor_set_file_path_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_sinc_interpolation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "File_auto_accessor_set_file_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioProcessorEngine_set_sinc_interpolation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1878,44 +1957,112 @@ fn wire__crate__api__types__file__File_auto_accessor_set_file_path_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.file_path = api_file_path;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::core::engine::AudioProcessorEngine::set_sinc_interpolation(
+                                &*api_that_guard,
+                                api_enabled,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_load_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_chart_visible_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "AudioProcessorEngine_set_viewport_chart_visible", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
+let api_id = <String>::sse_decode(&mut deserializer);
+let api_file_path = <String>::sse_decode(&mut deserializer);
+let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+let api_visible = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| async move {
+transform_result_sse::<_, crate::api::types::error::AppError>((move || async move {
+let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+for i in decode_indices_ { match i {
+0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await),
+_ => unreachable!(),
+} }
+let api_that_guard = api_that_guard.unwrap();
+let output_ok = crate::api::core::engine::AudioProcessorEngine::set_viewport_chart_visible(&*api_that_guard, api_id, api_file_path, api_data_type, api_visible).await?;
+Ok(output_ok)
+})().await)
+}
+})
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_points_num_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "AudioProcessorEngine_set_viewport_points_num", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>>::sse_decode(&mut deserializer);
+let api_id = <String>::sse_decode(&mut deserializer);
+let api_points_num = <usize>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| async move {
+transform_result_sse::<_, crate::api::types::error::AppError>((move || async move {
+let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+for i in decode_indices_ { match i {
+0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await),
+_ => unreachable!(),
+} }
+let api_that_guard = api_that_guard.unwrap();
+let output_ok = crate::api::core::engine::AudioProcessorEngine::set_viewport_points_num(&*api_that_guard, api_id, api_points_num).await?;
+Ok(output_ok)
+})().await)
+}
+})
+}
+fn wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvAudioStorage_load",
+            debug_name: "AudioProcessorEngine_set_viewport_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1930,45 +2077,57 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_load_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_start = <f32>::sse_decode(&mut deserializer);
+            let api_end = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok = crate::api::storage::kv_audio_storage::KvAudioStorage::load(
-                        &*api_that_guard,
-                        api_key,
-                    )?;
-                    Ok(output_ok)
-                })())
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::set_viewport_range(
+                                &*api_that_guard,
+                                api_id,
+                                api_start,
+                                api_end,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_new_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_stored_audio_keys_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvAudioStorage_new",
+            debug_name: "AudioProcessorEngine_stored_audio_keys",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1982,27 +2141,52 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::storage::kv_audio_storage::KvAudioStorage::new(),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::core::engine::AudioProcessorEngine::stored_audio_keys(
+                                &*api_that_guard,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_remove_impl(
+fn wire__crate__api__core__engine__AudioProcessorEngine_true_peak_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvAudioStorage_remove",
+            debug_name: "AudioProcessorEngine_true_peak",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2017,37 +2201,48 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_remove_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_start = <f32>::sse_decode(&mut deserializer);
+            let api_end = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, crate::api::types::error::AppError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok = crate::api::storage::kv_audio_storage::KvAudioStorage::remove(
-                        &*api_that_guard,
-                        api_key,
-                    )?;
-                    Ok(output_ok)
-                })())
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::core::engine::AudioProcessorEngine::true_peak(
+                            &*api_that_guard,
+                            api_file_path,
+                            api_start,
+                            api_end,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_save_impl(
+fn wire__crate__api__types__decode__CancellationToken_cancel_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2055,7 +2250,7 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_save_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvAudioStorage_save",
+            debug_name: "CancellationToken_cancel",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2070,13 +2265,11 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_save_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_storage_unit = <Audio>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2091,18 +2284,16 @@ fn wire__crate__api__storage__kv_audio_storage__KvAudioStorage_save_impl(
                         }
                     }
                     let api_that_guard = api_that_guard.unwrap();
-                    let output_ok = crate::api::storage::kv_audio_storage::KvAudioStorage::save(
-                        &*api_that_guard,
-                        api_key,
-                        api_storage_unit,
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::types::decode::CancellationToken::cancel(&*api_that_guard);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_add_impl(
+fn wire__crate__api__types__decode__CancellationToken_is_cancelled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2110,7 +2301,7 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_add_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_add",
+            debug_name: "CancellationToken_is_cancelled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2125,13 +2316,11 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_add_
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_chart = <Chart>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2146,28 +2335,28 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_add_
                         }
                     }
                     let api_that_guard = api_that_guard.unwrap();
-                    let output_ok =
-                        crate::api::storage::kv_cached_chart_storage::KvCachedChartStorage::add(
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::types::decode::CancellationToken::is_cancelled(
                             &*api_that_guard,
-                            api_key,
-                            api_chart,
-                        )?;
+                        ),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto_accessor_get_config_impl(
+fn wire__crate__api__types__decode__CancellationToken_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_auto_accessor_get_config",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "CancellationToken_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2179,41 +2368,29 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-            >>::sse_decode(&mut deserializer);
+
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.config.clone())?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::types::decode::CancellationToken::new())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto_accessor_set_config_impl(
+fn wire__crate__api__types__decode__DecodeOptions_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_auto_accessor_set_config",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "DecodeOptions_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2225,37 +2402,19 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-            >>::sse_decode(&mut deserializer);
-            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, true,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                        _ => unreachable!(),
-                    }
-                }
-                let mut api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    {
-                        api_that_guard.config = api_config;
-                    };
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::types::decode::DecodeOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_impl(
+fn wire__crate__api__types__decode__DecodeProgress_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2263,7 +2422,7 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_get",
+            debug_name: "DecodeProgress_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2277,65 +2436,53 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
+                transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        crate::api::storage::kv_cached_chart_storage::KvCachedChartStorage::get(
-                            &*api_that_guard,
-                            api_key,
-                            api_data_type,
-                        )?;
+                        Result::<_, ()>::Ok(crate::api::types::decode::DecodeProgress::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_all_cache_impl(
+fn wire__crate__api__types__decode__DecodeReport_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "KvCachedChartStorage_get_all_cache", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, crate::api::types::error::AppError>((move ||  {
-                        let mut api_that_guard = None;
-let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
-        for i in decode_indices_ {
-            match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                _ => unreachable!(),
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DecodeReport_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::types::decode::DecodeReport::default())?;
+                    Ok(output_ok)
+                })())
             }
-        }
-        let api_that_guard = api_that_guard.unwrap();
- let output_ok = crate::api::storage::kv_cached_chart_storage::KvCachedChartStorage::get_all_cache(&*api_that_guard)?;   Ok(output_ok)
-                    })())
-                } })
+        },
+    )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_new_impl(
+fn wire__crate__api__types__decode__DecodeReport_is_clean_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2343,7 +2490,7 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_new_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_new",
+            debug_name: "DecodeReport_is_clean",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2357,11 +2504,12 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_new_
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::types::decode::DecodeReport>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::storage::kv_cached_chart_storage::KvCachedChartStorage::new(),
+                        crate::api::types::decode::DecodeReport::is_clean(&api_that),
                     )?;
                     Ok(output_ok)
                 })())
@@ -2369,7 +2517,7 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_new_
         },
     )
 }
-fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_remove_impl(
+fn wire__crate__api__types__decode__DecodeReport_mark_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2377,7 +2525,7 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_remo
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "KvCachedChartStorage_remove",
+            debug_name: "DecodeReport_mark",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2391,41 +2539,31 @@ fn wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_remo
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);
+            let mut api_that =
+                <crate::api::types::decode::DecodeReport>::sse_decode(&mut deserializer);
+            let api_start_frame = <u64>::sse_decode(&mut deserializer);
+            let api_end_frame = <u64>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok =
-                        crate::api::storage::kv_cached_chart_storage::KvCachedChartStorage::remove(
-                            &*api_that_guard,
-                            api_key,
-                            api_data_type,
-                        )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::types::decode::DecodeReport::mark(
+                            &mut api_that,
+                            api_start_frame,
+                            api_end_frame,
+                            api_sample_rate,
+                            &api_reason,
+                        );
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__types__config__config_default_impl(
+fn wire__crate__api__types__decode__RawSampleFormat_bytes_per_sample_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2433,7 +2571,7 @@ fn wire__crate__api__types__config__config_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "config_default",
+            debug_name: "RawSampleFormat_bytes_per_sample",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2447,11 +2585,14 @@ fn wire__crate__api__types__config__config_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::types::decode::RawSampleFormat>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::types::config::Config::default())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::types::decode::RawSampleFormat::bytes_per_sample(&api_that),
+                    )?;
                     Ok(output_ok)
                 })())
             }
@@ -2498,15 +2639,15 @@ fn wire__crate__api__events__communicator_events__create_chart_event_stream_impl
         },
     )
 }
-fn wire__crate__api__core__engine__create_default_engine_impl(
+fn wire__crate__api__events__decode_events__create_decode_event_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_default_engine",
+            debug_name: "create_decode_event_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2520,31 +2661,31 @@ fn wire__crate__api__core__engine__create_default_engine_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::types::events::DecodeEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::core::engine::create_default_engine(api_config).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::events::decode_events::create_decode_event_stream(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__events__communicator_events__emit_chart_event_impl(
+fn wire__crate__api__core__engine__create_default_engine_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "emit_chart_event",
+            debug_name: "create_default_engine",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2558,20 +2699,23 @@ fn wire__crate__api__events__communicator_events__emit_chart_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event = <crate::api::types::events::ChartEvent>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::events::communicator_events::emit_chart_event(api_event);
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::core::engine::create_default_engine(api_config).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transform__energy__energy_calculator_transform_impl(
+fn wire__crate__api__core__engine__create_engine_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2579,7 +2723,7 @@ fn wire__crate__api__transform__energy__energy_calculator_transform_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "energy_calculator_transform",
+            debug_name: "create_engine",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2593,16 +2737,19 @@ fn wire__crate__api__transform__energy__energy_calculator_transform_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::transform::energy::EnergyCalculator>::sse_decode(&mut deserializer);
-            let api_data = <Audio>::sse_decode(&mut deserializer);
             let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+            let api_storage =
+                <crate::api::types::config::AudioStorageBackend>::sse_decode(&mut deserializer);
+            let api_cache =
+                <crate::api::types::config::ChartCacheBackend>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::types::error::AppError>(
                     (move || async move {
-                        let output_ok = crate::api::transform::energy::EnergyCalculator::transform(
-                            &api_that, api_data, api_config,
+                        let output_ok = crate::api::core::engine::create_engine(
+                            api_config,
+                            api_storage,
+                            api_cache,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2613,7 +2760,7 @@ fn wire__crate__api__transform__energy__energy_calculator_transform_impl(
         },
     )
 }
-fn wire__crate__api__sampling__equal_step__equal_step_down_sample_impl(
+fn wire__crate__api__events__communicator_events__emit_chart_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2621,7 +2768,7 @@ fn wire__crate__api__sampling__equal_step__equal_step_down_sample_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "equal_step_down_sample",
+            debug_name: "emit_chart_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2635,35 +2782,28 @@ fn wire__crate__api__sampling__equal_step__equal_step_down_sample_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::sampling::equal_step::EqualStep>::sse_decode(&mut deserializer);
-            let api_chart = <Chart>::sse_decode(&mut deserializer);
-            let api_target_points_num = <usize>::sse_decode(&mut deserializer);
+            let api_event = <crate::api::types::events::ChartEvent>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::sampling::equal_step::EqualStep::down_sample(
-                            &api_that,
-                            api_chart,
-                            api_target_points_num,
-                        ),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::events::communicator_events::emit_chart_event(api_event);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transform__fft__fft_transform_transform_impl(
+fn wire__crate__api__events__decode_events__emit_decode_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fft_transform_transform",
+            debug_name: "emit_decode_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2677,389 +2817,22 @@ fn wire__crate__api__transform__fft__fft_transform_transform_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::transform::fft::FftTransform>::sse_decode(&mut deserializer);
-            let api_data = <Audio>::sse_decode(&mut deserializer);
-            let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);
+            let api_event = <crate::api::types::events::DecodeEvent>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::types::error::AppError>(
-                    (move || async move {
-                        let output_ok = crate::api::transform::fft::FftTransform::transform(
-                            &api_that, api_data, api_config,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::events::decode_events::emit_decode_event(api_event);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__util__get_min_max__get_min_max_par_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_min_max_par",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_points = <Vec<crate::api::types::chart::Point>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::util::get_min_max::get_min_max_par(&api_points).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__sampling__minmax__minmax_down_sample_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "minmax_down_sample",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::sampling::minmax::Minmax>::sse_decode(&mut deserializer);
-            let api_chart = <Chart>::sse_decode(&mut deserializer);
-            let api_target_points_num = <usize>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::sampling::minmax::Minmax::down_sample(
-                            &api_that,
-                            api_chart,
-                            api_target_points_num,
-                        ))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__util__format_getter__simple_format_getter_get_format_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "simple_format_getter_get_format",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::util::format_getter::SimpleFormatGetter>::sse_decode(
-                &mut deserializer,
-            );
-            let api_file_path = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let output_ok =
-                        crate::api::util::format_getter::SimpleFormatGetter::get_format(
-                            &api_that,
-                            api_file_path,
-                        )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_add_chart_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_add_chart", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);
-let api_key = <String>::sse_decode(&mut deserializer);
-let api_chart = <Chart>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::add_chart(&api_that, api_key, api_chart); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_new_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stream_communicator_new",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::communicator::stream_sink_communicator::StreamCommunicator::new(
-                        ),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_remove_all_charts_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_remove_all_charts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::remove_all_charts(&api_that); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_remove_chart_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_remove_chart", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);
-let api_key = <String>::sse_decode(&mut deserializer);
-let api_data_type = <crate::api::types::chart::DataType>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::remove_chart(&api_that, api_key, api_data_type); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_all_charts_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_update_all_charts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);
-let api_charts = <Vec< ChartWIthKey>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::update_all_charts(&api_that, api_charts); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_max_index_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_update_max_index", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);
-let api_max_index = <f32>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::update_max_index(&api_that, api_max_index); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_y_range_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_communicator_update_y_range", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::communicator::stream_sink_communicator::StreamCommunicator>::sse_decode(&mut deserializer);
-let api_min_y = <f32>::sse_decode(&mut deserializer);
-let api_max_y = <f32>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, ()>((move ||  {
-                         let output_ok = Result::<_,()>::Ok({ crate::api::communicator::stream_sink_communicator::StreamCommunicator::update_y_range(&api_that, api_min_y, api_max_y); })?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__decoder__symphonia_decoder__symphonia_decoder_decode_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "symphonia_decoder_decode",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::decoder::symphonia_decoder::SymphoniaDecoder>::sse_decode(
-                &mut deserializer,
-            );
-            let api_format = <String>::sse_decode(&mut deserializer);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::types::error::AppError>((move || {
-                    let output_ok =
-                        crate::api::decoder::symphonia_decoder::SymphoniaDecoder::decode(
-                            &api_that, api_format, api_data,
-                        )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__decoder__symphonia_decoder__symphonia_decoder_new_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "symphonia_decoder_new",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::decoder::symphonia_decoder::SymphoniaDecoder::new(),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__transform__zero_crossing_rate__zero_crossing_rate_calculator_transform_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "zero_crossing_rate_calculator_transform", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator>::sse_decode(&mut deserializer);
-let api_data = <Audio>::sse_decode(&mut deserializer);
-let api_config = <crate::api::types::config::Config>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
-                    transform_result_sse::<_, crate::api::types::error::AppError>((move || async move {
-                         let output_ok = crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator::transform(&api_that, api_data, api_config).await?;   Ok(output_ok)
-                    })().await)
-                } })
-}
 
 // Section: related_funcs
 
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<AtomicBool>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<Point>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<f32>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<u8>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>
-);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>
 );
@@ -3078,2026 +2851,3636 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Communicator + Send + Sync>>
 );
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>
 );
 
 // Section: dart2rust
 
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for AudioProcessorEngine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Box<dyn AudioDecoder + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn AudioDecoder + Send + Sync>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Box<dyn AudioStorage + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn AudioStorage + Send + Sync>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Box<dyn CachedChartStorage + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn CachedChartStorage + Send + Sync>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Box<dyn Communicator + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn Communicator + Send + Sync>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for CancellationToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn AudioDecoder + Send + Sync>,
+        >,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn AudioStorage + Send + Sync>,
+        >,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn CachedChartStorage + Send + Sync>,
+        >,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn Communicator + Send + Sync>,
+        >,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::types::events::ChartEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::types::events::DecodeEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::types::error::AppError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Io(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Format(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Decode(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Storage(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Cache(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::NotFound(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::Generic(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::ProcessingError(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::error::AppError::InvalidChartName(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::audio::AudioInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_channelLayout =
+            <crate::api::types::audio::ChannelLayout>::sse_decode(deserializer);
+        let mut var_codec = <String>::sse_decode(deserializer);
+        let mut var_container = <String>::sse_decode(deserializer);
+        let mut var_bitsPerSample = <Option<u32>>::sse_decode(deserializer);
+        let mut var_totalFrames = <u64>::sse_decode(deserializer);
+        let mut var_duration = <f64>::sse_decode(deserializer);
+        let mut var_encoderDelay = <Option<u32>>::sse_decode(deserializer);
+        let mut var_encoderPadding = <Option<u32>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::types::audio::AudioTag>>::sse_decode(deserializer);
+        let mut var_decodeReport =
+            <crate::api::types::decode::DecodeReport>::sse_decode(deserializer);
+        return crate::api::types::audio::AudioInfo {
+            sample_rate: var_sampleRate,
+            channels: var_channels,
+            channel_layout: var_channelLayout,
+            codec: var_codec,
+            container: var_container,
+            bits_per_sample: var_bitsPerSample,
+            total_frames: var_totalFrames,
+            duration: var_duration,
+            encoder_delay: var_encoderDelay,
+            encoder_padding: var_encoderPadding,
+            tags: var_tags,
+            decode_report: var_decodeReport,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::memory::AudioMemoryUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        return crate::api::types::memory::AudioMemoryUsage {
+            key: var_key,
+            bytes: var_bytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::file::AudioSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::types::file::AudioSource::Bytes(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::types::file::AudioSource::Path(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::config::AudioStorageBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::types::config::AudioStorageBackend::Memory;
+            }
+            1 => {
+                let mut var_directory = <String>::sse_decode(deserializer);
+                return crate::api::types::config::AudioStorageBackend::Disk {
+                    directory: var_directory,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::audio::AudioTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::types::audio::AudioTag {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::audio::ChannelLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::types::audio::ChannelLayout::Mono;
+            }
+            1 => {
+                return crate::api::types::audio::ChannelLayout::Stereo;
+            }
+            2 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::types::audio::ChannelLayout::Multichannel(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::audio::ChannelMix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::types::audio::ChannelMix::Mean;
+            }
+            1 => {
+                return crate::api::types::audio::ChannelMix::Left;
+            }
+            2 => {
+                return crate::api::types::audio::ChannelMix::Right;
+            }
+            3 => {
+                return crate::api::types::audio::ChannelMix::Mid;
+            }
+            4 => {
+                return crate::api::types::audio::ChannelMix::Side;
+            }
+            5 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::types::audio::ChannelMix::Channel(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::chart::ChartAxis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dataType = <crate::api::types::chart::DataType>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_unit = <String>::sse_decode(deserializer);
+        let mut var_minY = <f32>::sse_decode(deserializer);
+        let mut var_maxY = <f32>::sse_decode(deserializer);
+        let mut var_keys = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::types::chart::ChartAxis {
+            data_type: var_dataType,
+            label: var_label,
+            unit: var_unit,
+            min_y: var_minY,
+            max_y: var_maxY,
+            keys: var_keys,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::config::ChartCacheBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::types::config::ChartCacheBackend::Memory;
+            }
+            1 => {
+                let mut var_directory = <String>::sse_decode(deserializer);
+                return crate::api::types::config::ChartCacheBackend::Disk {
+                    directory: var_directory,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::events::ChartEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_chart =
+                    <crate::api::types::chart::CommunicatorChart>::sse_decode(deserializer);
+                let mut var_seq = <u64>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::AddChart {
+                    viewport: var_viewport,
+                    chart: var_chart,
+                    seq: var_seq,
+                };
+            }
+            1 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_dataType =
+                    <crate::api::types::chart::DataType>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::RemoveChart {
+                    key: var_key,
+                    data_type: var_dataType,
+                };
+            }
+            2 => {
+                let mut var_charts =
+                    <Vec<crate::api::types::chart::CommunicatorChart>>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::UpdateAllCharts {
+                    charts: var_charts,
+                };
+            }
+            3 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_chart =
+                    <crate::api::types::chart::CommunicatorChart>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::UpdateOverview {
+                    viewport: var_viewport,
+                    chart: var_chart,
+                };
+            }
+            4 => {
+                return crate::api::types::events::ChartEvent::RemoveAllCharts;
+            }
+            5 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_maxIndex = <f32>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::UpdateMaxIndex {
+                    viewport: var_viewport,
+                    max_index: var_maxIndex,
+                };
+            }
+            6 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_axes =
+                    <Vec<crate::api::types::chart::ChartAxis>>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::UpdateAxes {
+                    viewport: var_viewport,
+                    axes: var_axes,
+                };
+            }
+            7 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_regions =
+                    <Vec<crate::api::types::chart::ChartRegion>>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::UpdateRegions {
+                    key: var_key,
+                    regions: var_regions,
+                };
+            }
+            8 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_dataType =
+                    <crate::api::types::chart::DataType>::sse_decode(deserializer);
+                let mut var_points =
+                    <crate::api::types::chart::ChartPayload>::sse_decode(deserializer);
+                let mut var_baseSeq = <u64>::sse_decode(deserializer);
+                let mut var_seq = <u64>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::AppendPoints {
+                    viewport: var_viewport,
+                    key: var_key,
+                    data_type: var_dataType,
+                    points: var_points,
+                    base_seq: var_baseSeq,
+                    seq: var_seq,
+                };
+            }
+            9 => {
+                let mut var_viewport = <String>::sse_decode(deserializer);
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_dataType =
+                    <crate::api::types::chart::DataType>::sse_decode(deserializer);
+                let mut var_start = <usize>::sse_decode(deserializer);
+                let mut var_end = <usize>::sse_decode(deserializer);
+                let mut var_points =
+                    <crate::api::types::chart::ChartPayload>::sse_decode(deserializer);
+                let mut var_baseSeq = <u64>::sse_decode(deserializer);
+                let mut var_seq = <u64>::sse_decode(deserializer);
+                return crate::api::types::events::ChartEvent::ReplaceRange {
+                    viewport: var_viewport,
+                    key: var_key,
+                    data_type: var_dataType,
+                    start: var_start,
+                    end: var_end,
+                    points: var_points,
+                    base_seq: var_baseSeq,
+                    seq: var_seq,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for Arc<AtomicBool> {
+impl SseDecode for crate::api::types::memory::ChartMemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<AtomicBool>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_dataType = <crate::api::types::chart::DataType>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        return crate::api::types::memory::ChartMemoryUsage {
+            key: var_key,
+            data_type: var_dataType,
+            bytes: var_bytes,
+        };
     }
 }
 
-impl SseDecode for Arc<Vec<Point>> {
+impl SseDecode for crate::api::types::chart::ChartPayload {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<Point>>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_xs = <Vec<f32>>::sse_decode(deserializer);
+                let mut var_ys = <Vec<f32>>::sse_decode(deserializer);
+                return crate::api::types::chart::ChartPayload::Points {
+                    xs: var_xs,
+                    ys: var_ys,
+                };
+            }
+            1 => {
+                let mut var_x0 = <f32>::sse_decode(deserializer);
+                let mut var_dx = <f32>::sse_decode(deserializer);
+                let mut var_ys = <Vec<f32>>::sse_decode(deserializer);
+                return crate::api::types::chart::ChartPayload::Uniform {
+                    x0: var_x0,
+                    dx: var_dx,
+                    ys: var_ys,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for Arc<Vec<f32>> {
+impl SseDecode for crate::api::types::chart::ChartRegion {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<f32>>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_startX = <f32>::sse_decode(deserializer);
+        let mut var_endX = <f32>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        return crate::api::types::chart::ChartRegion {
+            start_x: var_startX,
+            end_x: var_endX,
+            label: var_label,
+        };
     }
 }
 
-impl SseDecode for Arc<Vec<u8>> {
+impl SseDecode for crate::api::types::chart::CommunicatorChart {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<u8>>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_dataType = <crate::api::types::chart::DataType>::sse_decode(deserializer);
+        let mut var_chart = <crate::api::types::chart::ChartPayload>::sse_decode(deserializer);
+        return crate::api::types::chart::CommunicatorChart {
+            key: var_key,
+            data_type: var_dataType,
+            chart: var_chart,
+        };
     }
 }
 
-impl SseDecode for Audio {
+impl SseDecode for crate::api::types::config::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_frameSize = <usize>::sse_decode(deserializer);
+        return crate::api::types::config::Config {
+            frame_size: var_frameSize,
+        };
     }
 }
 
-impl SseDecode for AudioData {
+impl SseDecode for crate::api::types::decode::DamagedRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_startFrame = <u64>::sse_decode(deserializer);
+        let mut var_endFrame = <u64>::sse_decode(deserializer);
+        let mut var_start = <f64>::sse_decode(deserializer);
+        let mut var_end = <f64>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::types::decode::DamagedRange {
+            start_frame: var_startFrame,
+            end_frame: var_endFrame,
+            start: var_start,
+            end: var_end,
+            reason: var_reason,
+        };
     }
 }
 
-impl SseDecode for AudioProcessorEngine {
+impl SseDecode for crate::api::types::chart::DataType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::chart::DataType::Audio,
+            1 => crate::api::types::chart::DataType::Spectrum,
+            2 => crate::api::types::chart::DataType::Energy,
+            3 => crate::api::types::chart::DataType::ZeroCrossingRate,
+            _ => unreachable!("Invalid variant for DataType: {}", inner),
+        };
     }
 }
 
-impl SseDecode for Box<dyn AudioDecoder + Send + Sync> {
+impl SseDecode for crate::api::types::events::DecodeEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn AudioDecoder + Send + Sync>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::types::decode::DecodeProgress>::sse_decode(deserializer);
+                return crate::api::types::events::DecodeEvent::Progress {
+                    key: var_key,
+                    progress: var_progress,
+                };
+            }
+            1 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::types::decode::DecodeProgress>::sse_decode(deserializer);
+                return crate::api::types::events::DecodeEvent::Finished {
+                    key: var_key,
+                    progress: var_progress,
+                };
+            }
+            2 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_progress =
+                    <crate::api::types::decode::DecodeProgress>::sse_decode(deserializer);
+                return crate::api::types::events::DecodeEvent::Cancelled {
+                    key: var_key,
+                    progress: var_progress,
+                };
+            }
+            3 => {
+                let mut var_key = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::types::events::DecodeEvent::Failed {
+                    key: var_key,
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseDecode for Box<dyn AudioStorage + Send + Sync> {
+impl SseDecode for crate::api::types::decode::DecodeOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn AudioStorage + Send + Sync>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_trackId = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::types::decode::DecodeOptions {
+            track_id: var_trackId,
+        };
     }
 }
 
-impl SseDecode for Box<dyn CachedChartStorage + Send + Sync> {
+impl SseDecode for crate::api::types::decode::DecodeProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn CachedChartStorage + Send + Sync>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_packets = <u64>::sse_decode(deserializer);
+        let mut var_bytesRead = <u64>::sse_decode(deserializer);
+        let mut var_totalBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_decodedFrames = <u64>::sse_decode(deserializer);
+        let mut var_decodedDuration = <f64>::sse_decode(deserializer);
+        let mut var_estimatedDuration = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::types::decode::DecodeProgress {
+            packets: var_packets,
+            bytes_read: var_bytesRead,
+            total_bytes: var_totalBytes,
+            decoded_frames: var_decodedFrames,
+            decoded_duration: var_decodedDuration,
+            estimated_duration: var_estimatedDuration,
+        };
     }
 }
 
-impl SseDecode for Box<dyn Communicator + Send + Sync> {
+impl SseDecode for crate::api::types::decode::DecodeReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn Communicator + Send + Sync>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_damaged =
+            <Vec<crate::api::types::decode::DamagedRange>>::sse_decode(deserializer);
+        let mut var_skippedPackets = <u64>::sse_decode(deserializer);
+        let mut var_decoderResets = <u32>::sse_decode(deserializer);
+        let mut var_truncated = <bool>::sse_decode(deserializer);
+        return crate::api::types::decode::DecodeReport {
+            damaged: var_damaged,
+            skipped_packets: var_skippedPackets,
+            decoder_resets: var_decoderResets,
+            truncated: var_truncated,
+        };
     }
 }
 
-impl SseDecode for Chart {
+impl SseDecode for crate::api::util::format_detector::DetectedFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_container = <String>::sse_decode(deserializer);
+        let mut var_extension = <String>::sse_decode(deserializer);
+        let mut var_codec = <Option<String>>::sse_decode(deserializer);
+        return crate::api::util::format_detector::DetectedFormat {
+            container: var_container,
+            extension: var_extension,
+            codec: var_codec,
+        };
     }
 }
 
-impl SseDecode for ChartWIthKey {
+impl SseDecode for crate::api::types::chart::DownSampleMethod {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::chart::DownSampleMethod::MinMax,
+            1 => crate::api::types::chart::DownSampleMethod::EqualStep,
+            2 => crate::api::types::chart::DownSampleMethod::Lttb,
+            3 => crate::api::types::chart::DownSampleMethod::M4,
+            _ => unreachable!("Invalid variant for DownSampleMethod: {}", inner),
+        };
     }
 }
 
-impl SseDecode for File {
+impl SseDecode for crate::api::types::decode::Endianness {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::decode::Endianness::Little,
+            1 => crate::api::types::decode::Endianness::Big,
+            _ => unreachable!("Invalid variant for Endianness: {}", inner),
+        };
     }
 }
 
-impl SseDecode for KvAudioStorage {
+impl SseDecode for crate::api::types::encode::ExportContainer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::encode::ExportContainer::Wav,
+            1 => crate::api::types::encode::ExportContainer::Flac,
+            _ => unreachable!("Invalid variant for ExportContainer: {}", inner),
+        };
     }
 }
 
-impl SseDecode for KvCachedChartStorage {
+impl SseDecode for crate::api::types::encode::ExportOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+        let mut var_container =
+            <crate::api::types::encode::ExportContainer>::sse_decode(deserializer);
+        let mut var_sampleFormat =
+            <crate::api::types::encode::ExportSampleFormat>::sse_decode(deserializer);
+        let mut var_dither = <bool>::sse_decode(deserializer);
+        return crate::api::types::encode::ExportOptions {
+            container: var_container,
+            sample_format: var_sampleFormat,
+            dither: var_dither,
+        };
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<AtomicBool>>>
-{
+impl SseDecode for crate::api::types::encode::ExportSampleFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::encode::ExportSampleFormat::I16,
+            1 => crate::api::types::encode::ExportSampleFormat::I24,
+            2 => crate::api::types::encode::ExportSampleFormat::F32,
+            _ => unreachable!("Invalid variant for ExportSampleFormat: {}", inner),
+        };
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<Point>>>>
-{
+impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<f32>>>>
-{
+impl SseDecode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<u8>>>>
-{
+impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>> {
+impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>
-{
+impl SseDecode for Vec<crate::api::types::memory::AudioMemoryUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::memory::AudioMemoryUsage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>
-{
+impl SseDecode for Vec<crate::api::types::audio::AudioTag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::audio::AudioTag>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn AudioDecoder + Send + Sync>,
-        >,
-    >
-{
+impl SseDecode for Vec<crate::api::types::chart::ChartAxis> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::chart::ChartAxis>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn AudioStorage + Send + Sync>,
-        >,
-    >
-{
+impl SseDecode for Vec<crate::api::types::memory::ChartMemoryUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::memory::ChartMemoryUsage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn CachedChartStorage + Send + Sync>,
-        >,
-    >
-{
+impl SseDecode for Vec<crate::api::types::chart::ChartRegion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::chart::ChartRegion>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn Communicator + Send + Sync>,
-        >,
-    >
-{
+impl SseDecode for Vec<crate::api::types::chart::CommunicatorChart> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::chart::CommunicatorChart>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>> {
+impl SseDecode for Vec<crate::api::types::decode::DamagedRange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::decode::DamagedRange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>
-{
+impl SseDecode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>> {
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>
-{
+impl SseDecode for Vec<crate::api::types::audio::TrackInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::audio::TrackInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>
-{
+impl SseDecode for crate::api::types::memory::MemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut var_audio =
+            <Vec<crate::api::types::memory::AudioMemoryUsage>>::sse_decode(deserializer);
+        let mut var_charts =
+            <Vec<crate::api::types::memory::ChartMemoryUsage>>::sse_decode(deserializer);
+        let mut var_audioBytes = <u64>::sse_decode(deserializer);
+        let mut var_chartBytes = <u64>::sse_decode(deserializer);
+        let mut var_audioBudget = <Option<u64>>::sse_decode(deserializer);
+        let mut var_chartBudget = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::types::memory::MemoryUsage {
+            audio: var_audio,
+            charts: var_charts,
+            audio_bytes: var_audioBytes,
+            chart_bytes: var_chartBytes,
+            audio_budget: var_audioBudget,
+            chart_budget: var_chartBudget,
+        };
     }
 }
 
-impl SseDecode
-    for StreamSink<
-        crate::api::types::events::ChartEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for Option<crate::api::types::audio::ChannelLayout> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::types::audio::ChannelLayout>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::types::error::AppError {
+impl SseDecode for Option<crate::api::types::chart::DownSampleMethod> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Io(var_field0);
-            }
-            1 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Format(var_field0);
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Decode(var_field0);
-            }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Storage(var_field0);
-            }
-            4 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Cache(var_field0);
-            }
-            5 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::NotFound(var_field0);
-            }
-            6 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::Generic(var_field0);
-            }
-            7 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::ProcessingError(var_field0);
-            }
-            8 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::types::error::AppError::InvalidChartName(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::types::chart::DownSampleMethod>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::types::audio::AudioInfo {
+impl SseDecode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sampleRate = <u32>::sse_decode(deserializer);
-        return crate::api::types::audio::AudioInfo {
-            sample_rate: var_sampleRate,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::types::events::ChartEvent {
+impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_chart =
-                    <crate::api::types::chart::CommunicatorChart>::sse_decode(deserializer);
-                return crate::api::types::events::ChartEvent::AddChart { chart: var_chart };
-            }
-            1 => {
-                let mut var_key = <String>::sse_decode(deserializer);
-                let mut var_dataType =
-                    <crate::api::types::chart::DataType>::sse_decode(deserializer);
-                return crate::api::types::events::ChartEvent::RemoveChart {
-                    key: var_key,
-                    data_type: var_dataType,
-                };
-            }
-            2 => {
-                let mut var_charts =
-                    <Vec<crate::api::types::chart::CommunicatorChart>>::sse_decode(deserializer);
-                return crate::api::types::events::ChartEvent::UpdateAllCharts {
-                    charts: var_charts,
-                };
-            }
-            3 => {
-                return crate::api::types::events::ChartEvent::RemoveAllCharts;
-            }
-            4 => {
-                let mut var_maxIndex = <f32>::sse_decode(deserializer);
-                return crate::api::types::events::ChartEvent::UpdateMaxIndex {
-                    max_index: var_maxIndex,
-                };
-            }
-            5 => {
-                let mut var_minY = <f32>::sse_decode(deserializer);
-                let mut var_maxY = <f32>::sse_decode(deserializer);
-                return crate::api::types::events::ChartEvent::UpdateYRange {
-                    min_y: var_minY,
-                    max_y: var_maxY,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
         }
     }
 }
 
-impl SseDecode for crate::api::types::chart::CommunicatorChart {
+impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_dataType = <crate::api::types::chart::DataType>::sse_decode(deserializer);
-        let mut var_chart = <Vec<crate::api::types::chart::Point>>::sse_decode(deserializer);
-        return crate::api::types::chart::CommunicatorChart {
-            key: var_key,
-            data_type: var_dataType,
-            chart: var_chart,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::types::config::Config {
+impl SseDecode for crate::api::types::decode::RawPcmFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_frameSize = <usize>::sse_decode(deserializer);
-        return crate::api::types::config::Config {
-            frame_size: var_frameSize,
+        let mut var_sampleFormat =
+            <crate::api::types::decode::RawSampleFormat>::sse_decode(deserializer);
+        let mut var_endianness = <crate::api::types::decode::Endianness>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_headerSkip = <u64>::sse_decode(deserializer);
+        return crate::api::types::decode::RawPcmFormat {
+            sample_format: var_sampleFormat,
+            endianness: var_endianness,
+            channels: var_channels,
+            sample_rate: var_sampleRate,
+            header_skip: var_headerSkip,
         };
     }
 }
 
-impl SseDecode for crate::api::types::chart::DataType {
+impl SseDecode for crate::api::types::decode::RawSampleFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
-            0 => crate::api::types::chart::DataType::Audio,
-            1 => crate::api::types::chart::DataType::Spectrum,
-            2 => crate::api::types::chart::DataType::Energy,
-            3 => crate::api::types::chart::DataType::ZeroCrossingRate,
-            _ => unreachable!("Invalid variant for DataType: {}", inner),
+            0 => crate::api::types::decode::RawSampleFormat::U8,
+            1 => crate::api::types::decode::RawSampleFormat::S16,
+            2 => crate::api::types::decode::RawSampleFormat::S24,
+            3 => crate::api::types::decode::RawSampleFormat::S32,
+            4 => crate::api::types::decode::RawSampleFormat::F32,
+            5 => crate::api::types::decode::RawSampleFormat::MuLaw,
+            6 => crate::api::types::decode::RawSampleFormat::ALaw,
+            _ => unreachable!("Invalid variant for RawSampleFormat: {}", inner),
         };
     }
 }
 
-impl SseDecode for crate::api::transform::energy::EnergyCalculator {
+impl SseDecode for crate::api::types::samples::SampleStorage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::transform::energy::EnergyCalculator {};
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::samples::SampleStorage::F32,
+            1 => crate::api::types::samples::SampleStorage::I16,
+            2 => crate::api::types::samples::SampleStorage::F16,
+            _ => unreachable!("Invalid variant for SampleStorage: {}", inner),
+        };
     }
 }
 
-impl SseDecode for crate::api::sampling::equal_step::EqualStep {
+impl SseDecode for crate::api::types::audio::TrackInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::sampling::equal_step::EqualStep {};
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_codec = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_channelLayout =
+            <Option<crate::api::types::audio::ChannelLayout>>::sse_decode(deserializer);
+        let mut var_totalFrames = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::types::audio::TrackInfo {
+            id: var_id,
+            codec: var_codec,
+            language: var_language,
+            sample_rate: var_sampleRate,
+            channels: var_channels,
+            channel_layout: var_channelLayout,
+            total_frames: var_totalFrames,
+        };
     }
 }
 
-impl SseDecode for f32 {
+impl SseDecode for crate::api::types::chart::TruePeak {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+        let mut var_x = <f32>::sse_decode(deserializer);
+        let mut var_value = <f32>::sse_decode(deserializer);
+        return crate::api::types::chart::TruePeak {
+            x: var_x,
+            value: var_value,
+        };
     }
 }
 
-impl SseDecode for crate::api::transform::fft::FftTransform {
+impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::transform::fft::FftTransform {};
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for Vec<ChartWIthKey> {
+impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<ChartWIthKey>::sse_decode(deserializer));
-        }
-        return ans_;
+        deserializer.cursor.read_u8().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::types::chart::CommunicatorChart> {
+impl SseDecode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::types::chart::CommunicatorChart>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
-impl SseDecode for Vec<crate::api::types::chart::Point> {
+impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::types::chart::Point>::sse_decode(deserializer));
-        }
-        return ans_;
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__core__engine__AudioProcessorEngine_add_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__core__engine__AudioProcessorEngine_add_all_tracks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__core__engine__AudioProcessorEngine_add_cancellable_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__core__engine__AudioProcessorEngine_add_channel_charts_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__core__engine__AudioProcessorEngine_add_from_path_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__core__engine__AudioProcessorEngine_add_raw_pcm_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__core__engine__AudioProcessorEngine_add_viewport_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__core__engine__AudioProcessorEngine_detect_format_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__core__engine__AudioProcessorEngine_export_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__core__engine__AudioProcessorEngine_export_to_path_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__core__engine__AudioProcessorEngine_get_audio_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__core__engine__AudioProcessorEngine_get_decode_report_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__core__engine__AudioProcessorEngine_list_tracks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__core__engine__AudioProcessorEngine_memory_usage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__core__engine__AudioProcessorEngine_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__core__engine__AudioProcessorEngine_remove_viewport_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__core__engine__AudioProcessorEngine_resend_chart_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__core__engine__AudioProcessorEngine_set_channel_mix_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => {
+            wire__crate__api__core__engine__AudioProcessorEngine_set_chart_down_sample_method_impl(
+                port,
+                ptr,
+                rust_vec_len,
+                data_len,
+            )
         }
-        return ans_;
+        24 => wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_method_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_points_num_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__core__engine__AudioProcessorEngine_set_memory_budget_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__core__engine__AudioProcessorEngine_set_overview_points_num_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__core__engine__AudioProcessorEngine_set_sample_storage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__core__engine__AudioProcessorEngine_set_sinc_interpolation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_chart_visible_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_points_num_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__core__engine__AudioProcessorEngine_set_viewport_range_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__core__engine__AudioProcessorEngine_stored_audio_keys_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__core__engine__AudioProcessorEngine_true_peak_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__types__decode__CancellationToken_cancel_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__types__decode__CancellationToken_is_cancelled_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__types__decode__CancellationToken_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__types__decode__DecodeOptions_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__types__decode__DecodeProgress_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__types__decode__DecodeReport_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__types__decode__DecodeReport_is_clean_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__types__decode__DecodeReport_mark_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__types__decode__RawSampleFormat_bytes_per_sample_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__events__communicator_events__create_chart_event_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__events__decode_events__create_decode_event_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => wire__crate__api__core__engine__create_default_engine_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__core__engine__create_engine_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__events__communicator_events__emit_chart_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__events__decode_events__emit_decode_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for crate::api::sampling::minmax::Minmax {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::sampling::minmax::Minmax {};
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioProcessorEngine> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<AudioProcessorEngine>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioProcessorEngine>> for AudioProcessorEngine {
+    fn into_into_dart(self) -> FrbWrapper<AudioProcessorEngine> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn AudioDecoder + Send + Sync>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<Box<dyn AudioDecoder + Send + Sync>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn AudioDecoder + Send + Sync>>>
+    for Box<dyn AudioDecoder + Send + Sync>
+{
+    fn into_into_dart(self) -> FrbWrapper<Box<dyn AudioDecoder + Send + Sync>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn AudioStorage + Send + Sync>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<Box<dyn AudioStorage + Send + Sync>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn AudioStorage + Send + Sync>>>
+    for Box<dyn AudioStorage + Send + Sync>
+{
+    fn into_into_dart(self) -> FrbWrapper<Box<dyn AudioStorage + Send + Sync>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>>>
+    for Box<dyn CachedChartStorage + Send + Sync>
+{
+    fn into_into_dart(self) -> FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn Communicator + Send + Sync>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<Box<dyn Communicator + Send + Sync>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn Communicator + Send + Sync>>>
+    for Box<dyn Communicator + Send + Sync>
+{
+    fn into_into_dart(self) -> FrbWrapper<Box<dyn Communicator + Send + Sync>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<CancellationToken> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CancellationToken> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CancellationToken>> for CancellationToken {
+    fn into_into_dart(self) -> FrbWrapper<CancellationToken> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::error::AppError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::types::error::AppError::Io(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::Format(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::Decode(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::Storage(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::Cache(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::NotFound(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::Generic(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::ProcessingError(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::error::AppError::InvalidChartName(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::error::AppError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::error::AppError>
+    for crate::api::types::error::AppError
+{
+    fn into_into_dart(self) -> crate::api::types::error::AppError {
+        self
+    }
+}
 
-impl SseDecode for crate::api::types::chart::Point {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_x = <f32>::sse_decode(deserializer);
-        let mut var_y = <f32>::sse_decode(deserializer);
-        return crate::api::types::chart::Point { x: var_x, y: var_y };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::audio::AudioInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sample_rate.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.channel_layout.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+            self.container.into_into_dart().into_dart(),
+            self.bits_per_sample.into_into_dart().into_dart(),
+            self.total_frames.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.encoder_delay.into_into_dart().into_dart(),
+            self.encoder_padding.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.decode_report.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for (f32, f32) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <f32>::sse_decode(deserializer);
-        let mut var_field1 = <f32>::sse_decode(deserializer);
-        return (var_field0, var_field1);
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::audio::AudioInfo
+{
 }
-
-impl SseDecode for crate::api::util::format_getter::SimpleFormatGetter {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::util::format_getter::SimpleFormatGetter {};
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::AudioInfo>
+    for crate::api::types::audio::AudioInfo
+{
+    fn into_into_dart(self) -> crate::api::types::audio::AudioInfo {
+        self
     }
 }
 
-impl SseDecode for crate::api::communicator::stream_sink_communicator::StreamCommunicator {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::communicator::stream_sink_communicator::StreamCommunicator {};
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::memory::AudioMemoryUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::decoder::symphonia_decoder::SymphoniaDecoder {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::decoder::symphonia_decoder::SymphoniaDecoder {};
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::memory::AudioMemoryUsage
+{
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::memory::AudioMemoryUsage>
+    for crate::api::types::memory::AudioMemoryUsage
+{
+    fn into_into_dart(self) -> crate::api::types::memory::AudioMemoryUsage {
+        self
     }
 }
 
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::file::AudioSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::types::file::AudioSource::Bytes(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::file::AudioSource::Path(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::file::AudioSource
+{
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::file::AudioSource>
+    for crate::api::types::file::AudioSource
+{
+    fn into_into_dart(self) -> crate::api::types::file::AudioSource {
+        self
     }
 }
 
-impl SseDecode for crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator {};
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::config::AudioStorageBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::types::config::AudioStorageBackend::Memory => [0.into_dart()].into_dart(),
+            crate::api::types::config::AudioStorageBackend::Disk { directory } => {
+                [1.into_dart(), directory.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::config::AudioStorageBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::config::AudioStorageBackend>
+    for crate::api::types::config::AudioStorageBackend
+{
+    fn into_into_dart(self) -> crate::api::types::config::AudioStorageBackend {
+        self
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-                        3 => wire__crate__api__core__engine__AudioProcessorEngine_add_impl(port, ptr, rust_vec_len, data_len),
-4 => wire__crate__api__core__engine__AudioProcessorEngine_add_chart_impl(port, ptr, rust_vec_len, data_len),
-5 => wire__crate__api__core__engine__AudioProcessorEngine_new_impl(port, ptr, rust_vec_len, data_len),
-6 => wire__crate__api__core__engine__AudioProcessorEngine_remove_audio_impl(port, ptr, rust_vec_len, data_len),
-7 => wire__crate__api__core__engine__AudioProcessorEngine_remove_chart_impl(port, ptr, rust_vec_len, data_len),
-8 => wire__crate__api__core__engine__AudioProcessorEngine_reserve_visible_impl(port, ptr, rust_vec_len, data_len),
-9 => wire__crate__api__core__engine__AudioProcessorEngine_set_config_impl(port, ptr, rust_vec_len, data_len),
-10 => wire__crate__api__core__engine__AudioProcessorEngine_set_down_sample_points_num_impl(port, ptr, rust_vec_len, data_len),
-11 => wire__crate__api__core__engine__AudioProcessorEngine_set_index_range_impl(port, ptr, rust_vec_len, data_len),
-12 => wire__crate__api__core__engine__AudioProcessorEngine_set_selected_audio_impl(port, ptr, rust_vec_len, data_len),
-13 => wire__crate__api__types__audio__Audio_audio_to_chart_impl(port, ptr, rust_vec_len, data_len),
-32 => wire__crate__api__types__chart__Chart_get_range_impl(port, ptr, rust_vec_len, data_len),
-37 => wire__crate__api__storage__kv_audio_storage__KvAudioStorage_load_impl(port, ptr, rust_vec_len, data_len),
-38 => wire__crate__api__storage__kv_audio_storage__KvAudioStorage_new_impl(port, ptr, rust_vec_len, data_len),
-39 => wire__crate__api__storage__kv_audio_storage__KvAudioStorage_remove_impl(port, ptr, rust_vec_len, data_len),
-40 => wire__crate__api__storage__kv_audio_storage__KvAudioStorage_save_impl(port, ptr, rust_vec_len, data_len),
-41 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_add_impl(port, ptr, rust_vec_len, data_len),
-44 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_impl(port, ptr, rust_vec_len, data_len),
-45 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_get_all_cache_impl(port, ptr, rust_vec_len, data_len),
-46 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_new_impl(port, ptr, rust_vec_len, data_len),
-47 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_remove_impl(port, ptr, rust_vec_len, data_len),
-65 => wire__crate__api__types__config__config_default_impl(port, ptr, rust_vec_len, data_len),
-66 => wire__crate__api__events__communicator_events__create_chart_event_stream_impl(port, ptr, rust_vec_len, data_len),
-67 => wire__crate__api__core__engine__create_default_engine_impl(port, ptr, rust_vec_len, data_len),
-68 => wire__crate__api__events__communicator_events__emit_chart_event_impl(port, ptr, rust_vec_len, data_len),
-69 => wire__crate__api__transform__energy__energy_calculator_transform_impl(port, ptr, rust_vec_len, data_len),
-70 => wire__crate__api__sampling__equal_step__equal_step_down_sample_impl(port, ptr, rust_vec_len, data_len),
-71 => wire__crate__api__transform__fft__fft_transform_transform_impl(port, ptr, rust_vec_len, data_len),
-72 => wire__crate__api__util__get_min_max__get_min_max_par_impl(port, ptr, rust_vec_len, data_len),
-73 => wire__crate__api__sampling__minmax__minmax_down_sample_impl(port, ptr, rust_vec_len, data_len),
-74 => wire__crate__api__util__format_getter__simple_format_getter_get_format_impl(port, ptr, rust_vec_len, data_len),
-75 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_add_chart_impl(port, ptr, rust_vec_len, data_len),
-76 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_new_impl(port, ptr, rust_vec_len, data_len),
-77 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_remove_all_charts_impl(port, ptr, rust_vec_len, data_len),
-78 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_remove_chart_impl(port, ptr, rust_vec_len, data_len),
-79 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_all_charts_impl(port, ptr, rust_vec_len, data_len),
-80 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_max_index_impl(port, ptr, rust_vec_len, data_len),
-81 => wire__crate__api__communicator__stream_sink_communicator__stream_communicator_update_y_range_impl(port, ptr, rust_vec_len, data_len),
-82 => wire__crate__api__decoder__symphonia_decoder__symphonia_decoder_decode_impl(port, ptr, rust_vec_len, data_len),
-83 => wire__crate__api__decoder__symphonia_decoder__symphonia_decoder_new_impl(port, ptr, rust_vec_len, data_len),
-84 => wire__crate__api__transform__zero_crossing_rate__zero_crossing_rate_calculator_transform_impl(port, ptr, rust_vec_len, data_len),
-                        _ => unreachable!(),
-                    }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::audio::AudioTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-                        1 => wire__crate__api__types__audio__AudioData_auto_accessor_get_samples_impl(ptr, rust_vec_len, data_len),
-2 => wire__crate__api__types__audio__AudioData_auto_accessor_set_samples_impl(ptr, rust_vec_len, data_len),
-14 => wire__crate__api__types__audio__Audio_auto_accessor_get_data_impl(ptr, rust_vec_len, data_len),
-15 => wire__crate__api__types__audio__Audio_auto_accessor_get_info_impl(ptr, rust_vec_len, data_len),
-16 => wire__crate__api__types__audio__Audio_auto_accessor_set_data_impl(ptr, rust_vec_len, data_len),
-17 => wire__crate__api__types__audio__Audio_auto_accessor_set_info_impl(ptr, rust_vec_len, data_len),
-18 => wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_chart_impl(ptr, rust_vec_len, data_len),
-19 => wire__crate__api__types__chart__ChartWIthKey_auto_accessor_get_key_impl(ptr, rust_vec_len, data_len),
-20 => wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_chart_impl(ptr, rust_vec_len, data_len),
-21 => wire__crate__api__types__chart__ChartWIthKey_auto_accessor_set_key_impl(ptr, rust_vec_len, data_len),
-22 => wire__crate__api__types__chart__Chart_auto_accessor_get_data_type_impl(ptr, rust_vec_len, data_len),
-23 => wire__crate__api__types__chart__Chart_auto_accessor_get_max_y_impl(ptr, rust_vec_len, data_len),
-24 => wire__crate__api__types__chart__Chart_auto_accessor_get_min_y_impl(ptr, rust_vec_len, data_len),
-25 => wire__crate__api__types__chart__Chart_auto_accessor_get_points_impl(ptr, rust_vec_len, data_len),
-26 => wire__crate__api__types__chart__Chart_auto_accessor_get_visible_impl(ptr, rust_vec_len, data_len),
-27 => wire__crate__api__types__chart__Chart_auto_accessor_set_data_type_impl(ptr, rust_vec_len, data_len),
-28 => wire__crate__api__types__chart__Chart_auto_accessor_set_max_y_impl(ptr, rust_vec_len, data_len),
-29 => wire__crate__api__types__chart__Chart_auto_accessor_set_min_y_impl(ptr, rust_vec_len, data_len),
-30 => wire__crate__api__types__chart__Chart_auto_accessor_set_points_impl(ptr, rust_vec_len, data_len),
-31 => wire__crate__api__types__chart__Chart_auto_accessor_set_visible_impl(ptr, rust_vec_len, data_len),
-33 => wire__crate__api__types__file__File_auto_accessor_get_bytes_impl(ptr, rust_vec_len, data_len),
-34 => wire__crate__api__types__file__File_auto_accessor_get_file_path_impl(ptr, rust_vec_len, data_len),
-35 => wire__crate__api__types__file__File_auto_accessor_set_bytes_impl(ptr, rust_vec_len, data_len),
-36 => wire__crate__api__types__file__File_auto_accessor_set_file_path_impl(ptr, rust_vec_len, data_len),
-42 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto_accessor_get_config_impl(ptr, rust_vec_len, data_len),
-43 => wire__crate__api__storage__kv_cached_chart_storage__KvCachedChartStorage_auto_accessor_set_config_impl(ptr, rust_vec_len, data_len),
-                        _ => unreachable!(),
-                    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::audio::AudioTag
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::AudioTag>
+    for crate::api::types::audio::AudioTag
+{
+    fn into_into_dart(self) -> crate::api::types::audio::AudioTag {
+        self
+    }
 }
-
-// Section: rust2dart
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<AtomicBool>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::audio::ChannelLayout {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::audio::ChannelLayout::Mono => [0.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelLayout::Stereo => [1.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelLayout::Multichannel(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Arc<AtomicBool>> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<AtomicBool>>> for Arc<AtomicBool> {
-    fn into_into_dart(self) -> FrbWrapper<Arc<AtomicBool>> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::audio::ChannelLayout
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::ChannelLayout>
+    for crate::api::types::audio::ChannelLayout
+{
+    fn into_into_dart(self) -> crate::api::types::audio::ChannelLayout {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<Vec<Point>>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::audio::ChannelMix {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::audio::ChannelMix::Mean => [0.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelMix::Left => [1.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelMix::Right => [2.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelMix::Mid => [3.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelMix::Side => [4.into_dart()].into_dart(),
+            crate::api::types::audio::ChannelMix::Channel(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Arc<Vec<Point>>> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<Vec<Point>>>> for Arc<Vec<Point>> {
-    fn into_into_dart(self) -> FrbWrapper<Arc<Vec<Point>>> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::audio::ChannelMix
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::ChannelMix>
+    for crate::api::types::audio::ChannelMix
+{
+    fn into_into_dart(self) -> crate::api::types::audio::ChannelMix {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<Vec<f32>>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::ChartAxis {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.data_type.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.min_y.into_into_dart().into_dart(),
+            self.max_y.into_into_dart().into_dart(),
+            self.keys.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Arc<Vec<f32>>> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<Vec<f32>>>> for Arc<Vec<f32>> {
-    fn into_into_dart(self) -> FrbWrapper<Arc<Vec<f32>>> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::chart::ChartAxis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::ChartAxis>
+    for crate::api::types::chart::ChartAxis
+{
+    fn into_into_dart(self) -> crate::api::types::chart::ChartAxis {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<Vec<u8>>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::config::ChartCacheBackend {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::config::ChartCacheBackend::Memory => [0.into_dart()].into_dart(),
+            crate::api::types::config::ChartCacheBackend::Disk { directory } => {
+                [1.into_dart(), directory.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Arc<Vec<u8>>> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<Vec<u8>>>> for Arc<Vec<u8>> {
-    fn into_into_dart(self) -> FrbWrapper<Arc<Vec<u8>>> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::config::ChartCacheBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::config::ChartCacheBackend>
+    for crate::api::types::config::ChartCacheBackend
+{
+    fn into_into_dart(self) -> crate::api::types::config::ChartCacheBackend {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Audio> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::events::ChartEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::events::ChartEvent::AddChart {
+                viewport,
+                chart,
+                seq,
+            } => [
+                0.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                chart.into_into_dart().into_dart(),
+                seq.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::RemoveChart { key, data_type } => [
+                1.into_dart(),
+                key.into_into_dart().into_dart(),
+                data_type.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::UpdateAllCharts { charts } => {
+                [2.into_dart(), charts.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::types::events::ChartEvent::UpdateOverview { viewport, chart } => [
+                3.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                chart.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::RemoveAllCharts => [4.into_dart()].into_dart(),
+            crate::api::types::events::ChartEvent::UpdateMaxIndex {
+                viewport,
+                max_index,
+            } => [
+                5.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                max_index.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::UpdateAxes { viewport, axes } => [
+                6.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                axes.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::UpdateRegions { key, regions } => [
+                7.into_dart(),
+                key.into_into_dart().into_dart(),
+                regions.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::AppendPoints {
+                viewport,
+                key,
+                data_type,
+                points,
+                base_seq,
+                seq,
+            } => [
+                8.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                key.into_into_dart().into_dart(),
+                data_type.into_into_dart().into_dart(),
+                points.into_into_dart().into_dart(),
+                base_seq.into_into_dart().into_dart(),
+                seq.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::ChartEvent::ReplaceRange {
+                viewport,
+                key,
+                data_type,
+                start,
+                end,
+                points,
+                base_seq,
+                seq,
+            } => [
+                9.into_dart(),
+                viewport.into_into_dart().into_dart(),
+                key.into_into_dart().into_dart(),
+                data_type.into_into_dart().into_dart(),
+                start.into_into_dart().into_dart(),
+                end.into_into_dart().into_dart(),
+                points.into_into_dart().into_dart(),
+                base_seq.into_into_dart().into_dart(),
+                seq.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Audio> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Audio>> for Audio {
-    fn into_into_dart(self) -> FrbWrapper<Audio> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::events::ChartEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::events::ChartEvent>
+    for crate::api::types::events::ChartEvent
+{
+    fn into_into_dart(self) -> crate::api::types::events::ChartEvent {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioData> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::memory::ChartMemoryUsage {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.key.into_into_dart().into_dart(),
+            self.data_type.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AudioData> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioData>> for AudioData {
-    fn into_into_dart(self) -> FrbWrapper<AudioData> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::memory::ChartMemoryUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::memory::ChartMemoryUsage>
+    for crate::api::types::memory::ChartMemoryUsage
+{
+    fn into_into_dart(self) -> crate::api::types::memory::ChartMemoryUsage {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioProcessorEngine> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::ChartPayload {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::chart::ChartPayload::Points { xs, ys } => [
+                0.into_dart(),
+                xs.into_into_dart().into_dart(),
+                ys.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::chart::ChartPayload::Uniform { x0, dx, ys } => [
+                1.into_dart(),
+                x0.into_into_dart().into_dart(),
+                dx.into_into_dart().into_dart(),
+                ys.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<AudioProcessorEngine>
+    for crate::api::types::chart::ChartPayload
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioProcessorEngine>> for AudioProcessorEngine {
-    fn into_into_dart(self) -> FrbWrapper<AudioProcessorEngine> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::ChartPayload>
+    for crate::api::types::chart::ChartPayload
+{
+    fn into_into_dart(self) -> crate::api::types::chart::ChartPayload {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn AudioDecoder + Send + Sync>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::ChartRegion {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.start_x.into_into_dart().into_dart(),
+            self.end_x.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<Box<dyn AudioDecoder + Send + Sync>>
+    for crate::api::types::chart::ChartRegion
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn AudioDecoder + Send + Sync>>>
-    for Box<dyn AudioDecoder + Send + Sync>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::ChartRegion>
+    for crate::api::types::chart::ChartRegion
 {
-    fn into_into_dart(self) -> FrbWrapper<Box<dyn AudioDecoder + Send + Sync>> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::types::chart::ChartRegion {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn AudioStorage + Send + Sync>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::CommunicatorChart {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.key.into_into_dart().into_dart(),
+            self.data_type.into_into_dart().into_dart(),
+            self.chart.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<Box<dyn AudioStorage + Send + Sync>>
+    for crate::api::types::chart::CommunicatorChart
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn AudioStorage + Send + Sync>>>
-    for Box<dyn AudioStorage + Send + Sync>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::CommunicatorChart>
+    for crate::api::types::chart::CommunicatorChart
 {
-    fn into_into_dart(self) -> FrbWrapper<Box<dyn AudioStorage + Send + Sync>> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::types::chart::CommunicatorChart {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::config::Config {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [self.frame_size.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>>
+    for crate::api::types::config::Config
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>>>
-    for Box<dyn CachedChartStorage + Send + Sync>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::config::Config>
+    for crate::api::types::config::Config
 {
-    fn into_into_dart(self) -> FrbWrapper<Box<dyn CachedChartStorage + Send + Sync>> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::types::config::Config {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn Communicator + Send + Sync>> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::DamagedRange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.start_frame.into_into_dart().into_dart(),
+            self.end_frame.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<Box<dyn Communicator + Send + Sync>>
+    for crate::api::types::decode::DamagedRange
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn Communicator + Send + Sync>>>
-    for Box<dyn Communicator + Send + Sync>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::DamagedRange>
+    for crate::api::types::decode::DamagedRange
 {
-    fn into_into_dart(self) -> FrbWrapper<Box<dyn Communicator + Send + Sync>> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::types::decode::DamagedRange {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Chart> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::DataType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Audio => 0.into_dart(),
+            Self::Spectrum => 1.into_dart(),
+            Self::Energy => 2.into_dart(),
+            Self::ZeroCrossingRate => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Chart> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Chart>> for Chart {
-    fn into_into_dart(self) -> FrbWrapper<Chart> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::chart::DataType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::DataType>
+    for crate::api::types::chart::DataType
+{
+    fn into_into_dart(self) -> crate::api::types::chart::DataType {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ChartWIthKey> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::events::DecodeEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::types::events::DecodeEvent::Progress { key, progress } => [
+                0.into_dart(),
+                key.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::DecodeEvent::Finished { key, progress } => [
+                1.into_dart(),
+                key.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::DecodeEvent::Cancelled { key, progress } => [
+                2.into_dart(),
+                key.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::types::events::DecodeEvent::Failed { key, message } => [
+                3.into_dart(),
+                key.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ChartWIthKey> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ChartWIthKey>> for ChartWIthKey {
-    fn into_into_dart(self) -> FrbWrapper<ChartWIthKey> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::events::DecodeEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::events::DecodeEvent>
+    for crate::api::types::events::DecodeEvent
+{
+    fn into_into_dart(self) -> crate::api::types::events::DecodeEvent {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<File> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::DecodeOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [self.track_id.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<File> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<File>> for File {
-    fn into_into_dart(self) -> FrbWrapper<File> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::decode::DecodeOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::DecodeOptions>
+    for crate::api::types::decode::DecodeOptions
+{
+    fn into_into_dart(self) -> crate::api::types::decode::DecodeOptions {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<KvAudioStorage> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::DecodeProgress {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.packets.into_into_dart().into_dart(),
+            self.bytes_read.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.decoded_frames.into_into_dart().into_dart(),
+            self.decoded_duration.into_into_dart().into_dart(),
+            self.estimated_duration.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<KvAudioStorage> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<KvAudioStorage>> for KvAudioStorage {
-    fn into_into_dart(self) -> FrbWrapper<KvAudioStorage> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::decode::DecodeProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::DecodeProgress>
+    for crate::api::types::decode::DecodeProgress
+{
+    fn into_into_dart(self) -> crate::api::types::decode::DecodeProgress {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<KvCachedChartStorage> {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::DecodeReport {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.damaged.into_into_dart().into_dart(),
+            self.skipped_packets.into_into_dart().into_dart(),
+            self.decoder_resets.into_into_dart().into_dart(),
+            self.truncated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<KvCachedChartStorage>
+    for crate::api::types::decode::DecodeReport
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<KvCachedChartStorage>> for KvCachedChartStorage {
-    fn into_into_dart(self) -> FrbWrapper<KvCachedChartStorage> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::DecodeReport>
+    for crate::api::types::decode::DecodeReport
+{
+    fn into_into_dart(self) -> crate::api::types::decode::DecodeReport {
+        self
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::error::AppError {
+impl flutter_rust_bridge::IntoDart for crate::api::util::format_detector::DetectedFormat {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::types::error::AppError::Io(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::Format(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::Decode(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::Storage(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::Cache(field0) => {
-                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::NotFound(field0) => {
-                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::Generic(field0) => {
-                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::ProcessingError(field0) => {
-                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::error::AppError::InvalidChartName(field0) => {
-                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.container.into_into_dart().into_dart(),
+            self.extension.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::error::AppError
+    for crate::api::util::format_detector::DetectedFormat
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::error::AppError>
-    for crate::api::types::error::AppError
+impl flutter_rust_bridge::IntoIntoDart<crate::api::util::format_detector::DetectedFormat>
+    for crate::api::util::format_detector::DetectedFormat
 {
-    fn into_into_dart(self) -> crate::api::types::error::AppError {
+    fn into_into_dart(self) -> crate::api::util::format_detector::DetectedFormat {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::audio::AudioInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::DownSampleMethod {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.sample_rate.into_into_dart().into_dart()].into_dart()
+        match self {
+            Self::MinMax => 0.into_dart(),
+            Self::EqualStep => 1.into_dart(),
+            Self::Lttb => 2.into_dart(),
+            Self::M4 => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::audio::AudioInfo
+    for crate::api::types::chart::DownSampleMethod
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::AudioInfo>
-    for crate::api::types::audio::AudioInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::DownSampleMethod>
+    for crate::api::types::chart::DownSampleMethod
 {
-    fn into_into_dart(self) -> crate::api::types::audio::AudioInfo {
+    fn into_into_dart(self) -> crate::api::types::chart::DownSampleMethod {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::events::ChartEvent {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::Endianness {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            crate::api::types::events::ChartEvent::AddChart { chart } => {
-                [0.into_dart(), chart.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::events::ChartEvent::RemoveChart { key, data_type } => [
-                1.into_dart(),
-                key.into_into_dart().into_dart(),
-                data_type.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::types::events::ChartEvent::UpdateAllCharts { charts } => {
-                [2.into_dart(), charts.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::events::ChartEvent::RemoveAllCharts => [3.into_dart()].into_dart(),
-            crate::api::types::events::ChartEvent::UpdateMaxIndex { max_index } => {
-                [4.into_dart(), max_index.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::types::events::ChartEvent::UpdateYRange { min_y, max_y } => [
-                5.into_dart(),
-                min_y.into_into_dart().into_dart(),
-                max_y.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            _ => {
-                unimplemented!("");
-            }
+            Self::Little => 0.into_dart(),
+            Self::Big => 1.into_dart(),
+            _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::events::ChartEvent
+    for crate::api::types::decode::Endianness
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::events::ChartEvent>
-    for crate::api::types::events::ChartEvent
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::Endianness>
+    for crate::api::types::decode::Endianness
 {
-    fn into_into_dart(self) -> crate::api::types::events::ChartEvent {
+    fn into_into_dart(self) -> crate::api::types::decode::Endianness {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::chart::CommunicatorChart {
+impl flutter_rust_bridge::IntoDart for crate::api::types::encode::ExportContainer {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.key.into_into_dart().into_dart(),
-            self.data_type.into_into_dart().into_dart(),
-            self.chart.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Wav => 0.into_dart(),
+            Self::Flac => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::chart::CommunicatorChart
+    for crate::api::types::encode::ExportContainer
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::CommunicatorChart>
-    for crate::api::types::chart::CommunicatorChart
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::encode::ExportContainer>
+    for crate::api::types::encode::ExportContainer
 {
-    fn into_into_dart(self) -> crate::api::types::chart::CommunicatorChart {
+    fn into_into_dart(self) -> crate::api::types::encode::ExportContainer {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::config::Config {
+impl flutter_rust_bridge::IntoDart for crate::api::types::encode::ExportOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.frame_size.into_into_dart().into_dart()].into_dart()
+        [
+            self.container.into_into_dart().into_dart(),
+            self.sample_format.into_into_dart().into_dart(),
+            self.dither.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::config::Config
+    for crate::api::types::encode::ExportOptions
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::config::Config>
-    for crate::api::types::config::Config
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::encode::ExportOptions>
+    for crate::api::types::encode::ExportOptions
 {
-    fn into_into_dart(self) -> crate::api::types::config::Config {
+    fn into_into_dart(self) -> crate::api::types::encode::ExportOptions {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::chart::DataType {
+impl flutter_rust_bridge::IntoDart for crate::api::types::encode::ExportSampleFormat {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::Audio => 0.into_dart(),
-            Self::Spectrum => 1.into_dart(),
-            Self::Energy => 2.into_dart(),
-            Self::ZeroCrossingRate => 3.into_dart(),
+            Self::I16 => 0.into_dart(),
+            Self::I24 => 1.into_dart(),
+            Self::F32 => 2.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::chart::DataType
+    for crate::api::types::encode::ExportSampleFormat
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::DataType>
-    for crate::api::types::chart::DataType
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::encode::ExportSampleFormat>
+    for crate::api::types::encode::ExportSampleFormat
 {
-    fn into_into_dart(self) -> crate::api::types::chart::DataType {
+    fn into_into_dart(self) -> crate::api::types::encode::ExportSampleFormat {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transform::energy::EnergyCalculator {
+impl flutter_rust_bridge::IntoDart for crate::api::types::memory::MemoryUsage {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        [
+            self.audio.into_into_dart().into_dart(),
+            self.charts.into_into_dart().into_dart(),
+            self.audio_bytes.into_into_dart().into_dart(),
+            self.chart_bytes.into_into_dart().into_dart(),
+            self.audio_budget.into_into_dart().into_dart(),
+            self.chart_budget.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transform::energy::EnergyCalculator
+    for crate::api::types::memory::MemoryUsage
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transform::energy::EnergyCalculator>
-    for crate::api::transform::energy::EnergyCalculator
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::memory::MemoryUsage>
+    for crate::api::types::memory::MemoryUsage
 {
-    fn into_into_dart(self) -> crate::api::transform::energy::EnergyCalculator {
+    fn into_into_dart(self) -> crate::api::types::memory::MemoryUsage {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::sampling::equal_step::EqualStep {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::RawPcmFormat {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        [
+            self.sample_format.into_into_dart().into_dart(),
+            self.endianness.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.header_skip.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::sampling::equal_step::EqualStep
+    for crate::api::types::decode::RawPcmFormat
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::sampling::equal_step::EqualStep>
-    for crate::api::sampling::equal_step::EqualStep
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::RawPcmFormat>
+    for crate::api::types::decode::RawPcmFormat
 {
-    fn into_into_dart(self) -> crate::api::sampling::equal_step::EqualStep {
+    fn into_into_dart(self) -> crate::api::types::decode::RawPcmFormat {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::transform::fft::FftTransform {
+impl flutter_rust_bridge::IntoDart for crate::api::types::decode::RawSampleFormat {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        match self {
+            Self::U8 => 0.into_dart(),
+            Self::S16 => 1.into_dart(),
+            Self::S24 => 2.into_dart(),
+            Self::S32 => 3.into_dart(),
+            Self::F32 => 4.into_dart(),
+            Self::MuLaw => 5.into_dart(),
+            Self::ALaw => 6.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transform::fft::FftTransform
+    for crate::api::types::decode::RawSampleFormat
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::transform::fft::FftTransform>
-    for crate::api::transform::fft::FftTransform
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::decode::RawSampleFormat>
+    for crate::api::types::decode::RawSampleFormat
 {
-    fn into_into_dart(self) -> crate::api::transform::fft::FftTransform {
+    fn into_into_dart(self) -> crate::api::types::decode::RawSampleFormat {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::sampling::minmax::Minmax {
+impl flutter_rust_bridge::IntoDart for crate::api::types::samples::SampleStorage {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        match self {
+            Self::F32 => 0.into_dart(),
+            Self::I16 => 1.into_dart(),
+            Self::F16 => 2.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::sampling::minmax::Minmax
+    for crate::api::types::samples::SampleStorage
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::sampling::minmax::Minmax>
-    for crate::api::sampling::minmax::Minmax
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::samples::SampleStorage>
+    for crate::api::types::samples::SampleStorage
 {
-    fn into_into_dart(self) -> crate::api::sampling::minmax::Minmax {
+    fn into_into_dart(self) -> crate::api::types::samples::SampleStorage {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::types::chart::Point {
+impl flutter_rust_bridge::IntoDart for crate::api::types::audio::TrackInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.x.into_into_dart().into_dart(),
-            self.y.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.channel_layout.into_into_dart().into_dart(),
+            self.total_frames.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::types::chart::Point
+    for crate::api::types::audio::TrackInfo
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::Point>
-    for crate::api::types::chart::Point
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::audio::TrackInfo>
+    for crate::api::types::audio::TrackInfo
 {
-    fn into_into_dart(self) -> crate::api::types::chart::Point {
+    fn into_into_dart(self) -> crate::api::types::audio::TrackInfo {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::util::format_getter::SimpleFormatGetter {
+impl flutter_rust_bridge::IntoDart for crate::api::types::chart::TruePeak {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        [
+            self.x.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::util::format_getter::SimpleFormatGetter
+    for crate::api::types::chart::TruePeak
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::util::format_getter::SimpleFormatGetter>
-    for crate::api::util::format_getter::SimpleFormatGetter
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::chart::TruePeak>
+    for crate::api::types::chart::TruePeak
 {
-    fn into_into_dart(self) -> crate::api::util::format_getter::SimpleFormatGetter {
+    fn into_into_dart(self) -> crate::api::types::chart::TruePeak {
         self
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::communicator::stream_sink_communicator::StreamCommunicator
-{
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::communicator::stream_sink_communicator::StreamCommunicator
+
+impl SseEncode for AudioProcessorEngine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Box<dyn AudioDecoder + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn AudioDecoder + Send + Sync>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Box<dyn AudioStorage + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn AudioStorage + Send + Sync>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Box<dyn CachedChartStorage + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn CachedChartStorage + Send + Sync>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Box<dyn Communicator + Send + Sync> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                Box<dyn Communicator + Send + Sync>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for CancellationToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::communicator::stream_sink_communicator::StreamCommunicator,
-    > for crate::api::communicator::stream_sink_communicator::StreamCommunicator
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn AudioDecoder + Send + Sync>,
+        >,
+    >
 {
-    fn into_into_dart(
-        self,
-    ) -> crate::api::communicator::stream_sink_communicator::StreamCommunicator {
-        self
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::decoder::symphonia_decoder::SymphoniaDecoder {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn AudioStorage + Send + Sync>,
+        >,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::decoder::symphonia_decoder::SymphoniaDecoder
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn CachedChartStorage + Send + Sync>,
+        >,
+    >
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::decoder::symphonia_decoder::SymphoniaDecoder>
-    for crate::api::decoder::symphonia_decoder::SymphoniaDecoder
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+            Box<dyn Communicator + Send + Sync>,
+        >,
+    >
 {
-    fn into_into_dart(self) -> crate::api::decoder::symphonia_decoder::SymphoniaDecoder {
-        self
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
 {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator
+
+impl SseEncode
+    for StreamSink<
+        crate::api::types::events::ChartEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator,
-    > for crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator
+
+impl SseEncode
+    for StreamSink<
+        crate::api::types::events::DecodeEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
 {
-    fn into_into_dart(
-        self,
-    ) -> crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator {
-        self
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::error::AppError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::types::error::AppError::Io(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::Format(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::Decode(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::Storage(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::Cache(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::NotFound(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::Generic(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::ProcessingError(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::types::error::AppError::InvalidChartName(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::types::audio::AudioInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <crate::api::types::audio::ChannelLayout>::sse_encode(self.channel_layout, serializer);
+        <String>::sse_encode(self.codec, serializer);
+        <String>::sse_encode(self.container, serializer);
+        <Option<u32>>::sse_encode(self.bits_per_sample, serializer);
+        <u64>::sse_encode(self.total_frames, serializer);
+        <f64>::sse_encode(self.duration, serializer);
+        <Option<u32>>::sse_encode(self.encoder_delay, serializer);
+        <Option<u32>>::sse_encode(self.encoder_padding, serializer);
+        <Vec<crate::api::types::audio::AudioTag>>::sse_encode(self.tags, serializer);
+        <crate::api::types::decode::DecodeReport>::sse_encode(self.decode_report, serializer);
     }
 }
 
-impl SseEncode for Arc<AtomicBool> {
+impl SseEncode for crate::api::types::memory::AudioMemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < AtomicBool >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.key, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
     }
 }
 
-impl SseEncode for Arc<Vec<Point>> {
+impl SseEncode for crate::api::types::file::AudioSource {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < Point > >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        match self {
+            crate::api::types::file::AudioSource::Bytes(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            crate::api::types::file::AudioSource::Path(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Arc<Vec<f32>> {
+impl SseEncode for crate::api::types::config::AudioStorageBackend {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < f32 > >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        match self {
+            crate::api::types::config::AudioStorageBackend::Memory => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::types::config::AudioStorageBackend::Disk { directory } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(directory, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Arc<Vec<u8>> {
+impl SseEncode for crate::api::types::audio::AudioTag {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
 
-impl SseEncode for Audio {
+impl SseEncode for crate::api::types::audio::ChannelLayout {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        match self {
+            crate::api::types::audio::ChannelLayout::Mono => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::types::audio::ChannelLayout::Stereo => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::types::audio::ChannelLayout::Multichannel(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for AudioData {
+impl SseEncode for crate::api::types::audio::ChannelMix {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        match self {
+            crate::api::types::audio::ChannelMix::Mean => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::types::audio::ChannelMix::Left => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::types::audio::ChannelMix::Right => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::types::audio::ChannelMix::Mid => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::types::audio::ChannelMix::Side => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::types::audio::ChannelMix::Channel(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for AudioProcessorEngine {
+impl SseEncode for crate::api::types::chart::ChartAxis {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <crate::api::types::chart::DataType>::sse_encode(self.data_type, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.unit, serializer);
+        <f32>::sse_encode(self.min_y, serializer);
+        <f32>::sse_encode(self.max_y, serializer);
+        <Vec<String>>::sse_encode(self.keys, serializer);
     }
 }
 
-impl SseEncode for Box<dyn AudioDecoder + Send + Sync> {
+impl SseEncode for crate::api::types::config::ChartCacheBackend {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn AudioDecoder + Send + Sync>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        match self {
+            crate::api::types::config::ChartCacheBackend::Memory => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::types::config::ChartCacheBackend::Disk { directory } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(directory, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Box<dyn AudioStorage + Send + Sync> {
+impl SseEncode for crate::api::types::events::ChartEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn AudioStorage + Send + Sync>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        match self {
+            crate::api::types::events::ChartEvent::AddChart {
+                viewport,
+                chart,
+                seq,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <crate::api::types::chart::CommunicatorChart>::sse_encode(chart, serializer);
+                <u64>::sse_encode(seq, serializer);
+            }
+            crate::api::types::events::ChartEvent::RemoveChart { key, data_type } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::chart::DataType>::sse_encode(data_type, serializer);
+            }
+            crate::api::types::events::ChartEvent::UpdateAllCharts { charts } => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<crate::api::types::chart::CommunicatorChart>>::sse_encode(charts, serializer);
+            }
+            crate::api::types::events::ChartEvent::UpdateOverview { viewport, chart } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <crate::api::types::chart::CommunicatorChart>::sse_encode(chart, serializer);
+            }
+            crate::api::types::events::ChartEvent::RemoveAllCharts => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::types::events::ChartEvent::UpdateMaxIndex {
+                viewport,
+                max_index,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <f32>::sse_encode(max_index, serializer);
+            }
+            crate::api::types::events::ChartEvent::UpdateAxes { viewport, axes } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <Vec<crate::api::types::chart::ChartAxis>>::sse_encode(axes, serializer);
+            }
+            crate::api::types::events::ChartEvent::UpdateRegions { key, regions } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(key, serializer);
+                <Vec<crate::api::types::chart::ChartRegion>>::sse_encode(regions, serializer);
+            }
+            crate::api::types::events::ChartEvent::AppendPoints {
+                viewport,
+                key,
+                data_type,
+                points,
+                base_seq,
+                seq,
+            } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::chart::DataType>::sse_encode(data_type, serializer);
+                <crate::api::types::chart::ChartPayload>::sse_encode(points, serializer);
+                <u64>::sse_encode(base_seq, serializer);
+                <u64>::sse_encode(seq, serializer);
+            }
+            crate::api::types::events::ChartEvent::ReplaceRange {
+                viewport,
+                key,
+                data_type,
+                start,
+                end,
+                points,
+                base_seq,
+                seq,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(viewport, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::chart::DataType>::sse_encode(data_type, serializer);
+                <usize>::sse_encode(start, serializer);
+                <usize>::sse_encode(end, serializer);
+                <crate::api::types::chart::ChartPayload>::sse_encode(points, serializer);
+                <u64>::sse_encode(base_seq, serializer);
+                <u64>::sse_encode(seq, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Box<dyn CachedChartStorage + Send + Sync> {
+impl SseEncode for crate::api::types::memory::ChartMemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn CachedChartStorage + Send + Sync>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <String>::sse_encode(self.key, serializer);
+        <crate::api::types::chart::DataType>::sse_encode(self.data_type, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
     }
 }
 
-impl SseEncode for Box<dyn Communicator + Send + Sync> {
+impl SseEncode for crate::api::types::chart::ChartPayload {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                Box<dyn Communicator + Send + Sync>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        match self {
+            crate::api::types::chart::ChartPayload::Points { xs, ys } => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<f32>>::sse_encode(xs, serializer);
+                <Vec<f32>>::sse_encode(ys, serializer);
+            }
+            crate::api::types::chart::ChartPayload::Uniform { x0, dx, ys } => {
+                <i32>::sse_encode(1, serializer);
+                <f32>::sse_encode(x0, serializer);
+                <f32>::sse_encode(dx, serializer);
+                <Vec<f32>>::sse_encode(ys, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Chart {
+impl SseEncode for crate::api::types::chart::ChartRegion {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <f32>::sse_encode(self.start_x, serializer);
+        <f32>::sse_encode(self.end_x, serializer);
+        <String>::sse_encode(self.label, serializer);
     }
 }
 
-impl SseEncode for ChartWIthKey {
+impl SseEncode for crate::api::types::chart::CommunicatorChart {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.key, serializer);
+        <crate::api::types::chart::DataType>::sse_encode(self.data_type, serializer);
+        <crate::api::types::chart::ChartPayload>::sse_encode(self.chart, serializer);
     }
 }
 
-impl SseEncode for File {
+impl SseEncode for crate::api::types::config::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <usize>::sse_encode(self.frame_size, serializer);
     }
 }
 
-impl SseEncode for KvAudioStorage {
+impl SseEncode for crate::api::types::decode::DamagedRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <u64>::sse_encode(self.start_frame, serializer);
+        <u64>::sse_encode(self.end_frame, serializer);
+        <f64>::sse_encode(self.start, serializer);
+        <f64>::sse_encode(self.end, serializer);
+        <String>::sse_encode(self.reason, serializer);
     }
 }
 
-impl SseEncode for KvCachedChartStorage {
+impl SseEncode for crate::api::types::chart::DataType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::chart::DataType::Audio => 0,
+                crate::api::types::chart::DataType::Spectrum => 1,
+                crate::api::types::chart::DataType::Energy => 2,
+                crate::api::types::chart::DataType::ZeroCrossingRate => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<AtomicBool>>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
-    }
-}
-
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<Point>>>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
-    }
-}
-
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<f32>>>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
-    }
-}
-
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<Vec<u8>>>>
-{
+impl SseEncode for crate::api::types::events::DecodeEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        match self {
+            crate::api::types::events::DecodeEvent::Progress { key, progress } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::decode::DecodeProgress>::sse_encode(progress, serializer);
+            }
+            crate::api::types::events::DecodeEvent::Finished { key, progress } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::decode::DecodeProgress>::sse_encode(progress, serializer);
+            }
+            crate::api::types::events::DecodeEvent::Cancelled { key, progress } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(key, serializer);
+                <crate::api::types::decode::DecodeProgress>::sse_encode(progress, serializer);
+            }
+            crate::api::types::events::DecodeEvent::Failed { key, message } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(key, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>> {
+impl SseEncode for crate::api::types::decode::DecodeOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <Option<u32>>::sse_encode(self.track_id, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>
-{
+impl SseEncode for crate::api::types::decode::DecodeProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <u64>::sse_encode(self.packets, serializer);
+        <u64>::sse_encode(self.bytes_read, serializer);
+        <Option<u64>>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.decoded_frames, serializer);
+        <f64>::sse_encode(self.decoded_duration, serializer);
+        <Option<f64>>::sse_encode(self.estimated_duration, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioProcessorEngine>>
-{
+impl SseEncode for crate::api::types::decode::DecodeReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::types::decode::DamagedRange>>::sse_encode(self.damaged, serializer);
+        <u64>::sse_encode(self.skipped_packets, serializer);
+        <u32>::sse_encode(self.decoder_resets, serializer);
+        <bool>::sse_encode(self.truncated, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn AudioDecoder + Send + Sync>,
-        >,
-    >
-{
+impl SseEncode for crate::api::util::format_detector::DetectedFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.container, serializer);
+        <String>::sse_encode(self.extension, serializer);
+        <Option<String>>::sse_encode(self.codec, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn AudioStorage + Send + Sync>,
-        >,
-    >
-{
+impl SseEncode for crate::api::types::chart::DownSampleMethod {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::chart::DownSampleMethod::MinMax => 0,
+                crate::api::types::chart::DownSampleMethod::EqualStep => 1,
+                crate::api::types::chart::DownSampleMethod::Lttb => 2,
+                crate::api::types::chart::DownSampleMethod::M4 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn CachedChartStorage + Send + Sync>,
-        >,
-    >
-{
+impl SseEncode for crate::api::types::decode::Endianness {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::decode::Endianness::Little => 0,
+                crate::api::types::decode::Endianness::Big => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-            Box<dyn Communicator + Send + Sync>,
-        >,
-    >
-{
+impl SseEncode for crate::api::types::encode::ExportContainer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::encode::ExportContainer::Wav => 0,
+                crate::api::types::encode::ExportContainer::Flac => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>> {
+impl SseEncode for crate::api::types::encode::ExportOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <crate::api::types::encode::ExportContainer>::sse_encode(self.container, serializer);
+        <crate::api::types::encode::ExportSampleFormat>::sse_encode(self.sample_format, serializer);
+        <bool>::sse_encode(self.dither, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>
-{
+impl SseEncode for crate::api::types::encode::ExportSampleFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::encode::ExportSampleFormat::I16 => 0,
+                crate::api::types::encode::ExportSampleFormat::I24 => 1,
+                crate::api::types::encode::ExportSampleFormat::F32 => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>> {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>
-{
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>
-{
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::types::events::ChartEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::types::memory::AudioMemoryUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::memory::AudioMemoryUsage>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::types::error::AppError {
+impl SseEncode for Vec<crate::api::types::audio::AudioTag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::types::error::AppError::Io(field0) => {
-                <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::Format(field0) => {
-                <i32>::sse_encode(1, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::Decode(field0) => {
-                <i32>::sse_encode(2, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::Storage(field0) => {
-                <i32>::sse_encode(3, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::Cache(field0) => {
-                <i32>::sse_encode(4, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::NotFound(field0) => {
-                <i32>::sse_encode(5, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::Generic(field0) => {
-                <i32>::sse_encode(6, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::ProcessingError(field0) => {
-                <i32>::sse_encode(7, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::types::error::AppError::InvalidChartName(field0) => {
-                <i32>::sse_encode(8, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::audio::AudioTag>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::types::audio::AudioInfo {
+impl SseEncode for Vec<crate::api::types::chart::ChartAxis> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.sample_rate, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::chart::ChartAxis>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::types::events::ChartEvent {
+impl SseEncode for Vec<crate::api::types::memory::ChartMemoryUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::types::events::ChartEvent::AddChart { chart } => {
-                <i32>::sse_encode(0, serializer);
-                <crate::api::types::chart::CommunicatorChart>::sse_encode(chart, serializer);
-            }
-            crate::api::types::events::ChartEvent::RemoveChart { key, data_type } => {
-                <i32>::sse_encode(1, serializer);
-                <String>::sse_encode(key, serializer);
-                <crate::api::types::chart::DataType>::sse_encode(data_type, serializer);
-            }
-            crate::api::types::events::ChartEvent::UpdateAllCharts { charts } => {
-                <i32>::sse_encode(2, serializer);
-                <Vec<crate::api::types::chart::CommunicatorChart>>::sse_encode(charts, serializer);
-            }
-            crate::api::types::events::ChartEvent::RemoveAllCharts => {
-                <i32>::sse_encode(3, serializer);
-            }
-            crate::api::types::events::ChartEvent::UpdateMaxIndex { max_index } => {
-                <i32>::sse_encode(4, serializer);
-                <f32>::sse_encode(max_index, serializer);
-            }
-            crate::api::types::events::ChartEvent::UpdateYRange { min_y, max_y } => {
-                <i32>::sse_encode(5, serializer);
-                <f32>::sse_encode(min_y, serializer);
-                <f32>::sse_encode(max_y, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::memory::ChartMemoryUsage>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::types::chart::CommunicatorChart {
+impl SseEncode for Vec<crate::api::types::chart::ChartRegion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.key, serializer);
-        <crate::api::types::chart::DataType>::sse_encode(self.data_type, serializer);
-        <Vec<crate::api::types::chart::Point>>::sse_encode(self.chart, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::chart::ChartRegion>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::types::config::Config {
+impl SseEncode for Vec<crate::api::types::chart::CommunicatorChart> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.frame_size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::chart::CommunicatorChart>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::types::chart::DataType {
+impl SseEncode for Vec<crate::api::types::decode::DamagedRange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::types::chart::DataType::Audio => 0,
-                crate::api::types::chart::DataType::Spectrum => 1,
-                crate::api::types::chart::DataType::Energy => 2,
-                crate::api::types::chart::DataType::ZeroCrossingRate => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::decode::DamagedRange>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::transform::energy::EnergyCalculator {
+impl SseEncode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseEncode for crate::api::sampling::equal_step::EqualStep {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Vec<crate::api::types::audio::TrackInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::audio::TrackInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::transform::fft::FftTransform {
+impl SseEncode for crate::api::types::memory::MemoryUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::types::memory::AudioMemoryUsage>>::sse_encode(self.audio, serializer);
+        <Vec<crate::api::types::memory::ChartMemoryUsage>>::sse_encode(self.charts, serializer);
+        <u64>::sse_encode(self.audio_bytes, serializer);
+        <u64>::sse_encode(self.chart_bytes, serializer);
+        <Option<u64>>::sse_encode(self.audio_budget, serializer);
+        <Option<u64>>::sse_encode(self.chart_budget, serializer);
+    }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<ChartWIthKey> {
+impl SseEncode for Option<crate::api::types::audio::ChannelLayout> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <ChartWIthKey>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::types::audio::ChannelLayout>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::types::chart::CommunicatorChart> {
+impl SseEncode for Option<crate::api::types::chart::DownSampleMethod> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::types::chart::CommunicatorChart>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::types::chart::DownSampleMethod>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::types::chart::Point> {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::types::chart::Point>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::sampling::minmax::Minmax {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
-
-impl SseEncode for Option<String> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for crate::api::types::chart::Point {
+impl SseEncode for crate::api::types::decode::RawPcmFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f32>::sse_encode(self.x, serializer);
-        <f32>::sse_encode(self.y, serializer);
+        <crate::api::types::decode::RawSampleFormat>::sse_encode(self.sample_format, serializer);
+        <crate::api::types::decode::Endianness>::sse_encode(self.endianness, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u64>::sse_encode(self.header_skip, serializer);
     }
 }
 
-impl SseEncode for (f32, f32) {
+impl SseEncode for crate::api::types::decode::RawSampleFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f32>::sse_encode(self.0, serializer);
-        <f32>::sse_encode(self.1, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::decode::RawSampleFormat::U8 => 0,
+                crate::api::types::decode::RawSampleFormat::S16 => 1,
+                crate::api::types::decode::RawSampleFormat::S24 => 2,
+                crate::api::types::decode::RawSampleFormat::S32 => 3,
+                crate::api::types::decode::RawSampleFormat::F32 => 4,
+                crate::api::types::decode::RawSampleFormat::MuLaw => 5,
+                crate::api::types::decode::RawSampleFormat::ALaw => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::util::format_getter::SimpleFormatGetter {
+impl SseEncode for crate::api::types::samples::SampleStorage {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::samples::SampleStorage::F32 => 0,
+                crate::api::types::samples::SampleStorage::I16 => 1,
+                crate::api::types::samples::SampleStorage::F16 => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
 
-impl SseEncode for crate::api::communicator::stream_sink_communicator::StreamCommunicator {
+impl SseEncode for crate::api::types::audio::TrackInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.codec, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <Option<crate::api::types::audio::ChannelLayout>>::sse_encode(
+            self.channel_layout,
+            serializer,
+        );
+        <Option<u64>>::sse_encode(self.total_frames, serializer);
+    }
 }
 
-impl SseEncode for crate::api::decoder::symphonia_decoder::SymphoniaDecoder {
+impl SseEncode for crate::api::types::chart::TruePeak {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.x, serializer);
+        <f32>::sse_encode(self.value, serializer);
+    }
 }
 
 impl SseEncode for u32 {
@@ -5107,6 +6490,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5129,11 +6519,6 @@ impl SseEncode for usize {
     }
 }
 
-impl SseEncode for crate::api::transform::zero_crossing_rate::ZeroCrossingRateCalculator {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
-
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5150,18 +6535,11 @@ mod io {
 
     use super::*;
     use crate::api::core::engine::*;
-    use crate::api::storage::kv_audio_storage::*;
-    use crate::api::storage::kv_cached_chart_storage::*;
     use crate::api::traits::audio_decoder::AudioDecoder;
     use crate::api::traits::audio_storage::AudioStorage;
     use crate::api::traits::cached_chart_storage::CachedChartStorage;
     use crate::api::traits::communicator::Communicator;
-    use crate::api::traits::down_sample::DownSample;
-    use crate::api::traits::transform::SignalTransform;
-    use crate::api::types::audio::*;
-    use crate::api::types::chart::*;
-    use crate::api::types::file::*;
-    use crate::api::util::format_getter::FormatGetter;
+    use crate::api::types::decode::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
@@ -5173,90 +6551,6 @@ mod io {
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < AtomicBool >>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < AtomicBool >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < Point > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < Point > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < f32 > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < f32 > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>::decrement_strong_count(ptr as _);
-    }
-
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
         ptr: *const std::ffi::c_void,
@@ -5360,73 +6654,17 @@ mod io {
     }
 
     #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>::decrement_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    pub extern "C" fn frbgen_vad_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
         ptr: *const std::ffi::c_void,
     ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>::increment_strong_count(ptr as _);
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>::increment_strong_count(ptr as _);
     }
 
     #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    pub extern "C" fn frbgen_vad_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
         ptr: *const std::ffi::c_void,
     ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>::decrement_strong_count(ptr as _);
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>::decrement_strong_count(ptr as _);
     }
 }
 #[cfg(not(target_family = "wasm"))]
@@ -5442,18 +6680,11 @@ mod web {
 
     use super::*;
     use crate::api::core::engine::*;
-    use crate::api::storage::kv_audio_storage::*;
-    use crate::api::storage::kv_cached_chart_storage::*;
     use crate::api::traits::audio_decoder::AudioDecoder;
     use crate::api::traits::audio_storage::AudioStorage;
     use crate::api::traits::cached_chart_storage::CachedChartStorage;
     use crate::api::traits::communicator::Communicator;
-    use crate::api::traits::down_sample::DownSample;
-    use crate::api::traits::transform::SignalTransform;
-    use crate::api::types::audio::*;
-    use crate::api::types::chart::*;
-    use crate::api::types::file::*;
-    use crate::api::util::format_getter::FormatGetter;
+    use crate::api::types::decode::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
@@ -5467,90 +6698,6 @@ mod web {
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
 
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < AtomicBool >>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcAtomicBool(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < AtomicBool >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < Point > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecPoint(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < Point > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < f32 > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecf32(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < f32 > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcVecu8(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc < Vec < u8 > >>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudio(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Audio>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioData(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioData>>::decrement_strong_count(ptr as _);
-    }
-
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessorEngine(
         ptr: *const std::ffi::c_void,
@@ -5654,73 +6801,17 @@ mod web {
     }
 
     #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChart(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Chart>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChartWIthKey(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ChartWIthKey>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFile(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<File>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvAudioStorage(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvAudioStorage>>::decrement_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
         ptr: *const std::ffi::c_void,
     ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>::increment_strong_count(ptr as _);
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>::increment_strong_count(ptr as _);
     }
 
     #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKvCachedChartStorage(
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
         ptr: *const std::ffi::c_void,
     ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<KvCachedChartStorage>>::decrement_strong_count(ptr as _);
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>::decrement_strong_count(ptr as _);
     }
 }
 #[cfg(target_family = "wasm")]